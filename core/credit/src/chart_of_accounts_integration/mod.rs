@@ -18,11 +18,14 @@ pub struct ChartOfAccountsIntegrationConfig {
     pub chart_of_account_facility_omnibus_parent_code: AccountCode,
     pub chart_of_account_collateral_omnibus_parent_code: AccountCode,
     pub chart_of_account_in_liquidation_omnibus_parent_code: AccountCode,
+    pub chart_of_account_disbursal_settlement_omnibus_parent_code: AccountCode,
+    pub chart_of_account_participation_principal_omnibus_parent_code: AccountCode,
     pub chart_of_account_facility_parent_code: AccountCode,
     pub chart_of_account_collateral_parent_code: AccountCode,
     pub chart_of_account_in_liquidation_parent_code: AccountCode,
     pub chart_of_account_interest_income_parent_code: AccountCode,
     pub chart_of_account_fee_income_parent_code: AccountCode,
+    pub chart_of_account_participation_payable_parent_code: AccountCode,
 
     pub chart_of_account_short_term_individual_disbursed_receivable_parent_code: AccountCode,
     pub chart_of_account_short_term_government_entity_disbursed_receivable_parent_code: AccountCode,
@@ -145,6 +148,13 @@ where
         let in_liquidation_omnibus_parent_account_set_id = chart.account_set_id_from_code(
             &config.chart_of_account_in_liquidation_omnibus_parent_code,
         )?;
+        let disbursal_settlement_omnibus_parent_account_set_id = chart.account_set_id_from_code(
+            &config.chart_of_account_disbursal_settlement_omnibus_parent_code,
+        )?;
+        let participation_principal_omnibus_parent_account_set_id = chart
+            .account_set_id_from_code(
+                &config.chart_of_account_participation_principal_omnibus_parent_code,
+            )?;
         let facility_parent_account_set_id =
             chart.account_set_id_from_code(&config.chart_of_account_facility_parent_code)?;
         let collateral_parent_account_set_id =
@@ -155,6 +165,8 @@ where
             chart.account_set_id_from_code(&config.chart_of_account_interest_income_parent_code)?;
         let fee_income_parent_account_set_id =
             chart.account_set_id_from_code(&config.chart_of_account_fee_income_parent_code)?;
+        let participation_payable_parent_account_set_id = chart
+            .account_set_id_from_code(&config.chart_of_account_participation_payable_parent_code)?;
 
         let short_term_individual_disbursed_receivable_parent_account_set_id = chart
             .account_set_id_from_code(
@@ -335,11 +347,14 @@ where
             facility_omnibus_parent_account_set_id,
             collateral_omnibus_parent_account_set_id,
             in_liquidation_omnibus_parent_account_set_id,
+            disbursal_settlement_omnibus_parent_account_set_id,
+            participation_principal_omnibus_parent_account_set_id,
             facility_parent_account_set_id,
             collateral_parent_account_set_id,
             in_liquidation_parent_account_set_id,
             interest_income_parent_account_set_id,
             fee_income_parent_account_set_id,
+            participation_payable_parent_account_set_id,
 
             short_term_disbursed_integration_meta: ShortTermDisbursedIntegrationMeta {
                 short_term_individual_disbursed_receivable_parent_account_set_id,
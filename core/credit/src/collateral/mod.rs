@@ -120,6 +120,30 @@ where
         Ok(res)
     }
 
+    /// Releases all posted collateral, regardless of whether it is tracked
+    /// manually or via a custodian wallet.
+    #[instrument(name = "collateral.remove_collateral_in_op", skip(db, self), err)]
+    pub(super) async fn remove_collateral_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        collateral_id: CollateralId,
+        effective: chrono::NaiveDate,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<Option<CollateralUpdate>, CollateralError> {
+        let mut collateral = self.repo.find_by_id(collateral_id).await?;
+
+        let res = if let es_entity::Idempotent::Executed(data) = collateral
+            .record_collateral_update_via_manual_input(Satoshis::ZERO, effective, audit_info)
+        {
+            self.repo.update_in_op(db, &mut collateral).await?;
+            Some(data)
+        } else {
+            None
+        };
+
+        Ok(res)
+    }
+
     #[instrument(
         name = "collateral.record_collateral_update_via_custodian_sync",
         fields(credit_facility = %credit_facility.id, updated_collateral = %updated_collateral, effective = %effective),
@@ -132,6 +156,10 @@ where
         updated_collateral: core_money::Satoshis,
         effective: chrono::NaiveDate,
     ) -> Result<(), CollateralError> {
+        if credit_facility.is_cancelled() {
+            return Ok(());
+        }
+
         let mut collateral = self.repo.find_by_id(credit_facility.collateral_id).await?;
 
         if let es_entity::Idempotent::Executed(data) =
@@ -2,7 +2,7 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::primitives::CVLPct;
+use crate::{primitives::CVLPct, underwriting::UnderwritingConfig};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -11,6 +11,10 @@ pub struct CreditConfig {
     pub upgrade_buffer_cvl_pct: CVLPct,
     #[serde(default = "default_customer_active_check_enabled")]
     pub customer_active_check_enabled: bool,
+    #[serde(default = "default_interest_recomputation_enabled")]
+    pub interest_recomputation_enabled: bool,
+    #[serde(default)]
+    pub underwriting: UnderwritingConfig,
 }
 
 impl Default for CreditConfig {
@@ -18,6 +22,8 @@ impl Default for CreditConfig {
         CreditConfig {
             upgrade_buffer_cvl_pct: default_upgrade_buffer_cvl_pct(),
             customer_active_check_enabled: default_customer_active_check_enabled(),
+            interest_recomputation_enabled: default_interest_recomputation_enabled(),
+            underwriting: UnderwritingConfig::default(),
         }
     }
 }
@@ -29,3 +35,7 @@ fn default_upgrade_buffer_cvl_pct() -> CVLPct {
 fn default_customer_active_check_enabled() -> bool {
     true
 }
+
+fn default_interest_recomputation_enabled() -> bool {
+    false
+}
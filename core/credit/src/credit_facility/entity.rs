@@ -14,6 +14,8 @@ use crate::{
     obligation::{NewObligation, ObligationsAmounts},
     primitives::*,
     terms::{InterestPeriod, TermValues},
+    terms_template::TermsTemplateVersionRef,
+    underwriting::UnderwritingDecision,
 };
 
 use super::error::CreditFacilityError;
@@ -30,6 +32,10 @@ pub enum CreditFacilityEvent {
         collateral_id: CollateralId,
         ledger_tx_id: LedgerTxId,
         terms: TermValues,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        terms_template: Option<TermsTemplateVersionRef>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        underwriting: Option<UnderwritingDecision>,
         amount: UsdCents,
         account_ids: CreditFacilityAccountIds,
         disbursal_credit_account_id: CalaAccountId,
@@ -70,9 +76,18 @@ pub enum CreditFacilityEvent {
         collateralization_ratio: Option<Decimal>,
         audit_info: AuditInfo,
     },
+    PastDueSinceUpdated {
+        past_due_since: Option<chrono::NaiveDate>,
+        principal_outstanding: UsdCents,
+        audit_info: AuditInfo,
+    },
     Completed {
         audit_info: AuditInfo,
     },
+    Cancelled {
+        reason: String,
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -147,6 +162,7 @@ impl From<(InterestAccrualCycleData, CreditFacilityAccountIds)>
                 effective,
                 tx_ref,
                 tx_id,
+                capitalized,
             },
             credit_facility_account_ids,
         ) = data;
@@ -155,6 +171,7 @@ impl From<(InterestAccrualCycleData, CreditFacilityAccountIds)>
             effective,
             tx_ref,
             tx_id,
+            capitalized,
             credit_facility_account_ids,
         }
     }
@@ -169,6 +186,10 @@ pub struct CreditFacility {
     pub collateral_id: CollateralId,
     pub amount: UsdCents,
     pub terms: TermValues,
+    #[builder(default)]
+    pub terms_template: Option<TermsTemplateVersionRef>,
+    #[builder(default)]
+    pub underwriting: Option<UnderwritingDecision>,
     pub account_ids: CreditFacilityAccountIds,
     pub disbursal_credit_account_id: CalaAccountId,
     pub public_id: PublicId,
@@ -210,6 +231,14 @@ impl CreditFacility {
             .expect("entity_first_persisted_at not found")
     }
 
+    pub fn terms_template_id(&self) -> Option<TermsTemplateId> {
+        self.terms_template.map(|t| t.terms_template_id)
+    }
+
+    pub fn terms_template_version(&self) -> Option<i32> {
+        self.terms_template.map(|t| t.version as i32)
+    }
+
     pub fn structuring_fee(&self) -> UsdCents {
         self.terms.one_time_fee_rate.apply(self.amount)
     }
@@ -258,6 +287,8 @@ impl CreditFacility {
     pub fn status(&self) -> CreditFacilityStatus {
         if self.is_completed() {
             CreditFacilityStatus::Closed
+        } else if self.is_cancelled() {
+            CreditFacilityStatus::Cancelled
         } else if self.is_after_maturity_date() {
             CreditFacilityStatus::Matured
         } else if self.is_activated() {
@@ -277,6 +308,7 @@ impl CreditFacility {
         idempotency_guard!(
             self.events.iter_all(),
             CreditFacilityEvent::ApprovalProcessConcluded { .. }
+                | CreditFacilityEvent::Cancelled { .. }
         );
         self.events
             .push(CreditFacilityEvent::ApprovalProcessConcluded {
@@ -294,7 +326,7 @@ impl CreditFacility {
         balances: CreditFacilityBalanceSummary,
         audit_info: AuditInfo,
     ) -> Result<Idempotent<(CreditFacilityActivation, InterestPeriod)>, CreditFacilityError> {
-        if self.is_activated() {
+        if self.is_activated() || self.is_cancelled() {
             return Ok(Idempotent::Ignored);
         }
 
@@ -427,6 +459,7 @@ impl CreditFacility {
             .accrual_cycle_data()
             .ok_or(CreditFacilityError::InterestAccrualNotCompletedYet)?;
 
+        let account_ids = self.account_ids;
         let (idx, new_obligation) = {
             let accrual = self
                 .interest_accrual_cycle_in_progress_mut()
@@ -434,7 +467,11 @@ impl CreditFacility {
 
             (
                 accrual.idx,
-                match accrual.record_accrual_cycle(accrual_cycle_data.clone(), audit_info.clone()) {
+                match accrual.record_accrual_cycle(
+                    accrual_cycle_data.clone(),
+                    account_ids,
+                    audit_info.clone(),
+                ) {
                     Idempotent::Executed(new_obligation) => new_obligation,
                     Idempotent::Ignored => {
                         return Ok(Idempotent::Ignored);
@@ -479,6 +516,28 @@ impl CreditFacility {
         }
     }
 
+    pub(crate) fn interest_accrual_cycle_ids(&self) -> Vec<InterestAccrualCycleId> {
+        self.events
+            .iter_all()
+            .filter_map(|event| match event {
+                CreditFacilityEvent::InterestAccrualCycleStarted {
+                    interest_accrual_id,
+                    ..
+                } => Some(*interest_accrual_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub(crate) fn interest_accrual_cycle_mut(
+        &mut self,
+        id: InterestAccrualCycleId,
+    ) -> &mut InterestAccrualCycle {
+        self.interest_accruals
+            .get_persisted_mut(&id)
+            .expect("Interest accrual not found")
+    }
+
     pub fn interest_accrual_cycle_in_progress_mut(&mut self) -> Option<&mut InterestAccrualCycle> {
         if let Some(id) = self
             .events
@@ -505,7 +564,7 @@ impl CreditFacility {
     }
 
     pub fn last_collateralization_state(&self) -> CollateralizationState {
-        if self.is_completed() {
+        if self.is_completed() || self.is_cancelled() {
             return CollateralizationState::NoCollateral;
         }
 
@@ -532,6 +591,54 @@ impl CreditFacility {
         })
     }
 
+    /// Due date of the oldest unpaid obligation that has fallen due.
+    pub fn past_due_since(&self) -> Option<chrono::NaiveDate> {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                CreditFacilityEvent::PastDueSinceUpdated { past_due_since, .. } => {
+                    Some(*past_due_since)
+                }
+                _ => None,
+            })
+            .flatten()
+    }
+
+    pub fn days_past_due(&self) -> u32 {
+        self.days_past_due_as_of(crate::time::now().date_naive())
+    }
+
+    pub fn days_past_due_as_of(&self, as_of: chrono::NaiveDate) -> u32 {
+        self.past_due_since()
+            .map(|past_due_since| (as_of - past_due_since).num_days())
+            .and_then(|days| u32::try_from(days).ok())
+            .unwrap_or(0)
+    }
+
+    pub fn aging_bucket(&self) -> AgingBucket {
+        AgingBucket::from_days_past_due(self.days_past_due())
+    }
+
+    pub(crate) fn update_past_due_since(
+        &mut self,
+        past_due_since: Option<chrono::NaiveDate>,
+        balances: &CreditFacilityBalanceSummary,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<()> {
+        if self.past_due_since() == past_due_since {
+            return Idempotent::Ignored;
+        }
+
+        self.events.push(CreditFacilityEvent::PastDueSinceUpdated {
+            past_due_since,
+            principal_outstanding: balances.principal_outstanding(),
+            audit_info: audit_info.clone(),
+        });
+
+        Idempotent::Executed(())
+    }
+
     fn is_fully_collateralized(&self) -> bool {
         self.last_collateralization_state() == CollateralizationState::FullyCollateralized
     }
@@ -565,7 +672,9 @@ impl CreditFacility {
                     false,
                 )
             }
-            CreditFacilityStatus::Closed => Some(CollateralizationState::NoCollateral),
+            CreditFacilityStatus::Closed | CreditFacilityStatus::Cancelled => {
+                Some(CollateralizationState::NoCollateral)
+            }
         };
 
         if let Some(calculated_collateralization) = collateralization_update {
@@ -620,6 +729,45 @@ impl CreditFacility {
         Ok(Idempotent::Executed(res))
     }
 
+    pub fn is_cancelled(&self) -> bool {
+        self.events
+            .iter_all()
+            .rev()
+            .any(|event| matches!(event, CreditFacilityEvent::Cancelled { .. }))
+    }
+
+    pub fn cancellation_reason(&self) -> Option<&str> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            CreditFacilityEvent::Cancelled { reason, .. } => Some(reason.as_str()),
+            _ => None,
+        })
+    }
+
+    pub(crate) fn cancel(
+        &mut self,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, CreditFacilityError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            CreditFacilityEvent::Cancelled { .. }
+        );
+        if self.is_activated() || self.is_completed() {
+            return Err(CreditFacilityError::AlreadyActivated);
+        }
+        let reason = reason.trim();
+        if reason.is_empty() {
+            return Err(CreditFacilityError::CancellationReasonMissing);
+        }
+
+        self.events.push(CreditFacilityEvent::Cancelled {
+            reason: reason.to_string(),
+            audit_info,
+        });
+
+        Ok(Idempotent::Executed(()))
+    }
+
     fn update_collateralization_ratio(
         &mut self,
         balance: &CreditFacilityBalanceSummary,
@@ -655,6 +803,8 @@ impl TryFromEvents<CreditFacilityEvent> for CreditFacility {
                     account_ids,
                     disbursal_credit_account_id,
                     terms: t,
+                    terms_template,
+                    underwriting,
                     approval_process_id,
                     public_id,
                     ..
@@ -666,6 +816,8 @@ impl TryFromEvents<CreditFacilityEvent> for CreditFacility {
                         .customer_id(*customer_id)
                         .collateral_id(*collateral_id)
                         .terms(*t)
+                        .terms_template(*terms_template)
+                        .underwriting(underwriting.clone())
                         .account_ids(*account_ids)
                         .disbursal_credit_account_id(*disbursal_credit_account_id)
                         .approval_process_id(*approval_process_id)
@@ -683,7 +835,9 @@ impl TryFromEvents<CreditFacilityEvent> for CreditFacility {
                 CreditFacilityEvent::InterestAccrualCycleConcluded { .. } => (),
                 CreditFacilityEvent::CollateralizationStateChanged { .. } => (),
                 CreditFacilityEvent::CollateralizationRatioChanged { .. } => (),
+                CreditFacilityEvent::PastDueSinceUpdated { .. } => (),
                 CreditFacilityEvent::Completed { .. } => (),
+                CreditFacilityEvent::Cancelled { .. } => (),
             }
         }
         builder.events(events).build()
@@ -703,6 +857,10 @@ pub struct NewCreditFacility {
     #[builder(setter(into))]
     pub(super) collateral_id: CollateralId,
     terms: TermValues,
+    #[builder(default)]
+    terms_template: Option<TermsTemplateVersionRef>,
+    #[builder(setter(strip_option), default)]
+    underwriting: Option<UnderwritingDecision>,
     amount: UsdCents,
     #[builder(setter(skip), default)]
     pub(super) status: CreditFacilityStatus,
@@ -720,6 +878,14 @@ impl NewCreditFacility {
     pub fn builder() -> NewCreditFacilityBuilder {
         NewCreditFacilityBuilder::default()
     }
+
+    pub(super) fn terms_template_id(&self) -> Option<TermsTemplateId> {
+        self.terms_template.map(|t| t.terms_template_id)
+    }
+
+    pub(super) fn terms_template_version(&self) -> Option<i32> {
+        self.terms_template.map(|t| t.version as i32)
+    }
 }
 
 impl IntoEvents<CreditFacilityEvent> for NewCreditFacility {
@@ -733,6 +899,8 @@ impl IntoEvents<CreditFacilityEvent> for NewCreditFacility {
                 customer_id: self.customer_id,
                 collateral_id: self.collateral_id,
                 terms: self.terms,
+                terms_template: self.terms_template,
+                underwriting: self.underwriting,
                 amount: self.amount,
                 account_ids: self.account_ids,
                 disbursal_credit_account_id: self.disbursal_credit_account_id,
@@ -827,6 +995,8 @@ mod test {
             collateral_id: CollateralId::new(),
             amount: default_facility(),
             terms: default_terms(),
+            terms_template: None,
+            underwriting: None,
             account_ids: CreditFacilityAccountIds::new(),
             disbursal_credit_account_id: CalaAccountId::new(),
             approval_process_id: ApprovalProcessId::new(),
@@ -1012,6 +1182,36 @@ mod test {
         assert_eq!(credit_facility.status(), CreditFacilityStatus::Active);
     }
 
+    #[test]
+    fn days_past_due_counts_from_oldest_due_date() {
+        let mut credit_facility = facility_from(initial_events());
+        let today = Utc::now().date_naive();
+        assert_eq!(credit_facility.days_past_due_as_of(today), 0);
+        assert_eq!(credit_facility.aging_bucket(), AgingBucket::Current);
+
+        let balances = default_balances(credit_facility.amount);
+        let past_due_since = today - chrono::Days::new(45);
+        assert!(
+            credit_facility
+                .update_past_due_since(Some(past_due_since), &balances, &dummy_audit_info())
+                .did_execute()
+        );
+        assert!(
+            credit_facility
+                .update_past_due_since(Some(past_due_since), &balances, &dummy_audit_info())
+                .was_ignored()
+        );
+        assert_eq!(credit_facility.days_past_due_as_of(today), 45);
+        assert_eq!(credit_facility.aging_bucket(), AgingBucket::Days31To60);
+
+        assert!(
+            credit_facility
+                .update_past_due_since(None, &balances, &dummy_audit_info())
+                .did_execute()
+        );
+        assert_eq!(credit_facility.days_past_due_as_of(today), 0);
+    }
+
     #[test]
     fn structuring_fee() {
         let credit_facility = facility_from(initial_events());
@@ -1427,4 +1627,75 @@ mod test {
             ));
         }
     }
+
+    mod cancel {
+        use super::*;
+
+        #[test]
+        fn can_cancel_before_activation() {
+            let mut credit_facility = facility_from(initial_events());
+
+            assert!(
+                credit_facility
+                    .cancel("customer withdrew".to_string(), dummy_audit_info())
+                    .unwrap()
+                    .did_execute()
+            );
+            assert_eq!(credit_facility.status(), CreditFacilityStatus::Cancelled);
+            assert_eq!(
+                credit_facility.cancellation_reason(),
+                Some("customer withdrew")
+            );
+            assert!(
+                credit_facility
+                    .cancel("again".to_string(), dummy_audit_info())
+                    .unwrap()
+                    .was_ignored()
+            );
+            assert!(
+                credit_facility
+                    .approval_process_concluded(true, dummy_audit_info())
+                    .was_ignored()
+            );
+            let mut balances = default_balances(credit_facility.amount);
+            balances.collateral = default_full_collateral();
+            assert!(matches!(
+                credit_facility.activate(Utc::now(), default_price(), balances, dummy_audit_info()),
+                Ok(Idempotent::Ignored)
+            ));
+        }
+
+        #[test]
+        fn errors_if_reason_missing() {
+            let mut credit_facility = facility_from(initial_events());
+
+            assert!(matches!(
+                credit_facility.cancel("  ".to_string(), dummy_audit_info()),
+                Err(CreditFacilityError::CancellationReasonMissing)
+            ));
+        }
+
+        #[test]
+        fn errors_if_already_activated() {
+            let mut events = initial_events();
+            events.extend([
+                CreditFacilityEvent::ApprovalProcessConcluded {
+                    approval_process_id: ApprovalProcessId::new(),
+                    approved: true,
+                    audit_info: dummy_audit_info(),
+                },
+                CreditFacilityEvent::Activated {
+                    ledger_tx_id: LedgerTxId::new(),
+                    activated_at: Utc::now(),
+                    audit_info: dummy_audit_info(),
+                },
+            ]);
+            let mut credit_facility = facility_from(events);
+
+            assert!(matches!(
+                credit_facility.cancel("too late".to_string(), dummy_audit_info()),
+                Err(CreditFacilityError::AlreadyActivated)
+            ));
+        }
+    }
 }
@@ -40,6 +40,12 @@ pub enum CreditFacilityError {
     FacilityLedgerBalanceMismatch,
     #[error("CreditFacilityError - OutstandingAmount")]
     OutstandingAmount,
+    #[error("CreditFacilityError - AlreadyActivated")]
+    AlreadyActivated,
+    #[error("CreditFacilityError - Cancelled")]
+    Cancelled,
+    #[error("CreditFacilityError - CancellationReasonMissing")]
+    CancellationReasonMissing,
     #[error("CreditFacilityError - InterestAccrualCycleWithInvalidFutureStartDate")]
     InterestAccrualCycleWithInvalidFutureStartDate,
     #[error(
@@ -15,7 +15,7 @@ use crate::{
     interest_accrual_cycle::NewInterestAccrualCycleData,
     ledger::{
         CreditFacilityActivation, CreditFacilityInterestAccrual,
-        CreditFacilityInterestAccrualCycle, CreditLedger,
+        CreditFacilityInterestAccrualAdjustment, CreditFacilityInterestAccrualCycle, CreditLedger,
     },
     obligation::Obligations,
     primitives::*,
@@ -82,6 +82,12 @@ pub(super) enum CompletionOutcome {
     Completed((CreditFacility, crate::CreditFacilityCompletion)),
 }
 
+#[allow(clippy::large_enum_variant)]
+pub(super) enum CancellationOutcome {
+    Ignored(CreditFacility),
+    Cancelled(CreditFacility),
+}
+
 #[derive(Clone)]
 pub(super) struct ConfirmedAccrual {
     pub(super) accrual: CreditFacilityInterestAccrual,
@@ -288,6 +294,30 @@ where
         Ok(CompletionOutcome::Completed((credit_facility, completion)))
     }
 
+    pub(super) async fn cancel_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: CreditFacilityId,
+        reason: String,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<CancellationOutcome, CreditFacilityError> {
+        let mut credit_facility = self.repo.find_by_id(id).await?;
+
+        if credit_facility
+            .cancel(reason, audit_info.clone())?
+            .was_ignored()
+        {
+            return Ok(CancellationOutcome::Ignored(credit_facility));
+        }
+
+        self.repo.update_in_op(db, &mut credit_facility).await?;
+        self.governance
+            .cancel_process_in_op(db, credit_facility.approval_process_id)
+            .await?;
+
+        Ok(CancellationOutcome::Cancelled(credit_facility))
+    }
+
     #[instrument(
         name = "credit.facility.complete_interest_cycle_and_maybe_start_new_cycle",
         skip(self, db)
@@ -335,6 +365,67 @@ where
         })
     }
 
+    /// Reverses interest that was accrued on principal which a back-dated repayment
+    /// shows to have already been repaid as of `since`.
+    #[instrument(
+        name = "credit.facility.recompute_interest_accruals_in_op",
+        skip(self, db, audit_info)
+    )]
+    pub(super) async fn recompute_interest_accruals_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: CreditFacilityId,
+        principal_repaid: UsdCents,
+        since: chrono::NaiveDate,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<Vec<CreditFacilityInterestAccrualAdjustment>, CreditFacilityError> {
+        let mut credit_facility = self.repo.find_by_id(id).await?;
+        let account_ids = credit_facility.account_ids;
+
+        let mut adjustments = vec![];
+        for cycle_id in credit_facility.interest_accrual_cycle_ids() {
+            let cycle = credit_facility.interest_accrual_cycle_mut(cycle_id);
+            let mut amount = cycle.overaccrued_interest(principal_repaid, since);
+            if amount.is_zero() {
+                continue;
+            }
+
+            let tx_id = LedgerTxId::new();
+            let mut posted_receivable_account_id = None;
+            if let Some(obligation_id) = cycle.posted_obligation_id().flatten() {
+                match self
+                    .obligations
+                    .record_adjustment_in_op(db, obligation_id, tx_id, amount, since, audit_info)
+                    .await?
+                {
+                    Some(obligation_adjustment) => {
+                        amount = obligation_adjustment.amount;
+                        posted_receivable_account_id =
+                            Some(obligation_adjustment.receivable_account_id);
+                    }
+                    None => continue,
+                }
+            }
+
+            let adjustment =
+                cycle.record_accrual_adjustment(tx_id, amount, since, audit_info.clone());
+            adjustments.push(CreditFacilityInterestAccrualAdjustment {
+                tx_id: adjustment.tx_id,
+                tx_ref: adjustment.tx_ref,
+                amount: adjustment.amount,
+                effective: adjustment.effective,
+                posted_receivable_account_id,
+                credit_facility_account_ids: account_ids,
+            });
+        }
+
+        if !adjustments.is_empty() {
+            self.repo.update_in_op(db, &mut credit_facility).await?;
+        }
+
+        Ok(adjustments)
+    }
+
     pub async fn find_by_id_without_audit(
         &self,
         id: impl Into<CreditFacilityId> + std::fmt::Debug,
@@ -465,6 +556,43 @@ where
         Ok(credit_facility)
     }
 
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    pub(super) async fn update_past_due_from_obligations(
+        &self,
+        id: CreditFacilityId,
+    ) -> Result<CreditFacility, CreditFacilityError> {
+        let mut db = self.repo.begin_op().await?;
+        let mut credit_facility = self.repo.find_by_id_in_tx(db.tx(), id).await?;
+
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCreditObject::credit_facility(id),
+                CoreCreditAction::CREDIT_FACILITY_UPDATE_DAYS_PAST_DUE,
+            )
+            .await?;
+
+        let past_due_since = self.obligations.past_due_since(id).await?;
+        let balances = self
+            .ledger
+            .get_credit_facility_balance(credit_facility.account_ids)
+            .await?;
+
+        if credit_facility
+            .update_past_due_since(past_due_since, &balances, &audit_info)
+            .did_execute()
+        {
+            self.repo
+                .update_in_op(&mut db, &mut credit_facility)
+                .await?;
+
+            db.commit().await?;
+        }
+        Ok(credit_facility)
+    }
+
     #[instrument(name = "credit.credit_facility.list", skip(self), err)]
     pub async fn list(
         &self,
@@ -486,6 +614,18 @@ where
         self.repo.find_many(filter, sort.into(), query).await
     }
 
+    pub(super) async fn list_by_created_at_without_audit(
+        &self,
+        query: es_entity::PaginatedQueryArgs<CreditFacilitiesByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<CreditFacility, CreditFacilitiesByCreatedAtCursor>,
+        CreditFacilityError,
+    > {
+        self.repo
+            .list_by_created_at(query, es_entity::ListDirection::Ascending)
+            .await
+    }
+
     pub(super) async fn list_by_collateralization_ratio_without_audit(
         &self,
         query: es_entity::PaginatedQueryArgs<CreditFacilitiesByCollateralizationRatioCursor>,
@@ -565,6 +705,89 @@ where
             .await
     }
 
+    /// Facility amount committed to the customer across facilities that are
+    /// neither closed nor cancelled.
+    pub(crate) async fn customer_exposure_without_audit(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<UsdCents, CreditFacilityError> {
+        let mut exposure = UsdCents::ZERO;
+        let mut has_next_page = true;
+        let mut after: Option<CreditFacilitiesByCreatedAtCursor> = None;
+        while has_next_page {
+            let credit_facilities = self
+                .repo
+                .list_for_customer_id_by_created_at(
+                    customer_id,
+                    es_entity::PaginatedQueryArgs::<CreditFacilitiesByCreatedAtCursor> {
+                        first: 100,
+                        after,
+                    },
+                    ListDirection::Ascending,
+                )
+                .await?;
+            (after, has_next_page) = (
+                credit_facilities.end_cursor,
+                credit_facilities.has_next_page,
+            );
+
+            for facility in credit_facilities.entities.iter() {
+                if matches!(
+                    facility.status(),
+                    CreditFacilityStatus::Closed | CreditFacilityStatus::Cancelled
+                ) {
+                    continue;
+                }
+                exposure += facility.amount;
+            }
+        }
+
+        Ok(exposure)
+    }
+
+    #[instrument(
+        name = "credit.credit_facility.list_for_terms_template_version",
+        skip(self),
+        err
+    )]
+    pub async fn list_for_terms_template_version(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        terms_template_id: impl Into<TermsTemplateId> + std::fmt::Debug,
+        version: u32,
+        query: es_entity::PaginatedQueryArgs<CreditFacilitiesCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<CreditFacility, CreditFacilitiesCursor>,
+        CreditFacilityError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_LIST,
+            )
+            .await?;
+
+        let es_entity::PaginatedQueryArgs { first, after } = query;
+        let after = after
+            .map(CreditFacilitiesByCreatedAtCursor::try_from)
+            .transpose()?;
+        let res = self
+            .repo
+            .list_for_terms_template_version(
+                terms_template_id.into(),
+                version,
+                es_entity::PaginatedQueryArgs { first, after },
+            )
+            .await?;
+
+        Ok(es_entity::PaginatedQueryRet {
+            entities: res.entities,
+            has_next_page: res.has_next_page,
+            end_cursor: res.end_cursor.map(CreditFacilitiesCursor::from),
+        })
+    }
+
     #[instrument(name = "credit.credit_facility.find_by_wallet", skip(self), err)]
     pub async fn find_by_custody_wallet(
         &self,
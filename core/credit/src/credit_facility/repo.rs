@@ -34,7 +34,17 @@ use super::{entity::*, error::CreditFacilityError};
             update(accessor = "last_collateralization_state()")
         ),
         status(ty = "CreditFacilityStatus", list_for, update(accessor = "status()")),
-        public_id(ty = "PublicId", list_by)
+        public_id(ty = "PublicId", list_by),
+        terms_template_id(
+            ty = "Option<TermsTemplateId>",
+            create(accessor = "terms_template_id()"),
+            update(persist = false)
+        ),
+        terms_template_version(
+            ty = "Option<i32>",
+            create(accessor = "terms_template_version()"),
+            update(persist = false)
+        )
     ),
     tbl_prefix = "core",
     post_persist_hook = "publish"
@@ -103,6 +113,59 @@ where
         .fetch_one()
         .await
     }
+
+    pub async fn list_for_terms_template_version(
+        &self,
+        terms_template_id: TermsTemplateId,
+        version: u32,
+        query: es_entity::PaginatedQueryArgs<
+            credit_facility_cursor::CreditFacilitiesByCreatedAtCursor,
+        >,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<
+            CreditFacility,
+            credit_facility_cursor::CreditFacilitiesByCreatedAtCursor,
+        >,
+        CreditFacilityError,
+    > {
+        let es_entity::PaginatedQueryArgs { first, after } = query;
+        let (id, created_at) = if let Some(after) = after {
+            (Some(after.id), Some(after.created_at))
+        } else {
+            (None, None)
+        };
+
+        let (mut entities, has_next_page) = es_query!(
+            "core",
+            self.pool(),
+            r#"
+                SELECT created_at, id FROM core_credit_facilities
+                WHERE terms_template_id = $1
+                  AND terms_template_version = $2
+                  AND (COALESCE((created_at, id) < ($5, $4), $4 IS NULL))
+                ORDER BY created_at DESC, id DESC
+                LIMIT $3"#,
+            terms_template_id as TermsTemplateId,
+            version as i32,
+            (first + 1) as i64,
+            id as Option<CreditFacilityId>,
+            created_at as Option<chrono::DateTime<chrono::Utc>>,
+        )
+        .fetch_n(first)
+        .await?;
+
+        self.find_nested_interest_accruals(&mut entities).await?;
+
+        let end_cursor = entities
+            .last()
+            .map(credit_facility_cursor::CreditFacilitiesByCreatedAtCursor::from);
+
+        Ok(es_entity::PaginatedQueryRet {
+            entities,
+            has_next_page,
+            end_cursor,
+        })
+    }
 }
 
 #[derive(EsRepo)]
@@ -0,0 +1,308 @@
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::{primitives::*, terms::TermValues, terms_template::TermsTemplateVersionRef};
+
+use super::error::CreditFacilityProposalError;
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "CreditFacilityProposalId")]
+pub enum CreditFacilityProposalEvent {
+    Initialized {
+        id: CreditFacilityProposalId,
+        customer_id: CustomerId,
+        amount: UsdCents,
+        terms: TermValues,
+        terms_template: TermsTemplateVersionRef,
+        audit_info: AuditInfo,
+    },
+    Converted {
+        credit_facility_id: CreditFacilityId,
+        audit_info: AuditInfo,
+    },
+    Rejected {
+        reason: String,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct CreditFacilityProposal {
+    pub id: CreditFacilityProposalId,
+    pub customer_id: CustomerId,
+    pub amount: UsdCents,
+    pub terms: TermValues,
+    pub terms_template: TermsTemplateVersionRef,
+    #[builder(default)]
+    pub status: CreditFacilityProposalStatus,
+    #[builder(default)]
+    pub credit_facility_id: Option<CreditFacilityId>,
+    #[builder(default)]
+    pub rejection_reason: Option<String>,
+
+    events: EntityEvents<CreditFacilityProposalEvent>,
+}
+
+impl CreditFacilityProposal {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("entity_first_persisted_at not found")
+    }
+
+    pub fn concluded_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.events
+            .iter_persisted()
+            .find(|event| {
+                matches!(
+                    event.event,
+                    CreditFacilityProposalEvent::Converted { .. }
+                        | CreditFacilityProposalEvent::Rejected { .. }
+                )
+            })
+            .map(|event| event.recorded_at)
+    }
+
+    pub fn is_pending_review(&self) -> bool {
+        self.status == CreditFacilityProposalStatus::PendingReview
+    }
+
+    pub(crate) fn convert(
+        &mut self,
+        credit_facility_id: CreditFacilityId,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, CreditFacilityProposalError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityProposalEvent::Converted { .. }
+        );
+        if !self.is_pending_review() {
+            return Err(CreditFacilityProposalError::NotPendingReview(self.id));
+        }
+
+        self.events.push(CreditFacilityProposalEvent::Converted {
+            credit_facility_id,
+            audit_info,
+        });
+        self.status = CreditFacilityProposalStatus::Converted;
+        self.credit_facility_id = Some(credit_facility_id);
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn reject(
+        &mut self,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, CreditFacilityProposalError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityProposalEvent::Rejected { .. }
+        );
+        if !self.is_pending_review() {
+            return Err(CreditFacilityProposalError::NotPendingReview(self.id));
+        }
+        let reason = reason.trim().to_string();
+        if reason.is_empty() {
+            return Err(CreditFacilityProposalError::RejectionReasonMissing);
+        }
+
+        self.events.push(CreditFacilityProposalEvent::Rejected {
+            reason: reason.clone(),
+            audit_info,
+        });
+        self.status = CreditFacilityProposalStatus::Rejected;
+        self.rejection_reason = Some(reason);
+
+        Ok(Idempotent::Executed(()))
+    }
+}
+
+impl TryFromEvents<CreditFacilityProposalEvent> for CreditFacilityProposal {
+    fn try_from_events(
+        events: EntityEvents<CreditFacilityProposalEvent>,
+    ) -> Result<Self, EsEntityError> {
+        let mut builder = CreditFacilityProposalBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                CreditFacilityProposalEvent::Initialized {
+                    id,
+                    customer_id,
+                    amount,
+                    terms,
+                    terms_template,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .customer_id(*customer_id)
+                        .amount(*amount)
+                        .terms(*terms)
+                        .terms_template(*terms_template)
+                }
+                CreditFacilityProposalEvent::Converted {
+                    credit_facility_id, ..
+                } => {
+                    builder = builder
+                        .status(CreditFacilityProposalStatus::Converted)
+                        .credit_facility_id(Some(*credit_facility_id))
+                }
+                CreditFacilityProposalEvent::Rejected { reason, .. } => {
+                    builder = builder
+                        .status(CreditFacilityProposalStatus::Rejected)
+                        .rejection_reason(Some(reason.clone()))
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewCreditFacilityProposal {
+    #[builder(setter(into))]
+    pub(super) id: CreditFacilityProposalId,
+    #[builder(setter(into))]
+    pub(super) customer_id: CustomerId,
+    pub(super) amount: UsdCents,
+    pub(super) terms: TermValues,
+    pub(super) terms_template: TermsTemplateVersionRef,
+    #[builder(setter(skip), default)]
+    pub(super) status: CreditFacilityProposalStatus,
+    pub(super) audit_info: AuditInfo,
+}
+
+impl NewCreditFacilityProposal {
+    pub fn builder() -> NewCreditFacilityProposalBuilder {
+        NewCreditFacilityProposalBuilder::default()
+    }
+}
+
+impl IntoEvents<CreditFacilityProposalEvent> for NewCreditFacilityProposal {
+    fn into_events(self) -> EntityEvents<CreditFacilityProposalEvent> {
+        EntityEvents::init(
+            self.id,
+            [CreditFacilityProposalEvent::Initialized {
+                id: self.id,
+                customer_id: self.customer_id,
+                amount: self.amount,
+                terms: self.terms,
+                terms_template: self.terms_template,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::{AuditEntryId, AuditInfo};
+    use rust_decimal_macros::dec;
+
+    use crate::terms::{FacilityDuration, InterestInterval, ObligationDuration, OneTimeFeeRatePct};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn terms() -> TermValues {
+        TermValues::builder()
+            .annual_rate(dec!(12))
+            .duration(FacilityDuration::Months(3))
+            .interest_due_duration_from_accrual(ObligationDuration::Days(0))
+            .obligation_overdue_duration_from_due(None)
+            .obligation_liquidation_duration_from_due(None)
+            .accrual_cycle_interval(InterestInterval::EndOfMonth)
+            .accrual_interval(InterestInterval::EndOfDay)
+            .one_time_fee_rate(OneTimeFeeRatePct::ZERO)
+            .liquidation_cvl(dec!(105))
+            .margin_call_cvl(dec!(125))
+            .initial_cvl(dec!(140))
+            .build()
+            .expect("should build a valid term")
+    }
+
+    fn proposal() -> CreditFacilityProposal {
+        let id = CreditFacilityProposalId::new();
+        CreditFacilityProposal::try_from_events(EntityEvents::init(
+            id,
+            [CreditFacilityProposalEvent::Initialized {
+                id,
+                customer_id: CustomerId::new(),
+                amount: UsdCents::from(1_000_000),
+                terms: terms(),
+                terms_template: TermsTemplateVersionRef {
+                    terms_template_id: TermsTemplateId::new(),
+                    version: 1,
+                },
+                audit_info: dummy_audit_info(),
+            }],
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn converted_proposal_cannot_be_rejected() {
+        let mut proposal = proposal();
+        assert!(proposal.is_pending_review());
+
+        let credit_facility_id = CreditFacilityId::new();
+        assert!(
+            proposal
+                .convert(credit_facility_id, dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(proposal.status, CreditFacilityProposalStatus::Converted);
+        assert_eq!(proposal.credit_facility_id, Some(credit_facility_id));
+
+        assert!(
+            proposal
+                .convert(credit_facility_id, dummy_audit_info())
+                .unwrap()
+                .was_ignored()
+        );
+        assert!(matches!(
+            proposal.reject("too late".to_string(), dummy_audit_info()),
+            Err(CreditFacilityProposalError::NotPendingReview(_))
+        ));
+    }
+
+    #[test]
+    fn rejection_requires_a_reason() {
+        let mut proposal = proposal();
+
+        assert!(matches!(
+            proposal.reject("  ".to_string(), dummy_audit_info()),
+            Err(CreditFacilityProposalError::RejectionReasonMissing)
+        ));
+        assert!(
+            proposal
+                .reject("insufficient collateral".to_string(), dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(proposal.status, CreditFacilityProposalStatus::Rejected);
+        assert_eq!(
+            proposal.rejection_reason.as_deref(),
+            Some("insufficient collateral")
+        );
+        assert!(matches!(
+            proposal.convert(CreditFacilityId::new(), dummy_audit_info()),
+            Err(CreditFacilityProposalError::NotPendingReview(_))
+        ));
+    }
+}
@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+use crate::primitives::CreditFacilityProposalId;
+
+#[derive(Error, Debug)]
+pub enum CreditFacilityProposalError {
+    #[error("CreditFacilityProposalError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("CreditFacilityProposalError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("CreditFacilityProposalError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("CreditFacilityProposalError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("CreditFacilityProposalError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+    #[error("CreditFacilityProposalError - ZeroAmount")]
+    ZeroAmount,
+    #[error("CreditFacilityProposalError - NotPendingReview: {0}")]
+    NotPendingReview(CreditFacilityProposalId),
+    #[error("CreditFacilityProposalError - RejectionReasonMissing")]
+    RejectionReasonMissing,
+}
+
+es_entity::from_es_entity_error!(CreditFacilityProposalError);
@@ -0,0 +1,229 @@
+mod entity;
+pub mod error;
+mod repo;
+
+use audit::{AuditInfo, AuditSvc};
+use authz::PermissionCheck;
+use es_entity::{PaginatedQueryArgs, PaginatedQueryRet};
+use outbox::OutboxEventMarker;
+use tracing::instrument;
+
+use crate::{
+    event::CoreCreditEvent, primitives::*, publisher::CreditFacilityPublisher, terms::TermValues,
+    terms_template::TermsTemplateVersionRef,
+};
+
+pub use entity::CreditFacilityProposal;
+#[cfg(feature = "json-schema")]
+pub use entity::CreditFacilityProposalEvent;
+pub(crate) use entity::*;
+use error::CreditFacilityProposalError;
+pub use repo::credit_facility_proposal_cursor;
+use repo::{CreditFacilityProposalRepo, ListDirection, credit_facility_proposal_cursor::*};
+
+pub struct CreditFacilityProposals<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    authz: Perms,
+    repo: CreditFacilityProposalRepo<E>,
+}
+
+impl<Perms, E> Clone for CreditFacilityProposals<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+}
+
+impl<Perms, E> CreditFacilityProposals<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    pub(crate) fn new(
+        pool: &sqlx::PgPool,
+        authz: &Perms,
+        publisher: &CreditFacilityPublisher<E>,
+    ) -> Self {
+        Self {
+            authz: authz.clone(),
+            repo: CreditFacilityProposalRepo::new(pool, publisher),
+        }
+    }
+
+    pub(crate) async fn create(
+        &self,
+        audit_info: AuditInfo,
+        customer_id: CustomerId,
+        amount: UsdCents,
+        terms: TermValues,
+        terms_template: TermsTemplateVersionRef,
+    ) -> Result<CreditFacilityProposal, CreditFacilityProposalError> {
+        if amount.is_zero() {
+            return Err(CreditFacilityProposalError::ZeroAmount);
+        }
+
+        let new_proposal = NewCreditFacilityProposal::builder()
+            .id(CreditFacilityProposalId::new())
+            .customer_id(customer_id)
+            .amount(amount)
+            .terms(terms)
+            .terms_template(terms_template)
+            .audit_info(audit_info)
+            .build()
+            .expect("could not build new credit facility proposal");
+
+        self.repo.create(new_proposal).await
+    }
+
+    pub async fn subject_can_convert(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        enforce: bool,
+    ) -> Result<Option<AuditInfo>, CreditFacilityProposalError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::all_credit_facility_proposals(),
+                CoreCreditAction::CREDIT_FACILITY_PROPOSAL_CONVERT,
+                enforce,
+            )
+            .await?)
+    }
+
+    pub(crate) async fn convert_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        proposal: &mut CreditFacilityProposal,
+        credit_facility_id: CreditFacilityId,
+        audit_info: AuditInfo,
+    ) -> Result<(), CreditFacilityProposalError> {
+        if proposal
+            .convert(credit_facility_id, audit_info)?
+            .did_execute()
+        {
+            self.repo.update_in_op(db, proposal).await?;
+        }
+        Ok(())
+    }
+
+    #[instrument(name = "credit.credit_facility_proposal.reject", skip(self), err)]
+    pub async fn reject(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<CreditFacilityProposalId> + std::fmt::Debug,
+        reason: String,
+    ) -> Result<CreditFacilityProposal, CreditFacilityProposalError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::credit_facility_proposal(id),
+                CoreCreditAction::CREDIT_FACILITY_PROPOSAL_REJECT,
+            )
+            .await?;
+
+        let mut proposal = self.repo.find_by_id(id).await?;
+        if proposal.reject(reason, audit_info)?.did_execute() {
+            self.repo.update(&mut proposal).await?;
+        }
+
+        Ok(proposal)
+    }
+
+    pub async fn find_by_id_without_audit(
+        &self,
+        id: impl Into<CreditFacilityProposalId>,
+    ) -> Result<CreditFacilityProposal, CreditFacilityProposalError> {
+        self.repo.find_by_id(id.into()).await
+    }
+
+    #[instrument(name = "credit.credit_facility_proposal.find_by_id", skip(self), err)]
+    pub async fn find_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<CreditFacilityProposalId> + std::fmt::Debug,
+    ) -> Result<Option<CreditFacilityProposal>, CreditFacilityProposalError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::credit_facility_proposal(id),
+                CoreCreditAction::CREDIT_FACILITY_PROPOSAL_READ,
+            )
+            .await?;
+
+        match self.repo.find_by_id(id).await {
+            Ok(proposal) => Ok(Some(proposal)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[instrument(name = "credit.credit_facility_proposal.list", skip(self), err)]
+    pub async fn list(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: PaginatedQueryArgs<CreditFacilityProposalsByCreatedAtCursor>,
+        status: Option<CreditFacilityProposalStatus>,
+    ) -> Result<
+        PaginatedQueryRet<CreditFacilityProposal, CreditFacilityProposalsByCreatedAtCursor>,
+        CreditFacilityProposalError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_credit_facility_proposals(),
+                CoreCreditAction::CREDIT_FACILITY_PROPOSAL_LIST,
+            )
+            .await?;
+
+        match status {
+            Some(status) => {
+                self.repo
+                    .list_for_status_by_created_at(status, query, ListDirection::Descending)
+                    .await
+            }
+            None => {
+                self.repo
+                    .list_by_created_at(query, ListDirection::Descending)
+                    .await
+            }
+        }
+    }
+
+    pub async fn find_all<T: From<CreditFacilityProposal>>(
+        &self,
+        ids: &[CreditFacilityProposalId],
+    ) -> Result<std::collections::HashMap<CreditFacilityProposalId, T>, CreditFacilityProposalError>
+    {
+        self.repo.find_all(ids).await
+    }
+
+    pub(crate) async fn list_for_customer_without_audit(
+        &self,
+        customer_id: CustomerId,
+    ) -> Result<Vec<CreditFacilityProposal>, CreditFacilityProposalError> {
+        Ok(self
+            .repo
+            .list_for_customer_id_by_created_at(
+                customer_id,
+                Default::default(),
+                ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+}
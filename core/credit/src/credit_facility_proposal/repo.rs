@@ -0,0 +1,101 @@
+use sqlx::PgPool;
+
+pub use es_entity::ListDirection;
+use es_entity::*;
+use outbox::OutboxEventMarker;
+
+use crate::{event::CoreCreditEvent, primitives::*, publisher::CreditFacilityPublisher};
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo)]
+#[es_repo(
+    entity = "CreditFacilityProposal",
+    err = "CreditFacilityProposalError",
+    columns(
+        customer_id(ty = "CustomerId", list_for, update(persist = false)),
+        status(ty = "CreditFacilityProposalStatus", list_for),
+    ),
+    tbl_prefix = "core",
+    post_persist_hook = "publish"
+)]
+pub struct CreditFacilityProposalRepo<E>
+where
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    pool: PgPool,
+    publisher: CreditFacilityPublisher<E>,
+}
+
+impl<E> Clone for CreditFacilityProposalRepo<E>
+where
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            publisher: self.publisher.clone(),
+        }
+    }
+}
+
+impl<E> CreditFacilityProposalRepo<E>
+where
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    pub fn new(pool: &PgPool, publisher: &CreditFacilityPublisher<E>) -> Self {
+        Self {
+            pool: pool.clone(),
+            publisher: publisher.clone(),
+        }
+    }
+
+    async fn publish(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        entity: &CreditFacilityProposal,
+        new_events: es_entity::LastPersisted<'_, CreditFacilityProposalEvent>,
+    ) -> Result<(), CreditFacilityProposalError> {
+        self.publisher
+            .publish_credit_facility_proposal(db, entity, new_events)
+            .await
+    }
+}
+
+mod proposal_status_sqlx {
+    use sqlx::{Type, postgres::*};
+
+    use crate::primitives::CreditFacilityProposalStatus;
+
+    impl Type<Postgres> for CreditFacilityProposalStatus {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for CreditFacilityProposalStatus {
+        fn encode_by_ref(
+            &self,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            <String as sqlx::Encode<'_, Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for CreditFacilityProposalStatus {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(s.parse().map_err(|e: strum::ParseError| Box::new(e))?)
+        }
+    }
+
+    impl PgHasArrayType for CreditFacilityProposalStatus {
+        fn array_type_info() -> PgTypeInfo {
+            <String as sqlx::postgres::PgHasArrayType>::array_type_info()
+        }
+    }
+}
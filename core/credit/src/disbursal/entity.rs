@@ -13,6 +13,8 @@ use crate::{
     primitives::*,
 };
 
+use super::error::DisbursalError;
+
 #[allow(clippy::large_enum_variant)]
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -29,6 +31,7 @@ pub enum DisbursalEvent {
         due_date: DateTime<Utc>,
         overdue_date: Option<DateTime<Utc>>,
         liquidation_date: Option<DateTime<Utc>>,
+        destination: DisbursalDestination,
         public_id: PublicId,
         audit_info: AuditInfo,
     },
@@ -37,6 +40,14 @@ pub enum DisbursalEvent {
         approved: bool,
         audit_info: AuditInfo,
     },
+    SettlementInitiated {
+        beneficiary: ExternalBeneficiary,
+        audit_info: AuditInfo,
+    },
+    SettlementFailed {
+        reason: String,
+        audit_info: AuditInfo,
+    },
     Settled {
         ledger_tx_id: LedgerTxId,
         obligation_id: ObligationId,
@@ -62,6 +73,7 @@ pub struct Disbursal {
     pub due_date: DateTime<Utc>,
     pub overdue_date: Option<DateTime<Utc>>,
     pub liquidation_date: Option<DateTime<Utc>>,
+    pub destination: DisbursalDestination,
     #[builder(setter(strip_option), default)]
     pub concluded_tx_id: Option<LedgerTxId>,
     pub public_id: PublicId,
@@ -83,6 +95,7 @@ impl TryFromEvents<DisbursalEvent> for Disbursal {
                     due_date,
                     overdue_date,
                     liquidation_date,
+                    destination,
                     public_id,
                     ..
                 } => {
@@ -96,6 +109,7 @@ impl TryFromEvents<DisbursalEvent> for Disbursal {
                         .due_date(*due_date)
                         .overdue_date(*overdue_date)
                         .liquidation_date(*liquidation_date)
+                        .destination(destination.clone())
                         .public_id(public_id.clone())
                 }
                 DisbursalEvent::Settled { ledger_tx_id, .. } => {
@@ -104,7 +118,9 @@ impl TryFromEvents<DisbursalEvent> for Disbursal {
                 DisbursalEvent::Cancelled { ledger_tx_id, .. } => {
                     builder = builder.concluded_tx_id(*ledger_tx_id)
                 }
-                DisbursalEvent::ApprovalProcessConcluded { .. } => (),
+                DisbursalEvent::ApprovalProcessConcluded { .. }
+                | DisbursalEvent::SettlementInitiated { .. }
+                | DisbursalEvent::SettlementFailed { .. } => (),
             }
         }
         builder.events(events).build()
@@ -126,6 +142,10 @@ impl Disbursal {
     pub fn status(&self) -> DisbursalStatus {
         if self.is_confirmed() {
             DisbursalStatus::Confirmed
+        } else if self.is_settlement_failed() {
+            DisbursalStatus::SettlementFailed
+        } else if self.is_settlement_pending() {
+            DisbursalStatus::SettlementPending
         } else {
             match self.is_approved() {
                 Some(true) => DisbursalStatus::Approved,
@@ -158,6 +178,16 @@ impl Disbursal {
             approved,
             audit_info: audit_info.clone(),
         });
+        match &self.destination {
+            DisbursalDestination::ExternalAccount { beneficiary } if approved => {
+                self.events.push(DisbursalEvent::SettlementInitiated {
+                    beneficiary: beneficiary.clone(),
+                    audit_info,
+                });
+                return Idempotent::Executed(None);
+            }
+            _ => (),
+        }
         let tx_ref: &str = &format!("disbursal-{}", self.id);
         let new_obligation = if approved {
             if let Idempotent::Executed(new_obligation) =
@@ -179,6 +209,71 @@ impl Disbursal {
         Idempotent::Executed(new_obligation)
     }
 
+    pub fn is_settlement_pending(&self) -> bool {
+        let mut pending = false;
+        for event in self.events.iter_all() {
+            match event {
+                DisbursalEvent::SettlementInitiated { .. } => pending = true,
+                DisbursalEvent::Settled { .. } | DisbursalEvent::Cancelled { .. } => {
+                    pending = false
+                }
+                _ => (),
+            }
+        }
+        pending
+    }
+
+    fn is_settlement_failed(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, DisbursalEvent::SettlementFailed { .. }))
+    }
+
+    pub(crate) fn confirm_settlement(
+        &mut self,
+        tx_id: LedgerTxId,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<NewObligation>, DisbursalError> {
+        idempotency_guard!(self.events.iter_all(), DisbursalEvent::Settled { .. });
+        if !self.is_settlement_pending() {
+            return Err(DisbursalError::NotAwaitingSettlement(self.id));
+        }
+
+        let tx_ref: &str = &format!("disbursal-{}", self.id);
+        let res = self.settle_disbursal(tx_id, tx_ref, effective, audit_info);
+        self.concluded_tx_id = Some(tx_id);
+
+        Ok(res)
+    }
+
+    pub(crate) fn fail_settlement(
+        &mut self,
+        tx_id: LedgerTxId,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, DisbursalError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            DisbursalEvent::SettlementFailed { .. }
+        );
+        if !self.is_settlement_pending() {
+            return Err(DisbursalError::NotAwaitingSettlement(self.id));
+        }
+
+        self.events.push(DisbursalEvent::SettlementFailed {
+            reason,
+            audit_info: audit_info.clone(),
+        });
+        self.events.push(DisbursalEvent::Cancelled {
+            ledger_tx_id: tx_id,
+            audit_info,
+        });
+        self.concluded_tx_id = Some(tx_id);
+
+        Ok(Idempotent::Executed(()))
+    }
+
     pub(super) fn is_approved(&self) -> Option<bool> {
         for event in self.events.iter_all() {
             if let DisbursalEvent::ApprovalProcessConcluded { approved, .. } = event {
@@ -265,6 +360,8 @@ pub struct NewDisbursal {
     pub(super) due_date: DateTime<Utc>,
     pub(super) overdue_date: Option<DateTime<Utc>>,
     pub(super) liquidation_date: Option<DateTime<Utc>>,
+    #[builder(default)]
+    pub(super) destination: DisbursalDestination,
     #[builder(setter(into))]
     pub(super) public_id: PublicId,
     #[builder(setter(into))]
@@ -300,9 +397,128 @@ impl IntoEvents<DisbursalEvent> for NewDisbursal {
                 due_date: self.due_date,
                 overdue_date: self.overdue_date,
                 liquidation_date: self.liquidation_date,
+                destination: self.destination,
                 audit_info: self.audit_info,
                 public_id: self.public_id,
             }],
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use audit::{AuditEntryId, AuditInfo};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn disbursal(destination: DisbursalDestination) -> Disbursal {
+        let id = DisbursalId::new();
+        Disbursal::try_from_events(EntityEvents::init(
+            id,
+            [DisbursalEvent::Initialized {
+                id,
+                approval_process_id: id.into(),
+                facility_id: CreditFacilityId::new(),
+                amount: UsdCents::from(100_000),
+                account_ids: CreditFacilityAccountIds::new(),
+                disbursal_credit_account_id: CalaAccountId::new(),
+                due_date: chrono::Utc::now(),
+                overdue_date: None,
+                liquidation_date: None,
+                destination,
+                public_id: PublicId::new(format!("test-public-id-{}", uuid::Uuid::new_v4())),
+                audit_info: dummy_audit_info(),
+            }],
+        ))
+        .unwrap()
+    }
+
+    fn external() -> DisbursalDestination {
+        DisbursalDestination::ExternalAccount {
+            beneficiary: ExternalBeneficiary {
+                name: "Jane Doe".to_string(),
+                bank_name: "Bank".to_string(),
+                account_number: "0001".to_string(),
+                routing_number: None,
+            },
+        }
+    }
+
+    #[test]
+    fn approved_external_disbursal_awaits_settlement() {
+        let mut disbursal = disbursal(external());
+        let res = disbursal.approval_process_concluded(
+            LedgerTxId::new(),
+            true,
+            chrono::Utc::now().date_naive(),
+            dummy_audit_info(),
+        );
+
+        assert!(matches!(res, Idempotent::Executed(None)));
+        assert_eq!(disbursal.status(), DisbursalStatus::SettlementPending);
+        assert!(disbursal.concluded_tx_id.is_none());
+
+        let tx_id = LedgerTxId::new();
+        assert!(
+            disbursal
+                .confirm_settlement(tx_id, chrono::Utc::now().date_naive(), dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(disbursal.status(), DisbursalStatus::Confirmed);
+        assert_eq!(disbursal.concluded_tx_id, Some(tx_id));
+        assert!(disbursal.obligation_id().is_some());
+    }
+
+    #[test]
+    fn failed_settlement_cancels_disbursal() {
+        let mut disbursal = disbursal(external());
+        let _ = disbursal.approval_process_concluded(
+            LedgerTxId::new(),
+            true,
+            chrono::Utc::now().date_naive(),
+            dummy_audit_info(),
+        );
+
+        assert!(
+            disbursal
+                .fail_settlement(
+                    LedgerTxId::new(),
+                    "rejected".to_string(),
+                    dummy_audit_info()
+                )
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(disbursal.status(), DisbursalStatus::SettlementFailed);
+        assert!(matches!(
+            disbursal.confirm_settlement(
+                LedgerTxId::new(),
+                chrono::Utc::now().date_naive(),
+                dummy_audit_info()
+            ),
+            Err(DisbursalError::NotAwaitingSettlement(_))
+        ));
+    }
+
+    #[test]
+    fn deposit_account_disbursal_settles_on_approval() {
+        let mut disbursal = disbursal(DisbursalDestination::DepositAccount);
+        let res = disbursal.approval_process_concluded(
+            LedgerTxId::new(),
+            true,
+            chrono::Utc::now().date_naive(),
+            dummy_audit_info(),
+        );
+
+        assert!(matches!(res, Idempotent::Executed(Some(_))));
+        assert_eq!(disbursal.status(), DisbursalStatus::Confirmed);
+    }
+}
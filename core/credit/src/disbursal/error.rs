@@ -16,6 +16,8 @@ pub enum DisbursalError {
     GovernanceError(#[from] governance::error::GovernanceError),
     #[error("DisbursalError - ObligationError: {0}")]
     ObligationError(#[from] crate::obligation::error::ObligationError),
+    #[error("DisbursalError - NotAwaitingSettlement: {0}")]
+    NotAwaitingSettlement(crate::primitives::DisbursalId),
 }
 
 es_entity::from_es_entity_error!(DisbursalError);
@@ -50,6 +50,7 @@ where
 pub(super) enum ApprovalProcessOutcome {
     Ignored(Disbursal),
     Approved((Disbursal, Obligation)),
+    SettlementPending(Disbursal),
     Denied(Disbursal),
 }
 
@@ -221,12 +222,61 @@ where
             }
             es_entity::Idempotent::Executed(None) => {
                 self.repo.update_in_op(db, &mut disbursal).await?;
-                ApprovalProcessOutcome::Denied(disbursal)
+                if disbursal.is_settlement_pending() {
+                    ApprovalProcessOutcome::SettlementPending(disbursal)
+                } else {
+                    ApprovalProcessOutcome::Denied(disbursal)
+                }
             }
         };
         Ok(ret)
     }
 
+    pub(super) async fn confirm_settlement_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        disbursal_id: DisbursalId,
+        tx_id: LedgerTxId,
+        audit_info: audit::AuditInfo,
+    ) -> Result<(Disbursal, Option<Obligation>), DisbursalError> {
+        let mut disbursal = self.repo.find_by_id(disbursal_id).await?;
+
+        let obligation =
+            match disbursal.confirm_settlement(tx_id, db.now().date_naive(), audit_info)? {
+                es_entity::Idempotent::Executed(new_obligation) => {
+                    let obligation = self
+                        .obligations
+                        .create_with_jobs_in_op(db, new_obligation)
+                        .await?;
+                    self.repo.update_in_op(db, &mut disbursal).await?;
+                    Some(obligation)
+                }
+                es_entity::Idempotent::Ignored => None,
+            };
+
+        Ok((disbursal, obligation))
+    }
+
+    pub(super) async fn fail_settlement_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        disbursal_id: DisbursalId,
+        tx_id: LedgerTxId,
+        reason: String,
+        audit_info: audit::AuditInfo,
+    ) -> Result<(Disbursal, bool), DisbursalError> {
+        let mut disbursal = self.repo.find_by_id(disbursal_id).await?;
+
+        let failed = disbursal
+            .fail_settlement(tx_id, reason, audit_info)?
+            .did_execute();
+        if failed {
+            self.repo.update_in_op(db, &mut disbursal).await?;
+        }
+
+        Ok((disbursal, failed))
+    }
+
     #[instrument(name = "core_credit.disbursals.list", skip(self), err)]
     pub async fn list(
         &self,
@@ -18,10 +18,22 @@ pub enum CoreCreditError {
     ),
     #[error("CoreCreditError - CreditFacilityError: {0}")]
     CreditFacilityError(#[from] super::credit_facility::error::CreditFacilityError),
+    #[error("CoreCreditError - CreditFacilityProposalError: {0}")]
+    CreditFacilityProposalError(
+        #[from] super::credit_facility_proposal::error::CreditFacilityProposalError,
+    ),
+    #[error("CoreCreditError - ParticipationError: {0}")]
+    ParticipationError(#[from] super::participation::error::ParticipationError),
     #[error("CoreCreditError - HistoryError: {0}")]
     HistoryError(#[from] super::history::error::CreditFacilityHistoryError),
     #[error("CoreCreditError - RepaymentPlanError: {0}")]
     RepaymentPlanError(#[from] super::repayment_plan::error::CreditFacilityRepaymentPlanError),
+    #[error("CoreCreditError - TermsTemplateError: {0}")]
+    TermsTemplateError(#[from] super::terms_template::error::TermsTemplateError),
+    #[error("CoreCreditError - TermsSimulationError: {0}")]
+    TermsSimulationError(#[from] super::simulation::error::TermsSimulationError),
+    #[error("CoreCreditError - PriceShockStressTestError: {0}")]
+    PriceShockStressTestError(#[from] super::stress_test::error::PriceShockStressTestError),
     #[error("CoreCreditError - CollateralError: {0}")]
     CollateralError(#[from] super::collateral::error::CollateralError),
     #[error("CoreCreditError - CoreCustodyError: {0}")]
@@ -50,6 +62,10 @@ pub enum CoreCreditError {
     SubjectIsNotCustomer,
     #[error("CoreCreditError - CustomerIsNotActive")]
     CustomerNotActive,
+    #[error("CoreCreditError - NoDefaultedObligations: {0}")]
+    NoDefaultedObligations(crate::primitives::CreditFacilityId),
+    #[error("CoreCreditError - UnderwritingDeclined: {0}")]
+    UnderwritingDeclined(String),
     #[error("CoreCreditError - DisbursalBuilderError: {0}")]
     DisbursalBuilderError(#[from] super::NewDisbursalBuilderError),
     #[error("CoreCreditError - PublicIdError: {0}")]
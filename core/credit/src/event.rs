@@ -33,6 +33,31 @@ pub enum CoreCreditEvent {
         id: CreditFacilityId,
         completed_at: DateTime<Utc>,
     },
+    FacilityCancelled {
+        id: CreditFacilityId,
+        customer_id: CustomerId,
+        reason: String,
+        cancelled_at: DateTime<Utc>,
+    },
+    FacilityProposalSubmitted {
+        id: CreditFacilityProposalId,
+        customer_id: CustomerId,
+        amount: UsdCents,
+        terms_template_id: TermsTemplateId,
+        recorded_at: DateTime<Utc>,
+    },
+    FacilityProposalConverted {
+        id: CreditFacilityProposalId,
+        customer_id: CustomerId,
+        credit_facility_id: CreditFacilityId,
+        recorded_at: DateTime<Utc>,
+    },
+    FacilityProposalRejected {
+        id: CreditFacilityProposalId,
+        customer_id: CustomerId,
+        reason: String,
+        recorded_at: DateTime<Utc>,
+    },
     FacilityRepaymentRecorded {
         credit_facility_id: CreditFacilityId,
         obligation_id: ObligationId,
@@ -60,6 +85,12 @@ pub enum CoreCreditEvent {
         outstanding: CreditFacilityReceivable,
         price: PriceOfOneBTC,
     },
+    FacilityPastDueUpdated {
+        id: CreditFacilityId,
+        past_due_since: Option<chrono::NaiveDate>,
+        principal_outstanding: UsdCents,
+        recorded_at: DateTime<Utc>,
+    },
     DisbursalSettled {
         credit_facility_id: CreditFacilityId,
         ledger_tx_id: LedgerTxId,
@@ -76,6 +107,14 @@ pub enum CoreCreditEvent {
         recorded_at: DateTime<Utc>,
         effective: chrono::NaiveDate,
     },
+    AccrualAdjusted {
+        credit_facility_id: CreditFacilityId,
+        ledger_tx_id: LedgerTxId,
+        obligation_id: Option<ObligationId>,
+        amount: UsdCents,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
     ObligationCreated {
         id: ObligationId,
         obligation_type: ObligationType,
@@ -104,6 +143,15 @@ pub enum CoreCreditEvent {
         credit_facility_id: CreditFacilityId,
         amount: UsdCents,
     },
+    ObligationReinstated {
+        id: ObligationId,
+        credit_facility_id: CreditFacilityId,
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        status: ObligationStatus,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
     ObligationCompleted {
         id: ObligationId,
         credit_facility_id: CreditFacilityId,
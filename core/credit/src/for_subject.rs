@@ -4,7 +4,10 @@ use es_entity::{PaginatedQueryArgs, PaginatedQueryRet};
 use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
 
 use super::*;
-use crate::history::CreditFacilityHistoryEntry;
+use crate::{
+    credit_facility_proposal::{CreditFacilityProposal, CreditFacilityProposals},
+    history::CreditFacilityHistoryEntry,
+};
 
 pub struct CreditFacilitiesForSubject<'a, Perms, E>
 where
@@ -20,6 +23,8 @@ where
     histories: &'a HistoryRepo,
     repayment_plans: &'a RepaymentPlanRepo,
     ledger: &'a CreditLedger,
+    terms_templates: &'a TermsTemplates<Perms>,
+    proposals: &'a CreditFacilityProposals<Perms, E>,
 }
 
 impl<'a, Perms, E> CreditFacilitiesForSubject<'a, Perms, E>
@@ -42,6 +47,8 @@ where
         history: &'a HistoryRepo,
         repayment_plans: &'a RepaymentPlanRepo,
         ledger: &'a CreditLedger,
+        terms_templates: &'a TermsTemplates<Perms>,
+        proposals: &'a CreditFacilityProposals<Perms, E>,
     ) -> Self {
         Self {
             customer_id,
@@ -53,6 +60,8 @@ where
             histories: history,
             repayment_plans,
             ledger,
+            terms_templates,
+            proposals,
         }
     }
 
@@ -68,6 +77,73 @@ where
             .await?)
     }
 
+    pub async fn list_terms_templates(&self) -> Result<Vec<TermsTemplate>, CoreCreditError> {
+        self.authz
+            .audit()
+            .record_entry(
+                self.subject,
+                CoreCreditObject::all_terms_templates(),
+                CoreCreditAction::TERMS_TEMPLATE_LIST,
+                true,
+            )
+            .await?;
+
+        Ok(self.terms_templates.list_active_without_audit().await?)
+    }
+
+    pub async fn create_facility_proposal(
+        &self,
+        amount: UsdCents,
+        terms_template_id: impl Into<TermsTemplateId> + std::fmt::Debug,
+    ) -> Result<CreditFacilityProposal, CoreCreditError> {
+        let audit_info = self
+            .authz
+            .audit()
+            .record_entry(
+                self.subject,
+                CoreCreditObject::all_credit_facility_proposals(),
+                CoreCreditAction::CREDIT_FACILITY_PROPOSAL_CREATE,
+                true,
+            )
+            .await?;
+
+        let terms_template = self
+            .terms_templates
+            .find_by_id_without_audit(terms_template_id)
+            .await?;
+        let terms_template_ref = terms_template.current_version_ref()?;
+
+        Ok(self
+            .proposals
+            .create(
+                audit_info,
+                self.customer_id,
+                amount,
+                terms_template.values,
+                terms_template_ref,
+            )
+            .await?)
+    }
+
+    pub async fn list_facility_proposals(
+        &self,
+    ) -> Result<Vec<CreditFacilityProposal>, CoreCreditError> {
+        self.authz
+            .audit()
+            .record_entry(
+                self.subject,
+                CoreCreditObject::all_credit_facility_proposals(),
+                CoreCreditAction::CREDIT_FACILITY_PROPOSAL_LIST,
+                true,
+            )
+            .await?;
+
+        Ok(self
+            .proposals
+            .list_for_customer_without_audit(self.customer_id)
+            .await?)
+    }
+
     pub async fn history<T: From<CreditFacilityHistoryEntry>>(
         &self,
         id: impl Into<CreditFacilityId> + std::fmt::Debug,
@@ -55,6 +55,14 @@ pub struct InterestAccrualsPosted {
     pub tx_id: LedgerTxId,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct InterestAccrualAdjusted {
+    pub cents: UsdCents,
+    pub recorded_at: DateTime<Utc>,
+    pub effective: chrono::NaiveDate,
+    pub tx_id: LedgerTxId,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct ObligationMovedToLiquidation {
     pub cents: UsdCents,
@@ -63,6 +71,14 @@ pub struct ObligationMovedToLiquidation {
     pub tx_id: LedgerTxId,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct DefaultedObligationReinstated {
+    pub cents: UsdCents,
+    pub recorded_at: DateTime<Utc>,
+    pub effective: chrono::NaiveDate,
+    pub tx_id: LedgerTxId,
+}
+
 /// Represents an entry in Credit Facility history as it is stored in a database.
 /// The entries contain no running sums; if needed, they have to be calculated
 /// during replaying.
@@ -75,5 +91,7 @@ pub enum CreditFacilityHistoryEntry {
     Payment(IncrementalPayment),
     Disbursal(DisbursalExecuted),
     Interest(InterestAccrualsPosted),
+    InterestAdjustment(InterestAccrualAdjusted),
     ReservedForLiquidation(ObligationMovedToLiquidation),
+    Reinstatement(DefaultedObligationReinstated),
 }
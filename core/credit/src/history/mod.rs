@@ -120,6 +120,23 @@ impl CreditFacilityHistory {
                     },
                 ));
             }
+            AccrualAdjusted {
+                amount,
+                ledger_tx_id,
+                recorded_at,
+                effective,
+                ..
+            } => {
+                self.entries
+                    .push(CreditFacilityHistoryEntry::InterestAdjustment(
+                        InterestAccrualAdjusted {
+                            cents: *amount,
+                            recorded_at: *recorded_at,
+                            effective: *effective,
+                            tx_id: *ledger_tx_id,
+                        },
+                    ));
+            }
             LiquidationProcessStarted {
                 amount,
                 recorded_at,
@@ -137,13 +154,34 @@ impl CreditFacilityHistory {
                         },
                     ));
             }
+            ObligationReinstated {
+                amount,
+                recorded_at,
+                effective,
+                ledger_tx_id,
+                ..
+            } => {
+                self.entries.push(CreditFacilityHistoryEntry::Reinstatement(
+                    DefaultedObligationReinstated {
+                        cents: *amount,
+                        recorded_at: *recorded_at,
+                        effective: *effective,
+                        tx_id: *ledger_tx_id,
+                    },
+                ));
+            }
             FacilityCompleted { .. } => {}
+            FacilityCancelled { .. } => {}
+            FacilityPastDueUpdated { .. } => {}
             ObligationCreated { .. } => {}
             ObligationDue { .. } => {}
             ObligationOverdue { .. } => {}
             ObligationDefaulted { .. } => {}
             LiquidationProcessConcluded { .. } => {}
             ObligationCompleted { .. } => {}
+            FacilityProposalSubmitted { .. } => {}
+            FacilityProposalConverted { .. } => {}
+            FacilityProposalRejected { .. } => {}
         }
     }
 }
@@ -71,6 +71,16 @@ pub enum InterestAccrualCycleEvent {
         obligation_id: Option<ObligationId>,
         total: UsdCents,
         effective: chrono::NaiveDate,
+        #[serde(default)]
+        capitalized: bool,
+        audit_info: AuditInfo,
+    },
+    InterestAccrualAdjusted {
+        ledger_tx_id: LedgerTxId,
+        tx_ref: String,
+        obligation_id: Option<ObligationId>,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
         audit_info: AuditInfo,
     },
 }
@@ -94,6 +104,16 @@ pub(crate) struct InterestAccrualCycleData {
     pub(crate) tx_ref: String,
     pub(crate) tx_id: LedgerTxId,
     pub(crate) effective: chrono::NaiveDate,
+    pub(crate) capitalized: bool,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct InterestAccrualAdjustmentData {
+    pub(crate) amount: UsdCents,
+    pub(crate) tx_ref: String,
+    pub(crate) tx_id: LedgerTxId,
+    pub(crate) effective: chrono::NaiveDate,
+    pub(crate) obligation_id: Option<ObligationId>,
 }
 
 #[derive(Debug, Clone)]
@@ -138,6 +158,7 @@ impl TryFromEvents<InterestAccrualCycleEvent> for InterestAccrualCycle {
                 }
                 InterestAccrualCycleEvent::InterestAccrued { .. } => (),
                 InterestAccrualCycleEvent::InterestAccrualsPosted { .. } => (),
+                InterestAccrualCycleEvent::InterestAccrualAdjusted { .. } => (),
             }
         }
         builder.events(events).build()
@@ -155,15 +176,141 @@ impl InterestAccrualCycle {
     }
 
     fn total_accrued(&self) -> UsdCents {
-        self.events
+        let accrued = self
+            .events
             .iter_all()
             .filter_map(|event| match event {
                 InterestAccrualCycleEvent::InterestAccrued { amount, .. } => Some(*amount),
                 _ => None,
             })
+            .fold(UsdCents::ZERO, |acc, amount| acc + amount);
+        accrued - self.total_adjusted_before_posting()
+    }
+
+    fn total_adjusted(&self) -> UsdCents {
+        self.events
+            .iter_all()
+            .filter_map(|event| match event {
+                InterestAccrualCycleEvent::InterestAccrualAdjusted { amount, .. } => Some(*amount),
+                _ => None,
+            })
+            .fold(UsdCents::ZERO, |acc, amount| acc + amount)
+    }
+
+    fn total_adjusted_before_posting(&self) -> UsdCents {
+        self.events
+            .iter_all()
+            .filter_map(|event| match event {
+                InterestAccrualCycleEvent::InterestAccrualAdjusted {
+                    amount,
+                    obligation_id: None,
+                    ..
+                } => Some(*amount),
+                _ => None,
+            })
             .fold(UsdCents::ZERO, |acc, amount| acc + amount)
     }
 
+    fn accrued_periods(&self) -> Vec<(InterestPeriod, UsdCents)> {
+        let accrual_interval = self.terms.accrual_interval;
+        let cycle_ends_at = self.accrual_cycle_ends_at();
+
+        let mut periods = vec![];
+        let mut next_period = accrual_interval
+            .period_from(self.period.start)
+            .truncate(cycle_ends_at);
+        for event in self.events.iter_all() {
+            if let InterestAccrualCycleEvent::InterestAccrued { amount, .. } = event {
+                let period = next_period.expect("accrual recorded outside of cycle");
+                periods.push((period, *amount));
+                next_period = period.next().truncate(cycle_ends_at);
+            }
+        }
+        periods
+    }
+
+    pub(crate) fn posted_obligation_id(&self) -> Option<Option<ObligationId>> {
+        self.events.iter_all().find_map(|event| match event {
+            InterestAccrualCycleEvent::InterestAccrualsPosted { obligation_id, .. } => {
+                Some(*obligation_id)
+            }
+            _ => None,
+        })
+    }
+
+    /// Interest that was accrued on `principal_reduction` for days on or after `since`,
+    /// i.e. the amount that would not have accrued had a back-dated repayment been
+    /// recorded on time.
+    pub(crate) fn overaccrued_interest(
+        &self,
+        principal_reduction: UsdCents,
+        since: chrono::NaiveDate,
+    ) -> UsdCents {
+        let overaccrued = self
+            .accrued_periods()
+            .into_iter()
+            .filter(|(period, _)| period.end.date_naive() >= since)
+            .map(|(period, accrued)| {
+                let days = if period.start.date_naive() >= since {
+                    period.days()
+                } else {
+                    (period.end.date_naive() - since).num_days() as u32 + 1
+                };
+                let overaccrued = self
+                    .terms
+                    .annual_rate
+                    .interest_for_time_period(principal_reduction, days);
+                std::cmp::min(overaccrued, accrued)
+            })
+            .fold(UsdCents::ZERO, |acc, amount| acc + amount);
+
+        let remaining = self
+            .accrued_periods()
+            .into_iter()
+            .fold(UsdCents::ZERO, |acc, (_, accrued)| acc + accrued)
+            - self.total_adjusted();
+
+        std::cmp::min(overaccrued, remaining)
+    }
+
+    pub(crate) fn record_accrual_adjustment(
+        &mut self,
+        tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> InterestAccrualAdjustmentData {
+        let n_adjustments = self
+            .events
+            .iter_all()
+            .filter(|event| {
+                matches!(
+                    event,
+                    InterestAccrualCycleEvent::InterestAccrualAdjusted { .. }
+                )
+            })
+            .count();
+        let adjustment = InterestAccrualAdjustmentData {
+            amount,
+            tx_ref: format!("{}-interest-adjustment-{}", self.id, n_adjustments + 1),
+            tx_id,
+            effective,
+            obligation_id: self.posted_obligation_id().flatten(),
+        };
+
+        self.events
+            .push(InterestAccrualCycleEvent::InterestAccrualAdjusted {
+                ledger_tx_id: adjustment.tx_id,
+                tx_ref: adjustment.tx_ref.clone(),
+                obligation_id: adjustment.obligation_id,
+                amount,
+                effective,
+                audit_info,
+            });
+
+        adjustment
+    }
+
     fn last_accrual_period(&self) -> Option<InterestPeriod> {
         let mut last_accrued_at = None;
         let mut second_to_last_accrued_at = None;
@@ -259,6 +406,7 @@ impl InterestAccrualCycle {
                     tx_ref: accrual_cycle_tx_ref,
                     tx_id: LedgerTxId::new(),
                     effective: last_accrual_period.end.date_naive(),
+                    capitalized: self.terms.interest_capitalization.is_capitalized(),
                 };
 
                 Some(interest_accrual_cycle)
@@ -273,8 +421,9 @@ impl InterestAccrualCycle {
             tx_ref,
             tx_id,
             effective,
-            ..
+            capitalized,
         }: InterestAccrualCycleData,
+        facility_account_ids: CreditFacilityAccountIds,
         audit_info: AuditInfo,
     ) -> Idempotent<Option<NewObligation>> {
         idempotency_guard!(
@@ -290,12 +439,48 @@ impl InterestAccrualCycle {
                     obligation_id: None,
                     total: interest,
                     effective,
+                    capitalized,
                     audit_info: audit_info.clone(),
                 });
 
             return Idempotent::Executed(None);
         }
 
+        let new_obligation = if capitalized {
+            self.capitalized_interest_obligation(
+                interest,
+                &tx_ref,
+                tx_id,
+                effective,
+                facility_account_ids,
+                audit_info.clone(),
+            )
+        } else {
+            self.interest_obligation(interest, &tx_ref, tx_id, effective, audit_info.clone())
+        };
+
+        self.events
+            .push(InterestAccrualCycleEvent::InterestAccrualsPosted {
+                ledger_tx_id: tx_id,
+                tx_ref: tx_ref.to_string(),
+                obligation_id: Some(new_obligation.id),
+                total: interest,
+                effective,
+                capitalized,
+                audit_info,
+            });
+
+        Idempotent::Executed(Some(new_obligation))
+    }
+
+    fn interest_obligation(
+        &self,
+        interest: UsdCents,
+        tx_ref: &str,
+        tx_id: LedgerTxId,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> NewObligation {
         let due_date = self.accrual_cycle_ends_at();
         let overdue_date = self
             .terms
@@ -305,7 +490,7 @@ impl InterestAccrualCycle {
             .terms
             .obligation_liquidation_duration_from_due
             .map(|d| d.end_date(due_date));
-        let new_obligation = NewObligation::builder()
+        NewObligation::builder()
             .id(ObligationId::new())
             .credit_facility_id(self.credit_facility_id)
             .obligation_type(ObligationType::Interest)
@@ -330,21 +515,60 @@ impl InterestAccrualCycle {
             .overdue_date(overdue_date)
             .liquidation_date(liquidation_date)
             .effective(effective)
-            .audit_info(audit_info.clone())
+            .audit_info(audit_info)
             .build()
-            .expect("could not build new interest accrual cycle obligation");
-
-        self.events
-            .push(InterestAccrualCycleEvent::InterestAccrualsPosted {
-                ledger_tx_id: tx_id,
-                tx_ref: tx_ref.to_string(),
-                obligation_id: Some(new_obligation.id),
-                total: interest,
-                effective,
-                audit_info,
-            });
+            .expect("could not build new interest accrual cycle obligation")
+    }
 
-        Idempotent::Executed(Some(new_obligation))
+    /// Capitalized interest becomes part of the disbursed principal, so it is
+    /// repaid at facility maturity like any other disbursal.
+    fn capitalized_interest_obligation(
+        &self,
+        interest: UsdCents,
+        tx_ref: &str,
+        tx_id: LedgerTxId,
+        effective: chrono::NaiveDate,
+        facility_account_ids: CreditFacilityAccountIds,
+        audit_info: AuditInfo,
+    ) -> NewObligation {
+        let due_date = self.facility_matures_at;
+        let overdue_date = self
+            .terms
+            .obligation_overdue_duration_from_due
+            .map(|d| d.end_date(due_date));
+        let liquidation_date = self
+            .terms
+            .obligation_liquidation_duration_from_due
+            .map(|d| d.end_date(due_date));
+        NewObligation::builder()
+            .id(ObligationId::new())
+            .credit_facility_id(self.credit_facility_id)
+            .obligation_type(ObligationType::Disbursal)
+            .reference(tx_ref.to_string())
+            .amount(interest)
+            .tx_id(tx_id)
+            .not_yet_due_accounts(ObligationAccounts {
+                receivable_account_id: facility_account_ids
+                    .disbursed_receivable_not_yet_due_account_id,
+                account_to_be_credited_id: self.account_ids.interest_income_account_id,
+            })
+            .due_accounts(ObligationAccounts {
+                receivable_account_id: facility_account_ids.disbursed_receivable_due_account_id,
+                account_to_be_credited_id: self.account_ids.interest_income_account_id,
+            })
+            .overdue_accounts(ObligationAccounts {
+                receivable_account_id: facility_account_ids.disbursed_receivable_overdue_account_id,
+                account_to_be_credited_id: self.account_ids.interest_income_account_id,
+            })
+            .in_liquidation_account_id(self.account_ids.in_liquidation_account_id)
+            .defaulted_account_id(facility_account_ids.disbursed_defaulted_account_id)
+            .due_date(due_date)
+            .overdue_date(overdue_date)
+            .liquidation_date(liquidation_date)
+            .effective(effective)
+            .audit_info(audit_info)
+            .build()
+            .expect("could not build new capitalized interest obligation")
     }
 }
 
@@ -397,7 +621,10 @@ mod test {
     use chrono::{Datelike, TimeZone, Utc};
     use rust_decimal_macros::dec;
 
-    use crate::terms::{FacilityDuration, InterestInterval, ObligationDuration, OneTimeFeeRatePct};
+    use crate::terms::{
+        FacilityDuration, InterestCapitalization, InterestInterval, ObligationDuration,
+        OneTimeFeeRatePct,
+    };
 
     use super::*;
 
@@ -661,4 +888,89 @@ mod test {
             _ => panic!("Expected accrual to be returned"),
         }
     }
+
+    #[test]
+    fn capitalizing_terms_flag_posted_accruals() {
+        let mut events = initial_events();
+        if let InterestAccrualCycleEvent::Initialized { terms, .. } = &mut events[0] {
+            terms.interest_capitalization = InterestCapitalization::AtCycleEnd;
+        }
+        let mut accrual = accrual_from(events);
+
+        let mut accrual_cycle_data = None;
+        while accrual_cycle_data.is_none() {
+            accrual.record_accrual(UsdCents::from(1_000_000_00), dummy_audit_info());
+            accrual_cycle_data = accrual.accrual_cycle_data();
+        }
+        let accrual_cycle_data = accrual_cycle_data.unwrap();
+        assert!(accrual_cycle_data.capitalized);
+
+        let new_obligation = accrual
+            .record_accrual_cycle(
+                accrual_cycle_data,
+                CreditFacilityAccountIds::new(),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        assert!(new_obligation.is_some());
+        assert!(accrual.events.iter_all().any(|event| matches!(
+            event,
+            InterestAccrualCycleEvent::InterestAccrualsPosted {
+                capitalized: true,
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn overaccrued_interest_for_back_dated_repayment() {
+        let outstanding = UsdCents::from(1_000_000_00);
+        let repaid = UsdCents::from(400_000_00);
+        let mut accrual = accrual_from(initial_events());
+        for _ in 0..10 {
+            accrual.record_accrual(outstanding, dummy_audit_info());
+        }
+
+        let since = (default_started_at() + chrono::Duration::days(6)).date_naive();
+        let expected = default_terms()
+            .annual_rate
+            .interest_for_time_period(repaid, 1)
+            * 4;
+        assert_eq!(accrual.overaccrued_interest(repaid, since), expected);
+
+        let later = (default_started_at() + chrono::Duration::days(30)).date_naive();
+        assert_eq!(accrual.overaccrued_interest(repaid, later), UsdCents::ZERO);
+    }
+
+    #[test]
+    fn adjustment_reduces_interest_posted_for_cycle() {
+        let outstanding = UsdCents::from(1_000_000_00);
+        let mut accrual = accrual_from(initial_events());
+        let mut accrual_cycle_data = None;
+        while accrual_cycle_data.is_none() {
+            accrual.record_accrual(outstanding, dummy_audit_info());
+            accrual_cycle_data = accrual.accrual_cycle_data();
+        }
+        let accrued = accrual_cycle_data.unwrap().interest;
+
+        let overaccrued =
+            accrual.overaccrued_interest(outstanding, default_started_at().date_naive());
+        assert_eq!(overaccrued, accrued);
+
+        let adjustment = accrual.record_accrual_adjustment(
+            LedgerTxId::new(),
+            UsdCents::from(100),
+            default_started_at().date_naive(),
+            dummy_audit_info(),
+        );
+        assert_eq!(adjustment.obligation_id, None);
+        assert_eq!(
+            accrual.accrual_cycle_data().unwrap().interest,
+            accrued - UsdCents::from(100)
+        );
+        assert_eq!(
+            accrual.overaccrued_interest(outstanding, default_started_at().date_naive()),
+            accrued - UsdCents::from(100)
+        );
+    }
 }
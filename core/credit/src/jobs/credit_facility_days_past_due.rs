@@ -0,0 +1,146 @@
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::{EventSequence, Outbox, OutboxEventMarker};
+
+use crate::{credit_facility::CreditFacilities, event::CoreCreditEvent, primitives::*};
+
+#[derive(Serialize, Deserialize)]
+pub struct CreditFacilityDaysPastDueJobConfig<Perms, E> {
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> JobConfig for CreditFacilityDaysPastDueJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    type Initializer = CreditFacilityDaysPastDueInit<Perms, E>;
+}
+
+pub struct CreditFacilityDaysPastDueInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    outbox: Outbox<E>,
+    credit_facilities: CreditFacilities<Perms, E>,
+}
+
+impl<Perms, E> CreditFacilityDaysPastDueInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(outbox: &Outbox<E>, credit_facilities: &CreditFacilities<Perms, E>) -> Self {
+        Self {
+            outbox: outbox.clone(),
+            credit_facilities: credit_facilities.clone(),
+        }
+    }
+}
+
+const CREDIT_FACILITY_DAYS_PAST_DUE_JOB: JobType = JobType::new("credit-facility-days-past-due");
+impl<Perms, E> JobInitializer for CreditFacilityDaysPastDueInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        CREDIT_FACILITY_DAYS_PAST_DUE_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(CreditFacilityDaysPastDueJobRunner::<Perms, E> {
+            outbox: self.outbox.clone(),
+            credit_facilities: self.credit_facilities.clone(),
+        }))
+    }
+}
+
+#[derive(Default, Clone, Copy, Deserialize, Serialize)]
+struct CreditFacilityDaysPastDueJobData {
+    sequence: EventSequence,
+}
+
+pub struct CreditFacilityDaysPastDueJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    outbox: Outbox<E>,
+    credit_facilities: CreditFacilities<Perms, E>,
+}
+
+#[async_trait::async_trait]
+impl<Perms, E> JobRunner for CreditFacilityDaysPastDueJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<CreditFacilityDaysPastDueJobData>()?
+            .unwrap_or_default();
+        let mut stream = self.outbox.listen_persisted(Some(state.sequence)).await?;
+
+        while let Some(message) = stream.next().await {
+            match message.as_ref().as_event() {
+                Some(CoreCreditEvent::ObligationDue {
+                    credit_facility_id: id,
+                    ..
+                })
+                | Some(CoreCreditEvent::ObligationOverdue {
+                    credit_facility_id: id,
+                    ..
+                })
+                | Some(CoreCreditEvent::ObligationDefaulted {
+                    credit_facility_id: id,
+                    ..
+                })
+                | Some(CoreCreditEvent::ObligationReinstated {
+                    credit_facility_id: id,
+                    ..
+                })
+                | Some(CoreCreditEvent::ObligationCompleted {
+                    credit_facility_id: id,
+                    ..
+                }) => {
+                    self.credit_facilities
+                        .update_past_due_from_obligations(*id)
+                        .await?;
+                    state.sequence = message.sequence;
+                    current_job.update_execution_state(state).await?;
+                }
+                _ => (),
+            }
+        }
+
+        Ok(JobCompletion::RescheduleNow)
+    }
+}
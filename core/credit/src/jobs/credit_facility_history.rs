@@ -46,6 +46,7 @@ where
                     | FacilityApproved { id }
                     | FacilityActivated { id, .. }
                     | FacilityCompleted { id, .. }
+                    | FacilityCancelled { id, .. }
                     | FacilityRepaymentRecorded {
                         credit_facility_id: id,
                         ..
@@ -55,6 +56,7 @@ where
                         ..
                     }
                     | FacilityCollateralizationChanged { id, .. }
+                    | FacilityPastDueUpdated { id, .. }
                     | DisbursalSettled {
                         credit_facility_id: id,
                         ..
@@ -63,6 +65,10 @@ where
                         credit_facility_id: id,
                         ..
                     }
+                    | AccrualAdjusted {
+                        credit_facility_id: id,
+                        ..
+                    }
                     | ObligationCreated {
                         credit_facility_id: id,
                         ..
@@ -79,6 +85,10 @@ where
                         credit_facility_id: id,
                         ..
                     }
+                    | ObligationReinstated {
+                        credit_facility_id: id,
+                        ..
+                    }
                     | ObligationCompleted {
                         credit_facility_id: id,
                         ..
@@ -91,6 +101,9 @@ where
                         credit_facility_id: id,
                         ..
                     } => *id,
+                    FacilityProposalSubmitted { .. }
+                    | FacilityProposalConverted { .. }
+                    | FacilityProposalRejected { .. } => continue,
                 };
 
                 let mut db = self.repo.begin().await?;
@@ -1,5 +1,6 @@
 pub mod collateralization_from_events;
 pub mod collateralization_from_price;
+pub mod credit_facility_days_past_due;
 pub mod credit_facility_history;
 pub mod credit_facility_repayment_plan;
 pub mod interest_accrual_cycles;
@@ -8,4 +9,5 @@ pub mod obligation_defaulted;
 pub mod obligation_due;
 pub mod obligation_liquidation;
 pub mod obligation_overdue;
+pub mod participation_shares;
 pub mod wallet_collateral_sync;
@@ -0,0 +1,231 @@
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::{EventSequence, Outbox, OutboxEventMarker};
+
+use crate::{
+    credit_facility::CreditFacilities, event::CoreCreditEvent, ledger::CreditLedger,
+    participation::Participations, primitives::*,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct ParticipationSharesJobConfig<Perms, E> {
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> JobConfig for ParticipationSharesJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    type Initializer = ParticipationSharesInit<Perms, E>;
+}
+
+pub struct ParticipationSharesInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    outbox: Outbox<E>,
+    credit_facilities: CreditFacilities<Perms, E>,
+    participations: Participations<Perms>,
+    ledger: CreditLedger,
+}
+
+impl<Perms, E> ParticipationSharesInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        outbox: &Outbox<E>,
+        credit_facilities: &CreditFacilities<Perms, E>,
+        participations: &Participations<Perms>,
+        ledger: &CreditLedger,
+    ) -> Self {
+        Self {
+            outbox: outbox.clone(),
+            credit_facilities: credit_facilities.clone(),
+            participations: participations.clone(),
+            ledger: ledger.clone(),
+        }
+    }
+}
+
+const PARTICIPATION_SHARES_JOB: JobType = JobType::new("credit-facility-participation-shares");
+impl<Perms, E> JobInitializer for ParticipationSharesInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        PARTICIPATION_SHARES_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(ParticipationSharesJobRunner::<Perms, E> {
+            outbox: self.outbox.clone(),
+            credit_facilities: self.credit_facilities.clone(),
+            participations: self.participations.clone(),
+            ledger: self.ledger.clone(),
+        }))
+    }
+}
+
+#[derive(Default, Clone, Copy, Deserialize, Serialize)]
+struct ParticipationSharesJobData {
+    sequence: EventSequence,
+}
+
+pub struct ParticipationSharesJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    outbox: Outbox<E>,
+    credit_facilities: CreditFacilities<Perms, E>,
+    participations: Participations<Perms>,
+    ledger: CreditLedger,
+}
+
+impl<Perms, E> ParticipationSharesJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    async fn allocate_shares(
+        &self,
+        credit_facility_id: CreditFacilityId,
+        source_tx_id: LedgerTxId,
+        share_type: ParticipationShareType,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let participations = self
+            .participations
+            .list_for_credit_facility_without_audit(credit_facility_id)
+            .await?;
+        if participations.is_empty() {
+            return Ok(());
+        }
+
+        let credit_facility = self
+            .credit_facilities
+            .find_by_id_without_audit(credit_facility_id)
+            .await?;
+
+        for mut participation in participations {
+            let mut db = self.participations.begin_op().await?;
+            if let Some(allocation) = self
+                .participations
+                .allocate_share_in_op(
+                    &mut db,
+                    &mut participation,
+                    source_tx_id,
+                    share_type,
+                    amount,
+                    effective,
+                )
+                .await?
+            {
+                self.ledger
+                    .record_participation_share(
+                        db,
+                        allocation,
+                        credit_facility.account_ids.interest_income_account_id,
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<Perms, E> JobRunner for ParticipationSharesJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<ParticipationSharesJobData>()?
+            .unwrap_or_default();
+        let mut stream = self.outbox.listen_persisted(Some(state.sequence)).await?;
+
+        while let Some(message) = stream.next().await {
+            match message.as_ref().as_event() {
+                Some(CoreCreditEvent::AccrualPosted {
+                    credit_facility_id,
+                    ledger_tx_id,
+                    amount,
+                    effective,
+                    ..
+                }) => {
+                    self.allocate_shares(
+                        *credit_facility_id,
+                        *ledger_tx_id,
+                        ParticipationShareType::Interest,
+                        *amount,
+                        *effective,
+                    )
+                    .await?;
+                    state.sequence = message.sequence;
+                    current_job.update_execution_state(state).await?;
+                }
+                Some(CoreCreditEvent::FacilityRepaymentRecorded {
+                    credit_facility_id,
+                    obligation_type: ObligationType::Disbursal,
+                    payment_id,
+                    amount,
+                    effective,
+                    ..
+                }) => {
+                    self.allocate_shares(
+                        *credit_facility_id,
+                        LedgerTxId::from(*payment_id),
+                        ParticipationShareType::Principal,
+                        *amount,
+                        *effective,
+                    )
+                    .await?;
+                    state.sequence = message.sequence;
+                    current_job.update_execution_state(state).await?;
+                }
+                _ => (),
+            }
+        }
+
+        Ok(JobCompletion::RescheduleNow)
+    }
+}
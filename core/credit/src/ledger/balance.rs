@@ -72,6 +72,11 @@ impl CreditFacilityBalanceSummary {
         self.not_yet_due_disbursed_outstanding + self.disbursed_outstanding_payable()
     }
 
+    /// Principal still owed, including amounts already moved to defaulted.
+    pub fn principal_outstanding(&self) -> UsdCents {
+        self.disbursed_outstanding() + self.disbursed_defaulted
+    }
+
     pub fn overdue_interest_outstanding(&self) -> UsdCents {
         self.overdue_interest_outstanding
     }
@@ -111,7 +116,7 @@ impl CreditFacilityBalanceSummary {
         self.overdue_disbursed_outstanding + self.overdue_interest_outstanding
     }
 
-    fn total_defaulted(&self) -> UsdCents {
+    pub fn total_defaulted(&self) -> UsdCents {
         self.disbursed_defaulted + self.interest_defaulted
     }
 
@@ -15,6 +15,20 @@ pub const CREDIT_FACILITY_IN_LIQUIDATION_OMNIBUS_ACCOUNT_SET_REF: &str =
 pub const CREDIT_FACILITY_IN_LIQUIDATION_OMNIBUS_ACCOUNT_REF: &str =
     "credit-facility-in-liquidation-omnibus-account";
 
+pub const CREDIT_DISBURSAL_SETTLEMENT_OMNIBUS_ACCOUNT_SET_NAME: &str =
+    "Credit Disbursal Settlement Omnibus Account Set";
+pub const CREDIT_DISBURSAL_SETTLEMENT_OMNIBUS_ACCOUNT_SET_REF: &str =
+    "credit-disbursal-settlement-omnibus-account-set";
+pub const CREDIT_DISBURSAL_SETTLEMENT_OMNIBUS_ACCOUNT_REF: &str =
+    "credit-disbursal-settlement-omnibus-account";
+
+pub const CREDIT_PARTICIPATION_PRINCIPAL_OMNIBUS_ACCOUNT_SET_NAME: &str =
+    "Credit Participation Principal Omnibus Account Set";
+pub const CREDIT_PARTICIPATION_PRINCIPAL_OMNIBUS_ACCOUNT_SET_REF: &str =
+    "credit-participation-principal-omnibus-account-set";
+pub const CREDIT_PARTICIPATION_PRINCIPAL_OMNIBUS_ACCOUNT_REF: &str =
+    "credit-participation-principal-omnibus-account";
+
 // Summary Accounts
 pub const CREDIT_FACILITY_REMAINING_ACCOUNT_SET_NAME: &str =
     "Credit Facility Remaining Account Set";
@@ -188,6 +202,11 @@ pub const CREDIT_INTEREST_INCOME_ACCOUNT_SET_REF: &str = "credit-interest-income
 pub const CREDIT_FEE_INCOME_ACCOUNT_SET_NAME: &str = "Credit Fee Income Account Set";
 pub const CREDIT_FEE_INCOME_ACCOUNT_SET_REF: &str = "credit-fee-income-account-set";
 
+pub const CREDIT_PARTICIPATION_PAYABLE_ACCOUNT_SET_NAME: &str =
+    "Credit Participation Payable Account Set";
+pub const CREDIT_PARTICIPATION_PAYABLE_ACCOUNT_SET_REF: &str =
+    "credit-participation-payable-account-set";
+
 // Velocity Controls
 pub(super) const CREDIT_FACILITY_VELOCITY_CONTROL_ID: uuid::Uuid =
     uuid::uuid!("00000000-0000-0000-0000-000000000002");
@@ -88,5 +88,16 @@ pub struct CreditFacilityInterestAccrualCycle {
     pub tx_ref: String,
     pub interest: UsdCents,
     pub effective: chrono::NaiveDate,
+    pub capitalized: bool,
+    pub credit_facility_account_ids: CreditFacilityAccountIds,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreditFacilityInterestAccrualAdjustment {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub amount: UsdCents,
+    pub effective: chrono::NaiveDate,
+    pub posted_receivable_account_id: Option<CalaAccountId>,
     pub credit_facility_account_ids: CreditFacilityAccountIds,
 }
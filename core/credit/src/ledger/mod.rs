@@ -12,22 +12,25 @@ mod templates;
 mod velocity;
 
 use cala_ledger::{
-    CalaLedger, Currency, DebitOrCredit, JournalId, LedgerOperation, TransactionId,
+    BalanceId, CalaLedger, Currency, DebitOrCredit, JournalId, LedgerOperation, TransactionId,
     account::NewAccount,
     account_set::{AccountSet, AccountSetMemberId, AccountSetUpdate, NewAccountSet},
+    balance::AccountBalance,
     velocity::{NewVelocityControl, VelocityControlId},
 };
 
 use crate::{
     ChartOfAccountsIntegrationConfig, FacilityDurationType, Obligation,
     ObligationDefaultedReallocationData, ObligationDueReallocationData,
-    ObligationOverdueReallocationData,
+    ObligationOverdueReallocationData, ObligationReinstatementData,
     liquidation_process::LiquidationProcess,
+    participation::{Participation, ParticipationShareAllocation},
     payment_allocation::PaymentAllocation,
     primitives::{
         CalaAccountId, CalaAccountSetId, CollateralAction, CollateralUpdate, CreditFacilityId,
         CustomerType, DisbursedReceivableAccountCategory, DisbursedReceivableAccountType,
-        InterestReceivableAccountType, LedgerOmnibusAccountIds, LedgerTxId, Satoshis, UsdCents,
+        InterestReceivableAccountType, LedgerOmnibusAccountIds, LedgerTxId, ParticipationShareType,
+        Satoshis, UsdCents,
     },
 };
 
@@ -116,6 +119,7 @@ pub struct CreditFacilityInternalAccountSets {
     pub interest_defaulted: InternalAccountSetDetails,
     pub interest_income: InternalAccountSetDetails,
     pub fee_income: InternalAccountSetDetails,
+    pub participation_payable: InternalAccountSetDetails,
 }
 
 impl CreditFacilityInternalAccountSets {
@@ -126,6 +130,7 @@ impl CreditFacilityInternalAccountSets {
             in_liquidation,
             interest_income,
             fee_income,
+            participation_payable,
 
             disbursed_receivable:
                 DisbursedReceivable {
@@ -148,6 +153,7 @@ impl CreditFacilityInternalAccountSets {
             in_liquidation.id,
             interest_income.id,
             fee_income.id,
+            participation_payable.id,
             disbursed_defaulted.id,
             interest_defaulted.id,
         ];
@@ -172,6 +178,8 @@ pub struct CreditLedger {
     facility_omnibus_account_ids: LedgerOmnibusAccountIds,
     collateral_omnibus_account_ids: LedgerOmnibusAccountIds,
     in_liquidation_omnibus_account_ids: LedgerOmnibusAccountIds,
+    disbursal_settlement_omnibus_account_ids: LedgerOmnibusAccountIds,
+    participation_principal_omnibus_account_ids: LedgerOmnibusAccountIds,
     internal_account_sets: CreditFacilityInternalAccountSets,
     credit_facility_control_id: VelocityControlId,
     usd: Currency,
@@ -188,12 +196,18 @@ impl CreditLedger {
         templates::RecordObligationDueBalance::init(cala).await?;
         templates::RecordObligationOverdueBalance::init(cala).await?;
         templates::RecordObligationDefaultedBalance::init(cala).await?;
+        templates::RecordObligationReinstatedBalance::init(cala).await?;
         templates::CreditFacilityAccrueInterest::init(cala).await?;
         templates::CreditFacilityPostAccruedInterest::init(cala).await?;
+        templates::CreditFacilityAdjustAccruedInterest::init(cala).await?;
+        templates::CreditFacilityAdjustPostedInterest::init(cala).await?;
+        templates::CreditFacilityCapitalizeAccruedInterest::init(cala).await?;
         templates::InitiateDisbursal::init(cala).await?;
         templates::CancelDisbursal::init(cala).await?;
         templates::ConfirmDisbursal::init(cala).await?;
         templates::ReserveForLiquidation::init(cala).await?;
+        templates::RecordParticipationInterestShare::init(cala).await?;
+        templates::RecordParticipationPrincipalShare::init(cala).await?;
 
         let collateral_omnibus_normal_balance_type = DebitOrCredit::Debit;
         let collateral_omnibus_account_ids = Self::find_or_create_omnibus_account(
@@ -228,6 +242,28 @@ impl CreditLedger {
         )
         .await?;
 
+        let disbursal_settlement_omnibus_normal_balance_type = DebitOrCredit::Debit;
+        let disbursal_settlement_omnibus_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_DISBURSAL_SETTLEMENT_OMNIBUS_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{CREDIT_DISBURSAL_SETTLEMENT_OMNIBUS_ACCOUNT_REF}"),
+            CREDIT_DISBURSAL_SETTLEMENT_OMNIBUS_ACCOUNT_SET_NAME.to_string(),
+            disbursal_settlement_omnibus_normal_balance_type,
+        )
+        .await?;
+
+        let participation_principal_omnibus_normal_balance_type = DebitOrCredit::Debit;
+        let participation_principal_omnibus_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_PARTICIPATION_PRINCIPAL_OMNIBUS_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{CREDIT_PARTICIPATION_PRINCIPAL_OMNIBUS_ACCOUNT_REF}"),
+            CREDIT_PARTICIPATION_PRINCIPAL_OMNIBUS_ACCOUNT_SET_NAME.to_string(),
+            participation_principal_omnibus_normal_balance_type,
+        )
+        .await?;
+
         let facility_normal_balance_type = DebitOrCredit::Credit;
         let facility_account_set_id = Self::find_or_create_account_set(
             cala,
@@ -610,6 +646,16 @@ impl CreditLedger {
         )
         .await?;
 
+        let participation_payable_normal_balance_type = DebitOrCredit::Credit;
+        let participation_payable_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_PARTICIPATION_PAYABLE_ACCOUNT_SET_REF}"),
+            CREDIT_PARTICIPATION_PAYABLE_ACCOUNT_SET_NAME.to_string(),
+            participation_payable_normal_balance_type,
+        )
+        .await?;
+
         let disbursed_receivable = DisbursedReceivable {
             short_term: DisbursedReceivableAccountSets {
                 individual: InternalAccountSetDetails {
@@ -797,6 +843,10 @@ impl CreditLedger {
                 id: fee_income_account_set_id,
                 normal_balance_type: fee_income_normal_balance_type,
             },
+            participation_payable: InternalAccountSetDetails {
+                id: participation_payable_account_set_id,
+                normal_balance_type: participation_payable_normal_balance_type,
+            },
         };
 
         let disbursal_limit_id = velocity::DisbursalLimit::init(cala).await?;
@@ -819,6 +869,8 @@ impl CreditLedger {
             facility_omnibus_account_ids,
             collateral_omnibus_account_ids,
             in_liquidation_omnibus_account_ids,
+            disbursal_settlement_omnibus_account_ids,
+            participation_principal_omnibus_account_ids,
             internal_account_sets,
             credit_facility_control_id,
             usd: Currency::USD,
@@ -943,6 +995,86 @@ impl CreditLedger {
     }
 
     pub async fn get_credit_facility_balance(
+        &self,
+        account_ids: CreditFacilityAccountIds,
+    ) -> Result<CreditFacilityBalanceSummary, CreditLedgerError> {
+        let balances = self
+            .cala
+            .balances()
+            .find_all(&self.credit_facility_balance_ids(account_ids))
+            .await?;
+        self.credit_facility_balance_summary(account_ids, balances)
+    }
+
+    pub async fn get_credit_facility_balance_as_of(
+        &self,
+        account_ids: CreditFacilityAccountIds,
+        as_of: chrono::NaiveDate,
+    ) -> Result<CreditFacilityBalanceSummary, CreditLedgerError> {
+        let balances = self
+            .cala
+            .balances()
+            .effective()
+            .find_all_in_range(
+                &self.credit_facility_balance_ids(account_ids),
+                as_of,
+                Some(as_of),
+            )
+            .await?
+            .into_iter()
+            .map(|(id, range)| (id, range.close))
+            .collect();
+        self.credit_facility_balance_summary(account_ids, balances)
+    }
+
+    fn credit_facility_balance_ids(&self, account_ids: CreditFacilityAccountIds) -> Vec<BalanceId> {
+        vec![
+            (self.journal_id, account_ids.facility_account_id, self.usd),
+            (self.journal_id, account_ids.collateral_account_id, self.btc),
+            (
+                self.journal_id,
+                account_ids.disbursed_receivable_not_yet_due_account_id,
+                self.usd,
+            ),
+            (
+                self.journal_id,
+                account_ids.disbursed_receivable_due_account_id,
+                self.usd,
+            ),
+            (
+                self.journal_id,
+                account_ids.disbursed_receivable_overdue_account_id,
+                self.usd,
+            ),
+            (
+                self.journal_id,
+                account_ids.disbursed_defaulted_account_id,
+                self.usd,
+            ),
+            (
+                self.journal_id,
+                account_ids.interest_receivable_not_yet_due_account_id,
+                self.usd,
+            ),
+            (
+                self.journal_id,
+                account_ids.interest_receivable_due_account_id,
+                self.usd,
+            ),
+            (
+                self.journal_id,
+                account_ids.interest_receivable_overdue_account_id,
+                self.usd,
+            ),
+            (
+                self.journal_id,
+                account_ids.interest_defaulted_account_id,
+                self.usd,
+            ),
+        ]
+    }
+
+    fn credit_facility_balance_summary(
         &self,
         CreditFacilityAccountIds {
             facility_account_id,
@@ -961,6 +1093,7 @@ impl CreditLedger {
             fee_income_account_id: _,
             interest_income_account_id: _,
         }: CreditFacilityAccountIds,
+        balances: HashMap<BalanceId, AccountBalance>,
     ) -> Result<CreditFacilityBalanceSummary, CreditLedgerError> {
         let facility_id = (self.journal_id, facility_account_id, self.usd);
         let collateral_id = (self.journal_id, collateral_account_id, self.btc);
@@ -996,22 +1129,6 @@ impl CreditLedger {
             self.usd,
         );
         let interest_defaulted_id = (self.journal_id, interest_defaulted_account_id, self.usd);
-        let balances = self
-            .cala
-            .balances()
-            .find_all(&[
-                facility_id,
-                collateral_id,
-                disbursed_receivable_not_yet_due_id,
-                disbursed_receivable_due_id,
-                disbursed_receivable_overdue_id,
-                disbursed_defaulted_id,
-                interest_receivable_not_yet_due_id,
-                interest_receivable_due_id,
-                interest_receivable_overdue_id,
-                interest_defaulted_id,
-            ])
-            .await?;
         let facility = if let Some(b) = balances.get(&facility_id) {
             UsdCents::try_from_usd(b.details.pending.cr_balance)?
         } else {
@@ -1195,10 +1312,67 @@ impl CreditLedger {
         Ok(())
     }
 
+    async fn record_interest_accrual_adjustment_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        CreditFacilityInterestAccrualAdjustment {
+            tx_id,
+            tx_ref,
+            amount,
+            effective,
+            posted_receivable_account_id,
+            credit_facility_account_ids,
+        }: CreditFacilityInterestAccrualAdjustment,
+    ) -> Result<(), CreditLedgerError> {
+        match posted_receivable_account_id {
+            Some(receivable_account) => {
+                self.cala
+                    .post_transaction_in_op(
+                        op,
+                        tx_id,
+                        templates::CREDIT_FACILITY_ADJUST_POSTED_INTEREST_CODE,
+                        templates::CreditFacilityAdjustPostedInterestParams {
+                            journal_id: self.journal_id,
+                            receivable_account,
+                            credit_facility_interest_income_account: credit_facility_account_ids
+                                .interest_income_account_id,
+                            interest_amount: amount.to_usd(),
+                            external_id: tx_ref,
+                            effective,
+                        },
+                    )
+                    .await?;
+            }
+            None => {
+                self.cala
+                    .post_transaction_in_op(
+                        op,
+                        tx_id,
+                        templates::CREDIT_FACILITY_ADJUST_ACCRUED_INTEREST_CODE,
+                        templates::CreditFacilityAdjustAccruedInterestParams {
+                            journal_id: self.journal_id,
+                            credit_facility_interest_receivable_account:
+                                credit_facility_account_ids
+                                    .interest_receivable_not_yet_due_account_id,
+                            credit_facility_interest_income_account: credit_facility_account_ids
+                                .interest_income_account_id,
+                            interest_amount: amount.to_usd(),
+                            external_id: tx_ref,
+                            effective,
+                        },
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub async fn record_obligation_repayments(
         &self,
         op: es_entity::DbOp<'_>,
         payments: Vec<PaymentAllocation>,
+        interest_adjustments: Vec<CreditFacilityInterestAccrualAdjustment>,
     ) -> Result<(), CreditLedgerError> {
         let mut op = self.cala.ledger_operation_from_db_op(op);
 
@@ -1207,6 +1381,11 @@ impl CreditLedger {
                 .await?;
         }
 
+        for adjustment in interest_adjustments {
+            self.record_interest_accrual_adjustment_in_op(&mut op, adjustment)
+                .await?;
+        }
+
         op.commit().await?;
         Ok(())
     }
@@ -1304,6 +1483,39 @@ impl CreditLedger {
         Ok(())
     }
 
+    pub async fn record_obligations_reinstated(
+        &self,
+        op: es_entity::DbOp<'_>,
+        reinstatements: Vec<ObligationReinstatementData>,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        for ObligationReinstatementData {
+            tx_id,
+            amount,
+            defaulted_account_id,
+            receivable_account_id,
+            effective,
+        } in reinstatements
+        {
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    tx_id,
+                    templates::RECORD_OBLIGATION_REINSTATED_BALANCE_CODE,
+                    templates::RecordObligationReinstatedBalanceParams {
+                        journal_id: self.journal_id,
+                        amount: amount.to_usd(),
+                        receivable_account_id,
+                        defaulted_account_id,
+                        effective,
+                    },
+                )
+                .await?;
+        }
+        op.commit().await?;
+        Ok(())
+    }
+
     pub async fn reserve_for_liquidation(
         &self,
         op: es_entity::DbOp<'_>,
@@ -1473,28 +1685,52 @@ impl CreditLedger {
             tx_ref,
             interest,
             effective,
+            capitalized,
             credit_facility_account_ids,
         }: CreditFacilityInterestAccrualCycle,
     ) -> Result<(), CreditLedgerError> {
         let mut op = self.cala.ledger_operation_from_db_op(op);
-        self.cala
-            .post_transaction_in_op(
-                &mut op,
-                tx_id,
-                templates::CREDIT_FACILITY_POST_ACCRUED_INTEREST_CODE,
-                templates::CreditFacilityPostAccruedInterestParams {
-                    journal_id: self.journal_id,
-
-                    credit_facility_interest_receivable_account: credit_facility_account_ids
-                        .interest_receivable_not_yet_due_account_id,
-                    credit_facility_interest_income_account: credit_facility_account_ids
-                        .interest_income_account_id,
-                    interest_amount: interest.to_usd(),
-                    external_id: tx_ref,
-                    effective,
-                },
-            )
-            .await?;
+        if capitalized {
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    tx_id,
+                    templates::CREDIT_FACILITY_CAPITALIZE_ACCRUED_INTEREST_CODE,
+                    templates::CreditFacilityCapitalizeAccruedInterestParams {
+                        journal_id: self.journal_id,
+
+                        credit_facility_interest_receivable_account: credit_facility_account_ids
+                            .interest_receivable_not_yet_due_account_id,
+                        credit_facility_interest_income_account: credit_facility_account_ids
+                            .interest_income_account_id,
+                        credit_facility_disbursed_receivable_account: credit_facility_account_ids
+                            .disbursed_receivable_not_yet_due_account_id,
+                        interest_amount: interest.to_usd(),
+                        external_id: tx_ref,
+                        effective,
+                    },
+                )
+                .await?;
+        } else {
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    tx_id,
+                    templates::CREDIT_FACILITY_POST_ACCRUED_INTEREST_CODE,
+                    templates::CreditFacilityPostAccruedInterestParams {
+                        journal_id: self.journal_id,
+
+                        credit_facility_interest_receivable_account: credit_facility_account_ids
+                            .interest_receivable_not_yet_due_account_id,
+                        credit_facility_interest_income_account: credit_facility_account_ids
+                            .interest_income_account_id,
+                        interest_amount: interest.to_usd(),
+                        external_id: tx_ref,
+                        effective,
+                    },
+                )
+                .await?;
+        }
         op.commit().await?;
         Ok(())
     }
@@ -1549,6 +1785,10 @@ impl CreditLedger {
         Ok(())
     }
 
+    pub fn disbursal_settlement_omnibus_account_id(&self) -> CalaAccountId {
+        self.disbursal_settlement_omnibus_account_ids.account_id
+    }
+
     pub async fn settle_disbursal(
         &self,
         op: es_entity::DbOp<'_>,
@@ -1586,6 +1826,85 @@ impl CreditLedger {
         Ok(())
     }
 
+    pub async fn create_participation_account(
+        &self,
+        op: es_entity::DbOp<'_>,
+        participation: &Participation,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        let reference = &format!("credit-participation-payable:{}", participation.id);
+        let name = &format!(
+            "Participation Payable Account for {} on Credit Facility {}",
+            participation.partner_name, participation.credit_facility_id
+        );
+        self.create_account_in_op(
+            &mut op,
+            participation.payable_account_id,
+            self.internal_account_sets.participation_payable,
+            reference,
+            name,
+            name,
+        )
+        .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn record_participation_share(
+        &self,
+        op: es_entity::DbOp<'_>,
+        ParticipationShareAllocation {
+            tx_id,
+            share_type,
+            amount,
+            partner_payable_account_id,
+            effective,
+        }: ParticipationShareAllocation,
+        interest_income_account_id: CalaAccountId,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        match share_type {
+            ParticipationShareType::Interest => {
+                self.cala
+                    .post_transaction_in_op(
+                        &mut op,
+                        tx_id,
+                        templates::RECORD_PARTICIPATION_INTEREST_SHARE_CODE,
+                        templates::RecordParticipationInterestShareParams {
+                            journal_id: self.journal_id,
+                            amount: amount.to_usd(),
+                            interest_income_account_id,
+                            partner_payable_account_id,
+                            effective,
+                        },
+                    )
+                    .await?;
+            }
+            ParticipationShareType::Principal => {
+                self.cala
+                    .post_transaction_in_op(
+                        &mut op,
+                        tx_id,
+                        templates::RECORD_PARTICIPATION_PRINCIPAL_SHARE_CODE,
+                        templates::RecordParticipationPrincipalShareParams {
+                            journal_id: self.journal_id,
+                            amount: amount.to_usd(),
+                            participation_principal_omnibus_account_id: self
+                                .participation_principal_omnibus_account_ids
+                                .account_id,
+                            partner_payable_account_id,
+                            effective,
+                        },
+                    )
+                    .await?;
+            }
+        }
+        op.commit().await?;
+        Ok(())
+    }
+
     pub async fn create_credit_facility_control(
         cala: &CalaLedger,
     ) -> Result<VelocityControlId, CreditLedgerError> {
@@ -2032,6 +2351,8 @@ impl CreditLedger {
             facility_omnibus_account_ids,
             collateral_omnibus_account_ids,
             in_liquidation_omnibus_account_ids,
+            disbursal_settlement_omnibus_account_ids,
+            participation_principal_omnibus_account_ids,
             internal_account_sets,
 
             cala: _,
@@ -2045,6 +2366,8 @@ impl CreditLedger {
             facility_omnibus_account_ids.account_set_id,
             collateral_omnibus_account_ids.account_set_id,
             in_liquidation_omnibus_account_ids.account_set_id,
+            disbursal_settlement_omnibus_account_ids.account_set_id,
+            participation_principal_omnibus_account_ids.account_set_id,
         ];
         account_set_ids.extend(internal_account_sets.account_set_ids());
         let mut account_sets = self
@@ -2060,11 +2383,14 @@ impl CreditLedger {
             facility_omnibus_parent_account_set_id,
             collateral_omnibus_parent_account_set_id,
             in_liquidation_omnibus_parent_account_set_id,
+            disbursal_settlement_omnibus_parent_account_set_id,
+            participation_principal_omnibus_parent_account_set_id,
             facility_parent_account_set_id,
             collateral_parent_account_set_id,
             in_liquidation_parent_account_set_id,
             interest_income_parent_account_set_id,
             fee_income_parent_account_set_id,
+            participation_payable_parent_account_set_id,
             short_term_disbursed_integration_meta,
             long_term_disbursed_integration_meta,
             short_term_interest_integration_meta,
@@ -2102,6 +2428,27 @@ impl CreditLedger {
         )
         .await?;
 
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.disbursal_settlement_omnibus_account_ids.account_set_id,
+            *disbursal_settlement_omnibus_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.disbursal_settlement_omnibus_parent_account_set_id,
+        )
+        .await?;
+
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.participation_principal_omnibus_account_ids
+                .account_set_id,
+            *participation_principal_omnibus_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.participation_principal_omnibus_parent_account_set_id,
+        )
+        .await?;
+
         self.attach_charts_account_set(
             &mut op,
             &mut account_sets,
@@ -2148,6 +2495,16 @@ impl CreditLedger {
         )
         .await?;
 
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.internal_account_sets.participation_payable.id,
+            *participation_payable_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.participation_payable_parent_account_set_id,
+        )
+        .await?;
+
         self.attach_short_term_disbursed_receivable_account_sets(
             &mut op,
             short_term_disbursed_integration_meta,
@@ -2825,11 +3182,14 @@ pub struct ChartOfAccountsIntegrationMeta {
     pub facility_omnibus_parent_account_set_id: CalaAccountSetId,
     pub collateral_omnibus_parent_account_set_id: CalaAccountSetId,
     pub in_liquidation_omnibus_parent_account_set_id: CalaAccountSetId,
+    pub disbursal_settlement_omnibus_parent_account_set_id: CalaAccountSetId,
+    pub participation_principal_omnibus_parent_account_set_id: CalaAccountSetId,
     pub facility_parent_account_set_id: CalaAccountSetId,
     pub collateral_parent_account_set_id: CalaAccountSetId,
     pub in_liquidation_parent_account_set_id: CalaAccountSetId,
     pub interest_income_parent_account_set_id: CalaAccountSetId,
     pub fee_income_parent_account_set_id: CalaAccountSetId,
+    pub participation_payable_parent_account_set_id: CalaAccountSetId,
 
     pub short_term_disbursed_integration_meta: ShortTermDisbursedIntegrationMeta,
     pub long_term_disbursed_integration_meta: LongTermDisbursedIntegrationMeta,
@@ -1,29 +1,41 @@
 mod accrue_interest;
 mod activate_credit_facility;
 mod add_collateral;
+mod adjust_accrued_interest;
+mod adjust_posted_interest;
 mod cancel_disbursal;
+mod capitalize_accrued_interest;
 mod confirm_disbursal;
 mod create_credit_facility;
 mod initiate_disbursal;
 mod obligation_defaulted_balance;
 mod obligation_due_balance;
 mod obligation_overdue_balance;
+mod obligation_reinstated_balance;
 mod payment_allocation;
 mod post_accrued_interest;
+mod record_participation_interest_share;
+mod record_participation_principal_share;
 mod remove_collateral;
 mod reserve_for_liquidation;
 
 pub use accrue_interest::*;
 pub use activate_credit_facility::*;
 pub use add_collateral::*;
+pub use adjust_accrued_interest::*;
+pub use adjust_posted_interest::*;
 pub use cancel_disbursal::*;
+pub use capitalize_accrued_interest::*;
 pub use confirm_disbursal::*;
 pub use create_credit_facility::*;
 pub use initiate_disbursal::*;
 pub use obligation_defaulted_balance::*;
 pub use obligation_due_balance::*;
 pub use obligation_overdue_balance::*;
+pub use obligation_reinstated_balance::*;
 pub use payment_allocation::*;
 pub use post_accrued_interest::*;
+pub use record_participation_interest_share::*;
+pub use record_participation_principal_share::*;
 pub use remove_collateral::*;
 pub use reserve_for_liquidation::*;
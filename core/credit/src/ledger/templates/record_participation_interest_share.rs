@@ -0,0 +1,121 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const RECORD_PARTICIPATION_INTEREST_SHARE_CODE: &str = "RECORD_PARTICIPATION_INTEREST_SHARE";
+
+#[derive(Debug)]
+pub struct RecordParticipationInterestShareParams {
+    pub journal_id: JournalId,
+    pub amount: Decimal,
+    pub interest_income_account_id: CalaAccountId,
+    pub partner_payable_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
+impl RecordParticipationInterestShareParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("interest_income_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("partner_payable_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+impl From<RecordParticipationInterestShareParams> for Params {
+    fn from(
+        RecordParticipationInterestShareParams {
+            journal_id,
+            amount,
+            interest_income_account_id,
+            partner_payable_account_id,
+            effective,
+        }: RecordParticipationInterestShareParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("amount", amount);
+        params.insert("interest_income_account_id", interest_income_account_id);
+        params.insert("partner_payable_account_id", partner_payable_account_id);
+        params.insert("effective", effective);
+
+        params
+    }
+}
+
+pub struct RecordParticipationInterestShare;
+
+impl RecordParticipationInterestShare {
+    #[instrument(name = "ledger.record_participation_interest_share.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .description("'Record a participation partner share of interest income'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'RECORD_PARTICIPATION_INTEREST_SHARE_DR'")
+                .currency("'USD'")
+                .account_id("params.interest_income_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'RECORD_PARTICIPATION_INTEREST_SHARE_CR'")
+                .currency("'USD'")
+                .account_id("params.partner_payable_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = RecordParticipationInterestShareParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(RECORD_PARTICIPATION_INTEREST_SHARE_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
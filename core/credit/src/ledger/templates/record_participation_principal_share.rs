@@ -0,0 +1,124 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const RECORD_PARTICIPATION_PRINCIPAL_SHARE_CODE: &str = "RECORD_PARTICIPATION_PRINCIPAL_SHARE";
+
+#[derive(Debug)]
+pub struct RecordParticipationPrincipalShareParams {
+    pub journal_id: JournalId,
+    pub amount: Decimal,
+    pub participation_principal_omnibus_account_id: CalaAccountId,
+    pub partner_payable_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
+impl RecordParticipationPrincipalShareParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("participation_principal_omnibus_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("partner_payable_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+impl From<RecordParticipationPrincipalShareParams> for Params {
+    fn from(
+        RecordParticipationPrincipalShareParams {
+            journal_id,
+            amount,
+            participation_principal_omnibus_account_id,
+            partner_payable_account_id,
+            effective,
+        }: RecordParticipationPrincipalShareParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("amount", amount);
+        params.insert(
+            "participation_principal_omnibus_account_id",
+            participation_principal_omnibus_account_id,
+        );
+        params.insert("partner_payable_account_id", partner_payable_account_id);
+        params.insert("effective", effective);
+
+        params
+    }
+}
+
+pub struct RecordParticipationPrincipalShare;
+
+impl RecordParticipationPrincipalShare {
+    #[instrument(name = "ledger.record_participation_principal_share.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .description("'Record a participation partner share of principal repaid'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'RECORD_PARTICIPATION_PRINCIPAL_SHARE_DR'")
+                .currency("'USD'")
+                .account_id("params.participation_principal_omnibus_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'RECORD_PARTICIPATION_PRINCIPAL_SHARE_CR'")
+                .currency("'USD'")
+                .account_id("params.partner_payable_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = RecordParticipationPrincipalShareParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(RECORD_PARTICIPATION_PRINCIPAL_SHARE_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
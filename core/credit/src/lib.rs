@@ -9,6 +9,7 @@ mod chart_of_accounts_integration;
 mod collateral;
 mod config;
 mod credit_facility;
+mod credit_facility_proposal;
 mod disbursal;
 pub mod error;
 mod event;
@@ -18,16 +19,22 @@ mod interest_accrual_cycle;
 mod jobs;
 pub mod ledger;
 mod liquidation_process;
+pub mod loan_tape;
 mod obligation;
+mod participation;
 mod payment;
 mod payment_allocation;
+mod portfolio_aging;
 mod primitives;
 mod processes;
 mod publisher;
 mod repayment_plan;
+mod simulation;
+mod stress_test;
 mod terms;
 mod terms_template;
 mod time;
+mod underwriting;
 
 use audit::{AuditInfo, AuditSvc};
 use authz::PermissionCheck;
@@ -37,6 +44,7 @@ use core_custody::{
 };
 use core_customer::{CoreCustomerAction, CoreCustomerEvent, CustomerObject, Customers};
 use core_price::Price;
+use document_storage::DocumentStorage;
 use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject};
 use job::Jobs;
 use outbox::{Outbox, OutboxEventMarker};
@@ -51,6 +59,10 @@ pub use collateral::*;
 pub use config::*;
 pub use credit_facility::error::CreditFacilityError;
 pub use credit_facility::*;
+pub use credit_facility_proposal::{
+    CreditFacilityProposal, CreditFacilityProposals, credit_facility_proposal_cursor::*,
+    error::CreditFacilityProposalError,
+};
 pub use disbursal::{disbursal_cursor::*, *};
 use error::*;
 pub use event::*;
@@ -59,25 +71,37 @@ pub use history::*;
 pub use interest_accrual_cycle::*;
 use jobs::*;
 pub use ledger::*;
+pub use loan_tape::{LoanTape, LoanTapeFormat, LoanTapes, error::LoanTapeError};
 pub use obligation::{error::*, obligation_cursor::*, *};
+pub use participation::{
+    Participation, ParticipationShareAllocation, ParticipationStatementEntry, Participations,
+    error::ParticipationError,
+};
 pub use payment::*;
 pub use payment_allocation::*;
+pub use portfolio_aging::*;
 pub use primitives::*;
 use processes::activate_credit_facility::*;
 pub use processes::approve_credit_facility::*;
 pub use processes::approve_disbursal::*;
+pub use processes::approve_obligation_reinstatement::*;
 use publisher::CreditFacilityPublisher;
 pub use repayment_plan::*;
+pub use simulation::{error::TermsSimulationError, *};
+pub use stress_test::{error::PriceShockStressTestError, *};
 pub use terms::*;
 pub use terms_template::{error as terms_template_error, *};
+pub use underwriting::*;
 
 #[cfg(feature = "json-schema")]
 pub mod event_schema {
     pub use crate::{
         TermsTemplateEvent, collateral::CollateralEvent, credit_facility::CreditFacilityEvent,
-        disbursal::DisbursalEvent, interest_accrual_cycle::InterestAccrualCycleEvent,
+        credit_facility_proposal::CreditFacilityProposalEvent, disbursal::DisbursalEvent,
+        interest_accrual_cycle::InterestAccrualCycleEvent,
         liquidation_process::LiquidationProcessEvent, obligation::ObligationEvent,
-        payment::PaymentEvent, payment_allocation::PaymentAllocationEvent,
+        participation::ParticipationEvent, payment::PaymentEvent,
+        payment_allocation::PaymentAllocationEvent,
     };
 }
 
@@ -108,6 +132,9 @@ where
     custody: CoreCustody<Perms, E>,
     chart_of_accounts_integrations: ChartOfAccountsIntegrations<Perms>,
     terms_templates: TermsTemplates<Perms>,
+    loan_tapes: LoanTapes<Perms, E>,
+    proposals: CreditFacilityProposals<Perms, E>,
+    participations: Participations<Perms>,
     public_ids: PublicIds,
 }
 
@@ -140,6 +167,9 @@ where
             approve_credit_facility: self.approve_credit_facility.clone(),
             chart_of_accounts_integrations: self.chart_of_accounts_integrations.clone(),
             terms_templates: self.terms_templates.clone(),
+            loan_tapes: self.loan_tapes.clone(),
+            proposals: self.proposals.clone(),
+            participations: self.participations.clone(),
             public_ids: self.public_ids.clone(),
         }
     }
@@ -175,6 +205,7 @@ where
         cala: &CalaLedger,
         journal_id: cala_ledger::JournalId,
         public_ids: &PublicIds,
+        document_storage: DocumentStorage,
     ) -> Result<Self, CoreCreditError> {
         let publisher = CreditFacilityPublisher::new(outbox);
         let ledger = CreditLedger::init(cala, journal_id).await?;
@@ -199,6 +230,11 @@ where
 
         let approve_credit_facility =
             ApproveCreditFacility::new(&credit_facilities, authz.audit(), governance);
+        let _ = governance
+            .init_policy(APPROVE_OBLIGATION_REINSTATEMENT_PROCESS)
+            .await;
+        let approve_obligation_reinstatement =
+            ApproveObligationReinstatement::new(&obligations, &ledger);
         let activate_credit_facility = ActivateCreditFacility::new(
             &credit_facilities,
             &disbursals,
@@ -210,6 +246,17 @@ where
         );
         let chart_of_accounts_integrations = ChartOfAccountsIntegrations::new(authz, &ledger);
         let terms_templates = TermsTemplates::new(pool, authz);
+        let proposals = CreditFacilityProposals::new(pool, authz, &publisher);
+        let participations = Participations::new(pool, authz);
+        let loan_tapes = LoanTapes::new(
+            authz,
+            jobs,
+            document_storage,
+            &credit_facilities,
+            &obligations,
+            &ledger,
+            &history_repo,
+        );
 
         jobs
             .add_initializer_and_spawn_unique(
@@ -224,6 +271,16 @@ where
                 },
             )
             .await?;
+        jobs.add_initializer_and_spawn_unique(
+            credit_facility_days_past_due::CreditFacilityDaysPastDueInit::<Perms, E>::new(
+                outbox,
+                &credit_facilities,
+            ),
+            credit_facility_days_past_due::CreditFacilityDaysPastDueJobConfig {
+                _phantom: std::marker::PhantomData,
+            },
+        )
+        .await?;
         jobs
             .add_initializer_and_spawn_unique(
                 collateralization_from_events::CreditFacilityCollateralizationFromEventsInit::<
@@ -253,6 +310,18 @@ where
             },
         )
         .await?;
+        jobs.add_initializer_and_spawn_unique(
+            participation_shares::ParticipationSharesInit::<Perms, E>::new(
+                outbox,
+                &credit_facilities,
+                &participations,
+                &ledger,
+            ),
+            participation_shares::ParticipationSharesJobConfig {
+                _phantom: std::marker::PhantomData,
+            },
+        )
+        .await?;
         jobs.add_initializer(interest_accruals::InterestAccrualInit::<Perms, E>::new(
             &ledger,
             &credit_facilities,
@@ -297,6 +366,11 @@ where
             DisbursalApprovalJobConfig::<Perms, E>::new(),
         )
         .await?;
+        jobs.add_initializer_and_spawn_unique(
+            ObligationReinstatementApprovalInit::new(outbox, &approve_obligation_reinstatement),
+            ObligationReinstatementApprovalJobConfig::<Perms, E>::new(),
+        )
+        .await?;
         jobs.add_initializer_and_spawn_unique(
             CreditFacilityActivationInit::new(outbox, &activate_credit_facility),
             CreditFacilityActivationJobConfig::<Perms, E>::new(),
@@ -333,6 +407,9 @@ where
             approve_credit_facility,
             chart_of_accounts_integrations,
             terms_templates,
+            loan_tapes,
+            proposals,
+            participations,
             public_ids: public_ids.clone(),
         })
     }
@@ -365,6 +442,18 @@ where
         &self.terms_templates
     }
 
+    pub fn loan_tapes(&self) -> &LoanTapes<Perms, E> {
+        &self.loan_tapes
+    }
+
+    pub fn proposals(&self) -> &CreditFacilityProposals<Perms, E> {
+        &self.proposals
+    }
+
+    pub fn participations(&self) -> &Participations<Perms> {
+        &self.participations
+    }
+
     pub async fn subject_can_create(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -400,6 +489,8 @@ where
             &self.history_repo,
             &self.repayment_plan_repo,
             &self.ledger,
+            &self.terms_templates,
+            &self.proposals,
         ))
     }
 
@@ -418,12 +509,138 @@ where
             .await?
             .expect("audit info missing");
 
+        self.create_facility_with_audit(
+            sub,
+            audit_info,
+            customer_id.into(),
+            disbursal_credit_account_id.into(),
+            amount,
+            terms,
+            None,
+            custodian_id.map(Into::into),
+            None,
+        )
+        .await
+    }
+
+    #[instrument(name = "credit.create_facility_from_terms_template", skip(self), err)]
+    pub async fn create_facility_from_terms_template(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        customer_id: impl Into<CustomerId> + std::fmt::Debug + Copy,
+        disbursal_credit_account_id: impl Into<CalaAccountId> + std::fmt::Debug,
+        amount: UsdCents,
+        terms_template_id: impl Into<TermsTemplateId> + std::fmt::Debug,
+        custodian_id: Option<impl Into<CustodianId> + std::fmt::Debug + Copy>,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let audit_info = self
+            .subject_can_create(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let terms_template = self
+            .terms_templates
+            .find_by_id_without_audit(terms_template_id)
+            .await?;
+        let terms_template_ref = terms_template.current_version_ref()?;
+
+        self.create_facility_with_audit(
+            sub,
+            audit_info,
+            customer_id.into(),
+            disbursal_credit_account_id.into(),
+            amount,
+            terms_template.values,
+            Some(terms_template_ref),
+            custodian_id.map(Into::into),
+            None,
+        )
+        .await
+    }
+
+    #[instrument(name = "credit.convert_facility_proposal", skip(self), err)]
+    pub async fn convert_facility_proposal(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        proposal_id: impl Into<CreditFacilityProposalId> + std::fmt::Debug,
+        disbursal_credit_account_id: impl Into<CalaAccountId> + std::fmt::Debug,
+        custodian_id: Option<impl Into<CustodianId> + std::fmt::Debug + Copy>,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let audit_info = self
+            .proposals
+            .subject_can_convert(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let mut proposal = self
+            .proposals
+            .find_by_id_without_audit(proposal_id.into())
+            .await?;
+        if !proposal.is_pending_review() {
+            return Err(CreditFacilityProposalError::NotPendingReview(proposal.id).into());
+        }
+
+        self.create_facility_with_audit(
+            sub,
+            audit_info,
+            proposal.customer_id,
+            disbursal_credit_account_id.into(),
+            proposal.amount,
+            proposal.terms,
+            Some(proposal.terms_template),
+            custodian_id.map(Into::into),
+            Some(&mut proposal),
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(not(feature = "mock-custodian"), allow(unused_variables))]
+    async fn create_facility_with_audit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        audit_info: AuditInfo,
+        customer_id: CustomerId,
+        disbursal_credit_account_id: CalaAccountId,
+        amount: UsdCents,
+        terms: TermValues,
+        terms_template: Option<TermsTemplateVersionRef>,
+        custodian_id: Option<CustodianId>,
+        proposal: Option<&mut CreditFacilityProposal>,
+    ) -> Result<CreditFacility, CoreCreditError> {
         let customer = self.customer.find_by_id_without_audit(customer_id).await?;
 
         if self.config.customer_active_check_enabled && customer.status.is_inactive() {
             return Err(CoreCreditError::CustomerNotActive);
         }
 
+        let amount_bounds = match terms_template {
+            Some(version_ref) => {
+                self.terms_templates
+                    .find_by_id_without_audit(version_ref.terms_template_id)
+                    .await?
+                    .amount_bounds
+            }
+            None => FacilityAmountBounds::default(),
+        };
+        let underwriting = self.config.underwriting.evaluate(&UnderwritingRequest {
+            kyc_level: customer.level,
+            customer_type: customer.customer_type,
+            existing_exposure: self
+                .facilities
+                .customer_exposure_without_audit(customer_id)
+                .await?,
+            amount,
+            terms,
+            amount_bounds,
+            price: self.price.usd_cents_per_btc().await?,
+        });
+        if !underwriting.passed() && self.config.underwriting.decline_on_failure {
+            return Err(CoreCreditError::UnderwritingDeclined(
+                underwriting.failed_reasons().collect::<Vec<_>>().join("; "),
+            ));
+        }
+
         let id = CreditFacilityId::new();
         let account_ids = CreditFacilityAccountIds::new();
         let collateral_id = CollateralId::new();
@@ -431,8 +648,6 @@ where
         let mut db = self.facilities.begin_op().await?;
 
         let wallet_id = if let Some(custodian_id) = custodian_id {
-            let custodian_id = custodian_id.into();
-
             #[cfg(feature = "mock-custodian")]
             if custodian_id.is_mock_custodian() {
                 self.custody
@@ -467,9 +682,11 @@ where
             .collateral_id(collateral_id)
             .customer_id(customer_id)
             .terms(terms)
+            .terms_template(terms_template)
+            .underwriting(underwriting)
             .amount(amount)
             .account_ids(account_ids)
-            .disbursal_credit_account_id(disbursal_credit_account_id.into())
+            .disbursal_credit_account_id(disbursal_credit_account_id)
             .public_id(public_id.id)
             .audit_info(audit_info.clone())
             .build()
@@ -490,6 +707,12 @@ where
             .create_in_op(&mut db, new_credit_facility)
             .await?;
 
+        if let Some(proposal) = proposal {
+            self.proposals
+                .convert_in_op(&mut db, proposal, credit_facility.id, audit_info)
+                .await?;
+        }
+
         self.ledger
             .handle_facility_create(
                 db,
@@ -502,6 +725,44 @@ where
         Ok(credit_facility)
     }
 
+    #[instrument(name = "credit.create_participation", skip(self), err)]
+    pub async fn create_participation(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug,
+        partner_name: impl Into<String> + std::fmt::Debug,
+        share: ParticipationSharePct,
+    ) -> Result<Participation, CoreCreditError> {
+        let credit_facility_id = credit_facility_id.into();
+        let audit_info = self
+            .participations
+            .subject_can_manage(sub, credit_facility_id, true)
+            .await?
+            .expect("audit info missing");
+
+        let credit_facility = self
+            .facilities
+            .find_by_id_without_audit(credit_facility_id)
+            .await?;
+
+        let mut db = self.participations.begin_op().await?;
+        let participation = self
+            .participations
+            .create_in_op(
+                &mut db,
+                audit_info,
+                credit_facility.id,
+                partner_name.into(),
+                share,
+            )
+            .await?;
+        self.ledger
+            .create_participation_account(db, &participation)
+            .await?;
+
+        Ok(participation)
+    }
+
     #[instrument(name = "credit.history", skip(self), err)]
     pub async fn history<T: From<CreditFacilityHistoryEntry>>(
         &self,
@@ -538,6 +799,132 @@ where
         Ok(repayment_plan.entries.into_iter().map(T::from).collect())
     }
 
+    #[instrument(name = "credit.simulate_terms", skip(self, price_path), err)]
+    pub async fn simulate_terms(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        terms: TermValues,
+        amount: UsdCents,
+        collateral: Satoshis,
+        starts_at: Option<chrono::DateTime<chrono::Utc>>,
+        price_path: Vec<SimulatedPricePoint>,
+    ) -> Result<TermsSimulation, CoreCreditError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_SIMULATE,
+            )
+            .await?;
+
+        Ok(TermsSimulation::run(
+            terms,
+            amount,
+            collateral,
+            starts_at.unwrap_or_else(crate::time::now),
+            price_path,
+            self.config.upgrade_buffer_cvl_pct,
+        )?)
+    }
+
+    #[instrument(name = "credit.price_shock_stress_test", skip(self), err)]
+    pub async fn price_shock_stress_test(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        shocks_pct: Vec<rust_decimal::Decimal>,
+    ) -> Result<PriceShockStressTest, CoreCreditError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_STRESS_TEST,
+            )
+            .await?;
+
+        let price = self.price.usd_cents_per_btc().await?;
+
+        let mut facilities = Vec::new();
+        let mut query = Default::default();
+        loop {
+            let res = self
+                .facilities
+                .list_by_created_at_without_audit(query)
+                .await?;
+
+            for facility in res.entities.iter() {
+                if !facility.is_activated() || facility.is_completed() {
+                    continue;
+                }
+
+                let balance = self
+                    .ledger
+                    .get_credit_facility_balance(facility.account_ids)
+                    .await?;
+                facilities.push(StressTestFacility {
+                    terms: facility.terms,
+                    balance,
+                });
+            }
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            };
+        }
+
+        Ok(PriceShockStressTest::run(price, shocks_pct, facilities)?)
+    }
+
+    #[instrument(name = "credit.portfolio_aging", skip(self), err)]
+    pub async fn portfolio_aging(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        as_of: Option<chrono::NaiveDate>,
+    ) -> Result<PortfolioAging, CoreCreditError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_LIST,
+            )
+            .await?;
+
+        let as_of = as_of.unwrap_or_else(|| crate::time::now().date_naive());
+
+        let mut facilities = Vec::new();
+        let mut query = Default::default();
+        loop {
+            let res = self
+                .facilities
+                .list_by_created_at_without_audit(query)
+                .await?;
+
+            for facility in res.entities.iter() {
+                if !facility.is_activated() || facility.is_completed() {
+                    continue;
+                }
+
+                let balance = self
+                    .ledger
+                    .get_credit_facility_balance(facility.account_ids)
+                    .await?;
+                facilities.push((
+                    facility.days_past_due_as_of(as_of),
+                    balance.principal_outstanding(),
+                ));
+            }
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            };
+        }
+
+        Ok(PortfolioAging::new(as_of, facilities))
+    }
+
     pub async fn subject_can_initiate_disbursal(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -560,6 +947,7 @@ where
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         credit_facility_id: CreditFacilityId,
         amount: UsdCents,
+        destination: DisbursalDestination,
     ) -> Result<Disbursal, CoreCreditError> {
         let audit_info = self
             .subject_can_initiate_disbursal(sub, true)
@@ -617,7 +1005,12 @@ where
             .credit_facility_id(credit_facility_id)
             .amount(amount)
             .account_ids(facility.account_ids)
-            .disbursal_credit_account_id(facility.disbursal_credit_account_id)
+            .disbursal_credit_account_id(if destination.is_external() {
+                self.ledger.disbursal_settlement_omnibus_account_id()
+            } else {
+                facility.disbursal_credit_account_id
+            })
+            .destination(destination)
             .due_date(due_date)
             .overdue_date(overdue_date)
             .liquidation_date(liquidation_date)
@@ -639,6 +1032,96 @@ where
         Ok(disbursal)
     }
 
+    pub async fn subject_can_settle_disbursal(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        enforce: bool,
+    ) -> Result<Option<AuditInfo>, CoreCreditError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::all_disbursals(),
+                CoreCreditAction::DISBURSAL_SETTLE,
+                enforce,
+            )
+            .await?)
+    }
+
+    #[instrument(name = "credit.confirm_disbursal_settlement", skip(self), err)]
+    pub async fn confirm_disbursal_settlement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        disbursal_id: impl Into<DisbursalId> + std::fmt::Debug,
+    ) -> Result<Disbursal, CoreCreditError> {
+        let disbursal_id = disbursal_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::disbursal(disbursal_id),
+                CoreCreditAction::DISBURSAL_SETTLE,
+            )
+            .await?;
+
+        let mut db = self.disbursals.begin_op().await?;
+        let (disbursal, obligation) = self
+            .disbursals
+            .confirm_settlement_in_op(&mut db, disbursal_id, LedgerTxId::new(), audit_info)
+            .await?;
+
+        match obligation {
+            Some(obligation) => {
+                self.ledger
+                    .settle_disbursal(db, obligation, disbursal.account_ids.facility_account_id)
+                    .await?;
+            }
+            None => db.commit().await?,
+        }
+
+        Ok(disbursal)
+    }
+
+    #[instrument(name = "credit.fail_disbursal_settlement", skip(self), err)]
+    pub async fn fail_disbursal_settlement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        disbursal_id: impl Into<DisbursalId> + std::fmt::Debug,
+        reason: String,
+    ) -> Result<Disbursal, CoreCreditError> {
+        let disbursal_id = disbursal_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::disbursal(disbursal_id),
+                CoreCreditAction::DISBURSAL_SETTLE,
+            )
+            .await?;
+
+        let mut db = self.disbursals.begin_op().await?;
+        let tx_id = LedgerTxId::new();
+        let (disbursal, failed) = self
+            .disbursals
+            .fail_settlement_in_op(&mut db, disbursal_id, tx_id, reason, audit_info)
+            .await?;
+
+        if failed {
+            self.ledger
+                .cancel_disbursal(
+                    db,
+                    tx_id,
+                    disbursal.amount,
+                    disbursal.account_ids.facility_account_id,
+                )
+                .await?;
+        } else {
+            db.commit().await?;
+        }
+
+        Ok(disbursal)
+    }
+
     pub async fn ensure_up_to_date_disbursal_status(
         &self,
         disbursal: &Disbursal,
@@ -691,6 +1174,9 @@ where
             .facilities
             .find_by_id_without_audit(credit_facility_id)
             .await?;
+        if credit_facility.is_cancelled() {
+            return Err(CreditFacilityError::Cancelled.into());
+        }
 
         let mut db = self.facilities.begin_op().await?;
 
@@ -767,7 +1253,13 @@ where
 
         let allocations = self
             .payments
-            .record_in_op(&mut db, audit_info, credit_facility_id, amount, effective)
+            .record_in_op(
+                &mut db,
+                audit_info.clone(),
+                credit_facility_id,
+                amount,
+                effective,
+            )
             .await?;
 
         let amount_allocated = allocations.iter().fold(UsdCents::ZERO, |c, a| c + a.amount);
@@ -776,8 +1268,30 @@ where
             tracing::field::display(amount_allocated),
         );
 
+        let effective = effective.into();
+        let principal_repaid = allocations
+            .iter()
+            .filter(|allocation| allocation.obligation_type == ObligationType::Disbursal)
+            .fold(UsdCents::ZERO, |c, a| c + a.amount);
+        let interest_adjustments = if self.config.interest_recomputation_enabled
+            && effective < crate::time::now().date_naive()
+            && !principal_repaid.is_zero()
+        {
+            self.facilities
+                .recompute_interest_accruals_in_op(
+                    &mut db,
+                    credit_facility_id,
+                    principal_repaid,
+                    effective,
+                    &audit_info,
+                )
+                .await?
+        } else {
+            vec![]
+        };
+
         self.ledger
-            .record_obligation_repayments(db, allocations)
+            .record_obligation_repayments(db, allocations, interest_adjustments)
             .await?;
 
         Ok(credit_facility)
@@ -841,6 +1355,130 @@ where
         Ok(credit_facility)
     }
 
+    pub async fn subject_can_cancel(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        enforce: bool,
+    ) -> Result<Option<AuditInfo>, CoreCreditError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_CANCEL,
+                enforce,
+            )
+            .await?)
+    }
+
+    #[instrument(name = "credit.cancel_facility", skip(self), err)]
+    pub async fn cancel_facility(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug + Copy,
+        reason: impl Into<String> + std::fmt::Debug,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let id = credit_facility_id.into();
+
+        let audit_info = self
+            .subject_can_cancel(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let mut db = self.facilities.begin_op().await?;
+
+        let credit_facility = match self
+            .facilities
+            .cancel_in_op(&mut db, id, reason.into(), &audit_info)
+            .await?
+        {
+            CancellationOutcome::Ignored(facility) => facility,
+
+            CancellationOutcome::Cancelled(facility) => {
+                match self
+                    .collaterals
+                    .remove_collateral_in_op(
+                        &mut db,
+                        facility.collateral_id,
+                        crate::time::now().date_naive(),
+                        &audit_info,
+                    )
+                    .await?
+                {
+                    Some(collateral_update) => {
+                        self.ledger
+                            .update_credit_facility_collateral(
+                                db,
+                                collateral_update,
+                                facility.account_ids,
+                            )
+                            .await?;
+                    }
+                    None => db.commit().await?,
+                }
+                facility
+            }
+        };
+
+        Ok(credit_facility)
+    }
+
+    pub async fn subject_can_request_reinstatement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        enforce: bool,
+    ) -> Result<Option<AuditInfo>, CoreCreditError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_REQUEST_REINSTATEMENT,
+                enforce,
+            )
+            .await?)
+    }
+
+    /// Starts an approval process to move the facility's defaulted obligations
+    /// back to their due/overdue receivables once the arrears have been cured.
+    #[instrument(name = "credit.request_reinstatement", skip(self), err)]
+    pub async fn request_reinstatement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug + Copy,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let id = credit_facility_id.into();
+
+        let audit_info = self
+            .subject_can_request_reinstatement(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let credit_facility = self.facilities.find_by_id_without_audit(id).await?;
+
+        let mut db = self.obligations.begin_op().await?;
+        let approval_process_id = ApprovalProcessId::new();
+        let n_requested = self
+            .obligations
+            .request_reinstatement_in_op(&mut db, id, approval_process_id, &audit_info)
+            .await?;
+        if n_requested == 0 {
+            return Err(CoreCreditError::NoDefaultedObligations(id));
+        }
+
+        self.governance
+            .start_process(
+                &mut db,
+                approval_process_id,
+                id.to_string(),
+                APPROVE_OBLIGATION_REINSTATEMENT_PROCESS,
+            )
+            .await?;
+        db.commit().await?;
+
+        Ok(credit_facility)
+    }
+
     pub async fn can_be_completed(&self, entity: &CreditFacility) -> Result<bool, CoreCreditError> {
         Ok(self.outstanding(entity).await?.is_zero())
     }
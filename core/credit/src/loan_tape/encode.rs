@@ -0,0 +1,334 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use csv::Writer;
+use parquet::{
+    data_type::{BoolType, ByteArray, ByteArrayType, DoubleType, Int32Type, Int64Type},
+    file::{properties::WriterProperties, writer::SerializedFileWriter},
+    schema::parser::parse_message_type,
+};
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+
+use super::{error::LoanTapeError, primitives::LoanTapeFormat, row::LoanTapeRow};
+
+enum ColumnValues {
+    Text(Vec<Option<String>>),
+    Integer(Vec<Option<i64>>),
+    Decimal(Vec<Option<Decimal>>),
+    Date(Vec<Option<NaiveDate>>),
+    Boolean(Vec<Option<bool>>),
+}
+
+impl ColumnValues {
+    fn parquet_type(&self) -> &'static str {
+        match self {
+            Self::Text(_) => "BYTE_ARRAY",
+            Self::Integer(_) => "INT64",
+            Self::Decimal(_) => "DOUBLE",
+            Self::Date(_) => "INT32",
+            Self::Boolean(_) => "BOOLEAN",
+        }
+    }
+
+    fn parquet_annotation(&self) -> &'static str {
+        match self {
+            Self::Text(_) => " (UTF8)",
+            Self::Date(_) => " (DATE)",
+            _ => "",
+        }
+    }
+
+    fn csv_value(&self, idx: usize) -> String {
+        match self {
+            Self::Text(values) => values[idx].clone(),
+            Self::Integer(values) => values[idx].map(|v| v.to_string()),
+            Self::Decimal(values) => values[idx].map(|v| v.to_string()),
+            Self::Date(values) => values[idx].map(|v| v.to_string()),
+            Self::Boolean(values) => values[idx].map(|v| v.to_string()),
+        }
+        .unwrap_or_default()
+    }
+}
+
+struct Column {
+    name: &'static str,
+    values: ColumnValues,
+}
+
+fn column<T>(
+    rows: &[LoanTapeRow],
+    name: &'static str,
+    wrap: impl Fn(Vec<Option<T>>) -> ColumnValues,
+    value: impl Fn(&LoanTapeRow) -> Option<T>,
+) -> Column {
+    Column {
+        name,
+        values: wrap(rows.iter().map(value).collect()),
+    }
+}
+
+fn columns(rows: &[LoanTapeRow]) -> Vec<Column> {
+    use ColumnValues::*;
+
+    let cents = |c: crate::primitives::UsdCents| {
+        i64::try_from(c.into_inner()).expect("cents should fit in i64")
+    };
+    vec![
+        column(rows, "credit_facility_id", Text, |r| {
+            Some(r.credit_facility_id.to_string())
+        }),
+        column(rows, "public_id", Text, |r| Some(r.public_id.to_string())),
+        column(rows, "customer_id", Text, |r| {
+            Some(r.customer_id.to_string())
+        }),
+        column(rows, "status", Text, |r| Some(r.status.to_string())),
+        column(rows, "activated_at", Date, |r| r.activated_at),
+        column(rows, "matures_at", Date, |r| r.matures_at),
+        column(rows, "annual_rate_pct", Decimal, |r| {
+            Some(r.annual_rate.into())
+        }),
+        column(rows, "one_time_fee_rate_pct", Decimal, |r| {
+            Some(r.one_time_fee_rate.into())
+        }),
+        column(rows, "duration_months", Integer, |r| {
+            Some(i64::from(r.duration_months))
+        }),
+        column(rows, "interest_capitalized", Boolean, |r| {
+            Some(r.interest_capitalized)
+        }),
+        column(rows, "initial_cvl_pct", Decimal, |r| {
+            Some(r.initial_cvl.into())
+        }),
+        column(rows, "margin_call_cvl_pct", Decimal, |r| {
+            Some(r.margin_call_cvl.into())
+        }),
+        column(rows, "liquidation_cvl_pct", Decimal, |r| {
+            Some(r.liquidation_cvl.into())
+        }),
+        column(rows, "facility_amount_usd_cents", Integer, |r| {
+            Some(cents(r.facility_amount))
+        }),
+        column(rows, "total_disbursed_usd_cents", Integer, |r| {
+            Some(cents(r.total_disbursed))
+        }),
+        column(rows, "disbursed_outstanding_usd_cents", Integer, |r| {
+            Some(cents(r.disbursed_outstanding))
+        }),
+        column(rows, "interest_posted_usd_cents", Integer, |r| {
+            Some(cents(r.interest_posted))
+        }),
+        column(rows, "interest_outstanding_usd_cents", Integer, |r| {
+            Some(cents(r.interest_outstanding))
+        }),
+        column(rows, "total_outstanding_usd_cents", Integer, |r| {
+            Some(cents(r.total_outstanding))
+        }),
+        column(rows, "total_overdue_usd_cents", Integer, |r| {
+            Some(cents(r.total_overdue))
+        }),
+        column(rows, "total_defaulted_usd_cents", Integer, |r| {
+            Some(cents(r.total_defaulted))
+        }),
+        column(rows, "collateral_sats", Integer, |r| {
+            Some(i64::try_from(r.collateral.into_inner()).expect("sats should fit in i64"))
+        }),
+        column(rows, "btc_price_usd_cents", Integer, |r| {
+            r.price.map(|p| cents(p.into_inner()))
+        }),
+        column(rows, "cvl_pct", Decimal, |r| r.cvl.map(Into::into)),
+        column(rows, "collateralization_state", Text, |r| {
+            Some(r.collateralization_state.to_string())
+        }),
+        column(rows, "days_past_due", Integer, |r| {
+            Some(i64::from(r.days_past_due))
+        }),
+        column(rows, "payment_allocations", Integer, |r| {
+            Some(i64::from(r.payment_allocations))
+        }),
+        column(rows, "total_repaid_usd_cents", Integer, |r| {
+            Some(cents(r.total_repaid))
+        }),
+        column(rows, "last_payment_at", Date, |r| r.last_payment_at),
+    ]
+}
+
+pub(super) fn encode(
+    format: LoanTapeFormat,
+    rows: &[LoanTapeRow],
+) -> Result<Vec<u8>, LoanTapeError> {
+    match format {
+        LoanTapeFormat::Csv => to_csv(rows),
+        LoanTapeFormat::Parquet => to_parquet(rows),
+    }
+}
+
+fn to_csv(rows: &[LoanTapeRow]) -> Result<Vec<u8>, LoanTapeError> {
+    let columns = columns(rows);
+
+    let mut wtr = Writer::from_writer(vec![]);
+    wtr.write_record(columns.iter().map(|c| c.name))
+        .map_err(|e| LoanTapeError::CsvError(e.to_string()))?;
+    for idx in 0..rows.len() {
+        wtr.write_record(columns.iter().map(|c| c.values.csv_value(idx)))
+            .map_err(|e| LoanTapeError::CsvError(e.to_string()))?;
+    }
+
+    wtr.into_inner()
+        .map_err(|e| LoanTapeError::CsvError(e.to_string()))
+}
+
+fn definition_levels<T>(values: &[Option<T>]) -> Vec<i16> {
+    values.iter().map(|v| i16::from(v.is_some())).collect()
+}
+
+fn to_parquet(rows: &[LoanTapeRow]) -> Result<Vec<u8>, LoanTapeError> {
+    let columns = columns(rows);
+
+    let fields = columns
+        .iter()
+        .map(|c| {
+            format!(
+                "OPTIONAL {} {}{};",
+                c.values.parquet_type(),
+                c.name,
+                c.values.parquet_annotation()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let schema = Arc::new(parse_message_type(&format!(
+        "message loan_tape {{ {fields} }}"
+    ))?);
+    let props = Arc::new(WriterProperties::builder().build());
+    let mut writer = SerializedFileWriter::new(Vec::new(), schema, props)?;
+
+    let mut row_group = writer.next_row_group()?;
+    for column in columns.iter() {
+        let mut col_writer = row_group
+            .next_column()?
+            .expect("schema and columns should match");
+        match &column.values {
+            ColumnValues::Text(values) => {
+                let data = values
+                    .iter()
+                    .flatten()
+                    .map(|v| ByteArray::from(v.as_str()))
+                    .collect::<Vec<_>>();
+                col_writer.typed::<ByteArrayType>().write_batch(
+                    &data,
+                    Some(&definition_levels(values)),
+                    None,
+                )?;
+            }
+            ColumnValues::Integer(values) => {
+                let data = values.iter().flatten().copied().collect::<Vec<_>>();
+                col_writer.typed::<Int64Type>().write_batch(
+                    &data,
+                    Some(&definition_levels(values)),
+                    None,
+                )?;
+            }
+            ColumnValues::Decimal(values) => {
+                let data = values
+                    .iter()
+                    .flatten()
+                    .map(|v| v.to_f64().expect("decimal should fit in f64"))
+                    .collect::<Vec<_>>();
+                col_writer.typed::<DoubleType>().write_batch(
+                    &data,
+                    Some(&definition_levels(values)),
+                    None,
+                )?;
+            }
+            ColumnValues::Date(values) => {
+                let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).expect("valid date");
+                let data = values
+                    .iter()
+                    .flatten()
+                    .map(|v| {
+                        i32::try_from((*v - epoch).num_days()).expect("date should fit in i32")
+                    })
+                    .collect::<Vec<_>>();
+                col_writer.typed::<Int32Type>().write_batch(
+                    &data,
+                    Some(&definition_levels(values)),
+                    None,
+                )?;
+            }
+            ColumnValues::Boolean(values) => {
+                let data = values.iter().flatten().copied().collect::<Vec<_>>();
+                col_writer.typed::<BoolType>().write_batch(
+                    &data,
+                    Some(&definition_levels(values)),
+                    None,
+                )?;
+            }
+        }
+        col_writer.close()?;
+    }
+    row_group.close()?;
+
+    Ok(writer.into_inner()?)
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use crate::primitives::*;
+
+    use super::*;
+
+    fn row() -> LoanTapeRow {
+        LoanTapeRow {
+            credit_facility_id: CreditFacilityId::new(),
+            public_id: PublicId::new("1"),
+            customer_id: CustomerId::new(),
+            status: CreditFacilityStatus::Active,
+            activated_at: NaiveDate::from_ymd_opt(2025, 1, 1),
+            matures_at: NaiveDate::from_ymd_opt(2026, 1, 1),
+            annual_rate: dec!(12).into(),
+            one_time_fee_rate: dec!(1).into(),
+            duration_months: 12,
+            interest_capitalized: false,
+            initial_cvl: dec!(140).into(),
+            margin_call_cvl: dec!(125).into(),
+            liquidation_cvl: dec!(105).into(),
+            facility_amount: UsdCents::from(1_000_000_00),
+            total_disbursed: UsdCents::from(500_000_00),
+            disbursed_outstanding: UsdCents::from(400_000_00),
+            interest_posted: UsdCents::from(10_000_00),
+            interest_outstanding: UsdCents::ZERO,
+            total_outstanding: UsdCents::from(400_000_00),
+            total_overdue: UsdCents::ZERO,
+            total_defaulted: UsdCents::ZERO,
+            collateral: Satoshis::from(10_00_000_000),
+            price: Some(PriceOfOneBTC::new(UsdCents::from(100_000_00))),
+            cvl: Some(dec!(250).into()),
+            collateralization_state: CollateralizationState::FullyCollateralized,
+            days_past_due: 0,
+            payment_allocations: 2,
+            total_repaid: UsdCents::from(110_000_00),
+            last_payment_at: None,
+        }
+    }
+
+    #[test]
+    fn csv_has_header_and_one_line_per_row() {
+        let csv = String::from_utf8(to_csv(&[row(), row()]).unwrap()).unwrap();
+        let lines = csv.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("credit_facility_id,public_id,customer_id,status"));
+        assert!(lines[1].contains(",Active,2025-01-01,2026-01-01,12,1,12,false,"));
+        assert!(lines[1].ends_with(",0,2,11000000,"));
+    }
+
+    #[test]
+    fn parquet_file_is_framed_by_magic_bytes() {
+        let parquet = to_parquet(&[row(), row()]).unwrap();
+
+        assert_eq!(&parquet[..4], b"PAR1");
+        assert_eq!(&parquet[parquet.len() - 4..], b"PAR1");
+    }
+}
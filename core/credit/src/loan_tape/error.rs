@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum LoanTapeError {
+    #[error("LoanTapeError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("LoanTapeError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("LoanTapeError - JobError: {0}")]
+    JobError(#[from] job::error::JobError),
+    #[error("LoanTapeError - DocumentStorageError: {0}")]
+    DocumentStorageError(#[from] document_storage::error::DocumentStorageError),
+    #[error("LoanTapeError - CreditFacilityError: {0}")]
+    CreditFacilityError(#[from] crate::credit_facility::error::CreditFacilityError),
+    #[error("LoanTapeError - ObligationError: {0}")]
+    ObligationError(#[from] crate::obligation::error::ObligationError),
+    #[error("LoanTapeError - CreditLedgerError: {0}")]
+    CreditLedgerError(#[from] crate::ledger::error::CreditLedgerError),
+    #[error("LoanTapeError - CreditFacilityHistoryError: {0}")]
+    CreditFacilityHistoryError(#[from] crate::history::error::CreditFacilityHistoryError),
+    #[error("LoanTapeError - CsvError: {0}")]
+    CsvError(String),
+    #[error("LoanTapeError - ParquetError: {0}")]
+    ParquetError(#[from] parquet::errors::ParquetError),
+}
@@ -0,0 +1,91 @@
+use chrono::NaiveDate;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CoreCreditAction, CoreCreditEvent, CoreCreditObject, credit_facility::CreditFacilities,
+    history::HistoryRepo, ledger::CreditLedger, obligation::Obligations,
+};
+
+use super::{error::LoanTapeError, row::LoanTapeRow};
+
+pub struct GenerateLoanTape<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    credit_facilities: CreditFacilities<Perms, E>,
+    obligations: Obligations<Perms, E>,
+    ledger: CreditLedger,
+    history_repo: HistoryRepo,
+}
+
+impl<Perms, E> GenerateLoanTape<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        credit_facilities: &CreditFacilities<Perms, E>,
+        obligations: &Obligations<Perms, E>,
+        ledger: &CreditLedger,
+        history_repo: &HistoryRepo,
+    ) -> Self {
+        Self {
+            credit_facilities: credit_facilities.clone(),
+            obligations: obligations.clone(),
+            ledger: ledger.clone(),
+            history_repo: history_repo.clone(),
+        }
+    }
+
+    pub async fn rows(&self, as_of: NaiveDate) -> Result<Vec<LoanTapeRow>, LoanTapeError> {
+        let mut rows = Vec::new();
+        let mut query = Default::default();
+        loop {
+            let res = self
+                .credit_facilities
+                .list_by_created_at_without_audit(query)
+                .await?;
+
+            for facility in res.entities.iter() {
+                if !facility
+                    .activated_at
+                    .is_some_and(|activated_at| activated_at.date_naive() <= as_of)
+                {
+                    continue;
+                }
+
+                let balance = self
+                    .ledger
+                    .get_credit_facility_balance_as_of(facility.account_ids, as_of)
+                    .await?;
+                let history = self.history_repo.load(facility.id).await?;
+                let days_past_due = self.obligations.days_past_due(facility.id, as_of).await?;
+
+                rows.push(LoanTapeRow::new(
+                    facility,
+                    balance,
+                    &history,
+                    days_past_due,
+                    as_of,
+                ));
+            }
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            };
+        }
+
+        Ok(rows)
+    }
+}
@@ -0,0 +1,142 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use document_storage::{DocumentId, DocumentStorage};
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CoreCreditAction, CoreCreditEvent, CoreCreditObject, credit_facility::CreditFacilities,
+    history::HistoryRepo, ledger::CreditLedger, obligation::Obligations, primitives::LoanTapeId,
+};
+
+use super::{encode::encode, generate::GenerateLoanTape, primitives::LoanTapeFormat};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GenerateLoanTapeConfig<Perms, E> {
+    pub loan_tape_id: LoanTapeId,
+    pub as_of: chrono::NaiveDate,
+    pub documents: Vec<(LoanTapeFormat, DocumentId)>,
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+
+impl<Perms, E> JobConfig for GenerateLoanTapeConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    type Initializer = GenerateLoanTapeInit<Perms, E>;
+}
+
+pub struct GenerateLoanTapeInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    document_storage: DocumentStorage,
+    credit_facilities: CreditFacilities<Perms, E>,
+    obligations: Obligations<Perms, E>,
+    ledger: CreditLedger,
+    history_repo: HistoryRepo,
+}
+
+impl<Perms, E> GenerateLoanTapeInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        document_storage: &DocumentStorage,
+        credit_facilities: &CreditFacilities<Perms, E>,
+        obligations: &Obligations<Perms, E>,
+        ledger: &CreditLedger,
+        history_repo: &HistoryRepo,
+    ) -> Self {
+        Self {
+            document_storage: document_storage.clone(),
+            credit_facilities: credit_facilities.clone(),
+            obligations: obligations.clone(),
+            ledger: ledger.clone(),
+            history_repo: history_repo.clone(),
+        }
+    }
+}
+
+pub const GENERATE_LOAN_TAPE_JOB: JobType = JobType::new("generate-loan-tape");
+
+impl<Perms, E> JobInitializer for GenerateLoanTapeInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        GENERATE_LOAN_TAPE_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(GenerateLoanTapeJobRunner::<Perms, E> {
+            config: job.config()?,
+            document_storage: self.document_storage.clone(),
+            generator: GenerateLoanTape::new(
+                &self.credit_facilities,
+                &self.obligations,
+                &self.ledger,
+                &self.history_repo,
+            ),
+        }))
+    }
+}
+
+pub struct GenerateLoanTapeJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    config: GenerateLoanTapeConfig<Perms, E>,
+    document_storage: DocumentStorage,
+    generator: GenerateLoanTape<Perms, E>,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for GenerateLoanTapeJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let rows = self.generator.rows(self.config.as_of).await?;
+
+        for (format, document_id) in self.config.documents.iter() {
+            let content = encode(*format, &rows)?;
+            let mut document = self.document_storage.find_by_id(*document_id).await?;
+            self.document_storage.upload(content, &mut document).await?;
+        }
+
+        Ok(JobCompletion::Complete)
+    }
+}
@@ -0,0 +1,192 @@
+mod encode;
+pub mod error;
+mod generate;
+mod job;
+mod primitives;
+mod row;
+
+use tracing::instrument;
+
+use ::job::{JobId, Jobs};
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use document_storage::{
+    Document, DocumentId, DocumentStorage, GeneratedDocumentDownloadLink, ReferenceId,
+};
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CoreCreditAction, CoreCreditEvent, CoreCreditObject, credit_facility::CreditFacilities,
+    history::HistoryRepo, ledger::CreditLedger, obligation::Obligations, primitives::LoanTapeId,
+};
+
+use error::*;
+use job::*;
+pub use primitives::*;
+
+pub struct LoanTape {
+    pub id: LoanTapeId,
+    pub as_of: chrono::NaiveDate,
+    pub documents: Vec<Document>,
+}
+
+pub struct LoanTapes<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    authz: Perms,
+    jobs: Jobs,
+    document_storage: DocumentStorage,
+    _phantom: std::marker::PhantomData<E>,
+}
+
+impl<Perms, E> Clone for LoanTapes<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            jobs: self.jobs.clone(),
+            document_storage: self.document_storage.clone(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<Perms, E> LoanTapes<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub(crate) fn new(
+        authz: &Perms,
+        jobs: &Jobs,
+        document_storage: DocumentStorage,
+        credit_facilities: &CreditFacilities<Perms, E>,
+        obligations: &Obligations<Perms, E>,
+        ledger: &CreditLedger,
+        history_repo: &HistoryRepo,
+    ) -> Self {
+        jobs.add_initializer(GenerateLoanTapeInit::new(
+            &document_storage,
+            credit_facilities,
+            obligations,
+            ledger,
+            history_repo,
+        ));
+
+        Self {
+            authz: authz.clone(),
+            jobs: jobs.clone(),
+            document_storage,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    #[instrument(name = "credit.loan_tape.create", skip(self), err)]
+    pub async fn create(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        as_of: chrono::NaiveDate,
+    ) -> Result<LoanTape, LoanTapeError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_loan_tapes(),
+                CoreCreditAction::LOAN_TAPE_CREATE,
+            )
+            .await?;
+
+        let loan_tape_id = LoanTapeId::new();
+        let mut db = self.document_storage.begin_op().await?;
+        let mut documents = Vec::new();
+        for format in LoanTapeFormat::ALL {
+            let document = self
+                .document_storage
+                .create_in_op(
+                    audit_info.clone(),
+                    format!("loan-tape-{as_of}.{}", format.extension()),
+                    format.content_type(),
+                    ReferenceId::from(uuid::Uuid::from(loan_tape_id)),
+                    format.document_type(),
+                    &mut db,
+                )
+                .await?;
+            documents.push(document);
+        }
+
+        self.jobs
+            .create_and_spawn_in_op::<GenerateLoanTapeConfig<Perms, E>>(
+                &mut db,
+                JobId::from(loan_tape_id),
+                GenerateLoanTapeConfig {
+                    loan_tape_id,
+                    as_of,
+                    documents: LoanTapeFormat::ALL
+                        .into_iter()
+                        .zip(documents.iter().map(|document| document.id))
+                        .collect(),
+                    _phantom: std::marker::PhantomData,
+                },
+            )
+            .await?;
+        db.commit().await?;
+
+        Ok(LoanTape {
+            id: loan_tape_id,
+            as_of,
+            documents,
+        })
+    }
+
+    #[instrument(name = "credit.loan_tape.list_documents", skip(self), err)]
+    pub async fn list_documents(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        loan_tape_id: impl Into<LoanTapeId> + std::fmt::Debug,
+    ) -> Result<Vec<Document>, LoanTapeError> {
+        let loan_tape_id = loan_tape_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::loan_tape(loan_tape_id),
+                CoreCreditAction::LOAN_TAPE_READ,
+            )
+            .await?;
+
+        Ok(self
+            .document_storage
+            .list_for_reference_id(ReferenceId::from(uuid::Uuid::from(loan_tape_id)))
+            .await?)
+    }
+
+    #[instrument(name = "credit.loan_tape.generate_download_link", skip(self), err)]
+    pub async fn generate_download_link(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        document_id: impl Into<DocumentId> + std::fmt::Debug,
+    ) -> Result<GeneratedDocumentDownloadLink, LoanTapeError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_loan_tapes(),
+                CoreCreditAction::LOAN_TAPE_GENERATE_DOWNLOAD_LINK,
+            )
+            .await?;
+
+        Ok(self
+            .document_storage
+            .generate_download_link(audit_info, document_id.into())
+            .await?)
+    }
+}
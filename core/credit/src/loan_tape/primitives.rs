@@ -0,0 +1,46 @@
+use document_storage::DocumentType;
+use serde::{Deserialize, Serialize};
+
+pub const LOAN_TAPE_CSV: DocumentType = DocumentType::new("loan_tape_csv");
+pub const LOAN_TAPE_PARQUET: DocumentType = DocumentType::new("loan_tape_parquet");
+
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[serde(rename_all = "snake_case")]
+pub enum LoanTapeFormat {
+    Csv,
+    Parquet,
+}
+
+impl LoanTapeFormat {
+    pub const ALL: [LoanTapeFormat; 2] = [LoanTapeFormat::Csv, LoanTapeFormat::Parquet];
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Parquet => "application/vnd.apache.parquet",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Parquet => "parquet",
+        }
+    }
+
+    pub(super) fn document_type(&self) -> DocumentType {
+        match self {
+            Self::Csv => LOAN_TAPE_CSV,
+            Self::Parquet => LOAN_TAPE_PARQUET,
+        }
+    }
+
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|format| format.content_type() == content_type)
+    }
+}
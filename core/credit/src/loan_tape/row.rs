@@ -0,0 +1,117 @@
+use chrono::NaiveDate;
+
+use crate::{
+    credit_facility::CreditFacility,
+    history::{CreditFacilityHistory, CreditFacilityHistoryEntry},
+    ledger::CreditFacilityBalanceSummary,
+    primitives::*,
+    terms::{AnnualRatePct, FacilityDuration, OneTimeFeeRatePct},
+};
+
+/// A single line of the loan tape, describing one credit facility as of a given date.
+pub struct LoanTapeRow {
+    pub credit_facility_id: CreditFacilityId,
+    pub public_id: PublicId,
+    pub customer_id: CustomerId,
+    pub status: CreditFacilityStatus,
+    pub activated_at: Option<NaiveDate>,
+    pub matures_at: Option<NaiveDate>,
+
+    pub annual_rate: AnnualRatePct,
+    pub one_time_fee_rate: OneTimeFeeRatePct,
+    pub duration_months: u32,
+    pub interest_capitalized: bool,
+    pub initial_cvl: CVLPct,
+    pub margin_call_cvl: CVLPct,
+    pub liquidation_cvl: CVLPct,
+
+    pub facility_amount: UsdCents,
+    pub total_disbursed: UsdCents,
+    pub disbursed_outstanding: UsdCents,
+    pub interest_posted: UsdCents,
+    pub interest_outstanding: UsdCents,
+    pub total_outstanding: UsdCents,
+    pub total_overdue: UsdCents,
+    pub total_defaulted: UsdCents,
+
+    pub collateral: Satoshis,
+    pub price: Option<PriceOfOneBTC>,
+    pub cvl: Option<CVLPct>,
+    pub collateralization_state: CollateralizationState,
+
+    pub days_past_due: u32,
+    pub payment_allocations: u32,
+    pub total_repaid: UsdCents,
+    pub last_payment_at: Option<NaiveDate>,
+}
+
+impl LoanTapeRow {
+    pub(super) fn new(
+        facility: &CreditFacility,
+        balance: CreditFacilityBalanceSummary,
+        history: &CreditFacilityHistory,
+        days_past_due: u32,
+        as_of: NaiveDate,
+    ) -> Self {
+        let FacilityDuration::Months(duration_months) = facility.terms.duration;
+
+        let mut collateralization = None;
+        let mut payment_allocations = 0;
+        let mut total_repaid = UsdCents::ZERO;
+        let mut last_payment_at = None;
+        for entry in history.entries.iter() {
+            match entry {
+                CreditFacilityHistoryEntry::Collateralization(update)
+                    if update.effective <= as_of =>
+                {
+                    collateralization = Some((update.state, update.price));
+                }
+                CreditFacilityHistoryEntry::Payment(payment) if payment.effective <= as_of => {
+                    payment_allocations += 1;
+                    total_repaid += payment.cents;
+                    last_payment_at = last_payment_at.max(Some(payment.effective));
+                }
+                _ => (),
+            }
+        }
+        let price = collateralization.map(|(_, price)| price);
+
+        Self {
+            credit_facility_id: facility.id,
+            public_id: facility.public_id.clone(),
+            customer_id: facility.customer_id,
+            status: facility.status(),
+            activated_at: facility.activated_at.map(|at| at.date_naive()),
+            matures_at: facility.matures_at.map(|at| at.date_naive()),
+
+            annual_rate: facility.terms.annual_rate,
+            one_time_fee_rate: facility.terms.one_time_fee_rate,
+            duration_months,
+            interest_capitalized: facility.terms.interest_capitalization.is_capitalized(),
+            initial_cvl: facility.terms.initial_cvl,
+            margin_call_cvl: facility.terms.margin_call_cvl,
+            liquidation_cvl: facility.terms.liquidation_cvl,
+
+            facility_amount: facility.amount,
+            total_disbursed: balance.total_disbursed(),
+            disbursed_outstanding: balance.disbursed_outstanding(),
+            interest_posted: balance.interest_posted(),
+            interest_outstanding: balance.interest_outstanding(),
+            total_outstanding: balance.total_outstanding(),
+            total_overdue: balance.total_overdue(),
+            total_defaulted: balance.total_defaulted(),
+
+            collateral: balance.collateral(),
+            price,
+            cvl: price.map(|price| balance.current_cvl(price)),
+            collateralization_state: collateralization
+                .map(|(state, _)| state)
+                .unwrap_or_default(),
+
+            days_past_due,
+            payment_allocations,
+            total_repaid,
+            last_payment_at,
+        }
+    }
+}
@@ -55,12 +55,33 @@ pub enum ObligationEvent {
         defaulted_amount: UsdCents,
         audit_info: AuditInfo,
     },
+    ReinstatementRequested {
+        approval_process_id: ApprovalProcessId,
+        audit_info: AuditInfo,
+    },
+    ReinstatementDenied {
+        approval_process_id: ApprovalProcessId,
+        audit_info: AuditInfo,
+    },
+    Reinstated {
+        ledger_tx_id: LedgerTxId,
+        approval_process_id: ApprovalProcessId,
+        reinstated_amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
     PaymentAllocated {
         ledger_tx_id: LedgerTxId,
         payment_id: PaymentId,
         payment_allocation_id: PaymentAllocationId,
         payment_allocation_amount: UsdCents,
     },
+    AdjustmentRecorded {
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
     LiquidationProcessStarted {
         liquidation_process_id: LiquidationProcessId,
         ledger_tx_id: LedgerTxId,
@@ -269,7 +290,7 @@ impl Obligation {
         }
 
         if let Some(defaulted_date) = defaulted_date {
-            if now >= defaulted_date {
+            if now >= defaulted_date && !self.is_reinstated() {
                 return ObligationStatus::Defaulted;
             }
         }
@@ -288,17 +309,50 @@ impl Obligation {
     }
 
     pub fn status(&self) -> ObligationStatus {
+        self.status_with_pre_default_status().0
+    }
+
+    /// Current status alongside the status the obligation had before it was
+    /// last defaulted, which is the one it returns to when reinstated.
+    fn status_with_pre_default_status(&self) -> (ObligationStatus, ObligationStatus) {
+        self.events.iter_all().fold(
+            (ObligationStatus::NotYetDue, ObligationStatus::NotYetDue),
+            |(status, pre_default), event| match event {
+                ObligationEvent::DueRecorded { .. } => (ObligationStatus::Due, pre_default),
+                ObligationEvent::OverdueRecorded { .. } => (ObligationStatus::Overdue, pre_default),
+                ObligationEvent::DefaultedRecorded { .. } => (ObligationStatus::Defaulted, status),
+                ObligationEvent::Reinstated { .. } if status == ObligationStatus::Defaulted => {
+                    (pre_default, pre_default)
+                }
+                ObligationEvent::Completed { .. } => (ObligationStatus::Paid, pre_default),
+                _ => (status, pre_default),
+            },
+        )
+    }
+
+    pub fn is_reinstated(&self) -> bool {
         self.events
             .iter_all()
             .rev()
             .find_map(|event| match event {
-                ObligationEvent::DueRecorded { .. } => Some(ObligationStatus::Due),
-                ObligationEvent::OverdueRecorded { .. } => Some(ObligationStatus::Overdue),
-                ObligationEvent::DefaultedRecorded { .. } => Some(ObligationStatus::Defaulted),
-                ObligationEvent::Completed { .. } => Some(ObligationStatus::Paid),
+                ObligationEvent::Reinstated { .. } => Some(true),
+                ObligationEvent::DefaultedRecorded { .. } => Some(false),
                 _ => None,
             })
-            .unwrap_or(ObligationStatus::NotYetDue)
+            .unwrap_or(false)
+    }
+
+    pub fn pending_reinstatement(&self) -> Option<ApprovalProcessId> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            ObligationEvent::ReinstatementRequested {
+                approval_process_id,
+                ..
+            } => Some(Some(*approval_process_id)),
+            ObligationEvent::ReinstatementDenied { .. } | ObligationEvent::Reinstated { .. } => {
+                Some(None)
+            }
+            _ => None,
+        })?
     }
 
     pub fn is_status_up_to_date(&self, now: DateTime<Utc>) -> bool {
@@ -316,7 +370,8 @@ impl Obligation {
                     ObligationEvent::PaymentAllocated {
                         payment_allocation_amount: amount,
                         ..
-                    } => {
+                    }
+                    | ObligationEvent::AdjustmentRecorded { amount, .. } => {
                         total_sum -= *amount;
                     }
                     _ => (),
@@ -341,6 +396,33 @@ impl Obligation {
             .unwrap_or_default()
     }
 
+    pub fn paid_at(&self) -> Option<chrono::NaiveDate> {
+        self.events.iter_all().rev().find_map(|e| match e {
+            ObligationEvent::Completed { effective, .. } => Some(*effective),
+            _ => None,
+        })
+    }
+
+    pub fn days_past_due(&self, as_of: chrono::NaiveDate) -> u32 {
+        if self.paid_at().is_some_and(|paid_at| paid_at <= as_of) {
+            return 0;
+        }
+
+        let days = (as_of - self.due_at().date_naive()).num_days();
+        u32::try_from(days).unwrap_or(0)
+    }
+
+    /// Due date of the obligation while it has been recorded as due but is not
+    /// yet paid, i.e. the date from which its days past due are counted.
+    pub fn past_due_since(&self) -> Option<chrono::NaiveDate> {
+        match self.status() {
+            ObligationStatus::Due | ObligationStatus::Overdue | ObligationStatus::Defaulted => {
+                Some(self.due_at().date_naive())
+            }
+            ObligationStatus::NotYetDue | ObligationStatus::Paid => None,
+        }
+    }
+
     pub(crate) fn record_due(
         &mut self,
         effective: chrono::NaiveDate,
@@ -415,7 +497,8 @@ impl Obligation {
     ) -> Result<Idempotent<ObligationDefaultedReallocationData>, ObligationError> {
         idempotency_guard!(
             self.events.iter_all().rev(),
-            ObligationEvent::DefaultedRecorded { .. }
+            ObligationEvent::DefaultedRecorded { .. },
+            => ObligationEvent::Reinstated { .. }
         );
 
         match self.status() {
@@ -443,6 +526,90 @@ impl Obligation {
         Ok(Idempotent::Executed(res))
     }
 
+    pub(crate) fn request_reinstatement(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, ObligationError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            ObligationEvent::ReinstatementRequested { approval_process_id: id, .. }
+                if *id == approval_process_id
+        );
+
+        if self.status() != ObligationStatus::Defaulted {
+            return Err(ObligationError::NotDefaulted(self.id));
+        }
+        if self.pending_reinstatement().is_some() {
+            return Err(ObligationError::ReinstatementAlreadyPending(self.id));
+        }
+
+        self.events.push(ObligationEvent::ReinstatementRequested {
+            approval_process_id,
+            audit_info,
+        });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Concludes a pending reinstatement request. When approved the defaulted
+    /// balance is moved back to the receivable the obligation had before it
+    /// defaulted.
+    pub(crate) fn conclude_reinstatement(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Idempotent<Option<ObligationReinstatementData>> {
+        if self.pending_reinstatement() != Some(approval_process_id) {
+            return Idempotent::Ignored;
+        }
+
+        let (status, pre_default_status) = self.status_with_pre_default_status();
+        if !approved || status != ObligationStatus::Defaulted {
+            self.events.push(ObligationEvent::ReinstatementDenied {
+                approval_process_id,
+                audit_info,
+            });
+            return Idempotent::Executed(None);
+        }
+
+        let defaulted_amount = self
+            .events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                ObligationEvent::DefaultedRecorded {
+                    defaulted_amount, ..
+                } => Some(*defaulted_amount),
+                _ => None,
+            })
+            .expect("Obligation was not defaulted");
+        let receivable_account_id = match pre_default_status {
+            ObligationStatus::Due => self.due_accounts().receivable_account_id,
+            _ => self.overdue_accounts().receivable_account_id,
+        };
+
+        let res = ObligationReinstatementData {
+            tx_id: LedgerTxId::new(),
+            amount: defaulted_amount,
+            defaulted_account_id: self.defaulted_account(),
+            receivable_account_id,
+            effective,
+        };
+
+        self.events.push(ObligationEvent::Reinstated {
+            ledger_tx_id: res.tx_id,
+            approval_process_id,
+            reinstated_amount: res.amount,
+            effective,
+            audit_info,
+        });
+
+        Idempotent::Executed(Some(res))
+    }
+
     pub(crate) fn start_liquidation(
         &mut self,
         effective: chrono::NaiveDate,
@@ -553,6 +720,51 @@ impl Obligation {
 
         Idempotent::Executed(allocation)
     }
+
+    pub(crate) fn record_adjustment(
+        &mut self,
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<ObligationAdjustment> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            ObligationEvent::AdjustmentRecorded { ledger_tx_id: id, .. } if *id == ledger_tx_id
+        );
+        let pre_adjustment_outstanding = self.outstanding();
+        if pre_adjustment_outstanding.is_zero() || self.is_in_liquidation() {
+            return Idempotent::Ignored;
+        }
+
+        let adjustment = ObligationAdjustment {
+            amount: std::cmp::min(pre_adjustment_outstanding, amount),
+            receivable_account_id: self
+                .receivable_account_id()
+                .expect("Obligation was already paid"),
+        };
+        self.events.push(ObligationEvent::AdjustmentRecorded {
+            ledger_tx_id,
+            amount: adjustment.amount,
+            effective,
+            audit_info: audit_info.clone(),
+        });
+
+        if self.outstanding().is_zero() {
+            self.events.push(ObligationEvent::Completed {
+                effective,
+                audit_info: audit_info.clone(),
+            });
+        }
+
+        Idempotent::Executed(adjustment)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ObligationAdjustment {
+    pub(crate) amount: UsdCents,
+    pub(crate) receivable_account_id: CalaAccountId,
 }
 
 impl TryFromEvents<ObligationEvent> for Obligation {
@@ -582,7 +794,11 @@ impl TryFromEvents<ObligationEvent> for Obligation {
                 ObligationEvent::DueRecorded { .. } => (),
                 ObligationEvent::OverdueRecorded { .. } => (),
                 ObligationEvent::DefaultedRecorded { .. } => (),
+                ObligationEvent::ReinstatementRequested { .. } => (),
+                ObligationEvent::ReinstatementDenied { .. } => (),
+                ObligationEvent::Reinstated { .. } => (),
                 ObligationEvent::PaymentAllocated { .. } => (),
+                ObligationEvent::AdjustmentRecorded { .. } => (),
                 ObligationEvent::LiquidationProcessStarted { .. } => (),
                 ObligationEvent::LiquidationProcessConcluded { .. } => (),
                 ObligationEvent::Completed { .. } => (),
@@ -754,6 +970,41 @@ mod test {
         ));
     }
 
+    #[test]
+    fn days_past_due_counts_from_due_date_until_paid() {
+        let due_date = Utc::now().date_naive();
+        let mut obligation = obligation_from(initial_events());
+        assert_eq!(obligation.days_past_due(due_date), 0);
+        assert_eq!(obligation.days_past_due(due_date - chrono::Days::new(3)), 0);
+        assert_eq!(obligation.days_past_due(due_date + chrono::Days::new(3)), 3);
+
+        let paid_at = due_date + chrono::Days::new(5);
+        obligation.events.push(ObligationEvent::Completed {
+            effective: paid_at,
+            audit_info: dummy_audit_info(),
+        });
+        assert_eq!(obligation.days_past_due(due_date + chrono::Days::new(4)), 4);
+        assert_eq!(obligation.days_past_due(paid_at + chrono::Days::new(10)), 0);
+    }
+
+    #[test]
+    fn past_due_since_follows_status() {
+        let mut obligation = obligation_from(initial_events());
+        assert_eq!(obligation.past_due_since(), None);
+
+        let _ = obligation.record_due(Utc::now().date_naive(), dummy_audit_info());
+        assert_eq!(
+            obligation.past_due_since(),
+            Some(obligation.due_at().date_naive())
+        );
+
+        obligation.events.push(ObligationEvent::Completed {
+            effective: Utc::now().date_naive(),
+            audit_info: dummy_audit_info(),
+        });
+        assert_eq!(obligation.past_due_since(), None);
+    }
+
     #[test]
     fn can_record_due() {
         let mut obligation = obligation_from(initial_events());
@@ -860,6 +1111,87 @@ mod test {
         assert_eq!(res.amount, obligation.initial_amount);
     }
 
+    #[test]
+    fn reinstatement_restores_pre_default_status() {
+        let mut obligation = obligation_from(initial_events());
+        let _ = obligation.record_due(Utc::now().date_naive(), dummy_audit_info());
+        let _ = obligation.record_overdue(Utc::now().date_naive(), dummy_audit_info());
+        let _ = obligation.record_defaulted(Utc::now().date_naive(), dummy_audit_info());
+        assert_eq!(obligation.status(), ObligationStatus::Defaulted);
+
+        let approval_process_id = ApprovalProcessId::new();
+        assert!(
+            obligation
+                .request_reinstatement(approval_process_id, dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert!(matches!(
+            obligation.request_reinstatement(ApprovalProcessId::new(), dummy_audit_info()),
+            Err(ObligationError::ReinstatementAlreadyPending(_))
+        ));
+
+        let res = obligation
+            .conclude_reinstatement(
+                approval_process_id,
+                true,
+                Utc::now().date_naive(),
+                dummy_audit_info(),
+            )
+            .unwrap()
+            .expect("reinstatement should be approved");
+        assert_eq!(res.amount, obligation.initial_amount);
+        assert_eq!(res.defaulted_account_id, obligation.defaulted_account());
+        assert_eq!(
+            res.receivable_account_id,
+            obligation.overdue_accounts().receivable_account_id
+        );
+        assert_eq!(obligation.status(), ObligationStatus::Overdue);
+        assert!(obligation.pending_reinstatement().is_none());
+        assert!(
+            obligation
+                .conclude_reinstatement(
+                    approval_process_id,
+                    true,
+                    Utc::now().date_naive(),
+                    dummy_audit_info()
+                )
+                .was_ignored()
+        );
+
+        assert!(
+            obligation
+                .record_defaulted(Utc::now().date_naive(), dummy_audit_info())
+                .unwrap()
+                .did_execute(),
+            "a reinstated obligation can default again"
+        );
+    }
+
+    #[test]
+    fn denied_reinstatement_keeps_obligation_defaulted() {
+        let mut obligation = obligation_from(initial_events());
+        let _ = obligation.record_due(Utc::now().date_naive(), dummy_audit_info());
+        assert!(matches!(
+            obligation.request_reinstatement(ApprovalProcessId::new(), dummy_audit_info()),
+            Err(ObligationError::NotDefaulted(_))
+        ));
+        let _ = obligation.record_defaulted(Utc::now().date_naive(), dummy_audit_info());
+
+        let approval_process_id = ApprovalProcessId::new();
+        let _ = obligation.request_reinstatement(approval_process_id, dummy_audit_info());
+        let res = obligation.conclude_reinstatement(
+            approval_process_id,
+            false,
+            Utc::now().date_naive(),
+            dummy_audit_info(),
+        );
+
+        assert!(matches!(res, Idempotent::Executed(None)));
+        assert_eq!(obligation.status(), ObligationStatus::Defaulted);
+        assert!(obligation.pending_reinstatement().is_none());
+    }
+
     #[test]
     fn ignores_defaulted_recorded_if_paid() {
         let mut events = initial_events();
@@ -924,6 +1256,33 @@ mod test {
         );
     }
 
+    #[test]
+    fn adjustment_is_capped_at_outstanding_and_completes() {
+        let mut obligation = obligation_from(initial_events());
+        let adjustment = obligation
+            .record_adjustment(
+                LedgerTxId::new(),
+                UsdCents::from(100),
+                Utc::now().date_naive(),
+                &dummy_audit_info(),
+            )
+            .unwrap();
+        assert_eq!(adjustment.amount, UsdCents::from(10));
+        assert_eq!(obligation.outstanding(), UsdCents::ZERO);
+        assert_eq!(obligation.status(), ObligationStatus::Paid);
+
+        assert!(
+            obligation
+                .record_adjustment(
+                    LedgerTxId::new(),
+                    UsdCents::ONE,
+                    Utc::now().date_naive(),
+                    &dummy_audit_info(),
+                )
+                .was_ignored()
+        );
+    }
+
     mod is_status_up_to_date {
 
         use super::*;
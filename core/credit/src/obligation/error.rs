@@ -18,6 +18,10 @@ pub enum ObligationError {
     InvalidStatusTransitionToOverdue,
     #[error("ObligationError - InvalidStatusTransitionToDefaulted")]
     InvalidStatusTransitionToDefaulted,
+    #[error("ObligationError - NotDefaulted: {0}")]
+    NotDefaulted(crate::primitives::ObligationId),
+    #[error("ObligationError - ReinstatementAlreadyPending: {0}")]
+    ReinstatementAlreadyPending(crate::primitives::ObligationId),
     #[error("ObligationError - PaymentAmountGreaterThanOutstandingObligations")]
     PaymentAmountGreaterThanOutstandingObligations,
 }
@@ -18,8 +18,8 @@ use crate::{
     liquidation_process::{LiquidationProcess, LiquidationProcessRepo},
     payment_allocation::NewPaymentAllocation,
     primitives::{
-        CoreCreditAction, CoreCreditObject, CreditFacilityId, ObligationId, ObligationType,
-        PaymentId, UsdCents,
+        ApprovalProcessId, CoreCreditAction, CoreCreditObject, CreditFacilityId, LedgerTxId,
+        ObligationId, ObligationStatus, ObligationType, PaymentId, UsdCents,
     },
     publisher::CreditFacilityPublisher,
 };
@@ -203,6 +203,75 @@ where
         Ok(data)
     }
 
+    /// Requests reinstatement of every defaulted obligation of the facility
+    /// under a single approval process. Returns how many were requested.
+    pub(crate) async fn request_reinstatement_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        credit_facility_id: CreditFacilityId,
+        approval_process_id: ApprovalProcessId,
+        audit_info: &AuditInfo,
+    ) -> Result<usize, ObligationError> {
+        let mut obligations = self.facility_obligations(credit_facility_id).await?;
+
+        let mut n_requested = 0;
+        for obligation in obligations
+            .iter_mut()
+            .filter(|o| o.status() == ObligationStatus::Defaulted)
+        {
+            if obligation
+                .request_reinstatement(approval_process_id, audit_info.clone())?
+                .did_execute()
+            {
+                self.repo.update_in_op(db, obligation).await?;
+                n_requested += 1;
+            }
+        }
+
+        Ok(n_requested)
+    }
+
+    pub(crate) async fn conclude_reinstatement_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        credit_facility_id: CreditFacilityId,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        effective: chrono::NaiveDate,
+    ) -> Result<Vec<ObligationReinstatementData>, ObligationError> {
+        let mut obligations = self.facility_obligations(credit_facility_id).await?;
+
+        let mut reinstatements = Vec::new();
+        for obligation in obligations.iter_mut() {
+            if obligation.pending_reinstatement() != Some(approval_process_id) {
+                continue;
+            }
+
+            let audit_info = self
+                .authz
+                .audit()
+                .record_system_entry_in_tx(
+                    db.tx(),
+                    CoreCreditObject::obligation(obligation.id),
+                    CoreCreditAction::OBLIGATION_UPDATE_STATUS,
+                )
+                .await
+                .map_err(authz::error::AuthorizationError::from)?;
+
+            if let Idempotent::Executed(reinstatement) = obligation.conclude_reinstatement(
+                approval_process_id,
+                approved,
+                effective,
+                audit_info,
+            ) {
+                self.repo.update_in_op(db, obligation).await?;
+                reinstatements.extend(reinstatement);
+            }
+        }
+
+        Ok(reinstatements)
+    }
+
     pub async fn start_liquidation_process_in_op(
         &self,
         db: &mut es_entity::DbOp<'_>,
@@ -239,6 +308,29 @@ where
         Ok((obligation, liquidation_process))
     }
 
+    pub(crate) async fn record_adjustment_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: ObligationId,
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<Option<ObligationAdjustment>, ObligationError> {
+        let mut obligation = self.repo.find_by_id(id).await?;
+
+        let adjustment = if let Idempotent::Executed(adjustment) =
+            obligation.record_adjustment(ledger_tx_id, amount, effective, audit_info)
+        {
+            self.repo.update_in_op(db, &mut obligation).await?;
+            Some(adjustment)
+        } else {
+            None
+        };
+
+        Ok(adjustment)
+    }
+
     pub async fn find_by_id_without_audit(
         &self,
         id: ObligationId,
@@ -299,6 +391,32 @@ where
         Ok(true)
     }
 
+    pub(crate) async fn days_past_due(
+        &self,
+        credit_facility_id: CreditFacilityId,
+        as_of: chrono::NaiveDate,
+    ) -> Result<u32, ObligationError> {
+        let obligations = self.facility_obligations(credit_facility_id).await?;
+        Ok(obligations
+            .iter()
+            .map(|obligation| obligation.days_past_due(as_of))
+            .max()
+            .unwrap_or(0))
+    }
+
+    /// Earliest due date among the facility's unpaid obligations that have
+    /// already fallen due.
+    pub(crate) async fn past_due_since(
+        &self,
+        credit_facility_id: CreditFacilityId,
+    ) -> Result<Option<chrono::NaiveDate>, ObligationError> {
+        let obligations = self.facility_obligations(credit_facility_id).await?;
+        Ok(obligations
+            .iter()
+            .filter_map(|obligation| obligation.past_due_since())
+            .min())
+    }
+
     async fn facility_obligations(
         &self,
         credit_facility_id: CreditFacilityId,
@@ -26,6 +26,14 @@ pub struct ObligationOverdueReallocationData {
     pub effective: chrono::NaiveDate,
 }
 
+pub struct ObligationReinstatementData {
+    pub tx_id: LedgerTxId,
+    pub amount: UsdCents,
+    pub defaulted_account_id: CalaAccountId,
+    pub receivable_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
 pub struct ObligationDefaultedReallocationData {
     pub tx_id: LedgerTxId,
     pub amount: UsdCents,
@@ -0,0 +1,308 @@
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::primitives::*;
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "ParticipationId")]
+pub enum ParticipationEvent {
+    Initialized {
+        id: ParticipationId,
+        credit_facility_id: CreditFacilityId,
+        partner_name: String,
+        share: ParticipationSharePct,
+        payable_account_id: CalaAccountId,
+        audit_info: AuditInfo,
+    },
+    ShareAllocated {
+        ledger_tx_id: LedgerTxId,
+        source_tx_id: LedgerTxId,
+        share_type: ParticipationShareType,
+        facility_amount: UsdCents,
+        partner_amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct ParticipationShareAllocation {
+    pub tx_id: LedgerTxId,
+    pub share_type: ParticipationShareType,
+    pub amount: UsdCents,
+    pub partner_payable_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
+/// A single line of a partner statement: the facility cash flow and the
+/// portion passed on to the partner.
+#[derive(Debug, Clone)]
+pub struct ParticipationStatementEntry {
+    pub ledger_tx_id: LedgerTxId,
+    pub share_type: ParticipationShareType,
+    pub facility_amount: UsdCents,
+    pub partner_amount: UsdCents,
+    pub effective: chrono::NaiveDate,
+    pub recorded_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct Participation {
+    pub id: ParticipationId,
+    pub credit_facility_id: CreditFacilityId,
+    pub partner_name: String,
+    pub share: ParticipationSharePct,
+    pub payable_account_id: CalaAccountId,
+
+    events: EntityEvents<ParticipationEvent>,
+}
+
+impl Participation {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("entity_first_persisted_at not found")
+    }
+
+    pub fn statement(&self) -> Vec<ParticipationStatementEntry> {
+        self.events
+            .iter_persisted()
+            .filter_map(|event| match &event.event {
+                ParticipationEvent::ShareAllocated {
+                    ledger_tx_id,
+                    share_type,
+                    facility_amount,
+                    partner_amount,
+                    effective,
+                    ..
+                } => Some(ParticipationStatementEntry {
+                    ledger_tx_id: *ledger_tx_id,
+                    share_type: *share_type,
+                    facility_amount: *facility_amount,
+                    partner_amount: *partner_amount,
+                    effective: *effective,
+                    recorded_at: event.recorded_at,
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn total_partner_amount(&self, share_type: ParticipationShareType) -> UsdCents {
+        self.events
+            .iter_all()
+            .filter_map(|event| match event {
+                ParticipationEvent::ShareAllocated {
+                    share_type: event_share_type,
+                    partner_amount,
+                    ..
+                } if *event_share_type == share_type => Some(*partner_amount),
+                _ => None,
+            })
+            .fold(UsdCents::ZERO, |acc, amount| acc + amount)
+    }
+
+    pub(crate) fn allocate_share(
+        &mut self,
+        source_tx_id: LedgerTxId,
+        share_type: ParticipationShareType,
+        facility_amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Idempotent<ParticipationShareAllocation> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            ParticipationEvent::ShareAllocated { source_tx_id: id, .. } if id == &source_tx_id
+        );
+
+        let partner_amount = self.share.apply(facility_amount);
+        if partner_amount.is_zero() {
+            return Idempotent::Ignored;
+        }
+
+        let tx_id = LedgerTxId::new();
+        self.events.push(ParticipationEvent::ShareAllocated {
+            ledger_tx_id: tx_id,
+            source_tx_id,
+            share_type,
+            facility_amount,
+            partner_amount,
+            effective,
+            audit_info,
+        });
+
+        Idempotent::Executed(ParticipationShareAllocation {
+            tx_id,
+            share_type,
+            amount: partner_amount,
+            partner_payable_account_id: self.payable_account_id,
+            effective,
+        })
+    }
+}
+
+impl TryFromEvents<ParticipationEvent> for Participation {
+    fn try_from_events(events: EntityEvents<ParticipationEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = ParticipationBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                ParticipationEvent::Initialized {
+                    id,
+                    credit_facility_id,
+                    partner_name,
+                    share,
+                    payable_account_id,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .credit_facility_id(*credit_facility_id)
+                        .partner_name(partner_name.clone())
+                        .share(*share)
+                        .payable_account_id(*payable_account_id)
+                }
+                ParticipationEvent::ShareAllocated { .. } => (),
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewParticipation {
+    #[builder(setter(into))]
+    pub(super) id: ParticipationId,
+    #[builder(setter(into))]
+    pub(super) credit_facility_id: CreditFacilityId,
+    #[builder(setter(into))]
+    pub(super) partner_name: String,
+    pub(super) share: ParticipationSharePct,
+    #[builder(setter(into))]
+    pub(super) payable_account_id: CalaAccountId,
+    pub(super) audit_info: AuditInfo,
+}
+
+impl NewParticipation {
+    pub fn builder() -> NewParticipationBuilder {
+        NewParticipationBuilder::default()
+    }
+}
+
+impl IntoEvents<ParticipationEvent> for NewParticipation {
+    fn into_events(self) -> EntityEvents<ParticipationEvent> {
+        EntityEvents::init(
+            self.id,
+            [ParticipationEvent::Initialized {
+                id: self.id,
+                credit_facility_id: self.credit_facility_id,
+                partner_name: self.partner_name,
+                share: self.share,
+                payable_account_id: self.payable_account_id,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::{AuditEntryId, AuditInfo};
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn participation(share: ParticipationSharePct) -> Participation {
+        let id = ParticipationId::new();
+        Participation::try_from_events(EntityEvents::init(
+            id,
+            [ParticipationEvent::Initialized {
+                id,
+                credit_facility_id: CreditFacilityId::new(),
+                partner_name: "Partner Bank".to_string(),
+                share,
+                payable_account_id: CalaAccountId::new(),
+                audit_info: dummy_audit_info(),
+            }],
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn allocates_partner_share_rounded_down() {
+        let mut participation = participation(ParticipationSharePct::from(dec!(30)));
+
+        let allocation = participation
+            .allocate_share(
+                LedgerTxId::new(),
+                ParticipationShareType::Interest,
+                UsdCents::from(1001),
+                chrono::Utc::now().date_naive(),
+                dummy_audit_info(),
+            )
+            .unwrap();
+
+        assert_eq!(allocation.amount, UsdCents::from(300));
+        assert_eq!(allocation.share_type, ParticipationShareType::Interest);
+        assert_eq!(
+            allocation.partner_payable_account_id,
+            participation.payable_account_id
+        );
+        assert_eq!(
+            participation.total_partner_amount(ParticipationShareType::Interest),
+            UsdCents::from(300)
+        );
+        assert_eq!(
+            participation.total_partner_amount(ParticipationShareType::Principal),
+            UsdCents::ZERO
+        );
+    }
+
+    #[test]
+    fn allocation_is_idempotent_per_source_transaction() {
+        let mut participation = participation(ParticipationSharePct::from(dec!(50)));
+        let source_tx_id = LedgerTxId::new();
+        let effective = chrono::Utc::now().date_naive();
+
+        assert!(
+            participation
+                .allocate_share(
+                    source_tx_id,
+                    ParticipationShareType::Principal,
+                    UsdCents::from(1000),
+                    effective,
+                    dummy_audit_info(),
+                )
+                .did_execute()
+        );
+        assert!(
+            participation
+                .allocate_share(
+                    source_tx_id,
+                    ParticipationShareType::Principal,
+                    UsdCents::from(1000),
+                    effective,
+                    dummy_audit_info(),
+                )
+                .was_ignored()
+        );
+        assert_eq!(
+            participation.total_partner_amount(ParticipationShareType::Principal),
+            UsdCents::from(500)
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+use crate::primitives::ParticipationSharePct;
+
+#[derive(Error, Debug)]
+pub enum ParticipationError {
+    #[error("ParticipationError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("ParticipationError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("ParticipationError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("ParticipationError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("ParticipationError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+    #[error("ParticipationError - PartnerNameMissing")]
+    PartnerNameMissing,
+    #[error("ParticipationError - InvalidShare: {0:?}")]
+    InvalidShare(ParticipationSharePct),
+    #[error("ParticipationError - TotalShareExceeded: {0:?}")]
+    TotalShareExceeded(ParticipationSharePct),
+}
+
+es_entity::from_es_entity_error!(ParticipationError);
@@ -0,0 +1,213 @@
+mod entity;
+pub mod error;
+mod repo;
+
+use audit::{AuditInfo, AuditSvc};
+use authz::PermissionCheck;
+use tracing::instrument;
+
+use crate::primitives::*;
+
+#[cfg(feature = "json-schema")]
+pub use entity::ParticipationEvent;
+pub(crate) use entity::*;
+pub use entity::{Participation, ParticipationShareAllocation, ParticipationStatementEntry};
+use error::ParticipationError;
+use repo::{ListDirection, ParticipationRepo};
+
+pub struct Participations<Perms>
+where
+    Perms: PermissionCheck,
+{
+    authz: Perms,
+    repo: ParticipationRepo,
+}
+
+impl<Perms> Clone for Participations<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+}
+
+impl<Perms> Participations<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreCreditAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreCreditObject>,
+{
+    pub(crate) fn new(pool: &sqlx::PgPool, authz: &Perms) -> Self {
+        Self {
+            authz: authz.clone(),
+            repo: ParticipationRepo::new(pool),
+        }
+    }
+
+    pub(crate) async fn begin_op(&self) -> Result<es_entity::DbOp<'static>, ParticipationError> {
+        Ok(self.repo.begin_op().await?)
+    }
+
+    pub async fn subject_can_manage(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: CreditFacilityId,
+        enforce: bool,
+    ) -> Result<Option<AuditInfo>, ParticipationError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_MANAGE_PARTICIPATIONS,
+                enforce,
+            )
+            .await?)
+    }
+
+    /// Creates a participation after checking that the facility would still
+    /// retain part of its cash flows once the new share is sold.
+    pub(crate) async fn create_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        audit_info: AuditInfo,
+        credit_facility_id: CreditFacilityId,
+        partner_name: String,
+        share: ParticipationSharePct,
+    ) -> Result<Participation, ParticipationError> {
+        let partner_name = partner_name.trim().to_string();
+        if partner_name.is_empty() {
+            return Err(ParticipationError::PartnerNameMissing);
+        }
+        if !share.is_valid() {
+            return Err(ParticipationError::InvalidShare(share));
+        }
+
+        let total_share = self
+            .list_for_credit_facility_without_audit(credit_facility_id)
+            .await?
+            .iter()
+            .fold(share, |acc, participation| acc + participation.share);
+        if total_share >= ParticipationSharePct::FULL {
+            return Err(ParticipationError::TotalShareExceeded(total_share));
+        }
+
+        let new_participation = NewParticipation::builder()
+            .id(ParticipationId::new())
+            .credit_facility_id(credit_facility_id)
+            .partner_name(partner_name)
+            .share(share)
+            .payable_account_id(CalaAccountId::new())
+            .audit_info(audit_info)
+            .build()
+            .expect("could not build new participation");
+
+        self.repo.create_in_op(db, new_participation).await
+    }
+
+    pub(crate) async fn allocate_share_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        participation: &mut Participation,
+        source_tx_id: LedgerTxId,
+        share_type: ParticipationShareType,
+        facility_amount: UsdCents,
+        effective: chrono::NaiveDate,
+    ) -> Result<Option<ParticipationShareAllocation>, ParticipationError> {
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreCreditObject::credit_facility(participation.credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_MANAGE_PARTICIPATIONS,
+            )
+            .await?;
+
+        let allocation = if let es_entity::Idempotent::Executed(allocation) = participation
+            .allocate_share(
+                source_tx_id,
+                share_type,
+                facility_amount,
+                effective,
+                audit_info,
+            ) {
+            self.repo.update_in_op(db, participation).await?;
+            Some(allocation)
+        } else {
+            None
+        };
+
+        Ok(allocation)
+    }
+
+    pub async fn find_all<T: From<Participation>>(
+        &self,
+        ids: &[ParticipationId],
+    ) -> Result<std::collections::HashMap<ParticipationId, T>, ParticipationError> {
+        self.repo.find_all(ids).await
+    }
+
+    #[instrument(name = "credit.participation.find_by_id", skip(self), err)]
+    pub async fn find_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ParticipationId> + std::fmt::Debug,
+    ) -> Result<Option<Participation>, ParticipationError> {
+        let participation = match self.repo.find_by_id(id.into()).await {
+            Ok(participation) => participation,
+            Err(e) if e.was_not_found() => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::credit_facility(participation.credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_READ,
+            )
+            .await?;
+
+        Ok(Some(participation))
+    }
+
+    #[instrument(
+        name = "credit.participation.list_for_credit_facility",
+        skip(self),
+        err
+    )]
+    pub async fn list_for_credit_facility(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: CreditFacilityId,
+    ) -> Result<Vec<Participation>, ParticipationError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_READ,
+            )
+            .await?;
+
+        self.list_for_credit_facility_without_audit(credit_facility_id)
+            .await
+    }
+
+    pub(crate) async fn list_for_credit_facility_without_audit(
+        &self,
+        credit_facility_id: CreditFacilityId,
+    ) -> Result<Vec<Participation>, ParticipationError> {
+        Ok(self
+            .repo
+            .list_for_credit_facility_id_by_created_at(
+                credit_facility_id,
+                Default::default(),
+                ListDirection::Ascending,
+            )
+            .await?
+            .entities)
+    }
+}
@@ -0,0 +1,25 @@
+use sqlx::PgPool;
+
+pub use es_entity::ListDirection;
+use es_entity::*;
+
+use crate::primitives::*;
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "Participation",
+    err = "ParticipationError",
+    columns(credit_facility_id(ty = "CreditFacilityId", list_for, update(persist = false))),
+    tbl_prefix = "core"
+)]
+pub struct ParticipationRepo {
+    pool: PgPool,
+}
+
+impl ParticipationRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::primitives::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PortfolioAgingBucket {
+    pub bucket: AgingBucket,
+    pub facilities: u32,
+    pub outstanding: UsdCents,
+}
+
+/// Active portfolio grouped by days past due. Amounts are the principal still
+/// owed on the facilities in each bucket (portfolio at risk).
+#[derive(Debug, Clone)]
+pub struct PortfolioAging {
+    pub as_of: chrono::NaiveDate,
+    pub facilities: u32,
+    pub total_outstanding: UsdCents,
+    pub buckets: Vec<PortfolioAgingBucket>,
+}
+
+impl PortfolioAging {
+    pub fn new(
+        as_of: chrono::NaiveDate,
+        facilities: impl IntoIterator<Item = (u32, UsdCents)>,
+    ) -> Self {
+        let mut buckets: Vec<_> = AgingBucket::ALL
+            .into_iter()
+            .map(|bucket| PortfolioAgingBucket {
+                bucket,
+                facilities: 0,
+                outstanding: UsdCents::ZERO,
+            })
+            .collect();
+
+        for (days_past_due, outstanding) in facilities {
+            let bucket = AgingBucket::from_days_past_due(days_past_due);
+            let entry = buckets
+                .iter_mut()
+                .find(|entry| entry.bucket == bucket)
+                .expect("all buckets are present");
+            entry.facilities += 1;
+            entry.outstanding += outstanding;
+        }
+
+        Self {
+            as_of,
+            facilities: buckets.iter().map(|entry| entry.facilities).sum(),
+            total_outstanding: buckets
+                .iter()
+                .fold(UsdCents::ZERO, |total, entry| total + entry.outstanding),
+            buckets,
+        }
+    }
+
+    pub fn bucket(&self, bucket: AgingBucket) -> &PortfolioAgingBucket {
+        self.buckets
+            .iter()
+            .find(|entry| entry.bucket == bucket)
+            .expect("all buckets are present")
+    }
+
+    /// Share of outstanding principal sitting in `bucket` or any later one.
+    pub fn portfolio_at_risk_pct(&self, bucket: AgingBucket) -> rust_decimal::Decimal {
+        if self.total_outstanding.is_zero() {
+            return rust_decimal::Decimal::ZERO;
+        }
+
+        let at_risk = self
+            .buckets
+            .iter()
+            .filter(|entry| entry.bucket >= bucket)
+            .fold(UsdCents::ZERO, |total, entry| total + entry.outstanding);
+        (rust_decimal::Decimal::from(at_risk.into_inner()) * rust_decimal::Decimal::ONE_HUNDRED
+            / rust_decimal::Decimal::from(self.total_outstanding.into_inner()))
+        .round_dp(2)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn buckets_by_days_past_due() {
+        assert_eq!(AgingBucket::from_days_past_due(0), AgingBucket::Current);
+        assert_eq!(AgingBucket::from_days_past_due(1), AgingBucket::Days1To30);
+        assert_eq!(AgingBucket::from_days_past_due(30), AgingBucket::Days1To30);
+        assert_eq!(AgingBucket::from_days_past_due(31), AgingBucket::Days31To60);
+        assert_eq!(AgingBucket::from_days_past_due(60), AgingBucket::Days31To60);
+        assert_eq!(AgingBucket::from_days_past_due(61), AgingBucket::Days61To90);
+        assert_eq!(AgingBucket::from_days_past_due(90), AgingBucket::Days61To90);
+        assert_eq!(AgingBucket::from_days_past_due(91), AgingBucket::Over90Days);
+    }
+
+    #[test]
+    fn aggregates_facilities_into_buckets() {
+        let aging = PortfolioAging::new(
+            chrono::Utc::now().date_naive(),
+            vec![
+                (0, UsdCents::from(600)),
+                (0, UsdCents::from(100)),
+                (15, UsdCents::from(100)),
+                (45, UsdCents::from(100)),
+                (120, UsdCents::from(100)),
+            ],
+        );
+
+        assert_eq!(aging.buckets.len(), AgingBucket::ALL.len());
+        assert_eq!(aging.facilities, 5);
+        assert_eq!(aging.total_outstanding, UsdCents::from(1000));
+
+        let current = aging.bucket(AgingBucket::Current);
+        assert_eq!(current.facilities, 2);
+        assert_eq!(current.outstanding, UsdCents::from(700));
+        assert_eq!(aging.bucket(AgingBucket::Days61To90).facilities, 0);
+
+        assert_eq!(
+            aging.portfolio_at_risk_pct(AgingBucket::Days1To30),
+            dec!(30)
+        );
+        assert_eq!(
+            aging.portfolio_at_risk_pct(AgingBucket::Days31To60),
+            dec!(20)
+        );
+        assert_eq!(
+            aging.portfolio_at_risk_pct(AgingBucket::Over90Days),
+            dec!(10)
+        );
+    }
+
+    #[test]
+    fn empty_portfolio_has_no_risk() {
+        let aging = PortfolioAging::new(chrono::Utc::now().date_naive(), vec![]);
+
+        assert_eq!(aging.facilities, 0);
+        assert_eq!(
+            aging.portfolio_at_risk_pct(AgingBucket::Days1To30),
+            rust_decimal::Decimal::ZERO
+        );
+    }
+}
@@ -102,6 +102,12 @@ impl From<Decimal> for CVLPct {
     }
 }
 
+impl From<CVLPct> for Decimal {
+    fn from(value: CVLPct) -> Self {
+        value.0
+    }
+}
+
 #[cfg(test)]
 mod test {
     use rust_decimal_macros::dec;
@@ -34,7 +34,10 @@ es_entity::entity_id! {
     ObligationId,
     LiquidationProcessId,
     InterestAccrualCycleId,
-    TermsTemplateId;
+    TermsTemplateId,
+    LoanTapeId,
+    CreditFacilityProposalId,
+    ParticipationId;
 
     CreditFacilityId => governance::ApprovalProcessId,
     DisbursalId => governance::ApprovalProcessId,
@@ -42,6 +45,7 @@ es_entity::entity_id! {
     CreditFacilityId => job::JobId,
     InterestAccrualCycleId => job::JobId,
     ObligationId => job::JobId,
+    LoanTapeId => job::JobId,
 
     DisbursalId => LedgerTxId,
     PaymentAllocationId => LedgerTxId,
@@ -50,7 +54,8 @@ es_entity::entity_id! {
     DisbursalId => public_id::PublicIdTargetId,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 pub enum ObligationStatus {
     NotYetDue,
     Due,
@@ -122,6 +127,8 @@ pub type ChartOfAccountsIntegrationConfigAllOrOne = AllOrOne<ChartOfAccountsInte
 pub type DisbursalAllOrOne = AllOrOne<DisbursalId>;
 pub type ObligationAllOrOne = AllOrOne<ObligationId>;
 pub type TermsTemplateAllOrOne = AllOrOne<TermsTemplateId>;
+pub type LoanTapeAllOrOne = AllOrOne<LoanTapeId>;
+pub type CreditFacilityProposalAllOrOne = AllOrOne<CreditFacilityProposalId>;
 
 pub const PERMISSION_SET_CREDIT_WRITER: &str = "credit_writer";
 pub const PERMISSION_SET_CREDIT_VIEWER: &str = "credit_viewer";
@@ -140,6 +147,8 @@ pub enum CoreCreditObject {
     Disbursal(DisbursalAllOrOne),
     Obligation(ObligationAllOrOne),
     TermsTemplate(TermsTemplateAllOrOne),
+    LoanTape(LoanTapeAllOrOne),
+    CreditFacilityProposal(CreditFacilityProposalAllOrOne),
 }
 
 impl CoreCreditObject {
@@ -178,6 +187,22 @@ impl CoreCreditObject {
     pub fn all_terms_templates() -> Self {
         CoreCreditObject::TermsTemplate(AllOrOne::All)
     }
+
+    pub fn loan_tape(id: LoanTapeId) -> Self {
+        CoreCreditObject::LoanTape(AllOrOne::ById(id))
+    }
+
+    pub fn all_loan_tapes() -> Self {
+        CoreCreditObject::LoanTape(AllOrOne::All)
+    }
+
+    pub fn credit_facility_proposal(id: CreditFacilityProposalId) -> Self {
+        CoreCreditObject::CreditFacilityProposal(AllOrOne::ById(id))
+    }
+
+    pub fn all_credit_facility_proposals() -> Self {
+        CoreCreditObject::CreditFacilityProposal(AllOrOne::All)
+    }
 }
 
 impl std::fmt::Display for CoreCreditObject {
@@ -190,6 +215,8 @@ impl std::fmt::Display for CoreCreditObject {
             Disbursal(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             Obligation(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             TermsTemplate(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            LoanTape(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            CreditFacilityProposal(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
         }
     }
 }
@@ -221,6 +248,14 @@ impl FromStr for CoreCreditObject {
                 let obj_ref = id.parse().map_err(|_| "could not parse CoreCreditObject")?;
                 CoreCreditObject::TermsTemplate(obj_ref)
             }
+            LoanTape => {
+                let obj_ref = id.parse().map_err(|_| "could not parse CoreCreditObject")?;
+                CoreCreditObject::LoanTape(obj_ref)
+            }
+            CreditFacilityProposal => {
+                let obj_ref = id.parse().map_err(|_| "could not parse CoreCreditObject")?;
+                CoreCreditObject::CreditFacilityProposal(obj_ref)
+            }
         };
         Ok(res)
     }
@@ -235,6 +270,8 @@ pub enum CoreCreditAction {
     Disbursal(DisbursalAction),
     Obligation(ObligationAction),
     TermsTemplate(TermsTemplateAction),
+    LoanTape(LoanTapeAction),
+    CreditFacilityProposal(CreditFacilityProposalAction),
 }
 
 impl CoreCreditAction {
@@ -256,6 +293,18 @@ impl CoreCreditAction {
         CoreCreditAction::CreditFacility(CreditFacilityAction::UpdateCollateral);
     pub const CREDIT_FACILITY_UPDATE_COLLATERALIZATION_STATE: Self =
         CoreCreditAction::CreditFacility(CreditFacilityAction::UpdateCollateralizationState);
+    pub const CREDIT_FACILITY_SIMULATE: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::Simulate);
+    pub const CREDIT_FACILITY_STRESS_TEST: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::StressTest);
+    pub const CREDIT_FACILITY_CANCEL: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::Cancel);
+    pub const CREDIT_FACILITY_REQUEST_REINSTATEMENT: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::RequestReinstatement);
+    pub const CREDIT_FACILITY_MANAGE_PARTICIPATIONS: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::ManageParticipations);
+    pub const CREDIT_FACILITY_UPDATE_DAYS_PAST_DUE: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::UpdateDaysPastDue);
 
     pub const CHART_OF_ACCOUNTS_INTEGRATION_CONFIG_READ: Self =
         CoreCreditAction::ChartOfAccountsIntegrationConfig(
@@ -286,6 +335,22 @@ impl CoreCreditAction {
     pub const TERMS_TEMPLATE_LIST: Self =
         CoreCreditAction::TermsTemplate(TermsTemplateAction::List);
 
+    pub const LOAN_TAPE_CREATE: Self = CoreCreditAction::LoanTape(LoanTapeAction::Create);
+    pub const LOAN_TAPE_READ: Self = CoreCreditAction::LoanTape(LoanTapeAction::Read);
+    pub const LOAN_TAPE_GENERATE_DOWNLOAD_LINK: Self =
+        CoreCreditAction::LoanTape(LoanTapeAction::GenerateDownloadLink);
+
+    pub const CREDIT_FACILITY_PROPOSAL_CREATE: Self =
+        CoreCreditAction::CreditFacilityProposal(CreditFacilityProposalAction::Create);
+    pub const CREDIT_FACILITY_PROPOSAL_READ: Self =
+        CoreCreditAction::CreditFacilityProposal(CreditFacilityProposalAction::Read);
+    pub const CREDIT_FACILITY_PROPOSAL_LIST: Self =
+        CoreCreditAction::CreditFacilityProposal(CreditFacilityProposalAction::List);
+    pub const CREDIT_FACILITY_PROPOSAL_CONVERT: Self =
+        CoreCreditAction::CreditFacilityProposal(CreditFacilityProposalAction::Convert);
+    pub const CREDIT_FACILITY_PROPOSAL_REJECT: Self =
+        CoreCreditAction::CreditFacilityProposal(CreditFacilityProposalAction::Reject);
+
     pub fn entities() -> Vec<(
         CoreCreditActionDiscriminants,
         Vec<ActionDescription<NoPath>>,
@@ -303,6 +368,8 @@ impl CoreCreditAction {
                 Disbursal => DisbursalAction::describe(),
                 Obligation => ObligationAction::describe(),
                 TermsTemplate => TermsTemplateAction::describe(),
+                LoanTape => LoanTapeAction::describe(),
+                CreditFacilityProposal => CreditFacilityProposalAction::describe(),
             };
 
             result.push((*entity, actions));
@@ -322,6 +389,8 @@ impl std::fmt::Display for CoreCreditAction {
             Disbursal(action) => action.fmt(f),
             Obligation(action) => action.fmt(f),
             TermsTemplate(action) => action.fmt(f),
+            LoanTape(action) => action.fmt(f),
+            CreditFacilityProposal(action) => action.fmt(f),
         }
     }
 }
@@ -342,6 +411,10 @@ impl FromStr for CoreCreditAction {
             Disbursal => CoreCreditAction::from(action.parse::<DisbursalAction>()?),
             Obligation => CoreCreditAction::from(action.parse::<ObligationAction>()?),
             TermsTemplate => CoreCreditAction::from(action.parse::<TermsTemplateAction>()?),
+            LoanTape => CoreCreditAction::from(action.parse::<LoanTapeAction>()?),
+            CreditFacilityProposal => {
+                CoreCreditAction::from(action.parse::<CreditFacilityProposalAction>()?)
+            }
         };
         Ok(res)
     }
@@ -359,6 +432,12 @@ pub enum CreditFacilityAction {
     RecordInterest,
     Complete,
     UpdateCollateralizationState,
+    Simulate,
+    StressTest,
+    Cancel,
+    RequestReinstatement,
+    ManageParticipations,
+    UpdateDaysPastDue,
 }
 
 impl CreditFacilityAction {
@@ -390,6 +469,24 @@ impl CreditFacilityAction {
                 Self::UpdateCollateralizationState => {
                     ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER])
                 }
+                Self::Simulate => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_CREDIT_VIEWER, PERMISSION_SET_CREDIT_WRITER],
+                ),
+                Self::StressTest => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_CREDIT_VIEWER, PERMISSION_SET_CREDIT_WRITER],
+                ),
+                Self::Cancel => ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER]),
+                Self::RequestReinstatement => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER])
+                }
+                Self::ManageParticipations => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER])
+                }
+                Self::UpdateDaysPastDue => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER])
+                }
             };
             res.push(action_description);
         }
@@ -552,6 +649,84 @@ impl From<TermsTemplateAction> for CoreCreditAction {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum LoanTapeAction {
+    Create,
+    Read,
+    GenerateDownloadLink,
+}
+
+impl LoanTapeAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Create => ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER]),
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_CREDIT_VIEWER, PERMISSION_SET_CREDIT_WRITER],
+                ),
+                Self::GenerateDownloadLink => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_CREDIT_VIEWER, PERMISSION_SET_CREDIT_WRITER],
+                ),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<LoanTapeAction> for CoreCreditAction {
+    fn from(action: LoanTapeAction) -> Self {
+        Self::LoanTape(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum CreditFacilityProposalAction {
+    Create,
+    Read,
+    List,
+    Convert,
+    Reject,
+}
+
+impl CreditFacilityProposalAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Create => ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER]),
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_CREDIT_VIEWER, PERMISSION_SET_CREDIT_WRITER],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_CREDIT_VIEWER, PERMISSION_SET_CREDIT_WRITER],
+                ),
+                Self::Convert => ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER]),
+                Self::Reject => ActionDescription::new(variant, &[PERMISSION_SET_CREDIT_WRITER]),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<CreditFacilityProposalAction> for CoreCreditAction {
+    fn from(action: CreditFacilityProposalAction) -> Self {
+        Self::CreditFacilityProposal(action)
+    }
+}
+
 #[derive(
     Debug,
     Default,
@@ -573,6 +748,48 @@ pub enum CreditFacilityStatus {
     Active,
     Matured,
     Closed,
+    Cancelled,
+}
+
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum CreditFacilityProposalStatus {
+    #[default]
+    PendingReview,
+    Converted,
+    Rejected,
+}
+
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum TermsTemplateStatus {
+    #[default]
+    Active,
+    Retired,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -582,9 +799,99 @@ pub enum DisbursalStatus {
     New,
     Approved,
     Denied,
+    SettlementPending,
+    SettlementFailed,
     Confirmed,
 }
 
+/// Bank account outside of the ledger that a disbursal can be wired to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct ExternalBeneficiary {
+    pub name: String,
+    pub bank_name: String,
+    pub account_number: String,
+    pub routing_number: Option<String>,
+}
+
+/// Where the funds of a disbursal are sent once it is approved.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DisbursalDestination {
+    #[default]
+    DepositAccount,
+    ExternalAccount {
+        beneficiary: ExternalBeneficiary,
+    },
+}
+
+impl DisbursalDestination {
+    pub fn is_external(&self) -> bool {
+        matches!(self, Self::ExternalAccount { .. })
+    }
+}
+
+/// Percentage of a facility's cash flows sold to a participation partner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(transparent)]
+pub struct ParticipationSharePct(rust_decimal::Decimal);
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(ParticipationSharePct);
+
+impl ParticipationSharePct {
+    pub const ZERO: Self = Self(rust_decimal::Decimal::ZERO);
+    pub const FULL: Self = Self(rust_decimal::Decimal::ONE_HUNDRED);
+
+    pub fn is_valid(&self) -> bool {
+        *self > Self::ZERO && *self < Self::FULL
+    }
+
+    /// Partner's portion of `amount`, rounded down to the cent so the
+    /// facility never passes on more than it received.
+    pub fn apply(&self, amount: UsdCents) -> UsdCents {
+        use rust_decimal::prelude::ToPrimitive;
+
+        let cents = rust_decimal::Decimal::from(amount.into_inner()) * self.0
+            / rust_decimal::Decimal::ONE_HUNDRED;
+        UsdCents::from(
+            cents
+                .floor()
+                .to_u64()
+                .expect("should return a valid integer"),
+        )
+    }
+}
+
+impl std::ops::Add for ParticipationSharePct {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl From<rust_decimal::Decimal> for ParticipationSharePct {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ParticipationSharePct> for rust_decimal::Decimal {
+    fn from(value: ParticipationSharePct) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum ParticipationShareType {
+    Interest,
+    Principal,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Hash, Deserialize, sqlx::Type)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(transparent)]
@@ -632,6 +939,38 @@ pub enum CollateralizationState {
     NoCollateral,
 }
 
+/// Delinquency bucket of a facility based on its days past due.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum AgingBucket {
+    Current,
+    Days1To30,
+    Days31To60,
+    Days61To90,
+    Over90Days,
+}
+
+impl AgingBucket {
+    pub const ALL: [Self; 5] = [
+        Self::Current,
+        Self::Days1To30,
+        Self::Days31To60,
+        Self::Days61To90,
+        Self::Over90Days,
+    ];
+
+    pub fn from_days_past_due(days_past_due: u32) -> Self {
+        match days_past_due {
+            0 => Self::Current,
+            1..=30 => Self::Days1To30,
+            31..=60 => Self::Days31To60,
+            61..=90 => Self::Days61To90,
+            _ => Self::Over90Days,
+        }
+    }
+}
+
 pub struct CollateralUpdate {
     pub tx_id: LedgerTxId,
     pub abs_diff: Satoshis,
@@ -134,6 +134,11 @@ where
                     .await?;
                 disbursal
             }
+            crate::ApprovalProcessOutcome::SettlementPending(disbursal) => {
+                tracing::Span::current().record("already_applied", false);
+                db.commit().await?;
+                disbursal
+            }
             crate::ApprovalProcessOutcome::Denied(disbursal) => {
                 tracing::Span::current().record("already_applied", false);
                 let credit_facility = self
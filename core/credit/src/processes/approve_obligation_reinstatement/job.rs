@@ -0,0 +1,159 @@
+use async_trait::async_trait;
+use futures::StreamExt;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::{Outbox, OutboxEventMarker};
+
+use crate::{CoreCreditAction, CoreCreditEvent, CoreCreditObject, CreditFacilityId};
+
+use super::ApproveObligationReinstatement;
+
+#[derive(serde::Serialize)]
+pub struct ObligationReinstatementApprovalJobConfig<Perms, E> {
+    _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> ObligationReinstatementApprovalJobConfig<Perms, E> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Perms, E> Default for ObligationReinstatementApprovalJobConfig<Perms, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<Perms, E> JobConfig for ObligationReinstatementApprovalJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    type Initializer = ObligationReinstatementApprovalInit<Perms, E>;
+}
+
+pub struct ObligationReinstatementApprovalInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    outbox: Outbox<E>,
+    process: ApproveObligationReinstatement<Perms, E>,
+}
+
+impl<Perms, E> ObligationReinstatementApprovalInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    pub fn new(outbox: &Outbox<E>, process: &ApproveObligationReinstatement<Perms, E>) -> Self {
+        Self {
+            process: process.clone(),
+            outbox: outbox.clone(),
+        }
+    }
+}
+
+const OBLIGATION_REINSTATEMENT_APPROVE_JOB: JobType =
+    JobType::new("obligation-reinstatement-approval");
+impl<Perms, E> JobInitializer for ObligationReinstatementApprovalInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        OBLIGATION_REINSTATEMENT_APPROVE_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(ObligationReinstatementApprovalJobRunner {
+            outbox: self.outbox.clone(),
+            process: self.process.clone(),
+        }))
+    }
+
+    fn retry_on_error_settings() -> RetrySettings
+    where
+        Self: Sized,
+    {
+        RetrySettings::repeat_indefinitely()
+    }
+}
+
+#[derive(Default, Clone, Copy, serde::Deserialize, serde::Serialize)]
+struct ObligationReinstatementApprovalJobData {
+    sequence: outbox::EventSequence,
+}
+
+pub struct ObligationReinstatementApprovalJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    outbox: Outbox<E>,
+    process: ApproveObligationReinstatement<Perms, E>,
+}
+#[async_trait]
+impl<Perms, E> JobRunner for ObligationReinstatementApprovalJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    #[allow(clippy::single_match)]
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<ObligationReinstatementApprovalJobData>()?
+            .unwrap_or_default();
+        let mut stream = self.outbox.listen_persisted(Some(state.sequence)).await?;
+
+        while let Some(message) = stream.next().await {
+            match message.as_ref().as_event() {
+                Some(GovernanceEvent::ApprovalProcessConcluded {
+                    id,
+                    approved,
+                    process_type,
+                    target_ref,
+                    ..
+                }) if process_type == &super::APPROVE_OBLIGATION_REINSTATEMENT_PROCESS => {
+                    self.process
+                        .execute(target_ref.parse::<CreditFacilityId>()?, *id, *approved)
+                        .await?;
+                    state.sequence = message.sequence;
+                    current_job.update_execution_state(state).await?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(JobCompletion::RescheduleNow)
+    }
+}
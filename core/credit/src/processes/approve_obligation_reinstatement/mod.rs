@@ -0,0 +1,95 @@
+mod job;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{ApprovalProcessType, GovernanceAction, GovernanceEvent, GovernanceObject};
+use tracing::instrument;
+
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CoreCreditAction, CoreCreditError, CoreCreditEvent, CoreCreditObject,
+    ledger::CreditLedger,
+    obligation::Obligations,
+    primitives::{ApprovalProcessId, CreditFacilityId},
+};
+
+pub use job::*;
+pub const APPROVE_OBLIGATION_REINSTATEMENT_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("obligation-reinstatement");
+
+pub struct ApproveObligationReinstatement<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    obligations: Obligations<Perms, E>,
+    ledger: CreditLedger,
+}
+
+impl<Perms, E> Clone for ApproveObligationReinstatement<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            obligations: self.obligations.clone(),
+            ledger: self.ledger.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ApproveObligationReinstatement<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    pub fn new(obligations: &Obligations<Perms, E>, ledger: &CreditLedger) -> Self {
+        Self {
+            obligations: obligations.clone(),
+            ledger: ledger.clone(),
+        }
+    }
+
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    #[instrument(
+        name = "credit_facility.approve_obligation_reinstatement",
+        skip(self),
+        fields(n_reinstated)
+    )]
+    pub async fn execute(
+        &self,
+        credit_facility_id: CreditFacilityId,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+    ) -> Result<(), CoreCreditError> {
+        let mut db = self.obligations.begin_op().await?;
+
+        let reinstatements = self
+            .obligations
+            .conclude_reinstatement_in_op(
+                &mut db,
+                credit_facility_id,
+                approval_process_id,
+                approved,
+                crate::time::now().date_naive(),
+            )
+            .await?;
+        tracing::Span::current().record("n_reinstated", reinstatements.len());
+
+        if reinstatements.is_empty() {
+            db.commit().await?;
+        } else {
+            self.ledger
+                .record_obligations_reinstated(db, reinstatements)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
 pub mod activate_credit_facility;
 pub mod approve_credit_facility;
 pub mod approve_disbursal;
+pub mod approve_obligation_reinstatement;
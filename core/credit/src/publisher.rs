@@ -3,6 +3,9 @@ use outbox::{Outbox, OutboxEventMarker};
 use crate::{
     collateral::{Collateral, CollateralEvent, error::CollateralError},
     credit_facility::{CreditFacility, CreditFacilityEvent, error::CreditFacilityError},
+    credit_facility_proposal::{
+        CreditFacilityProposal, CreditFacilityProposalEvent, error::CreditFacilityProposalError,
+    },
     disbursal::{Disbursal, DisbursalEvent, error::DisbursalError},
     event::*,
     interest_accrual_cycle::{
@@ -77,6 +80,12 @@ where
                     id: entity.id,
                     completed_at: event.recorded_at,
                 }),
+                Cancelled { reason, .. } => Some(CoreCreditEvent::FacilityCancelled {
+                    id: entity.id,
+                    customer_id: entity.customer_id,
+                    reason: reason.clone(),
+                    cancelled_at: event.recorded_at,
+                }),
                 CollateralizationStateChanged {
                     collateralization_state: state,
                     collateral,
@@ -92,6 +101,16 @@ where
                     outstanding: *outstanding,
                     price: *price,
                 }),
+                PastDueSinceUpdated {
+                    past_due_since,
+                    principal_outstanding,
+                    ..
+                } => Some(CoreCreditEvent::FacilityPastDueUpdated {
+                    id: entity.id,
+                    past_due_since: *past_due_since,
+                    principal_outstanding: *principal_outstanding,
+                    recorded_at: event.recorded_at,
+                }),
 
                 _ => None,
             })
@@ -188,6 +207,20 @@ where
                     recorded_at: event.recorded_at,
                     effective: *effective,
                 }),
+                InterestAccrualAdjusted {
+                    ledger_tx_id,
+                    obligation_id,
+                    amount,
+                    effective,
+                    ..
+                } => Some(CoreCreditEvent::AccrualAdjusted {
+                    credit_facility_id: entity.credit_facility_id,
+                    ledger_tx_id: *ledger_tx_id,
+                    obligation_id: *obligation_id,
+                    amount: *amount,
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                }),
 
                 _ => None,
             })
@@ -276,6 +309,20 @@ where
                     credit_facility_id: entity.credit_facility_id,
                     amount: *amount,
                 }),
+                Reinstated {
+                    ledger_tx_id,
+                    reinstated_amount,
+                    effective,
+                    ..
+                } => Some(CoreCreditEvent::ObligationReinstated {
+                    id: entity.id,
+                    credit_facility_id: entity.credit_facility_id,
+                    ledger_tx_id: *ledger_tx_id,
+                    amount: *reinstated_amount,
+                    status: entity.status(),
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                }),
                 Completed { .. } => Some(CoreCreditEvent::ObligationCompleted {
                     id: entity.id,
                     credit_facility_id: entity.credit_facility_id,
@@ -327,4 +374,48 @@ where
             .await?;
         Ok(())
     }
+
+    pub async fn publish_credit_facility_proposal(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        entity: &CreditFacilityProposal,
+        new_events: es_entity::LastPersisted<'_, CreditFacilityProposalEvent>,
+    ) -> Result<(), CreditFacilityProposalError> {
+        use CreditFacilityProposalEvent::*;
+        let publish_events = new_events
+            .map(|event| match &event.event {
+                Initialized {
+                    id,
+                    customer_id,
+                    amount,
+                    terms_template,
+                    ..
+                } => CoreCreditEvent::FacilityProposalSubmitted {
+                    id: *id,
+                    customer_id: *customer_id,
+                    amount: *amount,
+                    terms_template_id: terms_template.terms_template_id,
+                    recorded_at: event.recorded_at,
+                },
+                Converted {
+                    credit_facility_id, ..
+                } => CoreCreditEvent::FacilityProposalConverted {
+                    id: entity.id,
+                    customer_id: entity.customer_id,
+                    credit_facility_id: *credit_facility_id,
+                    recorded_at: event.recorded_at,
+                },
+                Rejected { reason, .. } => CoreCreditEvent::FacilityProposalRejected {
+                    id: entity.id,
+                    customer_id: entity.customer_id,
+                    reason: reason.clone(),
+                    recorded_at: event.recorded_at,
+                },
+            })
+            .collect::<Vec<_>>();
+        self.outbox
+            .publish_all_persisted(db.tx(), publish_events)
+            .await?;
+        Ok(())
+    }
 }
@@ -101,7 +101,7 @@ impl CreditFacilityRepaymentPlan {
                     .truncate(maturity_date)
             };
 
-        let disbursed_outstanding = updated_entries
+        let mut disbursed_outstanding = updated_entries
             .iter()
             .filter_map(|entry| match entry {
                 CreditFacilityRepaymentPlanEntry {
@@ -119,6 +119,14 @@ impl CreditFacilityRepaymentPlan {
                 .annual_rate
                 .interest_for_time_period(disbursed_outstanding, period.days());
 
+            // Capitalized interest is added to the principal and repaid at maturity
+            let due_at = if terms.interest_capitalization.is_capitalized() {
+                disbursed_outstanding += interest;
+                maturity_date
+            } else {
+                period.end
+            };
+
             planned_interest_entries.push(CreditFacilityRepaymentPlanEntry {
                 repayment_type: RepaymentType::Interest,
                 obligation_id: None,
@@ -126,7 +134,7 @@ impl CreditFacilityRepaymentPlan {
                 initial: interest,
                 outstanding: interest,
 
-                due_at: period.end,
+                due_at,
                 overdue_at: None,
                 defaulted_at: None,
                 recorded_at: period.end,
@@ -215,10 +223,24 @@ impl CreditFacilityRepaymentPlan {
 
                 existing_obligations.push(entry);
             }
+            CoreCreditEvent::AccrualPosted { effective, .. }
+                if self
+                    .terms
+                    .is_some_and(|terms| terms.interest_capitalization.is_capitalized()) =>
+            {
+                // Capitalized interest is recorded as a disbursal obligation
+                let effective = EffectiveDate::from(*effective);
+                self.last_interest_accrual_at = Some(effective.end_of_day());
+            }
             CoreCreditEvent::FacilityRepaymentRecorded {
                 obligation_id,
                 amount,
                 ..
+            }
+            | CoreCreditEvent::AccrualAdjusted {
+                obligation_id: Some(obligation_id),
+                amount,
+                ..
             } => {
                 if let Some(entry) = existing_obligations.iter_mut().find_map(|entry| {
                     (entry.obligation_id == Some(*obligation_id)).then_some(entry)
@@ -237,6 +259,9 @@ impl CreditFacilityRepaymentPlan {
             | CoreCreditEvent::ObligationDefaulted {
                 id: obligation_id, ..
             }
+            | CoreCreditEvent::ObligationReinstated {
+                id: obligation_id, ..
+            }
             | CoreCreditEvent::ObligationCompleted {
                 id: obligation_id, ..
             } => {
@@ -247,6 +272,7 @@ impl CreditFacilityRepaymentPlan {
                         CoreCreditEvent::ObligationDue { .. } => RepaymentStatus::Due,
                         CoreCreditEvent::ObligationOverdue { .. } => RepaymentStatus::Overdue,
                         CoreCreditEvent::ObligationDefaulted { .. } => RepaymentStatus::Defaulted,
+                        CoreCreditEvent::ObligationReinstated { status, .. } => (*status).into(),
                         CoreCreditEvent::ObligationCompleted { .. } => RepaymentStatus::Paid,
                         _ => unreachable!(),
                     };
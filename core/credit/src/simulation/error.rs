@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TermsSimulationError {
+    #[error("TermsSimulationError - EmptyPricePath")]
+    EmptyPricePath,
+    #[error("TermsSimulationError - ZeroAmount")]
+    ZeroAmount,
+}
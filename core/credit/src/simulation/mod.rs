@@ -0,0 +1,385 @@
+pub mod error;
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    primitives::*,
+    repayment_plan::{CreditFacilityRepaymentPlanEntry, RepaymentStatus, RepaymentType},
+    terms::{InterestPeriod, TermValues},
+};
+
+use error::TermsSimulationError;
+
+#[derive(Debug, Clone, Copy)]
+pub struct SimulatedPricePoint {
+    pub at: DateTime<Utc>,
+    pub price: PriceOfOneBTC,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SimulatedCvlPoint {
+    pub at: DateTime<Utc>,
+    pub price: PriceOfOneBTC,
+    pub outstanding: UsdCents,
+    pub cvl: CVLPct,
+    pub collateralization_state: CollateralizationState,
+}
+
+/// Projected outcome of a facility with the given terms, assuming the full amount
+/// is disbursed at activation and every interest obligation is paid when due.
+/// Under capitalizing terms the interest is instead added to the principal at the
+/// end of each accrual cycle and repaid at maturity.
+#[derive(Debug, Clone)]
+pub struct TermsSimulation {
+    pub starts_at: DateTime<Utc>,
+    pub matures_at: DateTime<Utc>,
+    pub structuring_fee: UsdCents,
+    pub total_interest: UsdCents,
+    pub repayment_plan: Vec<CreditFacilityRepaymentPlanEntry>,
+    pub cvl_points: Vec<SimulatedCvlPoint>,
+    pub margin_call_at: Option<DateTime<Utc>>,
+    pub liquidation_at: Option<DateTime<Utc>>,
+
+    terms: TermValues,
+    collateral: Satoshis,
+    upgrade_buffer_cvl_pct: CVLPct,
+}
+
+impl TermsSimulation {
+    pub fn run(
+        terms: TermValues,
+        amount: UsdCents,
+        collateral: Satoshis,
+        starts_at: DateTime<Utc>,
+        price_path: impl IntoIterator<Item = SimulatedPricePoint>,
+        upgrade_buffer_cvl_pct: CVLPct,
+    ) -> Result<Self, TermsSimulationError> {
+        if amount.is_zero() {
+            return Err(TermsSimulationError::ZeroAmount);
+        }
+
+        let mut price_path: Vec<_> = price_path.into_iter().collect();
+        if price_path.is_empty() {
+            return Err(TermsSimulationError::EmptyPricePath);
+        }
+        price_path.sort_by_key(|point| point.at);
+
+        let matures_at = terms.duration.maturity_date(starts_at);
+        let structuring_fee = terms.one_time_fee_rate.apply(amount);
+        let mut principal = amount + structuring_fee;
+
+        let mut simulation = Self {
+            starts_at,
+            matures_at,
+            structuring_fee,
+            total_interest: UsdCents::ZERO,
+            repayment_plan: vec![],
+            cvl_points: vec![],
+            margin_call_at: None,
+            liquidation_at: None,
+            terms,
+            collateral,
+            upgrade_buffer_cvl_pct,
+        };
+
+        for disbursed in [structuring_fee, amount] {
+            if !disbursed.is_zero() {
+                simulation.push_entry(RepaymentType::Disbursal, disbursed, starts_at, matures_at);
+            }
+        }
+
+        simulation.record_cvl(starts_at, price_at(&price_path, starts_at), principal);
+
+        let mut upcoming_prices = price_path
+            .iter()
+            .filter(|point| point.at > starts_at && point.at <= matures_at)
+            .peekable();
+
+        let mut next_cycle = terms
+            .accrual_cycle_interval
+            .period_from(starts_at)
+            .truncate(matures_at);
+        while let Some(cycle) = next_cycle {
+            let mut cycle_interest = UsdCents::ZERO;
+
+            let mut next_accrual = terms
+                .accrual_interval
+                .period_from(cycle.start)
+                .truncate(cycle.end);
+            while let Some(accrual) = next_accrual {
+                while let Some(point) = upcoming_prices.next_if(|point| point.at < accrual.end) {
+                    simulation.record_cvl(point.at, point.price, principal + cycle_interest);
+                }
+
+                cycle_interest += terms
+                    .annual_rate
+                    .interest_for_time_period(principal, accrual.days());
+                simulation.record_cvl(
+                    accrual.end,
+                    price_at(&price_path, accrual.end),
+                    principal + cycle_interest,
+                );
+
+                next_accrual = accrual.next().truncate(cycle.end);
+            }
+
+            simulation.post_interest(cycle, cycle_interest);
+            if terms.interest_capitalization.is_capitalized() {
+                principal += cycle_interest;
+            }
+            next_cycle = cycle.next().truncate(matures_at);
+        }
+
+        simulation.repayment_plan.sort();
+
+        Ok(simulation)
+    }
+
+    pub fn total_repayment(&self) -> UsdCents {
+        self.repayment_plan
+            .iter()
+            .fold(UsdCents::ZERO, |acc, entry| acc + entry.initial)
+    }
+
+    fn post_interest(&mut self, cycle: InterestPeriod, interest: UsdCents) {
+        self.total_interest += interest;
+        if self.terms.interest_capitalization.is_capitalized() {
+            if !interest.is_zero() {
+                self.push_entry(
+                    RepaymentType::Disbursal,
+                    interest,
+                    cycle.end,
+                    self.matures_at,
+                );
+            }
+        } else {
+            self.push_entry(RepaymentType::Interest, interest, cycle.end, cycle.end);
+        }
+    }
+
+    fn push_entry(
+        &mut self,
+        repayment_type: RepaymentType,
+        amount: UsdCents,
+        recorded_at: DateTime<Utc>,
+        due_at: DateTime<Utc>,
+    ) {
+        self.repayment_plan.push(CreditFacilityRepaymentPlanEntry {
+            repayment_type,
+            obligation_id: None,
+            status: RepaymentStatus::Upcoming,
+
+            initial: amount,
+            outstanding: amount,
+
+            due_at,
+            overdue_at: self
+                .terms
+                .obligation_overdue_duration_from_due
+                .map(|d| d.end_date(due_at)),
+            defaulted_at: None,
+            recorded_at,
+            effective: recorded_at.date_naive(),
+        });
+    }
+
+    fn record_cvl(&mut self, at: DateTime<Utc>, price: PriceOfOneBTC, outstanding: UsdCents) {
+        let collateral_value = price.sats_to_cents_round_down(self.collateral);
+        let cvl = CVLPct::from_loan_amounts(collateral_value, outstanding);
+
+        let collateralization_state = match self.cvl_points.last() {
+            None => self.terms.collateralization(cvl),
+            Some(last) => self
+                .terms
+                .collateralization_update(
+                    cvl,
+                    last.collateralization_state,
+                    Some(self.upgrade_buffer_cvl_pct),
+                    false,
+                )
+                .unwrap_or(last.collateralization_state),
+        };
+
+        match collateralization_state {
+            CollateralizationState::UnderMarginCallThreshold => {
+                self.margin_call_at.get_or_insert(at);
+            }
+            CollateralizationState::UnderLiquidationThreshold => {
+                self.margin_call_at.get_or_insert(at);
+                self.liquidation_at.get_or_insert(at);
+            }
+            _ => (),
+        }
+
+        self.cvl_points.push(SimulatedCvlPoint {
+            at,
+            price,
+            outstanding,
+            cvl,
+            collateralization_state,
+        });
+    }
+}
+
+fn price_at(price_path: &[SimulatedPricePoint], at: DateTime<Utc>) -> PriceOfOneBTC {
+    price_path
+        .iter()
+        .rev()
+        .find(|point| point.at <= at)
+        .unwrap_or(&price_path[0])
+        .price
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use crate::terms::{
+        FacilityDuration, InterestCapitalization, InterestInterval, ObligationDuration,
+        OneTimeFeeRatePct,
+    };
+
+    use super::*;
+
+    fn terms() -> TermValues {
+        TermValues::builder()
+            .annual_rate(dec!(12))
+            .duration(FacilityDuration::Months(3))
+            .interest_due_duration_from_accrual(ObligationDuration::Days(0))
+            .obligation_overdue_duration_from_due(None)
+            .obligation_liquidation_duration_from_due(None)
+            .accrual_cycle_interval(InterestInterval::EndOfMonth)
+            .accrual_interval(InterestInterval::EndOfDay)
+            .one_time_fee_rate(OneTimeFeeRatePct::ZERO)
+            .liquidation_cvl(dec!(105))
+            .margin_call_cvl(dec!(125))
+            .initial_cvl(dec!(140))
+            .build()
+            .expect("should build a valid term")
+    }
+
+    fn starts_at() -> DateTime<Utc> {
+        "2024-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+    }
+
+    fn price(usd: u64) -> PriceOfOneBTC {
+        PriceOfOneBTC::new(UsdCents::from(usd * 100))
+    }
+
+    fn flat_price_path() -> Vec<SimulatedPricePoint> {
+        vec![SimulatedPricePoint {
+            at: starts_at(),
+            price: price(100_000),
+        }]
+    }
+
+    fn simulate(price_path: Vec<SimulatedPricePoint>) -> TermsSimulation {
+        TermsSimulation::run(
+            terms(),
+            UsdCents::from(1_000_000_00),
+            Satoshis::from(15 * 100_000_000),
+            starts_at(),
+            price_path,
+            CVLPct::new(5),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn rejects_empty_price_path() {
+        let res = TermsSimulation::run(
+            terms(),
+            UsdCents::from(1_000_000_00),
+            Satoshis::from(100_000_000),
+            starts_at(),
+            vec![],
+            CVLPct::new(5),
+        );
+        assert!(matches!(res, Err(TermsSimulationError::EmptyPricePath)));
+    }
+
+    #[test]
+    fn projects_interest_per_accrual_cycle() {
+        let simulation = simulate(flat_price_path());
+
+        let interest_entries: Vec<_> = simulation
+            .repayment_plan
+            .iter()
+            .filter(|entry| entry.repayment_type == RepaymentType::Interest)
+            .collect();
+        assert_eq!(interest_entries.len(), 4);
+
+        let expected_january = terms()
+            .annual_rate
+            .interest_for_time_period(UsdCents::from(1_000_000_00), 1)
+            * 31;
+        assert_eq!(interest_entries[0].initial, expected_january);
+
+        let sum = interest_entries
+            .iter()
+            .fold(UsdCents::ZERO, |acc, entry| acc + entry.initial);
+        assert_eq!(simulation.total_interest, sum);
+        assert_eq!(
+            simulation.total_repayment(),
+            UsdCents::from(1_000_000_00) + sum
+        );
+    }
+
+    #[test]
+    fn no_trigger_dates_for_stable_price() {
+        let simulation = simulate(flat_price_path());
+
+        assert_eq!(simulation.margin_call_at, None);
+        assert_eq!(simulation.liquidation_at, None);
+        assert!(simulation.cvl_points.iter().all(|point| {
+            point.collateralization_state == CollateralizationState::FullyCollateralized
+        }));
+    }
+
+    #[test]
+    fn price_drop_triggers_margin_call_then_liquidation() {
+        let margin_call_at = starts_at() + chrono::Duration::days(10);
+        let liquidation_at = starts_at() + chrono::Duration::days(40) + chrono::Duration::hours(6);
+        let simulation = simulate(vec![
+            SimulatedPricePoint {
+                at: starts_at(),
+                price: price(100_000),
+            },
+            SimulatedPricePoint {
+                at: liquidation_at,
+                price: price(60_000),
+            },
+            SimulatedPricePoint {
+                at: margin_call_at,
+                price: price(80_000),
+            },
+        ]);
+
+        assert_eq!(simulation.margin_call_at, Some(margin_call_at));
+        assert_eq!(simulation.liquidation_at, Some(liquidation_at));
+    }
+
+    #[test]
+    fn capitalized_interest_is_repaid_at_maturity() {
+        let mut terms = terms();
+        terms.interest_capitalization = InterestCapitalization::AtCycleEnd;
+        let simulation = TermsSimulation::run(
+            terms,
+            UsdCents::from(1_000_000_00),
+            Satoshis::from(15 * 100_000_000),
+            starts_at(),
+            flat_price_path(),
+            CVLPct::new(5),
+        )
+        .unwrap();
+
+        assert!(
+            simulation
+                .repayment_plan
+                .iter()
+                .all(|entry| entry.repayment_type == RepaymentType::Disbursal
+                    && entry.due_at == simulation.matures_at)
+        );
+        assert!(simulation.total_interest > simulate(flat_price_path()).total_interest);
+    }
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum PriceShockStressTestError {
+    #[error("PriceShockStressTestError - InvalidShock: {0}")]
+    InvalidShock(rust_decimal::Decimal),
+}
@@ -0,0 +1,284 @@
+pub mod error;
+
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::{ledger::CreditFacilityBalanceSummary, primitives::*, terms::TermValues};
+
+use error::PriceShockStressTestError;
+
+pub const DEFAULT_PRICE_SHOCKS_PCT: [i64; 7] = [-10, -20, -30, -40, -50, -60, -70];
+
+#[derive(Debug, Clone, Copy)]
+pub struct StressTestFacility {
+    pub terms: TermValues,
+    pub balance: CreditFacilityBalanceSummary,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StressedExposure {
+    pub facilities: u32,
+    pub outstanding: UsdCents,
+}
+
+impl StressedExposure {
+    fn add(&mut self, outstanding: UsdCents) {
+        self.facilities += 1;
+        self.outstanding += outstanding;
+    }
+}
+
+/// Portfolio collateralization at a single shocked BTC price.
+/// `moved_to_*` only counts facilities that are not already in (or beyond)
+/// that state at the baseline price.
+#[derive(Debug, Clone)]
+pub struct PriceShockScenario {
+    pub shock_pct: Decimal,
+    pub price: PriceOfOneBTC,
+    pub under_margin_call: StressedExposure,
+    pub under_liquidation: StressedExposure,
+    pub moved_to_margin_call: StressedExposure,
+    pub moved_to_liquidation: StressedExposure,
+    pub expected_shortfall: UsdCents,
+}
+
+/// Outcome of applying hypothetical BTC price shocks to the active portfolio.
+/// Expected shortfall is the outstanding amount of facilities under the
+/// liquidation threshold that would not be covered by selling their collateral
+/// at the shocked price.
+#[derive(Debug, Clone)]
+pub struct PriceShockStressTest {
+    pub facilities: u32,
+    pub total_outstanding: UsdCents,
+    pub baseline: PriceShockScenario,
+    pub scenarios: Vec<PriceShockScenario>,
+}
+
+impl PriceShockStressTest {
+    pub fn run(
+        price: PriceOfOneBTC,
+        shocks_pct: impl IntoIterator<Item = Decimal>,
+        facilities: impl IntoIterator<Item = StressTestFacility>,
+    ) -> Result<Self, PriceShockStressTestError> {
+        let mut shocks_pct: Vec<_> = shocks_pct.into_iter().collect();
+        if shocks_pct.is_empty() {
+            shocks_pct = DEFAULT_PRICE_SHOCKS_PCT
+                .into_iter()
+                .map(Decimal::from)
+                .collect();
+        }
+        if let Some(shock) = shocks_pct
+            .iter()
+            .find(|shock| **shock <= -Decimal::ONE_HUNDRED)
+        {
+            return Err(PriceShockStressTestError::InvalidShock(*shock));
+        }
+
+        let facilities: Vec<_> = facilities.into_iter().collect();
+        let baseline_states: Vec<_> = facilities
+            .iter()
+            .map(|facility| facility.collateralization(price))
+            .collect();
+
+        let baseline = Self::scenario(Decimal::ZERO, price, &facilities, &baseline_states);
+        let scenarios = shocks_pct
+            .into_iter()
+            .map(|shock_pct| {
+                Self::scenario(
+                    shock_pct,
+                    shocked_price(price, shock_pct),
+                    &facilities,
+                    &baseline_states,
+                )
+            })
+            .collect();
+
+        Ok(Self {
+            facilities: facilities.len() as u32,
+            total_outstanding: facilities.iter().fold(UsdCents::ZERO, |total, facility| {
+                total + facility.balance.total_outstanding()
+            }),
+            baseline,
+            scenarios,
+        })
+    }
+
+    fn scenario(
+        shock_pct: Decimal,
+        price: PriceOfOneBTC,
+        facilities: &[StressTestFacility],
+        baseline_states: &[CollateralizationState],
+    ) -> PriceShockScenario {
+        let mut scenario = PriceShockScenario {
+            shock_pct,
+            price,
+            under_margin_call: StressedExposure::default(),
+            under_liquidation: StressedExposure::default(),
+            moved_to_margin_call: StressedExposure::default(),
+            moved_to_liquidation: StressedExposure::default(),
+            expected_shortfall: UsdCents::ZERO,
+        };
+
+        for (facility, baseline_state) in facilities.iter().zip(baseline_states) {
+            let outstanding = facility.balance.total_outstanding();
+            match facility.collateralization(price) {
+                CollateralizationState::UnderMarginCallThreshold => {
+                    scenario.under_margin_call.add(outstanding);
+                    if *baseline_state == CollateralizationState::FullyCollateralized {
+                        scenario.moved_to_margin_call.add(outstanding);
+                    }
+                }
+                CollateralizationState::UnderLiquidationThreshold => {
+                    scenario.under_liquidation.add(outstanding);
+                    if *baseline_state != CollateralizationState::UnderLiquidationThreshold {
+                        scenario.moved_to_liquidation.add(outstanding);
+                    }
+
+                    let recovered = price.sats_to_cents_round_down(facility.balance.collateral());
+                    if outstanding > recovered {
+                        scenario.expected_shortfall += outstanding - recovered;
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        scenario
+    }
+}
+
+impl StressTestFacility {
+    fn collateralization(&self, price: PriceOfOneBTC) -> CollateralizationState {
+        self.terms
+            .collateralization(self.balance.current_cvl(price))
+    }
+}
+
+fn shocked_price(price: PriceOfOneBTC, shock_pct: Decimal) -> PriceOfOneBTC {
+    let usd = (price.into_inner().to_usd() * (Decimal::ONE_HUNDRED + shock_pct)
+        / Decimal::ONE_HUNDRED)
+        .round_dp_with_strategy(2, RoundingStrategy::ToZero);
+    PriceOfOneBTC::new(UsdCents::try_from_usd(usd).expect("usd should be rounded to cents"))
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use crate::terms::{FacilityDuration, InterestInterval, ObligationDuration, OneTimeFeeRatePct};
+
+    use super::*;
+
+    fn terms() -> TermValues {
+        TermValues::builder()
+            .annual_rate(dec!(12))
+            .duration(FacilityDuration::Months(3))
+            .interest_due_duration_from_accrual(ObligationDuration::Days(0))
+            .obligation_overdue_duration_from_due(None)
+            .obligation_liquidation_duration_from_due(None)
+            .accrual_cycle_interval(InterestInterval::EndOfMonth)
+            .accrual_interval(InterestInterval::EndOfDay)
+            .one_time_fee_rate(OneTimeFeeRatePct::ZERO)
+            .liquidation_cvl(dec!(105))
+            .margin_call_cvl(dec!(125))
+            .initial_cvl(dec!(140))
+            .build()
+            .expect("should build a valid term")
+    }
+
+    fn price() -> PriceOfOneBTC {
+        PriceOfOneBTC::new(UsdCents::try_from_usd(dec!(100_000)).unwrap())
+    }
+
+    fn facility(outstanding_usd: Decimal, collateral_btc: Decimal) -> StressTestFacility {
+        let outstanding = UsdCents::try_from_usd(outstanding_usd).unwrap();
+        StressTestFacility {
+            terms: terms(),
+            balance: CreditFacilityBalanceSummary {
+                facility: outstanding,
+                facility_remaining: UsdCents::ZERO,
+                collateral: Satoshis::try_from_btc(collateral_btc).unwrap(),
+                disbursed: outstanding,
+                not_yet_due_disbursed_outstanding: outstanding,
+                due_disbursed_outstanding: UsdCents::ZERO,
+                overdue_disbursed_outstanding: UsdCents::ZERO,
+                disbursed_defaulted: UsdCents::ZERO,
+                interest_posted: UsdCents::ZERO,
+                not_yet_due_interest_outstanding: UsdCents::ZERO,
+                due_interest_outstanding: UsdCents::ZERO,
+                overdue_interest_outstanding: UsdCents::ZERO,
+                interest_defaulted: UsdCents::ZERO,
+            },
+        }
+    }
+
+    #[test]
+    fn applies_default_shocks_when_none_given() {
+        let res = PriceShockStressTest::run(price(), vec![], vec![]).unwrap();
+
+        assert_eq!(res.scenarios.len(), DEFAULT_PRICE_SHOCKS_PCT.len());
+        assert_eq!(res.scenarios[0].shock_pct, dec!(-10));
+        assert_eq!(
+            res.scenarios[0].price,
+            PriceOfOneBTC::new(UsdCents::try_from_usd(dec!(90_000)).unwrap())
+        );
+        assert_eq!(res.facilities, 0);
+    }
+
+    #[test]
+    fn rejects_shocks_wiping_out_the_price() {
+        assert!(matches!(
+            PriceShockStressTest::run(price(), vec![dec!(-100)], vec![]),
+            Err(PriceShockStressTestError::InvalidShock(_))
+        ));
+    }
+
+    #[test]
+    fn buckets_facilities_by_shocked_collateralization() {
+        // CVLs at baseline: 200%, 130%, 100%
+        let facilities = vec![
+            facility(dec!(50_000), dec!(1)),
+            facility(dec!(100_000), dec!(1.3)),
+            facility(dec!(100_000), dec!(1)),
+        ];
+
+        let res =
+            PriceShockStressTest::run(price(), vec![dec!(-10), dec!(-50)], facilities).unwrap();
+
+        assert_eq!(res.facilities, 3);
+        assert_eq!(
+            res.total_outstanding,
+            UsdCents::try_from_usd(dec!(250_000)).unwrap()
+        );
+        assert_eq!(res.baseline.under_liquidation.facilities, 1);
+        assert_eq!(
+            res.baseline.expected_shortfall,
+            UsdCents::ZERO,
+            "collateral exactly covers the outstanding amount"
+        );
+
+        // -10%: CVLs 180%, 117%, 90%
+        let mild = &res.scenarios[0];
+        assert_eq!(mild.under_margin_call.facilities, 1);
+        assert_eq!(mild.moved_to_margin_call.facilities, 1);
+        assert_eq!(mild.under_liquidation.facilities, 1);
+        assert_eq!(mild.moved_to_liquidation.facilities, 0);
+        assert_eq!(
+            mild.expected_shortfall,
+            UsdCents::try_from_usd(dec!(10_000)).unwrap()
+        );
+
+        // -50%: CVLs 100%, 65%, 50%
+        let severe = &res.scenarios[1];
+        assert_eq!(severe.under_margin_call, StressedExposure::default());
+        assert_eq!(severe.under_liquidation.facilities, 3);
+        assert_eq!(
+            severe.under_liquidation.outstanding,
+            UsdCents::try_from_usd(dec!(250_000)).unwrap()
+        );
+        assert_eq!(severe.moved_to_liquidation.facilities, 2);
+        assert_eq!(
+            severe.expected_shortfall,
+            UsdCents::try_from_usd(dec!(85_000)).unwrap()
+        );
+    }
+}
@@ -47,6 +47,12 @@ impl From<Decimal> for AnnualRatePct {
     }
 }
 
+impl From<AnnualRatePct> for Decimal {
+    fn from(value: AnnualRatePct) -> Self {
+        value.0
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(transparent)]
@@ -74,6 +80,12 @@ impl From<Decimal> for OneTimeFeeRatePct {
         OneTimeFeeRatePct(value)
     }
 }
+
+impl From<OneTimeFeeRatePct> for Decimal {
+    fn from(value: OneTimeFeeRatePct) -> Self {
+        value.0
+    }
+}
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value", rename_all = "snake_case")]
@@ -224,6 +236,22 @@ impl InterestInterval {
     }
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum InterestCapitalization {
+    #[default]
+    Never,
+    AtCycleEnd,
+}
+
+impl InterestCapitalization {
+    pub fn is_capitalized(&self) -> bool {
+        matches!(self, InterestCapitalization::AtCycleEnd)
+    }
+}
+
 #[derive(Builder, Debug, Serialize, Deserialize, Clone, Copy)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[builder(build_fn(validate = "Self::validate", error = "TermsError"))]
@@ -250,6 +278,9 @@ pub struct TermValues {
     pub margin_call_cvl: CVLPct,
     #[builder(setter(into))]
     pub initial_cvl: CVLPct,
+    #[builder(setter(into), default)]
+    #[serde(default)]
+    pub interest_capitalization: InterestCapitalization,
 }
 
 impl TermValues {
@@ -8,6 +8,8 @@ use es_entity::*;
 use crate::{TermValues, primitives::*};
 use audit::AuditInfo;
 
+use super::error::TermsTemplateError;
+
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -23,6 +25,39 @@ pub enum TermsTemplateEvent {
         values: TermValues,
         audit_info: AuditInfo,
     },
+    Retired {
+        audit_info: AuditInfo,
+    },
+    Activated {
+        audit_info: AuditInfo,
+    },
+    AmountBoundsUpdated {
+        amount_bounds: FacilityAmountBounds,
+        audit_info: AuditInfo,
+    },
+}
+
+/// Facility amounts that can be requested under a template.
+/// Enforced by underwriting rather than by the template itself.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct FacilityAmountBounds {
+    pub min_amount: Option<UsdCents>,
+    pub max_amount: Option<UsdCents>,
+}
+
+impl FacilityAmountBounds {
+    pub fn contains(&self, amount: UsdCents) -> bool {
+        self.min_amount.is_none_or(|min| amount >= min)
+            && self.max_amount.is_none_or(|max| amount <= max)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct TermsTemplateVersionRef {
+    pub terms_template_id: TermsTemplateId,
+    pub version: u32,
 }
 
 #[derive(EsEntity, Builder)]
@@ -31,6 +66,11 @@ pub struct TermsTemplate {
     pub id: TermsTemplateId,
     pub name: String,
     pub values: TermValues,
+    pub version: u32,
+    #[builder(default)]
+    pub status: TermsTemplateStatus,
+    #[builder(default)]
+    pub amount_bounds: FacilityAmountBounds,
     events: EntityEvents<TermsTemplateEvent>,
 }
 
@@ -41,32 +81,127 @@ impl TermsTemplate {
             .expect("TermsTemplate has never been persisted")
     }
 
-    pub fn update_values(&mut self, new_values: TermValues, audit_info: AuditInfo) {
+    pub fn update_values(
+        &mut self,
+        new_values: TermValues,
+        audit_info: AuditInfo,
+    ) -> Result<(), TermsTemplateError> {
+        if self.is_retired() {
+            return Err(TermsTemplateError::TermsTemplateRetired(self.id));
+        }
         self.events.push(TermsTemplateEvent::TermValuesUpdated {
             values: new_values,
             audit_info,
         });
         self.values = new_values;
+        self.version += 1;
+        Ok(())
+    }
+
+    pub fn update_amount_bounds(
+        &mut self,
+        amount_bounds: FacilityAmountBounds,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, TermsTemplateError> {
+        if self.is_retired() {
+            return Err(TermsTemplateError::TermsTemplateRetired(self.id));
+        }
+        match (amount_bounds.min_amount, amount_bounds.max_amount) {
+            (Some(min), Some(max)) if min > max => {
+                return Err(TermsTemplateError::InvalidAmountBounds(min, max));
+            }
+            _ => (),
+        }
+        if self.amount_bounds == amount_bounds {
+            return Ok(Idempotent::Ignored);
+        }
+
+        self.events.push(TermsTemplateEvent::AmountBoundsUpdated {
+            amount_bounds,
+            audit_info,
+        });
+        self.amount_bounds = amount_bounds;
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn is_retired(&self) -> bool {
+        self.status == TermsTemplateStatus::Retired
+    }
+
+    pub fn retire(&mut self, audit_info: AuditInfo) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            TermsTemplateEvent::Retired { .. },
+            => TermsTemplateEvent::Activated { .. }
+        );
+        self.events.push(TermsTemplateEvent::Retired { audit_info });
+        self.status = TermsTemplateStatus::Retired;
+        Idempotent::Executed(())
+    }
+
+    pub fn activate(&mut self, audit_info: AuditInfo) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            TermsTemplateEvent::Activated { .. } | TermsTemplateEvent::Initialized { .. },
+            => TermsTemplateEvent::Retired { .. }
+        );
+        self.events
+            .push(TermsTemplateEvent::Activated { audit_info });
+        self.status = TermsTemplateStatus::Active;
+        Idempotent::Executed(())
+    }
+
+    pub fn current_version_ref(&self) -> Result<TermsTemplateVersionRef, TermsTemplateError> {
+        if self.is_retired() {
+            return Err(TermsTemplateError::TermsTemplateRetired(self.id));
+        }
+        Ok(TermsTemplateVersionRef {
+            terms_template_id: self.id,
+            version: self.version,
+        })
+    }
+
+    pub fn values_for_version(&self, version: u32) -> Option<TermValues> {
+        self.events
+            .iter_all()
+            .filter_map(|event| match event {
+                TermsTemplateEvent::Initialized { values, .. }
+                | TermsTemplateEvent::TermValuesUpdated { values, .. } => Some(*values),
+                _ => None,
+            })
+            .nth(version.checked_sub(1)? as usize)
     }
 }
 
 impl TryFromEvents<TermsTemplateEvent> for TermsTemplate {
     fn try_from_events(events: EntityEvents<TermsTemplateEvent>) -> Result<Self, EsEntityError> {
         let mut builder = TermsTemplateBuilder::default();
+        let mut version = 0;
 
         for event in events.iter_all() {
             match event {
                 TermsTemplateEvent::Initialized {
                     id, name, values, ..
                 } => {
+                    version = 1;
                     builder = builder.id(*id).name(name.clone()).values(*values);
                 }
                 TermsTemplateEvent::TermValuesUpdated { values, .. } => {
+                    version += 1;
                     builder = builder.values(*values);
                 }
+                TermsTemplateEvent::Retired { .. } => {
+                    builder = builder.status(TermsTemplateStatus::Retired);
+                }
+                TermsTemplateEvent::Activated { .. } => {
+                    builder = builder.status(TermsTemplateStatus::Active);
+                }
+                TermsTemplateEvent::AmountBoundsUpdated { amount_bounds, .. } => {
+                    builder = builder.amount_bounds(*amount_bounds);
+                }
             }
         }
-        builder.events(events).build()
+        builder.version(version).events(events).build()
     }
 }
 
@@ -101,3 +236,143 @@ impl IntoEvents<TermsTemplateEvent> for NewTermsTemplate {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use audit::{AuditEntryId, AuditInfo};
+    use rust_decimal_macros::dec;
+
+    use crate::terms::{FacilityDuration, InterestInterval, ObligationDuration, OneTimeFeeRatePct};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn values(annual_rate: rust_decimal::Decimal) -> TermValues {
+        TermValues::builder()
+            .annual_rate(annual_rate)
+            .duration(FacilityDuration::Months(3))
+            .interest_due_duration_from_accrual(ObligationDuration::Days(0))
+            .obligation_overdue_duration_from_due(None)
+            .obligation_liquidation_duration_from_due(None)
+            .accrual_cycle_interval(InterestInterval::EndOfMonth)
+            .accrual_interval(InterestInterval::EndOfDay)
+            .one_time_fee_rate(OneTimeFeeRatePct::ZERO)
+            .liquidation_cvl(dec!(105))
+            .margin_call_cvl(dec!(125))
+            .initial_cvl(dec!(140))
+            .build()
+            .expect("should build a valid term")
+    }
+
+    fn template() -> TermsTemplate {
+        let id = TermsTemplateId::new();
+        TermsTemplate::try_from_events(EntityEvents::init(
+            id,
+            [TermsTemplateEvent::Initialized {
+                id,
+                name: "test".to_string(),
+                values: values(dec!(12)),
+                audit_info: dummy_audit_info(),
+            }],
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn version_increments_on_update() {
+        let mut template = template();
+        assert_eq!(template.version, 1);
+
+        template
+            .update_values(values(dec!(10)), dummy_audit_info())
+            .unwrap();
+        assert_eq!(template.version, 2);
+        assert_eq!(template.current_version_ref().unwrap().version, 2);
+        assert_eq!(
+            template.values_for_version(1).unwrap().annual_rate,
+            values(dec!(12)).annual_rate
+        );
+        assert_eq!(
+            template.values_for_version(2).unwrap().annual_rate,
+            values(dec!(10)).annual_rate
+        );
+        assert!(template.values_for_version(0).is_none());
+        assert!(template.values_for_version(3).is_none());
+    }
+
+    #[test]
+    fn retired_template_cannot_be_used_or_updated() {
+        let mut template = template();
+        assert!(template.retire(dummy_audit_info()).did_execute());
+        assert!(template.retire(dummy_audit_info()).was_ignored());
+
+        assert!(matches!(
+            template.current_version_ref(),
+            Err(TermsTemplateError::TermsTemplateRetired(_))
+        ));
+        assert!(matches!(
+            template.update_values(values(dec!(10)), dummy_audit_info()),
+            Err(TermsTemplateError::TermsTemplateRetired(_))
+        ));
+    }
+
+    #[test]
+    fn activate_is_idempotent_until_retired() {
+        let mut template = template();
+        assert!(template.activate(dummy_audit_info()).was_ignored());
+
+        let _ = template.retire(dummy_audit_info());
+        assert!(template.activate(dummy_audit_info()).did_execute());
+        assert_eq!(template.status, TermsTemplateStatus::Active);
+        assert!(template.current_version_ref().is_ok());
+    }
+
+    #[test]
+    fn amount_bounds_update_does_not_bump_version() {
+        let mut template = template();
+        let bounds = FacilityAmountBounds {
+            min_amount: Some(UsdCents::from(100000)),
+            max_amount: Some(UsdCents::from(5000000)),
+        };
+
+        assert!(
+            template
+                .update_amount_bounds(bounds, dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert!(
+            template
+                .update_amount_bounds(bounds, dummy_audit_info())
+                .unwrap()
+                .was_ignored()
+        );
+        assert_eq!(template.amount_bounds, bounds);
+        assert_eq!(template.version, 1);
+
+        assert!(bounds.contains(UsdCents::from(100000)));
+        assert!(!bounds.contains(UsdCents::from(99999)));
+        assert!(!bounds.contains(UsdCents::from(5000001)));
+    }
+
+    #[test]
+    fn amount_bounds_must_be_ordered() {
+        let mut template = template();
+        assert!(matches!(
+            template.update_amount_bounds(
+                FacilityAmountBounds {
+                    min_amount: Some(UsdCents::from(2)),
+                    max_amount: Some(UsdCents::from(1)),
+                },
+                dummy_audit_info()
+            ),
+            Err(TermsTemplateError::InvalidAmountBounds(_, _))
+        ));
+    }
+}
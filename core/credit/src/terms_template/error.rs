@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-use crate::primitives::TermsTemplateId;
+use crate::primitives::{TermsTemplateId, UsdCents};
 
 #[derive(Error, Debug)]
 pub enum TermsTemplateError {
@@ -16,6 +16,10 @@ pub enum TermsTemplateError {
     AuthorizationError(#[from] authz::error::AuthorizationError),
     #[error("TermsTemplateError - AuditError: {0}")]
     AuditError(#[from] audit::error::AuditError),
+    #[error("TermsTemplateError - TermsTemplateRetired: {0}")]
+    TermsTemplateRetired(TermsTemplateId),
+    #[error("TermsTemplateError - InvalidAmountBounds: min {0} is above max {1}")]
+    InvalidAmountBounds(UsdCents, UsdCents),
 }
 
 es_entity::from_es_entity_error!(TermsTemplateError);
@@ -106,13 +106,85 @@ where
             .expect("audit info missing");
 
         let mut terms_template = self.repo.find_by_id(id).await?;
-        terms_template.update_values(values, audit_info);
+        terms_template.update_values(values, audit_info)?;
 
         self.repo.update(&mut terms_template).await?;
 
         Ok(terms_template)
     }
 
+    #[instrument(
+        name = "core_credit.terms_template.update_amount_bounds",
+        skip(self),
+        err
+    )]
+    pub async fn update_amount_bounds(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<TermsTemplateId> + std::fmt::Debug,
+        amount_bounds: FacilityAmountBounds,
+    ) -> Result<TermsTemplate, TermsTemplateError> {
+        let audit_info = self
+            .subject_can_update_terms_template(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let mut terms_template = self.repo.find_by_id(id.into()).await?;
+        if terms_template
+            .update_amount_bounds(amount_bounds, audit_info)?
+            .did_execute()
+        {
+            self.repo.update(&mut terms_template).await?;
+        }
+
+        Ok(terms_template)
+    }
+
+    #[instrument(name = "core_credit.terms_template.retire", skip(self), err)]
+    pub async fn retire(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<TermsTemplateId> + std::fmt::Debug,
+    ) -> Result<TermsTemplate, TermsTemplateError> {
+        let audit_info = self
+            .subject_can_update_terms_template(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let mut terms_template = self.repo.find_by_id(id.into()).await?;
+        if terms_template.retire(audit_info).did_execute() {
+            self.repo.update(&mut terms_template).await?;
+        }
+
+        Ok(terms_template)
+    }
+
+    #[instrument(name = "core_credit.terms_template.activate", skip(self), err)]
+    pub async fn activate(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<TermsTemplateId> + std::fmt::Debug,
+    ) -> Result<TermsTemplate, TermsTemplateError> {
+        let audit_info = self
+            .subject_can_update_terms_template(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let mut terms_template = self.repo.find_by_id(id.into()).await?;
+        if terms_template.activate(audit_info).did_execute() {
+            self.repo.update(&mut terms_template).await?;
+        }
+
+        Ok(terms_template)
+    }
+
+    pub(crate) async fn find_by_id_without_audit(
+        &self,
+        id: impl Into<TermsTemplateId>,
+    ) -> Result<TermsTemplate, TermsTemplateError> {
+        self.repo.find_by_id(id.into()).await
+    }
+
     #[instrument(name = "core_credit.terms_template.find_by_id", skip(self))]
     pub async fn find_by_id(
         &self,
@@ -151,6 +223,19 @@ where
             .entities)
     }
 
+    pub(crate) async fn list_active_without_audit(
+        &self,
+    ) -> Result<Vec<TermsTemplate>, TermsTemplateError> {
+        Ok(self
+            .repo
+            .list_by_name(Default::default(), es_entity::ListDirection::Ascending)
+            .await?
+            .entities
+            .into_iter()
+            .filter(|template| !template.is_retired())
+            .collect())
+    }
+
     pub async fn find_all<T: From<TermsTemplate>>(
         &self,
         ids: &[TermsTemplateId],
@@ -0,0 +1,286 @@
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use core_customer::{CustomerType, KycLevel};
+
+use crate::{primitives::*, terms::TermValues, terms_template::FacilityAmountBounds};
+
+/// Rules evaluated before a credit facility is created.
+/// Unset rules are skipped, so the default configuration passes everything.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct UnderwritingConfig {
+    #[serde(default)]
+    pub min_kyc_level: Option<KycLevel>,
+    #[serde(default)]
+    pub allowed_customer_types: Option<Vec<CustomerType>>,
+    #[serde(default)]
+    pub max_customer_exposure: Option<UsdCents>,
+    #[serde(default)]
+    pub max_required_collateral: Option<Satoshis>,
+    /// Reject the facility instead of referring it to the approval committee
+    /// when a rule fails.
+    #[serde(default)]
+    pub decline_on_failure: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum UnderwritingRule {
+    KycLevel,
+    CustomerType,
+    CustomerExposure,
+    FacilityAmountBounds,
+    CollateralAvailability,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum UnderwritingOutcome {
+    Passed,
+    Referred,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct UnderwritingFinding {
+    pub rule: UnderwritingRule,
+    pub passed: bool,
+    pub reason: String,
+}
+
+/// Result of underwriting a facility request. Findings are recorded for every
+/// rule that was evaluated, failing ones explain why the request was referred.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct UnderwritingDecision {
+    pub outcome: UnderwritingOutcome,
+    pub findings: Vec<UnderwritingFinding>,
+}
+
+impl UnderwritingDecision {
+    pub fn passed(&self) -> bool {
+        self.outcome == UnderwritingOutcome::Passed
+    }
+
+    pub fn failed_reasons(&self) -> impl Iterator<Item = &str> {
+        self.findings
+            .iter()
+            .filter(|finding| !finding.passed)
+            .map(|finding| finding.reason.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct UnderwritingRequest {
+    pub kyc_level: KycLevel,
+    pub customer_type: CustomerType,
+    pub existing_exposure: UsdCents,
+    pub amount: UsdCents,
+    pub terms: TermValues,
+    pub amount_bounds: FacilityAmountBounds,
+    pub price: PriceOfOneBTC,
+}
+
+impl UnderwritingConfig {
+    pub fn evaluate(&self, request: &UnderwritingRequest) -> UnderwritingDecision {
+        let mut findings = Vec::new();
+
+        if let Some(min_kyc_level) = self.min_kyc_level {
+            let passed = request.kyc_level >= min_kyc_level;
+            findings.push(UnderwritingFinding {
+                rule: UnderwritingRule::KycLevel,
+                passed,
+                reason: if passed {
+                    format!(
+                        "KYC level {:?} meets {:?}",
+                        request.kyc_level, min_kyc_level
+                    )
+                } else {
+                    format!(
+                        "KYC level {:?} is below the required {:?}",
+                        request.kyc_level, min_kyc_level
+                    )
+                },
+            });
+        }
+
+        if let Some(allowed_customer_types) = &self.allowed_customer_types {
+            let passed = allowed_customer_types.contains(&request.customer_type);
+            findings.push(UnderwritingFinding {
+                rule: UnderwritingRule::CustomerType,
+                passed,
+                reason: if passed {
+                    format!("customer type {} is eligible", request.customer_type)
+                } else {
+                    format!("customer type {} is not eligible", request.customer_type)
+                },
+            });
+        }
+
+        if let Some(max_customer_exposure) = self.max_customer_exposure {
+            let exposure = request.existing_exposure + request.amount;
+            let passed = exposure <= max_customer_exposure;
+            findings.push(UnderwritingFinding {
+                rule: UnderwritingRule::CustomerExposure,
+                passed,
+                reason: format!(
+                    "total exposure {} USD {} the limit of {} USD",
+                    exposure.to_usd(),
+                    if passed { "is within" } else { "exceeds" },
+                    max_customer_exposure.to_usd()
+                ),
+            });
+        }
+
+        let bounds = request.amount_bounds;
+        if bounds.min_amount.is_some() || bounds.max_amount.is_some() {
+            let passed = bounds.contains(request.amount);
+            findings.push(UnderwritingFinding {
+                rule: UnderwritingRule::FacilityAmountBounds,
+                passed,
+                reason: format!(
+                    "requested {} USD is {} the terms template bounds ({} - {})",
+                    request.amount.to_usd(),
+                    if passed { "within" } else { "outside" },
+                    bounds
+                        .min_amount
+                        .map(|min| format!("{} USD", min.to_usd()))
+                        .unwrap_or_else(|| "no minimum".to_string()),
+                    bounds
+                        .max_amount
+                        .map(|max| format!("{} USD", max.to_usd()))
+                        .unwrap_or_else(|| "no maximum".to_string()),
+                ),
+            });
+        }
+
+        if let Some(max_required_collateral) = self.max_required_collateral {
+            let required = request
+                .terms
+                .required_collateral(request.amount, request.price);
+            let passed = required <= max_required_collateral;
+            findings.push(UnderwritingFinding {
+                rule: UnderwritingRule::CollateralAvailability,
+                passed,
+                reason: format!(
+                    "required collateral {} BTC {} the limit of {} BTC",
+                    required.to_btc(),
+                    if passed { "is within" } else { "exceeds" },
+                    max_required_collateral.to_btc()
+                ),
+            });
+        }
+
+        let outcome = if findings.iter().all(|finding| finding.passed) {
+            UnderwritingOutcome::Passed
+        } else {
+            UnderwritingOutcome::Referred
+        };
+
+        UnderwritingDecision { outcome, findings }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use crate::terms::{FacilityDuration, InterestInterval, ObligationDuration, OneTimeFeeRatePct};
+
+    use super::*;
+
+    fn terms() -> TermValues {
+        TermValues::builder()
+            .annual_rate(dec!(12))
+            .duration(FacilityDuration::Months(3))
+            .interest_due_duration_from_accrual(ObligationDuration::Days(0))
+            .obligation_overdue_duration_from_due(None)
+            .obligation_liquidation_duration_from_due(None)
+            .accrual_cycle_interval(InterestInterval::EndOfMonth)
+            .accrual_interval(InterestInterval::EndOfDay)
+            .one_time_fee_rate(OneTimeFeeRatePct::ZERO)
+            .liquidation_cvl(dec!(105))
+            .margin_call_cvl(dec!(125))
+            .initial_cvl(dec!(140))
+            .build()
+            .expect("should build a valid term")
+    }
+
+    fn request(amount_usd: rust_decimal::Decimal) -> UnderwritingRequest {
+        UnderwritingRequest {
+            kyc_level: KycLevel::Basic,
+            customer_type: CustomerType::Individual,
+            existing_exposure: UsdCents::try_from_usd(dec!(50_000)).unwrap(),
+            amount: UsdCents::try_from_usd(amount_usd).unwrap(),
+            terms: terms(),
+            amount_bounds: FacilityAmountBounds::default(),
+            price: PriceOfOneBTC::new(UsdCents::try_from_usd(dec!(100_000)).unwrap()),
+        }
+    }
+
+    #[test]
+    fn default_config_passes_without_findings() {
+        let decision = UnderwritingConfig::default().evaluate(&request(dec!(10_000)));
+
+        assert!(decision.passed());
+        assert!(decision.findings.is_empty());
+    }
+
+    #[test]
+    fn failing_rules_refer_with_reasons() {
+        let config = UnderwritingConfig {
+            min_kyc_level: Some(KycLevel::Advanced),
+            allowed_customer_types: Some(vec![CustomerType::Individual]),
+            max_customer_exposure: Some(UsdCents::try_from_usd(dec!(55_000)).unwrap()),
+            max_required_collateral: Some(Satoshis::try_from_btc(dec!(0.1)).unwrap()),
+            decline_on_failure: false,
+        };
+        let mut request = request(dec!(10_000));
+        request.amount_bounds = FacilityAmountBounds {
+            min_amount: Some(UsdCents::try_from_usd(dec!(1_000)).unwrap()),
+            max_amount: Some(UsdCents::try_from_usd(dec!(5_000)).unwrap()),
+        };
+
+        let decision = config.evaluate(&request);
+
+        assert_eq!(decision.outcome, UnderwritingOutcome::Referred);
+        let failed: Vec<_> = decision
+            .findings
+            .iter()
+            .filter(|finding| !finding.passed)
+            .map(|finding| finding.rule)
+            .collect();
+        assert_eq!(
+            failed,
+            vec![
+                UnderwritingRule::KycLevel,
+                UnderwritingRule::CustomerExposure,
+                UnderwritingRule::FacilityAmountBounds,
+                UnderwritingRule::CollateralAvailability,
+            ]
+        );
+        assert_eq!(decision.findings.len(), 5);
+        assert_eq!(decision.failed_reasons().count(), 4);
+    }
+
+    #[test]
+    fn passes_when_within_all_limits() {
+        let config = UnderwritingConfig {
+            min_kyc_level: Some(KycLevel::Basic),
+            allowed_customer_types: None,
+            max_customer_exposure: Some(UsdCents::try_from_usd(dec!(60_000)).unwrap()),
+            // 10_000 USD at 140% initial CVL and 100_000 USD/BTC
+            max_required_collateral: Some(Satoshis::try_from_btc(dec!(0.14)).unwrap()),
+            decline_on_failure: false,
+        };
+
+        let decision = config.evaluate(&request(dec!(10_000)));
+
+        assert!(decision.passed());
+        assert_eq!(decision.findings.len(), 3);
+    }
+}
@@ -24,7 +24,7 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
     let governance = governance::Governance::new(&pool, &authz, &outbox);
     let public_ids = public_id::PublicIds::new(&pool);
     let customers =
-        core_customer::Customers::new(&pool, &authz, &outbox, document_storage, public_ids);
+        core_customer::Customers::new(&pool, &authz, &outbox, document_storage.clone(), public_ids);
     let custody =
         core_custody::CoreCustody::init(&pool, &authz, helpers::custody_config(), &outbox).await?;
     let price = core_price::Price::new();
@@ -52,6 +52,7 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         &cala,
         journal_id,
         &public_ids,
+        document_storage,
     )
     .await?;
 
@@ -108,11 +109,14 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
                 .chart_of_account_facility_omnibus_parent_code("1".parse().unwrap())
                 .chart_of_account_collateral_omnibus_parent_code("2".parse().unwrap())
                 .chart_of_account_in_liquidation_omnibus_parent_code("1".parse().unwrap())
+                .chart_of_account_disbursal_settlement_omnibus_parent_code("1".parse().unwrap())
+                .chart_of_account_participation_principal_omnibus_parent_code("2".parse().unwrap())
                 .chart_of_account_facility_parent_code("3".parse().unwrap())
                 .chart_of_account_collateral_parent_code("4".parse().unwrap())
                 .chart_of_account_in_liquidation_parent_code("3".parse().unwrap())
                 .chart_of_account_interest_income_parent_code("7".parse().unwrap())
                 .chart_of_account_fee_income_parent_code("8".parse().unwrap())
+                .chart_of_account_participation_payable_parent_code("8".parse().unwrap())
                 .chart_of_account_short_term_individual_disbursed_receivable_parent_code("1".parse().unwrap())
                 .chart_of_account_short_term_government_entity_disbursed_receivable_parent_code(
                     "2".parse().unwrap(),
@@ -209,7 +213,7 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         .list_members_by_created_at(account_set_id, Default::default())
         .await?;
 
-    assert_eq!(res.entities.len(), 7);
+    assert_eq!(res.entities.len(), 8);
 
     let chart_ref = format!("other-ref-{:08}", rand::rng().random_range(0..10000));
     let chart = accounting
@@ -243,11 +247,14 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
                 .chart_of_account_facility_omnibus_parent_code("1".parse().unwrap())
                 .chart_of_account_collateral_omnibus_parent_code("2".parse().unwrap())
                 .chart_of_account_in_liquidation_omnibus_parent_code("1".parse().unwrap())
+                .chart_of_account_disbursal_settlement_omnibus_parent_code("1".parse().unwrap())
+                .chart_of_account_participation_principal_omnibus_parent_code("2".parse().unwrap())
                 .chart_of_account_facility_parent_code("3".parse().unwrap())
                 .chart_of_account_collateral_parent_code("4".parse().unwrap())
                 .chart_of_account_in_liquidation_parent_code("3".parse().unwrap())
                 .chart_of_account_interest_income_parent_code("7".parse().unwrap())
                 .chart_of_account_fee_income_parent_code("8".parse().unwrap())
+                .chart_of_account_participation_payable_parent_code("8".parse().unwrap())
                 .chart_of_account_short_term_individual_disbursed_receivable_parent_code("1".parse().unwrap())
                 .chart_of_account_short_term_government_entity_disbursed_receivable_parent_code(
                     "2".parse().unwrap(),
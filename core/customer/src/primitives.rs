@@ -15,7 +15,7 @@ es_entity::entity_id! {
     CustomerDocumentId => document_storage::DocumentId
 }
 
-#[derive(Debug, Deserialize, Clone, Copy, Serialize, Eq, PartialEq)]
+#[derive(Debug, Deserialize, Clone, Copy, Serialize, Eq, PartialEq, PartialOrd, Ord)]
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum KycLevel {
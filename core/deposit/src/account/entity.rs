@@ -1,4 +1,6 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use derive_builder::Builder;
+use rust_decimal::{Decimal, RoundingStrategy, prelude::ToPrimitive};
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -7,7 +9,7 @@ use es_entity::*;
 
 use audit::AuditInfo;
 
-use crate::primitives::*;
+use crate::{primitives::*, product::DepositInterestTerms, withdrawal_limit::WithdrawalLimits};
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -29,6 +31,98 @@ pub enum DepositAccountEvent {
         status: AccountStatus,
         audit_info: AuditInfo,
     },
+    ProductAssigned {
+        deposit_product_id: DepositProductId,
+        interest_accrual_starts_on: NaiveDate,
+        audit_info: AuditInfo,
+    },
+    InterestAccrued {
+        accrued_on: NaiveDate,
+        settled_balance: UsdCents,
+        amount: Decimal,
+        audit_info: AuditInfo,
+    },
+    InterestCapitalized {
+        ledger_tx_id: CalaTransactionId,
+        capitalized_on: NaiveDate,
+        amount: UsdCents,
+        audit_info: AuditInfo,
+    },
+    Frozen {
+        scope: AccountFreezeScope,
+        reason: String,
+        audit_info: AuditInfo,
+    },
+    Unfrozen {
+        audit_info: AuditInfo,
+    },
+    WithdrawalLimitsOverridden {
+        withdrawal_limits: Option<WithdrawalLimits>,
+        audit_info: AuditInfo,
+    },
+    WithdrawalLimitsApplied {
+        withdrawal_limits: WithdrawalLimits,
+        ledger_velocity_control_id: CalaVelocityControlId,
+    },
+    CustomerActivityRecorded {
+        recorded_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    },
+    DormancyStatusUpdated {
+        status: DormancyStatus,
+        audit_info: AuditInfo,
+    },
+    Closed {
+        balance_sweep: Option<BalanceSweep>,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AccountFreezeScope {
+    Debits,
+    Credits,
+    DebitsAndCredits,
+}
+
+/// Where the remaining balance of an account goes when it is closed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BalanceSweepDestination {
+    DepositAccount {
+        deposit_account_id: DepositAccountId,
+    },
+    ExternalBeneficiary {
+        beneficiary: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct BalanceSweep {
+    pub destination: BalanceSweepDestination,
+    pub amount: UsdCents,
+    pub ledger_tx_id: CalaTransactionId,
+}
+
+impl AccountFreezeScope {
+    pub fn debits_frozen(&self) -> bool {
+        matches!(self, Self::Debits | Self::DebitsAndCredits)
+    }
+
+    pub fn credits_frozen(&self) -> bool {
+        matches!(self, Self::Credits | Self::DebitsAndCredits)
+    }
+}
+
+pub struct InterestCapitalizationData {
+    pub ledger_tx_id: CalaTransactionId,
+    pub capitalized_on: NaiveDate,
+    pub amount: UsdCents,
 }
 
 #[derive(EsEntity, Builder)]
@@ -41,6 +135,8 @@ pub struct DepositAccount {
     pub description: String,
     pub status: AccountStatus,
     pub public_id: PublicId,
+    #[builder(setter(strip_option), default)]
+    pub deposit_product_id: Option<DepositProductId>,
 
     events: EntityEvents<DepositAccountEvent>,
 }
@@ -66,6 +162,340 @@ impl DepositAccount {
         self.status = status;
         Idempotent::Executed(())
     }
+
+    pub fn assign_product(
+        &mut self,
+        deposit_product_id: DepositProductId,
+        interest_accrual_starts_on: NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.deposit_product_id == Some(deposit_product_id) {
+            return Idempotent::Ignored;
+        }
+        self.events.push(DepositAccountEvent::ProductAssigned {
+            deposit_product_id,
+            interest_accrual_starts_on,
+            audit_info,
+        });
+        self.deposit_product_id = Some(deposit_product_id);
+        Idempotent::Executed(())
+    }
+
+    /// The postings currently blocked on the account, if it is frozen.
+    pub fn freeze_scope(&self) -> Option<AccountFreezeScope> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            DepositAccountEvent::Frozen { scope, .. } => Some(Some(*scope)),
+            DepositAccountEvent::Unfrozen { .. } => Some(None),
+            _ => None,
+        })?
+    }
+
+    pub fn freeze(
+        &mut self,
+        scope: AccountFreezeScope,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.freeze_scope() == Some(scope) {
+            return Idempotent::Ignored;
+        }
+        self.events.push(DepositAccountEvent::Frozen {
+            scope,
+            reason,
+            audit_info,
+        });
+        Idempotent::Executed(())
+    }
+
+    pub fn unfreeze(&mut self, audit_info: AuditInfo) -> Idempotent<()> {
+        if self.freeze_scope().is_none() {
+            return Idempotent::Ignored;
+        }
+        self.events
+            .push(DepositAccountEvent::Unfrozen { audit_info });
+        Idempotent::Executed(())
+    }
+
+    /// Limits set on this account that take precedence over the ones of its
+    /// deposit product.
+    pub fn withdrawal_limits_override(&self) -> Option<WithdrawalLimits> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            DepositAccountEvent::WithdrawalLimitsOverridden {
+                withdrawal_limits, ..
+            } => Some(*withdrawal_limits),
+            _ => None,
+        })?
+    }
+
+    pub fn override_withdrawal_limits(
+        &mut self,
+        withdrawal_limits: Option<WithdrawalLimits>,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.withdrawal_limits_override() == withdrawal_limits {
+            return Idempotent::Ignored;
+        }
+        self.events
+            .push(DepositAccountEvent::WithdrawalLimitsOverridden {
+                withdrawal_limits,
+                audit_info,
+            });
+        Idempotent::Executed(())
+    }
+
+    /// The limits currently enforced by the ledger on this account.
+    pub fn applied_withdrawal_limits(&self) -> WithdrawalLimits {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                DepositAccountEvent::WithdrawalLimitsApplied {
+                    withdrawal_limits, ..
+                } => Some(*withdrawal_limits),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Records that the ledger enforces `withdrawal_limits` through a new
+    /// velocity control. Returns the id of that control when the limits
+    /// differ from the ones already applied.
+    pub fn apply_withdrawal_limits(
+        &mut self,
+        withdrawal_limits: WithdrawalLimits,
+    ) -> Idempotent<CalaVelocityControlId> {
+        if self.applied_withdrawal_limits() == withdrawal_limits {
+            return Idempotent::Ignored;
+        }
+        let ledger_velocity_control_id = CalaVelocityControlId::new();
+        self.events
+            .push(DepositAccountEvent::WithdrawalLimitsApplied {
+                withdrawal_limits,
+                ledger_velocity_control_id,
+            });
+        Idempotent::Executed(ledger_velocity_control_id)
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|event| matches!(event, DepositAccountEvent::Closed { .. }))
+    }
+
+    pub fn closed_at(&self) -> Option<DateTime<Utc>> {
+        self.events
+            .iter_persisted()
+            .find_map(|event| match event.event {
+                DepositAccountEvent::Closed { .. } => Some(event.recorded_at),
+                _ => None,
+            })
+    }
+
+    pub fn balance_sweep(&self) -> Option<&BalanceSweep> {
+        self.events.iter_all().find_map(|event| match event {
+            DepositAccountEvent::Closed { balance_sweep, .. } => balance_sweep.as_ref(),
+            _ => None,
+        })
+    }
+
+    /// Closes the account for good. `balance_sweep` records where the
+    /// remaining balance was moved to, if there was any.
+    pub fn close(
+        &mut self,
+        balance_sweep: Option<BalanceSweep>,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(self.events.iter_all(), DepositAccountEvent::Closed { .. });
+        self.events.push(DepositAccountEvent::Closed {
+            balance_sweep,
+            audit_info,
+        });
+        Idempotent::Executed(())
+    }
+
+    /// The most recent activity initiated by the account holder, if any was
+    /// recorded since the account was opened.
+    pub fn last_customer_activity_at(&self) -> Option<DateTime<Utc>> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            DepositAccountEvent::CustomerActivityRecorded { recorded_at, .. } => Some(*recorded_at),
+            _ => None,
+        })
+    }
+
+    pub fn record_customer_activity(
+        &mut self,
+        recorded_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self
+            .last_customer_activity_at()
+            .is_some_and(|last| last >= recorded_at)
+        {
+            return Idempotent::Ignored;
+        }
+        self.events
+            .push(DepositAccountEvent::CustomerActivityRecorded {
+                recorded_at,
+                audit_info,
+            });
+        Idempotent::Executed(())
+    }
+
+    pub fn dormancy_status(&self) -> DormancyStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                DepositAccountEvent::DormancyStatusUpdated { status, .. } => Some(*status),
+                _ => None,
+            })
+            .unwrap_or(DormancyStatus::Active)
+    }
+
+    pub fn is_dormant(&self) -> bool {
+        self.dormancy_status() != DormancyStatus::Active
+    }
+
+    /// The status the account should be in at `now` given how long it has
+    /// gone without customer activity. Accounts without any recorded
+    /// activity count from the day they were opened.
+    pub fn dormancy_status_due(
+        &self,
+        now: DateTime<Utc>,
+        dormancy_period: Duration,
+        escheatment_period: Duration,
+    ) -> DormancyStatus {
+        let last_activity_at = self
+            .last_customer_activity_at()
+            .unwrap_or_else(|| self.created_at());
+        let inactive_for = now - last_activity_at;
+        if inactive_for >= escheatment_period {
+            DormancyStatus::EscheatmentDue
+        } else if inactive_for >= dormancy_period {
+            DormancyStatus::Dormant
+        } else {
+            DormancyStatus::Active
+        }
+    }
+
+    /// Moves the account further along the dormancy lifecycle. Only an
+    /// explicit reactivation brings a dormant account back to active, so
+    /// activity recorded in the meantime never downgrades the status.
+    pub fn update_dormancy_status(
+        &mut self,
+        now: DateTime<Utc>,
+        dormancy_period: Duration,
+        escheatment_period: Duration,
+        audit_info: AuditInfo,
+    ) -> Idempotent<DormancyStatus> {
+        let status = self.dormancy_status_due(now, dormancy_period, escheatment_period);
+        if status <= self.dormancy_status() {
+            return Idempotent::Ignored;
+        }
+        self.events
+            .push(DepositAccountEvent::DormancyStatusUpdated { status, audit_info });
+        Idempotent::Executed(status)
+    }
+
+    /// Lifts dormancy after the account holder got back in touch. The
+    /// reactivation itself counts as customer activity.
+    pub fn reactivate(&mut self, now: DateTime<Utc>, audit_info: AuditInfo) -> Idempotent<()> {
+        if !self.is_dormant() {
+            return Idempotent::Ignored;
+        }
+        self.events
+            .push(DepositAccountEvent::CustomerActivityRecorded {
+                recorded_at: now,
+                audit_info: audit_info.clone(),
+            });
+        self.events
+            .push(DepositAccountEvent::DormancyStatusUpdated {
+                status: DormancyStatus::Active,
+                audit_info,
+            });
+        Idempotent::Executed(())
+    }
+
+    /// The next day whose end-of-day balance has not accrued interest yet.
+    pub fn next_interest_accrual_date(&self) -> Option<NaiveDate> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            DepositAccountEvent::InterestAccrued { accrued_on, .. } => accrued_on.succ_opt(),
+            DepositAccountEvent::ProductAssigned {
+                interest_accrual_starts_on,
+                ..
+            } => Some(*interest_accrual_starts_on),
+            _ => None,
+        })
+    }
+
+    /// Interest accrued but not yet credited to the account, in USD.
+    pub fn accrued_interest(&self) -> Decimal {
+        self.events
+            .iter_all()
+            .fold(Decimal::ZERO, |total, event| match event {
+                DepositAccountEvent::InterestAccrued { amount, .. } => total + amount,
+                DepositAccountEvent::InterestCapitalized { amount, .. } => total - amount.to_usd(),
+                _ => total,
+            })
+    }
+
+    pub fn accrue_interest(
+        &mut self,
+        accrued_on: NaiveDate,
+        settled_balance: UsdCents,
+        interest_terms: DepositInterestTerms,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self
+            .next_interest_accrual_date()
+            .is_none_or(|next| accrued_on < next)
+        {
+            return Idempotent::Ignored;
+        }
+
+        self.events.push(DepositAccountEvent::InterestAccrued {
+            accrued_on,
+            settled_balance,
+            amount: interest_terms.annual_rate.daily_interest(settled_balance),
+            audit_info,
+        });
+        Idempotent::Executed(())
+    }
+
+    /// Credits the whole cents of accrued interest to the account. Any
+    /// sub-cent remainder stays accrued for the next period.
+    pub fn capitalize_interest(
+        &mut self,
+        capitalized_on: NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Idempotent<InterestCapitalizationData> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            DepositAccountEvent::InterestCapitalized { capitalized_on: existing, .. }
+                if existing == &capitalized_on
+        );
+
+        let cents = (self.accrued_interest() * Decimal::ONE_HUNDRED)
+            .round_dp_with_strategy(0, RoundingStrategy::ToZero)
+            .to_u64()
+            .unwrap_or(0);
+        if cents == 0 {
+            return Idempotent::Ignored;
+        }
+
+        let data = InterestCapitalizationData {
+            ledger_tx_id: CalaTransactionId::new(),
+            capitalized_on,
+            amount: UsdCents::from(cents),
+        };
+        self.events.push(DepositAccountEvent::InterestCapitalized {
+            ledger_tx_id: data.ledger_tx_id,
+            capitalized_on,
+            amount: data.amount,
+            audit_info,
+        });
+        Idempotent::Executed(data)
+    }
 }
 
 impl TryFromEvents<DepositAccountEvent> for DepositAccount {
@@ -95,6 +525,20 @@ impl TryFromEvents<DepositAccountEvent> for DepositAccount {
                 DepositAccountEvent::AccountStatusUpdated { status, .. } => {
                     builder = builder.status(*status);
                 }
+                DepositAccountEvent::ProductAssigned {
+                    deposit_product_id, ..
+                } => {
+                    builder = builder.deposit_product_id(*deposit_product_id);
+                }
+                DepositAccountEvent::InterestAccrued { .. } => {}
+                DepositAccountEvent::InterestCapitalized { .. } => {}
+                DepositAccountEvent::Frozen { .. } => {}
+                DepositAccountEvent::Unfrozen { .. } => {}
+                DepositAccountEvent::WithdrawalLimitsOverridden { .. } => {}
+                DepositAccountEvent::WithdrawalLimitsApplied { .. } => {}
+                DepositAccountEvent::CustomerActivityRecorded { .. } => {}
+                DepositAccountEvent::DormancyStatusUpdated { .. } => {}
+                DepositAccountEvent::Closed { .. } => {}
             }
         }
         builder.events(events).build()
@@ -144,3 +588,294 @@ impl IntoEvents<DepositAccountEvent> for NewDepositAccount {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+    use rust_decimal_macros::dec;
+
+    use crate::product::{CapitalizationFrequency, DepositInterestRate};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, day).unwrap()
+    }
+
+    fn interest_terms() -> DepositInterestTerms {
+        DepositInterestTerms {
+            annual_rate: DepositInterestRate::from(dec!(3.65)),
+            capitalization_frequency: CapitalizationFrequency::Monthly,
+        }
+    }
+
+    fn account_with_product() -> DepositAccount {
+        let id = DepositAccountId::new();
+        let events = EntityEvents::init(
+            id,
+            [
+                DepositAccountEvent::Initialized {
+                    id,
+                    account_holder_id: DepositAccountHolderId::new(),
+                    ledger_account_id: id.into(),
+                    reference: "ref".to_string(),
+                    name: "name".to_string(),
+                    description: "description".to_string(),
+                    status: AccountStatus::Active,
+                    public_id: PublicId::new("1"),
+                    audit_info: dummy_audit_info(),
+                },
+                DepositAccountEvent::ProductAssigned {
+                    deposit_product_id: DepositProductId::new(),
+                    interest_accrual_starts_on: date(1),
+                    audit_info: dummy_audit_info(),
+                },
+            ],
+        );
+        DepositAccount::try_from_events(events).unwrap()
+    }
+
+    #[test]
+    fn accrues_each_day_once() {
+        let mut account = account_with_product();
+        assert_eq!(account.next_interest_accrual_date(), Some(date(1)));
+
+        assert!(
+            account
+                .accrue_interest(
+                    date(1),
+                    UsdCents::from(100_000),
+                    interest_terms(),
+                    dummy_audit_info()
+                )
+                .did_execute()
+        );
+        assert!(
+            account
+                .accrue_interest(
+                    date(1),
+                    UsdCents::from(100_000),
+                    interest_terms(),
+                    dummy_audit_info()
+                )
+                .was_ignored()
+        );
+        assert_eq!(account.next_interest_accrual_date(), Some(date(2)));
+        assert_eq!(account.accrued_interest(), dec!(0.1));
+    }
+
+    #[test]
+    fn capitalizes_whole_cents_and_carries_remainder() {
+        let mut account = account_with_product();
+        for day in 1..=3 {
+            let _ = account.accrue_interest(
+                date(day),
+                UsdCents::from(150_000),
+                interest_terms(),
+                dummy_audit_info(),
+            );
+        }
+
+        let data = account
+            .capitalize_interest(date(31), dummy_audit_info())
+            .unwrap();
+        assert_eq!(data.amount, UsdCents::from(45));
+        assert_eq!(account.accrued_interest(), dec!(0.0));
+        assert!(
+            account
+                .capitalize_interest(date(31), dummy_audit_info())
+                .was_ignored()
+        );
+    }
+
+    #[test]
+    fn does_not_capitalize_less_than_a_cent() {
+        let mut account = account_with_product();
+        let _ = account.accrue_interest(
+            date(1),
+            UsdCents::from(10),
+            interest_terms(),
+            dummy_audit_info(),
+        );
+
+        assert!(
+            account
+                .capitalize_interest(date(31), dummy_audit_info())
+                .was_ignored()
+        );
+        assert_eq!(account.accrued_interest(), dec!(0.00001));
+    }
+
+    #[test]
+    fn freeze_scope_follows_latest_freeze() {
+        let mut account = account_with_product();
+        assert_eq!(account.freeze_scope(), None);
+        assert!(account.unfreeze(dummy_audit_info()).was_ignored());
+
+        assert!(
+            account
+                .freeze(
+                    AccountFreezeScope::Debits,
+                    "court order".to_string(),
+                    dummy_audit_info()
+                )
+                .did_execute()
+        );
+        assert!(
+            account
+                .freeze(
+                    AccountFreezeScope::Debits,
+                    "court order".to_string(),
+                    dummy_audit_info()
+                )
+                .was_ignored()
+        );
+        let _ = account.freeze(
+            AccountFreezeScope::DebitsAndCredits,
+            "court order".to_string(),
+            dummy_audit_info(),
+        );
+        assert_eq!(
+            account.freeze_scope(),
+            Some(AccountFreezeScope::DebitsAndCredits)
+        );
+
+        assert!(account.unfreeze(dummy_audit_info()).did_execute());
+        assert_eq!(account.freeze_scope(), None);
+    }
+
+    #[test]
+    fn accounts_without_product_do_not_accrue() {
+        let id = DepositAccountId::new();
+        let mut account = DepositAccount::try_from_events(EntityEvents::init(
+            id,
+            [DepositAccountEvent::Initialized {
+                id,
+                account_holder_id: DepositAccountHolderId::new(),
+                ledger_account_id: id.into(),
+                reference: "ref".to_string(),
+                name: "name".to_string(),
+                description: "description".to_string(),
+                status: AccountStatus::Active,
+                public_id: PublicId::new("1"),
+                audit_info: dummy_audit_info(),
+            }],
+        ))
+        .unwrap();
+
+        assert!(
+            account
+                .accrue_interest(
+                    date(1),
+                    UsdCents::from(100_000),
+                    interest_terms(),
+                    dummy_audit_info()
+                )
+                .was_ignored()
+        );
+    }
+
+    #[test]
+    fn applies_withdrawal_limits_only_when_changed() {
+        let mut account = account_with_product();
+        assert!(
+            account
+                .apply_withdrawal_limits(WithdrawalLimits::default())
+                .was_ignored()
+        );
+
+        let limits = WithdrawalLimits {
+            daily: crate::WithdrawalLimit {
+                max_amount: Some(UsdCents::from(1_000)),
+                max_count: None,
+            },
+            ..Default::default()
+        };
+        assert!(account.apply_withdrawal_limits(limits).did_execute());
+        assert!(account.apply_withdrawal_limits(limits).was_ignored());
+        assert_eq!(account.applied_withdrawal_limits(), limits);
+    }
+
+    #[test]
+    fn dormancy_escalates_until_reactivated() {
+        let mut account = account_with_product();
+        let last_activity = date(1).and_hms_opt(0, 0, 0).unwrap().and_utc();
+        let dormancy_period = Duration::days(10);
+        let escheatment_period = Duration::days(20);
+        let _ = account.record_customer_activity(last_activity, dummy_audit_info());
+
+        let now = last_activity + Duration::days(5);
+        assert!(
+            account
+                .update_dormancy_status(
+                    now,
+                    dormancy_period,
+                    escheatment_period,
+                    dummy_audit_info()
+                )
+                .was_ignored()
+        );
+
+        let now = last_activity + Duration::days(10);
+        assert_eq!(
+            account
+                .update_dormancy_status(
+                    now,
+                    dormancy_period,
+                    escheatment_period,
+                    dummy_audit_info()
+                )
+                .unwrap(),
+            DormancyStatus::Dormant
+        );
+        assert!(account.is_dormant());
+
+        // Activity alone does not lift dormancy.
+        let _ = account.record_customer_activity(now, dummy_audit_info());
+        assert!(
+            account
+                .update_dormancy_status(
+                    now,
+                    dormancy_period,
+                    escheatment_period,
+                    dummy_audit_info()
+                )
+                .was_ignored()
+        );
+        assert_eq!(account.dormancy_status(), DormancyStatus::Dormant);
+
+        let now = now + Duration::days(20);
+        assert_eq!(
+            account
+                .update_dormancy_status(
+                    now,
+                    dormancy_period,
+                    escheatment_period,
+                    dummy_audit_info()
+                )
+                .unwrap(),
+            DormancyStatus::EscheatmentDue
+        );
+
+        assert!(account.reactivate(now, dummy_audit_info()).did_execute());
+        assert_eq!(account.dormancy_status(), DormancyStatus::Active);
+        assert_eq!(account.last_customer_activity_at(), Some(now));
+        assert!(account.reactivate(now, dummy_audit_info()).was_ignored());
+    }
+
+    #[test]
+    fn closes_once() {
+        let mut account = account_with_product();
+        assert!(!account.is_closed());
+        assert!(account.close(None, dummy_audit_info()).did_execute());
+        assert!(account.is_closed());
+        assert!(account.close(None, dummy_audit_info()).was_ignored());
+    }
+}
@@ -2,8 +2,8 @@ mod entity;
 pub mod error;
 mod repo;
 
-pub use entity::DepositAccount;
 #[cfg(feature = "json-schema")]
 pub use entity::DepositAccountEvent;
 pub(crate) use entity::*;
+pub use entity::{AccountFreezeScope, BalanceSweep, BalanceSweepDestination, DepositAccount};
 pub(crate) use repo::*;
@@ -0,0 +1,298 @@
+use chrono::NaiveDate;
+use roxmltree::{Document, Node};
+use rust_decimal::Decimal;
+
+use std::str::FromStr;
+
+use crate::primitives::UsdCents;
+
+use super::error::CamtError;
+
+const USD: &str = "USD";
+const NOT_PROVIDED: &str = "NOTPROVIDED";
+
+/// A booked credit taken from a camt.053 statement or camt.054 notification.
+/// Entries batching several transfers are split into one line per transfer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CamtCreditLine {
+    pub statement_id: String,
+    /// Reference assigned by the bank, unique per credit.
+    pub reference: String,
+    pub amount: UsdCents,
+    pub booked_on: Option<NaiveDate>,
+    pub debtor_name: Option<String>,
+    /// Account the sender credited, i.e. the virtual account number.
+    pub creditor_account: Option<String>,
+    pub creditor_reference: Option<String>,
+    pub remittance_information: Option<String>,
+}
+
+/// Extracts booked USD credits. Debits, reversals and pending entries are
+/// skipped since they never result in a deposit.
+pub fn parse_credit_lines(xml: &str) -> Result<Vec<CamtCreditLine>, CamtError> {
+    let doc = Document::parse(xml)?;
+    let (message, report_tag) = child(doc.root_element(), "BkToCstmrStmt")
+        .map(|m| (m, "Stmt"))
+        .or_else(|| child(doc.root_element(), "BkToCstmrDbtCdtNtfctn").map(|m| (m, "Ntfctn")))
+        .ok_or(CamtError::UnsupportedMessage)?;
+
+    let mut lines = vec![];
+    for report in children(message, report_tag) {
+        let statement_id = text(report, &["Id"]).ok_or(CamtError::MissingField("Id"))?;
+        for entry in children(report, "Ntry") {
+            parse_entry(&statement_id, entry, &mut lines)?;
+        }
+    }
+    Ok(lines)
+}
+
+fn parse_entry(
+    statement_id: &str,
+    entry: Node,
+    lines: &mut Vec<CamtCreditLine>,
+) -> Result<(), CamtError> {
+    let is_credit = text(entry, &["CdtDbtInd"]).as_deref() == Some("CRDT");
+    let is_reversal = text(entry, &["RvslInd"]).as_deref() == Some("true");
+    let status = text(entry, &["Sts", "Cd"]).or_else(|| text(entry, &["Sts"]));
+    if !is_credit || is_reversal || status.is_some_and(|status| status != "BOOK") {
+        return Ok(());
+    }
+
+    let entry_amount = amount(entry)?.ok_or(CamtError::MissingField("Amt"))?;
+    let entry_reference = text(entry, &["AcctSvcrRef"]).or_else(|| text(entry, &["NtryRef"]));
+    let booked_on = text(entry, &["BookgDt", "Dt"])
+        .or_else(|| text(entry, &["BookgDt", "DtTm"]))
+        .and_then(|date| NaiveDate::parse_from_str(date.get(..10)?, "%Y-%m-%d").ok());
+
+    let transactions: Vec<_> = children(entry, "NtryDtls")
+        .flat_map(|details| children(details, "TxDtls"))
+        .filter(|tx| text(*tx, &["CdtDbtInd"]).is_none_or(|ind| ind == "CRDT"))
+        .collect();
+
+    if transactions.is_empty() {
+        lines.push(CamtCreditLine {
+            statement_id: statement_id.to_string(),
+            reference: entry_reference.ok_or(CamtError::MissingField("AcctSvcrRef"))?,
+            amount: entry_amount,
+            booked_on,
+            debtor_name: None,
+            creditor_account: None,
+            creditor_reference: None,
+            remittance_information: text(entry, &["AddtlNtryInf"]),
+        });
+        return Ok(());
+    }
+
+    let is_batch = transactions.len() > 1;
+    for (idx, tx) in transactions.into_iter().enumerate() {
+        let amount = match amount(tx)? {
+            Some(amount) => amount,
+            None if !is_batch => entry_amount,
+            None => return Err(CamtError::MissingField("TxDtls/Amt")),
+        };
+        let reference = text(tx, &["Refs", "AcctSvcrRef"])
+            .or_else(|| text(tx, &["Refs", "EndToEndId"]).filter(|r| r != NOT_PROVIDED))
+            .or_else(|| match &entry_reference {
+                Some(reference) if is_batch => Some(format!("{reference}:{}", idx + 1)),
+                reference => reference.clone(),
+            })
+            .ok_or(CamtError::MissingField("AcctSvcrRef"))?;
+        let remittance_information = children(tx, "RmtInf")
+            .flat_map(|info| children(info, "Ustrd"))
+            .filter_map(|n| n.text().map(str::trim))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        lines.push(CamtCreditLine {
+            statement_id: statement_id.to_string(),
+            reference,
+            amount,
+            booked_on,
+            debtor_name: text(tx, &["RltdPties", "Dbtr", "Nm"])
+                .or_else(|| text(tx, &["RltdPties", "Dbtr", "Pty", "Nm"])),
+            creditor_account: text(tx, &["RltdPties", "CdtrAcct", "Id", "IBAN"])
+                .or_else(|| text(tx, &["RltdPties", "CdtrAcct", "Id", "Othr", "Id"])),
+            creditor_reference: text(tx, &["RmtInf", "Strd", "CdtrRefInf", "Ref"]),
+            remittance_information: Some(remittance_information).filter(|r| !r.is_empty()),
+        });
+    }
+    Ok(())
+}
+
+/// Reads `Amt`, falling back to the instructed amount that older versions
+/// report under `AmtDtls`.
+fn amount(node: Node) -> Result<Option<UsdCents>, CamtError> {
+    let Some(amount) =
+        child(node, "Amt").or_else(|| descendant(node, &["AmtDtls", "TxAmt", "Amt"]))
+    else {
+        return Ok(None);
+    };
+    let currency = amount.attribute("Ccy").unwrap_or_default();
+    if currency != USD {
+        return Err(CamtError::UnsupportedCurrency(currency.to_string()));
+    }
+    let value = amount.text().unwrap_or_default().trim();
+    let usd = Decimal::from_str(value).map_err(|_| CamtError::InvalidAmount(value.to_string()))?;
+    if !(usd * Decimal::ONE_HUNDRED).fract().is_zero() {
+        return Err(CamtError::InvalidAmount(value.to_string()));
+    }
+    UsdCents::try_from_usd(usd)
+        .map(Some)
+        .map_err(|_| CamtError::InvalidAmount(value.to_string()))
+}
+
+fn child<'a, 'i>(node: Node<'a, 'i>, name: &str) -> Option<Node<'a, 'i>> {
+    node.children()
+        .find(|n| n.is_element() && n.tag_name().name() == name)
+}
+
+fn children<'a, 'i: 'a>(
+    node: Node<'a, 'i>,
+    name: &'a str,
+) -> impl Iterator<Item = Node<'a, 'i>> + 'a {
+    node.children()
+        .filter(move |n| n.is_element() && n.tag_name().name() == name)
+}
+
+fn descendant<'a, 'i>(node: Node<'a, 'i>, path: &[&str]) -> Option<Node<'a, 'i>> {
+    path.iter().try_fold(node, |node, name| child(node, name))
+}
+
+fn text(node: Node, path: &[&str]) -> Option<String> {
+    descendant(node, path)
+        .and_then(|n| n.text())
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CAMT_053: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Document xmlns="urn:iso:std:iso:20022:tech:xsd:camt.053.001.08">
+  <BkToCstmrStmt>
+    <GrpHdr><MsgId>MSG-1</MsgId></GrpHdr>
+    <Stmt>
+      <Id>STMT-2024-01</Id>
+      <Ntry>
+        <Amt Ccy="USD">1500.00</Amt>
+        <CdtDbtInd>CRDT</CdtDbtInd>
+        <Sts><Cd>BOOK</Cd></Sts>
+        <BookgDt><Dt>2024-01-15</Dt></BookgDt>
+        <AcctSvcrRef>BANKREF-1</AcctSvcrRef>
+        <NtryDtls>
+          <TxDtls>
+            <Refs><EndToEndId>NOTPROVIDED</EndToEndId></Refs>
+            <RltdPties>
+              <Dbtr><Pty><Nm>Jane Doe</Nm></Pty></Dbtr>
+              <CdtrAcct><Id><Othr><Id>4471</Id></Othr></Id></CdtrAcct>
+            </RltdPties>
+            <RmtInf><Ustrd>Invoice</Ustrd><Ustrd>March</Ustrd></RmtInf>
+          </TxDtls>
+        </NtryDtls>
+      </Ntry>
+      <Ntry>
+        <Amt Ccy="USD">300.00</Amt>
+        <CdtDbtInd>CRDT</CdtDbtInd>
+        <Sts><Cd>BOOK</Cd></Sts>
+        <BookgDt><DtTm>2024-01-16T10:00:00</DtTm></BookgDt>
+        <AcctSvcrRef>BANKREF-2</AcctSvcrRef>
+        <NtryDtls>
+          <TxDtls>
+            <Refs><EndToEndId>E2E-A</EndToEndId></Refs>
+            <Amt Ccy="USD">100.00</Amt>
+            <RmtInf><Strd><CdtrRefInf><Ref>4472</Ref></CdtrRefInf></Strd></RmtInf>
+          </TxDtls>
+          <TxDtls>
+            <Amt Ccy="USD">200.00</Amt>
+          </TxDtls>
+        </NtryDtls>
+      </Ntry>
+      <Ntry>
+        <Amt Ccy="USD">75.00</Amt>
+        <CdtDbtInd>DBIT</CdtDbtInd>
+        <Sts><Cd>BOOK</Cd></Sts>
+        <AcctSvcrRef>BANKREF-3</AcctSvcrRef>
+      </Ntry>
+      <Ntry>
+        <Amt Ccy="USD">80.00</Amt>
+        <CdtDbtInd>CRDT</CdtDbtInd>
+        <Sts><Cd>PDNG</Cd></Sts>
+        <AcctSvcrRef>BANKREF-4</AcctSvcrRef>
+      </Ntry>
+    </Stmt>
+  </BkToCstmrStmt>
+</Document>"#;
+
+    #[test]
+    fn parses_booked_credits_from_statement() {
+        let lines = parse_credit_lines(CAMT_053).unwrap();
+        assert_eq!(lines.len(), 3);
+
+        assert_eq!(lines[0].statement_id, "STMT-2024-01");
+        assert_eq!(lines[0].reference, "BANKREF-1");
+        assert_eq!(lines[0].amount, UsdCents::from(150_000));
+        assert_eq!(lines[0].booked_on, NaiveDate::from_ymd_opt(2024, 1, 15));
+        assert_eq!(lines[0].debtor_name.as_deref(), Some("Jane Doe"));
+        assert_eq!(lines[0].creditor_account.as_deref(), Some("4471"));
+        assert_eq!(
+            lines[0].remittance_information.as_deref(),
+            Some("Invoice March")
+        );
+
+        assert_eq!(lines[1].reference, "E2E-A");
+        assert_eq!(lines[1].amount, UsdCents::from(10_000));
+        assert_eq!(lines[1].creditor_reference.as_deref(), Some("4472"));
+        assert_eq!(lines[1].booked_on, NaiveDate::from_ymd_opt(2024, 1, 16));
+
+        assert_eq!(lines[2].reference, "BANKREF-2:2");
+        assert_eq!(lines[2].amount, UsdCents::from(20_000));
+    }
+
+    #[test]
+    fn parses_notification_without_transaction_details() {
+        let xml = r#"<Document xmlns="urn:iso:std:iso:20022:tech:xsd:camt.054.001.02">
+  <BkToCstmrDbtCdtNtfctn>
+    <Ntfctn>
+      <Id>NTF-1</Id>
+      <Ntry>
+        <NtryRef>NTRY-9</NtryRef>
+        <Amt Ccy="USD">12.34</Amt>
+        <CdtDbtInd>CRDT</CdtDbtInd>
+        <Sts>BOOK</Sts>
+        <AddtlNtryInf>Wire 4471</AddtlNtryInf>
+      </Ntry>
+    </Ntfctn>
+  </BkToCstmrDbtCdtNtfctn>
+</Document>"#;
+        let lines = parse_credit_lines(xml).unwrap();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].statement_id, "NTF-1");
+        assert_eq!(lines[0].reference, "NTRY-9");
+        assert_eq!(lines[0].amount, UsdCents::from(1_234));
+        assert_eq!(
+            lines[0].remittance_information.as_deref(),
+            Some("Wire 4471")
+        );
+    }
+
+    #[test]
+    fn rejects_non_usd_credits() {
+        let xml = CAMT_053.replacen(r#"Ccy="USD">1500.00"#, r#"Ccy="EUR">1500.00"#, 1);
+        assert!(matches!(
+            parse_credit_lines(&xml),
+            Err(CamtError::UnsupportedCurrency(currency)) if currency == "EUR"
+        ));
+    }
+
+    #[test]
+    fn rejects_other_messages() {
+        let xml = r#"<Document><CstmrCdtTrfInitn/></Document>"#;
+        assert!(matches!(
+            parse_credit_lines(xml),
+            Err(CamtError::UnsupportedMessage)
+        ));
+    }
+}
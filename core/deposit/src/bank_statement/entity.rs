@@ -0,0 +1,294 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{BankStatementLineId, DepositAccountId, DepositId, UsdCents};
+use audit::AuditInfo;
+
+use super::camt::CamtCreditLine;
+
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum BankStatementLineStatus {
+    #[default]
+    Unmatched,
+    Recorded,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "BankStatementLineId")]
+pub enum BankStatementLineEvent {
+    Initialized {
+        id: BankStatementLineId,
+        statement_id: String,
+        reference: String,
+        amount: UsdCents,
+        booked_on: Option<NaiveDate>,
+        debtor_name: Option<String>,
+        creditor_account: Option<String>,
+        creditor_reference: Option<String>,
+        remittance_information: Option<String>,
+        audit_info: AuditInfo,
+    },
+    DepositRecorded {
+        deposit_id: DepositId,
+        deposit_account_id: DepositAccountId,
+        matched_on_import: bool,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct BankStatementLine {
+    pub id: BankStatementLineId,
+    pub statement_id: String,
+    /// Reference the bank assigned to the credit.
+    pub reference: String,
+    pub amount: UsdCents,
+    #[builder(default)]
+    pub booked_on: Option<NaiveDate>,
+    #[builder(default)]
+    pub debtor_name: Option<String>,
+    #[builder(default)]
+    pub creditor_account: Option<String>,
+    #[builder(default)]
+    pub creditor_reference: Option<String>,
+    #[builder(default)]
+    pub remittance_information: Option<String>,
+
+    events: EntityEvents<BankStatementLineEvent>,
+}
+
+impl BankStatementLine {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for bank statement line")
+    }
+
+    pub fn status(&self) -> BankStatementLineStatus {
+        if self.deposit_id().is_some() {
+            BankStatementLineStatus::Recorded
+        } else {
+            BankStatementLineStatus::Unmatched
+        }
+    }
+
+    pub fn deposit_id(&self) -> Option<DepositId> {
+        self.events.iter_all().find_map(|e| match e {
+            BankStatementLineEvent::DepositRecorded { deposit_id, .. } => Some(*deposit_id),
+            _ => None,
+        })
+    }
+
+    pub fn deposit_account_id(&self) -> Option<DepositAccountId> {
+        self.events.iter_all().find_map(|e| match e {
+            BankStatementLineEvent::DepositRecorded {
+                deposit_account_id, ..
+            } => Some(*deposit_account_id),
+            _ => None,
+        })
+    }
+
+    /// Identifiers a sender may have used to point at a deposit account,
+    /// most specific first: the virtual account number credited, then the
+    /// structured and the free-text remittance reference.
+    pub fn match_candidates(&self) -> impl Iterator<Item = &str> {
+        [
+            &self.creditor_account,
+            &self.creditor_reference,
+            &self.remittance_information,
+        ]
+        .into_iter()
+        .filter_map(|candidate| candidate.as_deref())
+    }
+
+    pub fn record_deposit(
+        &mut self,
+        deposit_id: DepositId,
+        deposit_account_id: DepositAccountId,
+        matched_on_import: bool,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            BankStatementLineEvent::DepositRecorded { .. }
+        );
+        self.events.push(BankStatementLineEvent::DepositRecorded {
+            deposit_id,
+            deposit_account_id,
+            matched_on_import,
+            audit_info,
+        });
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<BankStatementLineEvent> for BankStatementLine {
+    fn try_from_events(
+        events: EntityEvents<BankStatementLineEvent>,
+    ) -> Result<Self, EsEntityError> {
+        let mut builder = BankStatementLineBuilder::default();
+        for event in events.iter_all() {
+            if let BankStatementLineEvent::Initialized {
+                id,
+                statement_id,
+                reference,
+                amount,
+                booked_on,
+                debtor_name,
+                creditor_account,
+                creditor_reference,
+                remittance_information,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .statement_id(statement_id.clone())
+                    .reference(reference.clone())
+                    .amount(*amount)
+                    .booked_on(*booked_on)
+                    .debtor_name(debtor_name.clone())
+                    .creditor_account(creditor_account.clone())
+                    .creditor_reference(creditor_reference.clone())
+                    .remittance_information(remittance_information.clone())
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewBankStatementLine {
+    #[builder(setter(into))]
+    pub(super) id: BankStatementLineId,
+    pub(super) line: CamtCreditLine,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewBankStatementLine {
+    pub fn builder() -> NewBankStatementLineBuilder {
+        NewBankStatementLineBuilder::default()
+    }
+
+    pub(super) fn status(&self) -> BankStatementLineStatus {
+        BankStatementLineStatus::Unmatched
+    }
+
+    pub(super) fn reference(&self) -> String {
+        self.line.reference.clone()
+    }
+}
+
+impl IntoEvents<BankStatementLineEvent> for NewBankStatementLine {
+    fn into_events(self) -> EntityEvents<BankStatementLineEvent> {
+        let line = self.line;
+        EntityEvents::init(
+            self.id,
+            [BankStatementLineEvent::Initialized {
+                id: self.id,
+                statement_id: line.statement_id,
+                reference: line.reference,
+                amount: line.amount,
+                booked_on: line.booked_on,
+                debtor_name: line.debtor_name,
+                creditor_account: line.creditor_account,
+                creditor_reference: line.creditor_reference,
+                remittance_information: line.remittance_information,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn line(creditor_account: Option<&str>, remittance: Option<&str>) -> BankStatementLine {
+        let new_line = NewBankStatementLine::builder()
+            .id(BankStatementLineId::new())
+            .line(CamtCreditLine {
+                statement_id: "STMT-1".to_string(),
+                reference: "BANKREF-1".to_string(),
+                amount: UsdCents::from(10_000),
+                booked_on: None,
+                debtor_name: None,
+                creditor_account: creditor_account.map(str::to_string),
+                creditor_reference: None,
+                remittance_information: remittance.map(str::to_string),
+            })
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        BankStatementLine::try_from_events(new_line.into_events()).unwrap()
+    }
+
+    #[test]
+    fn virtual_account_number_is_matched_first() {
+        let line = line(Some("4471"), Some("4472"));
+        assert_eq!(
+            line.match_candidates().collect::<Vec<_>>(),
+            ["4471", "4472"]
+        );
+    }
+
+    #[test]
+    fn deposit_is_recorded_once() {
+        let mut line = line(None, None);
+        assert_eq!(line.status(), BankStatementLineStatus::Unmatched);
+        assert_eq!(line.match_candidates().count(), 0);
+
+        let deposit_id = DepositId::new();
+        assert!(
+            line.record_deposit(
+                deposit_id,
+                DepositAccountId::new(),
+                false,
+                dummy_audit_info()
+            )
+            .did_execute()
+        );
+        assert_eq!(line.status(), BankStatementLineStatus::Recorded);
+        assert_eq!(line.deposit_id(), Some(deposit_id));
+        assert!(
+            line.record_deposit(
+                DepositId::new(),
+                DepositAccountId::new(),
+                false,
+                dummy_audit_info()
+            )
+            .was_ignored()
+        );
+    }
+}
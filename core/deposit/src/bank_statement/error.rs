@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum BankStatementLineError {
+    #[error("BankStatementLineError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("BankStatementLineError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("BankStatementLineError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+}
+
+es_entity::from_es_entity_error!(BankStatementLineError);
+
+#[derive(Error, Debug)]
+pub enum CamtError {
+    #[error("CamtError - Xml: {0}")]
+    Xml(#[from] roxmltree::Error),
+    #[error("CamtError - UnsupportedMessage: expected camt.053 or camt.054")]
+    UnsupportedMessage,
+    #[error("CamtError - MissingField: {0}")]
+    MissingField(&'static str),
+    #[error("CamtError - UnsupportedCurrency: {0}")]
+    UnsupportedCurrency(String),
+    #[error("CamtError - InvalidAmount: {0}")]
+    InvalidAmount(String),
+}
@@ -0,0 +1,12 @@
+mod camt;
+mod entity;
+pub mod error;
+mod repo;
+
+pub(crate) use camt::*;
+#[cfg(feature = "json-schema")]
+pub use entity::BankStatementLineEvent;
+pub(crate) use entity::*;
+pub use entity::{BankStatementLine, BankStatementLineStatus};
+pub use repo::bank_statement_line_cursor::BankStatementLinesByCreatedAtCursor;
+pub(crate) use repo::*;
@@ -0,0 +1,74 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::BankStatementLineId;
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "BankStatementLine",
+    err = "BankStatementLineError",
+    columns(
+        status(
+            ty = "BankStatementLineStatus",
+            list_for,
+            create(accessor = "status()"),
+            update(accessor = "status()")
+        ),
+        reference(
+            ty = "String",
+            create(accessor = "reference()"),
+            update(persist = false)
+        )
+    ),
+    tbl_prefix = "core"
+)]
+pub struct BankStatementLineRepo {
+    pool: PgPool,
+}
+
+impl BankStatementLineRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+mod bank_statement_line_status_sqlx {
+    use sqlx::{Type, postgres::*};
+
+    use super::BankStatementLineStatus;
+
+    impl Type<Postgres> for BankStatementLineStatus {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for BankStatementLineStatus {
+        fn encode_by_ref(
+            &self,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            <String as sqlx::Encode<'_, Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for BankStatementLineStatus {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(s.parse().map_err(|e: strum::ParseError| Box::new(e))?)
+        }
+    }
+
+    impl PgHasArrayType for BankStatementLineStatus {
+        fn array_type_info() -> PgTypeInfo {
+            <String as sqlx::postgres::PgHasArrayType>::array_type_info()
+        }
+    }
+}
@@ -0,0 +1,336 @@
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{ApprovalProcessId, BeneficiaryId, DepositAccountId};
+use audit::AuditInfo;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum BeneficiaryStatus {
+    PendingApproval,
+    Denied,
+    CoolingOff,
+    Active,
+    Removed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct BeneficiaryBankAccount {
+    pub account_holder_name: String,
+    /// IBAN or domestic account number at the receiving bank.
+    pub account_number: String,
+    pub bank_name: String,
+    /// BIC of the receiving bank.
+    pub bank_identifier: String,
+}
+
+impl BeneficiaryBankAccount {
+    fn is_valid(&self) -> bool {
+        [
+            &self.account_holder_name,
+            &self.account_number,
+            &self.bank_name,
+            &self.bank_identifier,
+        ]
+        .iter()
+        .all(|field| !field.trim().is_empty())
+    }
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "BeneficiaryId")]
+pub enum BeneficiaryEvent {
+    Initialized {
+        id: BeneficiaryId,
+        deposit_account_id: DepositAccountId,
+        bank_account: BeneficiaryBankAccount,
+        replaces: Option<BeneficiaryId>,
+        approval_process_id: ApprovalProcessId,
+        audit_info: AuditInfo,
+    },
+    ApprovalProcessConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        usable_from: Option<DateTime<Utc>>,
+        audit_info: AuditInfo,
+    },
+    Removed {
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct Beneficiary {
+    pub id: BeneficiaryId,
+    pub deposit_account_id: DepositAccountId,
+    pub bank_account: BeneficiaryBankAccount,
+    /// The beneficiary this one supersedes once approved.
+    #[builder(default)]
+    pub replaces: Option<BeneficiaryId>,
+    pub approval_process_id: ApprovalProcessId,
+
+    events: EntityEvents<BeneficiaryEvent>,
+}
+
+impl Beneficiary {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for beneficiary")
+    }
+
+    pub fn is_approved_or_denied(&self) -> Option<bool> {
+        self.events.iter_all().find_map(|e| match e {
+            BeneficiaryEvent::ApprovalProcessConcluded { approved, .. } => Some(*approved),
+            _ => None,
+        })
+    }
+
+    /// When the cooling-off period after approval ends and withdrawals to the
+    /// beneficiary are allowed.
+    pub fn usable_from(&self) -> Option<DateTime<Utc>> {
+        self.events.iter_all().find_map(|e| match e {
+            BeneficiaryEvent::ApprovalProcessConcluded { usable_from, .. } => *usable_from,
+            _ => None,
+        })
+    }
+
+    pub fn is_removed(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, BeneficiaryEvent::Removed { .. }))
+    }
+
+    pub fn status(&self) -> BeneficiaryStatus {
+        self.status_at(crate::time::now())
+    }
+
+    fn status_at(&self, now: DateTime<Utc>) -> BeneficiaryStatus {
+        if self.is_removed() {
+            return BeneficiaryStatus::Removed;
+        }
+        match (self.is_approved_or_denied(), self.usable_from()) {
+            (None, _) => BeneficiaryStatus::PendingApproval,
+            (Some(false), _) => BeneficiaryStatus::Denied,
+            (Some(true), Some(usable_from)) if usable_from > now => BeneficiaryStatus::CoolingOff,
+            (Some(true), _) => BeneficiaryStatus::Active,
+        }
+    }
+
+    /// Starts the cooling-off period when approved. Withdrawals to the
+    /// beneficiary stay blocked until `cooling_off` has elapsed.
+    pub fn approval_process_concluded(
+        &mut self,
+        approved: bool,
+        now: DateTime<Utc>,
+        cooling_off: chrono::Duration,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            BeneficiaryEvent::ApprovalProcessConcluded { .. }
+        );
+        self.events
+            .push(BeneficiaryEvent::ApprovalProcessConcluded {
+                approval_process_id: self.approval_process_id,
+                approved,
+                usable_from: approved.then(|| now + cooling_off),
+                audit_info,
+            });
+        Idempotent::Executed(())
+    }
+
+    pub fn remove(&mut self, audit_info: AuditInfo) -> Idempotent<()> {
+        idempotency_guard!(self.events.iter_all(), BeneficiaryEvent::Removed { .. });
+        self.events.push(BeneficiaryEvent::Removed { audit_info });
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<BeneficiaryEvent> for Beneficiary {
+    fn try_from_events(events: EntityEvents<BeneficiaryEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = BeneficiaryBuilder::default();
+        for event in events.iter_all() {
+            if let BeneficiaryEvent::Initialized {
+                id,
+                deposit_account_id,
+                bank_account,
+                replaces,
+                approval_process_id,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .deposit_account_id(*deposit_account_id)
+                    .bank_account(bank_account.clone())
+                    .replaces(*replaces)
+                    .approval_process_id(*approval_process_id)
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewBeneficiary {
+    #[builder(setter(into))]
+    pub(super) id: BeneficiaryId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    pub(super) bank_account: BeneficiaryBankAccount,
+    #[builder(default)]
+    pub(super) replaces: Option<BeneficiaryId>,
+    #[builder(setter(into))]
+    pub(super) approval_process_id: ApprovalProcessId,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewBeneficiary {
+    pub fn builder() -> NewBeneficiaryBuilder {
+        NewBeneficiaryBuilder::default()
+    }
+}
+
+impl NewBeneficiaryBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match &self.bank_account {
+            Some(bank_account) if !bank_account.is_valid() => {
+                Err("Beneficiary bank account details cannot be empty".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl IntoEvents<BeneficiaryEvent> for NewBeneficiary {
+    fn into_events(self) -> EntityEvents<BeneficiaryEvent> {
+        EntityEvents::init(
+            self.id,
+            [BeneficiaryEvent::Initialized {
+                id: self.id,
+                deposit_account_id: self.deposit_account_id,
+                bank_account: self.bank_account,
+                replaces: self.replaces,
+                approval_process_id: self.approval_process_id,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn bank_account() -> BeneficiaryBankAccount {
+        BeneficiaryBankAccount {
+            account_holder_name: "Jane Doe".to_string(),
+            account_number: "DE89370400440532013000".to_string(),
+            bank_name: "Commerzbank".to_string(),
+            bank_identifier: "COBADEFFXXX".to_string(),
+        }
+    }
+
+    fn beneficiary() -> Beneficiary {
+        let id = BeneficiaryId::new();
+        let new_beneficiary = NewBeneficiary::builder()
+            .id(id)
+            .deposit_account_id(DepositAccountId::new())
+            .bank_account(bank_account())
+            .approval_process_id(id)
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        Beneficiary::try_from_events(new_beneficiary.into_events()).unwrap()
+    }
+
+    #[test]
+    fn errors_on_empty_bank_account_details() {
+        let id = BeneficiaryId::new();
+        let new_beneficiary = NewBeneficiary::builder()
+            .id(id)
+            .deposit_account_id(DepositAccountId::new())
+            .bank_account(BeneficiaryBankAccount {
+                account_number: " ".to_string(),
+                ..bank_account()
+            })
+            .approval_process_id(id)
+            .audit_info(dummy_audit_info())
+            .build();
+
+        assert!(matches!(
+            new_beneficiary,
+            Err(NewBeneficiaryBuilderError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn usable_only_after_cooling_off() {
+        let mut beneficiary = beneficiary();
+        let now = crate::time::now();
+        assert_eq!(
+            beneficiary.status_at(now),
+            BeneficiaryStatus::PendingApproval
+        );
+
+        assert!(
+            beneficiary
+                .approval_process_concluded(
+                    true,
+                    now,
+                    chrono::Duration::hours(24),
+                    dummy_audit_info()
+                )
+                .did_execute()
+        );
+        assert_eq!(beneficiary.status_at(now), BeneficiaryStatus::CoolingOff);
+        assert_eq!(
+            beneficiary.status_at(now + chrono::Duration::hours(23)),
+            BeneficiaryStatus::CoolingOff
+        );
+        assert_eq!(
+            beneficiary.status_at(now + chrono::Duration::hours(24)),
+            BeneficiaryStatus::Active
+        );
+    }
+
+    #[test]
+    fn denied_beneficiary_is_never_usable() {
+        let mut beneficiary = beneficiary();
+        let now = crate::time::now();
+        let _ = beneficiary.approval_process_concluded(
+            false,
+            now,
+            chrono::Duration::zero(),
+            dummy_audit_info(),
+        );
+        assert_eq!(beneficiary.status_at(now), BeneficiaryStatus::Denied);
+        assert!(
+            beneficiary
+                .approval_process_concluded(true, now, chrono::Duration::zero(), dummy_audit_info())
+                .was_ignored()
+        );
+    }
+}
@@ -0,0 +1,9 @@
+mod entity;
+pub mod error;
+mod repo;
+
+#[cfg(feature = "json-schema")]
+pub use entity::BeneficiaryEvent;
+pub(crate) use entity::*;
+pub use entity::{Beneficiary, BeneficiaryBankAccount, BeneficiaryStatus};
+pub(crate) use repo::*;
@@ -0,0 +1,24 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::{BeneficiaryId, DepositAccountId};
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "Beneficiary",
+    err = "BeneficiaryError",
+    columns(deposit_account_id(ty = "DepositAccountId", list_for, update(persist = false))),
+    tbl_prefix = "core"
+)]
+pub struct BeneficiaryRepo {
+    pool: PgPool,
+}
+
+impl BeneficiaryRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::UsdCents;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DepositConfig {
+    /// Transfers between deposit accounts above this amount need to be
+    /// approved through governance before funds reach the recipient.
+    #[serde(default = "default_transfer_approval_threshold")]
+    pub transfer_approval_threshold: UsdCents,
+    /// Days without customer-initiated activity after which an account is
+    /// flagged dormant and withdrawals are blocked until it is reactivated.
+    #[serde(default = "default_dormancy_period_days")]
+    pub dormancy_period_days: u32,
+    /// Days without customer-initiated activity after which the balance of
+    /// an account has to be reported as unclaimed property.
+    #[serde(default = "default_escheatment_period_days")]
+    pub escheatment_period_days: u32,
+    /// Hours after approval before a newly registered or changed withdrawal
+    /// beneficiary can be paid out to.
+    #[serde(default = "default_beneficiary_cooling_off_period_hours")]
+    pub beneficiary_cooling_off_period_hours: u32,
+    /// Ordering party written into pain.001 payment files for confirmed
+    /// withdrawals. Payment files cannot be generated without it.
+    #[serde(default)]
+    pub payment_initiation: Option<PaymentInitiationConfig>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentInitiationConfig {
+    /// Name of the bank as the ordering customer.
+    pub debtor_name: String,
+    /// IBAN or account number the withdrawals are paid from.
+    pub debtor_account: String,
+    /// BIC of the bank holding the debtor account.
+    pub debtor_agent_bic: String,
+    /// Upper bound of credit transfers written into a single payment file.
+    #[serde(default = "default_max_transactions_per_file")]
+    pub max_transactions_per_file: usize,
+}
+
+impl Default for DepositConfig {
+    fn default() -> Self {
+        DepositConfig {
+            transfer_approval_threshold: default_transfer_approval_threshold(),
+            dormancy_period_days: default_dormancy_period_days(),
+            escheatment_period_days: default_escheatment_period_days(),
+            beneficiary_cooling_off_period_hours: default_beneficiary_cooling_off_period_hours(),
+            payment_initiation: None,
+        }
+    }
+}
+
+fn default_transfer_approval_threshold() -> UsdCents {
+    UsdCents::from(1_000_000)
+}
+
+fn default_dormancy_period_days() -> u32 {
+    365
+}
+
+fn default_escheatment_period_days() -> u32 {
+    365 * 3
+}
+
+fn default_beneficiary_cooling_off_period_hours() -> u32 {
+    24
+}
+
+fn default_max_transactions_per_file() -> usize {
+    500
+}
@@ -3,11 +3,17 @@ use core_money::UsdCents;
 pub struct DepositAccountBalance {
     pub settled: UsdCents,
     pub pending: UsdCents,
+    /// Amount reserved by active holds.
+    pub held: UsdCents,
+    /// Settled balance not covered by holds.
+    pub available: UsdCents,
 }
 
 impl DepositAccountBalance {
     pub const ZERO: Self = DepositAccountBalance {
         settled: UsdCents::ZERO,
         pending: UsdCents::ZERO,
+        held: UsdCents::ZERO,
+        available: UsdCents::ZERO,
     };
 }
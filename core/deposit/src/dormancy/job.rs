@@ -0,0 +1,107 @@
+use async_trait::async_trait;
+use chrono::Days;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{CoreDepositAction, CoreDepositEvent, CoreDepositObject};
+
+use super::TrackDormancy;
+
+#[derive(serde::Serialize)]
+pub struct DepositAccountDormancyJobConfig<Perms, E> {
+    _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> DepositAccountDormancyJobConfig<Perms, E> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Perms, E> JobConfig for DepositAccountDormancyJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    type Initializer = DepositAccountDormancyInit<Perms, E>;
+}
+
+pub struct DepositAccountDormancyInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    process: TrackDormancy<Perms, E>,
+}
+
+impl<Perms, E> DepositAccountDormancyInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(process: &TrackDormancy<Perms, E>) -> Self {
+        Self {
+            process: process.clone(),
+        }
+    }
+}
+
+const DEPOSIT_ACCOUNT_DORMANCY_JOB: JobType = JobType::new("deposit-account-dormancy");
+impl<Perms, E> JobInitializer for DepositAccountDormancyInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        DEPOSIT_ACCOUNT_DORMANCY_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(DepositAccountDormancyJobRunner {
+            process: self.process.clone(),
+        }))
+    }
+}
+
+pub struct DepositAccountDormancyJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    process: TrackDormancy<Perms, E>,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for DepositAccountDormancyJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let now = crate::time::now();
+        self.process.execute(now).await?;
+
+        let next_run = (now.date_naive() + Days::new(1))
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight is a valid time")
+            .and_utc();
+        Ok(JobCompletion::RescheduleAt(next_run))
+    }
+}
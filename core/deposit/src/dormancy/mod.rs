@@ -0,0 +1,180 @@
+mod job;
+
+use chrono::{DateTime, Duration, Utc};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CoreDepositAction, CoreDepositObject,
+    account::{DepositAccount, DepositAccountRepo},
+    config::DepositConfig,
+    error::CoreDepositError,
+    event::CoreDepositEvent,
+    history::{DepositAccountHistoryCursor, DepositAccountHistoryEntry},
+    ledger::DepositLedger,
+    primitives::DormancyStatus,
+};
+
+pub use job::*;
+
+pub struct TrackDormancy<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    accounts: DepositAccountRepo<E>,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+    dormancy_period: Duration,
+    escheatment_period: Duration,
+}
+
+impl<Perms, E> Clone for TrackDormancy<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            accounts: self.accounts.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+            dormancy_period: self.dormancy_period,
+            escheatment_period: self.escheatment_period,
+        }
+    }
+}
+
+impl<Perms, E> TrackDormancy<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        accounts: &DepositAccountRepo<E>,
+        ledger: &DepositLedger,
+        audit: &Perms::Audit,
+        config: &DepositConfig,
+    ) -> Self {
+        Self {
+            accounts: accounts.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+            dormancy_period: Duration::days(config.dormancy_period_days.into()),
+            escheatment_period: Duration::days(config.escheatment_period_days.into()),
+        }
+    }
+
+    /// Brings the last customer activity of every account up to date with
+    /// the ledger and flags the accounts that went without activity for
+    /// longer than the dormancy or escheatment periods.
+    pub async fn execute(&self, now: DateTime<Utc>) -> Result<(), CoreDepositError> {
+        let mut has_next_page = true;
+        let mut after = None;
+        while has_next_page {
+            let mut accounts = self
+                .accounts
+                .list_by_id(
+                    es_entity::PaginatedQueryArgs { first: 50, after },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            (after, has_next_page) = (accounts.end_cursor, accounts.has_next_page);
+
+            for account in accounts.entities.iter_mut() {
+                if account.is_closed() {
+                    continue;
+                }
+                self.track_account(account, now).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn track_account(
+        &self,
+        account: &mut DepositAccount,
+        now: DateTime<Utc>,
+    ) -> Result<(), CoreDepositError> {
+        let latest_activity = self.latest_customer_activity(account).await?;
+        let status_due =
+            account.dormancy_status_due(now, self.dormancy_period, self.escheatment_period);
+        if latest_activity.is_none() && status_due <= account.dormancy_status() {
+            return Ok(());
+        }
+
+        let mut op = self.accounts.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                op.tx(),
+                CoreDepositObject::deposit_account(account.id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_UPDATE_DORMANCY,
+            )
+            .await?;
+        if let Some(recorded_at) = latest_activity {
+            let _ = account.record_customer_activity(recorded_at, audit_info.clone());
+        }
+        let escheatment_due = account
+            .update_dormancy_status(
+                now,
+                self.dormancy_period,
+                self.escheatment_period,
+                audit_info,
+            )
+            .did_execute()
+            && account.dormancy_status() == DormancyStatus::EscheatmentDue;
+        if escheatment_due {
+            tracing::warn!(
+                deposit_account_id = %account.id,
+                "deposit account reached the unclaimed property threshold"
+            );
+        }
+        self.accounts.update_in_op(&mut op, account).await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    /// The newest customer-initiated ledger entry that is more recent than
+    /// the activity already recorded on the account.
+    async fn latest_customer_activity(
+        &self,
+        account: &DepositAccount,
+    ) -> Result<Option<DateTime<Utc>>, CoreDepositError> {
+        let recorded = account.last_customer_activity_at();
+        let mut has_next_page = true;
+        let mut after = None;
+        while has_next_page {
+            let history = self
+                .ledger
+                .account_history::<DepositAccountHistoryEntry, DepositAccountHistoryCursor>(
+                    account.id,
+                    es_entity::PaginatedQueryArgs { first: 50, after },
+                )
+                .await?;
+            (after, has_next_page) = (history.end_cursor, history.has_next_page);
+
+            if let Some(activity_at) = history
+                .entities
+                .iter()
+                .find_map(DepositAccountHistoryEntry::customer_activity_at)
+            {
+                return Ok(Some(activity_at).filter(|at| recorded.is_none_or(|r| *at > r)));
+            }
+            // Entries are listed newest first, so older pages cannot hold
+            // anything more recent than what is already recorded.
+            if after
+                .as_ref()
+                .zip(recorded)
+                .is_some_and(|(cursor, recorded)| cursor.created_at <= recorded)
+            {
+                break;
+            }
+        }
+        Ok(None)
+    }
+}
@@ -12,6 +12,24 @@ pub enum CoreDepositError {
     DepositAccountError(#[from] crate::account::error::DepositAccountError),
     #[error("CoreDepositError - DepositError: {0}")]
     DepositError(#[from] crate::deposit::error::DepositError),
+    #[error("CoreDepositError - DepositProductError: {0}")]
+    DepositProductError(#[from] crate::product::error::DepositProductError),
+    #[error("CoreDepositError - BeneficiaryError: {0}")]
+    BeneficiaryError(#[from] crate::beneficiary::error::BeneficiaryError),
+    #[error("CoreDepositError - BankStatementLineError: {0}")]
+    BankStatementLineError(#[from] crate::bank_statement::error::BankStatementLineError),
+    #[error("CoreDepositError - CamtError: {0}")]
+    CamtError(#[from] crate::bank_statement::error::CamtError),
+    #[error("CoreDepositError - PaymentBatchError: {0}")]
+    PaymentBatchError(#[from] crate::payment_batch::error::PaymentBatchError),
+    #[error("CoreDepositError - HoldError: {0}")]
+    HoldError(#[from] crate::hold::error::HoldError),
+    #[error("CoreDepositError - DepositAccountStatementError: {0}")]
+    DepositAccountStatementError(#[from] crate::statement::error::DepositAccountStatementError),
+    #[error("CoreDepositError - TimeDepositError: {0}")]
+    TimeDepositError(#[from] crate::time_deposit::error::TimeDepositError),
+    #[error("CoreDepositError - TransferError: {0}")]
+    TransferError(#[from] crate::transfer::error::TransferError),
     #[error("CoreDepositError - WithdrawalError: {0}")]
     WithdrawalError(#[from] crate::withdrawal::error::WithdrawalError),
     #[error("CoreDepositError - DepositLedgerError: {0}")]
@@ -26,6 +44,10 @@ pub enum CoreDepositError {
     JobError(#[from] job::error::JobError),
     #[error("CoreDepositError - ProcessError: {0}")]
     ProcessError(#[from] crate::processes::error::ProcessError),
+    #[error("CoreDepositError - DocumentStorageError: {0}")]
+    DocumentStorageError(#[from] document_storage::error::DocumentStorageError),
+    #[error("CoreDepositError - RenderingError: {0}")]
+    RenderingError(#[from] rendering::RenderingError),
     #[error("CoreDepositError - SubjectIsNotDepositAccountHolder")]
     SubjectIsNotDepositAccountHolder,
     #[error("CoreDepositError - DepositAccountNotFound")]
@@ -36,10 +58,50 @@ pub enum CoreDepositError {
     DepositConfigAlreadyExists,
     #[error("CoreDepositError - DepositAccountNotActive")]
     DepositAccountNotActive,
+    #[error("CoreDepositError - DepositAccountDormant")]
+    DepositAccountDormant,
+    #[error("CoreDepositError - DepositAccountClosed")]
+    DepositAccountClosed,
+    #[error("CoreDepositError - DepositAccountHasPendingWithdrawals")]
+    DepositAccountHasPendingWithdrawals,
+    #[error("CoreDepositError - DepositAccountHasUnsettledFunds")]
+    DepositAccountHasUnsettledFunds,
+    #[error("CoreDepositError - DepositAccountHasBalance: a sweep destination is required")]
+    DepositAccountHasBalance,
+    #[error("CoreDepositError - BeneficiaryNotUsable: {0:?}")]
+    BeneficiaryNotUsable(crate::BeneficiaryStatus),
+    #[error("CoreDepositError - BeneficiaryAccountMismatch")]
+    BeneficiaryAccountMismatch,
+    #[error("CoreDepositError - BankStatementLineAlreadyRecorded")]
+    BankStatementLineAlreadyRecorded,
+    #[error("CoreDepositError - PaymentInitiationNotConfigured")]
+    PaymentInitiationNotConfigured,
+    #[error("CoreDepositError - NoWithdrawalsToPay")]
+    NoWithdrawalsToPay,
+    #[error("CoreDepositError - WithdrawalLimitExceeded: {0:?} withdrawal amount limit reached")]
+    WithdrawalLimitExceeded(crate::WithdrawalLimitPeriod),
+    #[error(
+        "CoreDepositError - WithdrawalCountLimitExceeded: {0:?} withdrawal count limit reached"
+    )]
+    WithdrawalCountLimitExceeded(crate::WithdrawalLimitPeriod),
     #[error("CoreDepositError - WithdrawalBuilderError: {0}")]
     WithdrawalBuilderError(#[from] super::NewWithdrawalBuilderError),
     #[error("CoreDepositError - DepositBuilderError: {0}")]
     DepositBuilderError(#[from] super::NewDepositBuilderError),
+    #[error("CoreDepositError - TimeDepositBuilderError: {0}")]
+    TimeDepositBuilderError(#[from] super::NewTimeDepositBuilderError),
+    #[error("CoreDepositError - TransferBuilderError: {0}")]
+    TransferBuilderError(#[from] super::NewTransferBuilderError),
+    #[error("CoreDepositError - BeneficiaryBuilderError: {0}")]
+    BeneficiaryBuilderError(#[from] super::NewBeneficiaryBuilderError),
+    #[error("CoreDepositError - BankStatementLineBuilderError: {0}")]
+    BankStatementLineBuilderError(#[from] super::NewBankStatementLineBuilderError),
+    #[error("CoreDepositError - PaymentBatchBuilderError: {0}")]
+    PaymentBatchBuilderError(#[from] super::NewPaymentBatchBuilderError),
+    #[error("CoreDepositError - HoldBuilderError: {0}")]
+    HoldBuilderError(#[from] super::NewHoldBuilderError),
+    #[error("CoreDepositError - DepositAccountStatementBuilderError: {0}")]
+    DepositAccountStatementBuilderError(#[from] super::NewDepositAccountStatementBuilderError),
     #[error("CoreDepositError - PublicIdError: {0}")]
     PublicIdError(#[from] public_id::PublicIdError),
 }
@@ -1,7 +1,9 @@
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 use super::primitives::{
-    DepositAccountHolderId, DepositAccountId, DepositId, DepositStatus, WithdrawalId,
+    DepositAccountHolderId, DepositAccountId, DepositId, DepositStatus, DormancyStatus, TransferId,
+    WithdrawalId,
 };
 use core_money::UsdCents;
 
@@ -31,4 +33,35 @@ pub enum CoreDepositEvent {
         deposit_account_id: DepositAccountId,
         amount: UsdCents,
     },
+    DepositAccountClosed {
+        id: DepositAccountId,
+        account_holder_id: DepositAccountHolderId,
+    },
+    DepositAccountDormancyStatusUpdated {
+        id: DepositAccountId,
+        status: DormancyStatus,
+    },
+    DepositAccountInterestCapitalized {
+        id: DepositAccountId,
+        amount: UsdCents,
+        capitalized_on: NaiveDate,
+    },
+    TransferInitiated {
+        id: TransferId,
+        from_account_id: DepositAccountId,
+        to_account_id: DepositAccountId,
+        amount: UsdCents,
+    },
+    TransferConfirmed {
+        id: TransferId,
+        from_account_id: DepositAccountId,
+        to_account_id: DepositAccountId,
+        amount: UsdCents,
+    },
+    TransferCancelled {
+        id: TransferId,
+        from_account_id: DepositAccountId,
+        to_account_id: DepositAccountId,
+        amount: UsdCents,
+    },
 }
@@ -1,10 +1,13 @@
-use audit::AuditSvc;
+use audit::{AuditInfo, AuditSvc};
 use authz::PermissionCheck;
+use document_storage::{DocumentStorage, GeneratedDocumentDownloadLink};
+use governance::{Governance, GovernanceEvent};
 use outbox::OutboxEventMarker;
 use tracing::instrument;
 
 use crate::{
     account::*,
+    beneficiary::*,
     deposit::*,
     deposit_account_balance::*,
     deposit_account_cursor::DepositAccountsByCreatedAtCursor,
@@ -13,20 +16,26 @@ use crate::{
     history::{DepositAccountHistoryCursor, DepositAccountHistoryEntry},
     ledger::*,
     primitives::*,
+    processes::beneficiary_approval::APPROVE_BENEFICIARY_PROCESS,
+    statement::*,
     withdrawal::*,
 };
 
 pub struct DepositsForSubject<'a, Perms, E>
 where
     Perms: PermissionCheck,
-    E: OutboxEventMarker<CoreDepositEvent>,
+    E: OutboxEventMarker<CoreDepositEvent> + OutboxEventMarker<GovernanceEvent>,
 {
     account_holder_id: DepositAccountHolderId,
     sub: &'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
     accounts: &'a DepositAccountRepo<E>,
     deposits: &'a DepositRepo<E>,
     withdrawals: &'a WithdrawalRepo<E>,
+    statements: &'a DepositAccountStatementRepo,
+    beneficiaries: &'a BeneficiaryRepo,
     ledger: &'a DepositLedger,
+    governance: &'a Governance<Perms, E>,
+    document_storage: &'a DocumentStorage,
     authz: &'a Perms,
 }
 
@@ -37,15 +46,20 @@ where
         From<CoreDepositAction> + From<GovernanceAction>,
     <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
         From<CoreDepositObject> + From<GovernanceObject>,
-    E: OutboxEventMarker<CoreDepositEvent>,
+    E: OutboxEventMarker<CoreDepositEvent> + OutboxEventMarker<GovernanceEvent>,
 {
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn new(
         subject: &'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         account_holder_id: DepositAccountHolderId,
         accounts: &'a DepositAccountRepo<E>,
         deposits: &'a DepositRepo<E>,
         withdrawals: &'a WithdrawalRepo<E>,
+        statements: &'a DepositAccountStatementRepo,
+        beneficiaries: &'a BeneficiaryRepo,
         ledger: &'a DepositLedger,
+        governance: &'a Governance<Perms, E>,
+        document_storage: &'a DocumentStorage,
         authz: &'a Perms,
     ) -> Self {
         Self {
@@ -54,7 +68,11 @@ where
             accounts,
             deposits,
             withdrawals,
+            statements,
+            beneficiaries,
             ledger,
+            governance,
+            document_storage,
             authz,
         }
     }
@@ -234,12 +252,192 @@ where
         Ok(withdrawal)
     }
 
+    pub async fn list_statements_for_account(
+        &self,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<DepositAccountStatement>, CoreDepositError> {
+        let account_id = account_id.into();
+
+        self.ensure_account_access(
+            account_id,
+            CoreDepositObject::all_deposit_account_statements(),
+            CoreDepositAction::DEPOSIT_ACCOUNT_STATEMENT_LIST,
+        )
+        .await?;
+
+        Ok(self
+            .statements
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    pub async fn generate_statement_download_link(
+        &self,
+        statement_id: impl Into<DepositAccountStatementId> + std::fmt::Debug,
+    ) -> Result<GeneratedDocumentDownloadLink, CoreDepositError> {
+        let statement_id = statement_id.into();
+        let statement = self.statements.find_by_id(statement_id).await?;
+
+        let audit_info = self
+            .ensure_account_access(
+                statement.deposit_account_id,
+                CoreDepositObject::deposit_account_statement(statement_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_STATEMENT_GENERATE_DOWNLOAD_LINK,
+            )
+            .await?;
+
+        Ok(self
+            .document_storage
+            .generate_download_link(audit_info, statement.document_id)
+            .await?)
+    }
+
+    pub async fn list_beneficiaries_for_account(
+        &self,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<Beneficiary>, CoreDepositError> {
+        let account_id = account_id.into();
+
+        self.ensure_account_access(
+            account_id,
+            CoreDepositObject::all_beneficiaries(),
+            CoreDepositAction::BENEFICIARY_LIST,
+        )
+        .await?;
+
+        Ok(self
+            .beneficiaries
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    /// Registers a bank account withdrawals can be paid out to. The
+    /// beneficiary becomes usable once approved and its cooling-off period ends.
+    #[instrument(name = "deposit.for_subject.register_beneficiary", skip(self), err)]
+    pub async fn register_beneficiary(
+        &self,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        bank_account: BeneficiaryBankAccount,
+    ) -> Result<Beneficiary, CoreDepositError> {
+        let account_id = account_id.into();
+
+        let audit_info = self
+            .ensure_account_access(
+                account_id,
+                CoreDepositObject::all_beneficiaries(),
+                CoreDepositAction::BENEFICIARY_REGISTER,
+            )
+            .await?;
+
+        self.create_beneficiary(account_id, bank_account, None, audit_info)
+            .await
+    }
+
+    /// Changes the bank account details of a beneficiary. The change goes
+    /// through approval as a new beneficiary that replaces the existing one.
+    #[instrument(name = "deposit.for_subject.update_beneficiary", skip(self), err)]
+    pub async fn update_beneficiary(
+        &self,
+        beneficiary_id: impl Into<BeneficiaryId> + std::fmt::Debug,
+        bank_account: BeneficiaryBankAccount,
+    ) -> Result<Beneficiary, CoreDepositError> {
+        let beneficiary_id = beneficiary_id.into();
+        let beneficiary = self.beneficiaries.find_by_id(beneficiary_id).await?;
+
+        let audit_info = self
+            .ensure_account_access(
+                beneficiary.deposit_account_id,
+                CoreDepositObject::beneficiary(beneficiary_id),
+                CoreDepositAction::BENEFICIARY_UPDATE,
+            )
+            .await?;
+        if beneficiary.is_removed() {
+            return Err(CoreDepositError::BeneficiaryNotUsable(
+                BeneficiaryStatus::Removed,
+            ));
+        }
+
+        self.create_beneficiary(
+            beneficiary.deposit_account_id,
+            bank_account,
+            Some(beneficiary_id),
+            audit_info,
+        )
+        .await
+    }
+
+    #[instrument(name = "deposit.for_subject.remove_beneficiary", skip(self), err)]
+    pub async fn remove_beneficiary(
+        &self,
+        beneficiary_id: impl Into<BeneficiaryId> + std::fmt::Debug,
+    ) -> Result<Beneficiary, CoreDepositError> {
+        let beneficiary_id = beneficiary_id.into();
+        let mut beneficiary = self.beneficiaries.find_by_id(beneficiary_id).await?;
+
+        let audit_info = self
+            .ensure_account_access(
+                beneficiary.deposit_account_id,
+                CoreDepositObject::beneficiary(beneficiary_id),
+                CoreDepositAction::BENEFICIARY_REMOVE,
+            )
+            .await?;
+
+        if beneficiary.remove(audit_info).did_execute() {
+            self.beneficiaries.update(&mut beneficiary).await?;
+        }
+        Ok(beneficiary)
+    }
+
+    async fn create_beneficiary(
+        &self,
+        account_id: DepositAccountId,
+        bank_account: BeneficiaryBankAccount,
+        replaces: Option<BeneficiaryId>,
+        audit_info: AuditInfo,
+    ) -> Result<Beneficiary, CoreDepositError> {
+        let beneficiary_id = BeneficiaryId::new();
+        let new_beneficiary = NewBeneficiary::builder()
+            .id(beneficiary_id)
+            .deposit_account_id(account_id)
+            .bank_account(bank_account)
+            .replaces(replaces)
+            .approval_process_id(beneficiary_id)
+            .audit_info(audit_info)
+            .build()?;
+
+        let mut op = self.beneficiaries.begin_op().await?;
+        self.governance
+            .start_process(
+                &mut op,
+                beneficiary_id,
+                beneficiary_id.to_string(),
+                APPROVE_BENEFICIARY_PROCESS,
+            )
+            .await?;
+        let beneficiary = self
+            .beneficiaries
+            .create_in_op(&mut op, new_beneficiary)
+            .await?;
+        op.commit().await?;
+        Ok(beneficiary)
+    }
+
     async fn ensure_account_access(
         &self,
         account_id: DepositAccountId,
         object: CoreDepositObject,
         action: CoreDepositAction,
-    ) -> Result<(), CoreDepositError> {
+    ) -> Result<AuditInfo, CoreDepositError> {
         let account = self.accounts.find_by_id(account_id).await?;
 
         if account.account_holder_id != self.account_holder_id {
@@ -249,11 +447,12 @@ where
                 .await?;
             return Err(CoreDepositError::DepositAccountNotFound);
         }
-        self.authz
+        let audit_info = self
+            .authz
             .audit()
             .record_entry(self.sub, object, action, true)
             .await?;
 
-        Ok(())
+        Ok(audit_info)
     }
 }
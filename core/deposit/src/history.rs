@@ -1,17 +1,40 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::primitives::{CalaEntryId, CalaTransactionId as CalaTxId};
+use crate::primitives::{CalaEntryId, CalaTransactionId as CalaTxId, UsdCents};
 
 pub enum DepositAccountHistoryEntry {
     Deposit(DepositEntry),
     Withdrawal(WithdrawalEntry),
     CancelledWithdrawal(WithdrawalEntry),
+    WithdrawalFee(WithdrawalFeeEntry),
+    WithdrawalFeeRefund(WithdrawalFeeEntry),
     Disbursal(DisbursalEntry),
     Payment(PaymentEntry),
+    InterestCapitalization(InterestCapitalizationEntry),
+    TimeDepositOpening(TimeDepositEntry),
+    TimeDepositSettlement(TimeDepositEntry),
+    TransferOut(TransferEntry),
+    TransferIn(TransferEntry),
+    CancelledTransfer(TransferEntry),
+    ClosingBalanceSweep(BalanceSweepEntry),
     Unknown(UnknownEntry),
     Ignored,
 }
+impl DepositAccountHistoryEntry {
+    /// When the account holder initiated this entry. `None` for entries
+    /// booked by the bank or another customer.
+    pub(crate) fn customer_activity_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            DepositAccountHistoryEntry::Deposit(entry) => Some(entry.recorded_at),
+            DepositAccountHistoryEntry::Withdrawal(entry) => Some(entry.recorded_at),
+            DepositAccountHistoryEntry::TimeDepositOpening(entry) => Some(entry.recorded_at),
+            DepositAccountHistoryEntry::TransferOut(entry) => Some(entry.recorded_at),
+            _ => None,
+        }
+    }
+}
+
 pub struct DepositEntry {
     pub tx_id: CalaTxId,
     pub entry_id: CalaEntryId,
@@ -24,6 +47,13 @@ pub struct WithdrawalEntry {
     pub recorded_at: DateTime<Utc>,
 }
 
+pub struct WithdrawalFeeEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub amount: UsdCents,
+    pub recorded_at: DateTime<Utc>,
+}
+
 pub struct DisbursalEntry {
     pub tx_id: CalaTxId,
     pub entry_id: CalaEntryId,
@@ -36,6 +66,34 @@ pub struct PaymentEntry {
     pub recorded_at: DateTime<Utc>,
 }
 
+pub struct InterestCapitalizationEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub amount: UsdCents,
+    pub recorded_at: DateTime<Utc>,
+}
+
+pub struct TimeDepositEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub amount: UsdCents,
+    pub recorded_at: DateTime<Utc>,
+}
+
+pub struct TransferEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub amount: UsdCents,
+    pub recorded_at: DateTime<Utc>,
+}
+
+pub struct BalanceSweepEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub amount: UsdCents,
+    pub recorded_at: DateTime<Utc>,
+}
+
 pub struct UnknownEntry {
     pub tx_id: CalaTxId,
     pub entry_id: CalaEntryId,
@@ -45,12 +103,24 @@ pub struct UnknownEntry {
 const RECORD_DEPOSIT: &str = "RECORD_DEPOSIT_CR";
 const INITIATE_WITHDRAW: &str = "INITIATE_WITHDRAW_SETTLED_DR";
 const CANCEL_WITHDRAW: &str = "CANCEL_WITHDRAW_SETTLED_CR";
+const WITHDRAWAL_FEE: &str = "WITHDRAWAL_FEE_DR";
+const REFUND_WITHDRAWAL_FEE: &str = "REFUND_WITHDRAWAL_FEE_CR";
 const CONFIRM_DISBURSAL: &str = "CONFIRM_DISBURSAL_SETTLED_CR";
 const RECORD_PAYMENT_ALLOCATION: &str = "RECORD_PAYMENT_ALLOCATION_DR";
+const CAPITALIZE_DEPOSIT_INTEREST: &str = "CAPITALIZE_DEPOSIT_INTEREST_CR";
+const OPEN_TIME_DEPOSIT: &str = "OPEN_TIME_DEPOSIT_DR";
+const SETTLE_TIME_DEPOSIT: &str = "SETTLE_TIME_DEPOSIT_CR";
+const INITIATE_TRANSFER: &str = "INITIATE_TRANSFER_SETTLED_DR";
+const CONFIRM_TRANSFER: &str = "CONFIRM_TRANSFER_SETTLED_CR";
+const CANCEL_TRANSFER: &str = "CANCEL_TRANSFER_SETTLED_CR";
+const SWEEP_CLOSED_ACCOUNT: &str = "SWEEP_CLOSED_ACCOUNT_DR";
 
 const IGNORE_INITIATE_WITHDRAW_PENDING: &str = "INITIATE_WITHDRAW_PENDING_CR";
 const IGNORE_CONFIRM_WITHDRAWAL_PENDING: &str = "CONFIRM_WITHDRAW_PENDING_DR";
 const IGNORE_CANCEL_WITHDRAW_PENDING: &str = "CANCEL_WITHDRAW_PENDING_DR";
+const IGNORE_INITIATE_TRANSFER_PENDING: &str = "INITIATE_TRANSFER_PENDING_CR";
+const IGNORE_CONFIRM_TRANSFER_PENDING: &str = "CONFIRM_TRANSFER_PENDING_DR";
+const IGNORE_CANCEL_TRANSFER_PENDING: &str = "CANCEL_TRANSFER_PENDING_DR";
 
 impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
     fn from(entry: cala_ledger::entry::Entry) -> Self {
@@ -70,6 +140,22 @@ impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
                 entry_id: entry.id,
                 recorded_at: entry.created_at(),
             }),
+            WITHDRAWAL_FEE => DepositAccountHistoryEntry::WithdrawalFee(WithdrawalFeeEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                amount: UsdCents::try_from_usd(entry.values().units)
+                    .expect("fee amount should be positive"),
+                recorded_at: entry.created_at(),
+            }),
+            REFUND_WITHDRAWAL_FEE => {
+                DepositAccountHistoryEntry::WithdrawalFeeRefund(WithdrawalFeeEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    amount: UsdCents::try_from_usd(entry.values().units)
+                        .expect("fee amount should be positive"),
+                    recorded_at: entry.created_at(),
+                })
+            }
             CONFIRM_DISBURSAL => DepositAccountHistoryEntry::Disbursal(DisbursalEntry {
                 tx_id: entry.values().transaction_id,
                 entry_id: entry.id,
@@ -80,10 +166,68 @@ impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
                 entry_id: entry.id,
                 recorded_at: entry.created_at(),
             }),
+            CAPITALIZE_DEPOSIT_INTEREST => {
+                DepositAccountHistoryEntry::InterestCapitalization(InterestCapitalizationEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    amount: UsdCents::try_from_usd(entry.values().units)
+                        .expect("interest amount should be positive"),
+                    recorded_at: entry.created_at(),
+                })
+            }
+            OPEN_TIME_DEPOSIT => DepositAccountHistoryEntry::TimeDepositOpening(TimeDepositEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                amount: UsdCents::try_from_usd(entry.values().units)
+                    .expect("time deposit amount should be positive"),
+                recorded_at: entry.created_at(),
+            }),
+            SETTLE_TIME_DEPOSIT => {
+                DepositAccountHistoryEntry::TimeDepositSettlement(TimeDepositEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    amount: UsdCents::try_from_usd(entry.values().units)
+                        .expect("time deposit amount should be positive"),
+                    recorded_at: entry.created_at(),
+                })
+            }
+            INITIATE_TRANSFER => DepositAccountHistoryEntry::TransferOut(TransferEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                amount: UsdCents::try_from_usd(entry.values().units)
+                    .expect("transfer amount should be positive"),
+                recorded_at: entry.created_at(),
+            }),
+            CONFIRM_TRANSFER => DepositAccountHistoryEntry::TransferIn(TransferEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                amount: UsdCents::try_from_usd(entry.values().units)
+                    .expect("transfer amount should be positive"),
+                recorded_at: entry.created_at(),
+            }),
+            CANCEL_TRANSFER => DepositAccountHistoryEntry::CancelledTransfer(TransferEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                amount: UsdCents::try_from_usd(entry.values().units)
+                    .expect("transfer amount should be positive"),
+                recorded_at: entry.created_at(),
+            }),
+            SWEEP_CLOSED_ACCOUNT => {
+                DepositAccountHistoryEntry::ClosingBalanceSweep(BalanceSweepEntry {
+                    tx_id: entry.values().transaction_id,
+                    entry_id: entry.id,
+                    amount: UsdCents::try_from_usd(entry.values().units)
+                        .expect("sweep amount should be positive"),
+                    recorded_at: entry.created_at(),
+                })
+            }
 
             IGNORE_CONFIRM_WITHDRAWAL_PENDING => DepositAccountHistoryEntry::Ignored,
             IGNORE_INITIATE_WITHDRAW_PENDING => DepositAccountHistoryEntry::Ignored,
             IGNORE_CANCEL_WITHDRAW_PENDING => DepositAccountHistoryEntry::Ignored,
+            IGNORE_INITIATE_TRANSFER_PENDING => DepositAccountHistoryEntry::Ignored,
+            IGNORE_CONFIRM_TRANSFER_PENDING => DepositAccountHistoryEntry::Ignored,
+            IGNORE_CANCEL_TRANSFER_PENDING => DepositAccountHistoryEntry::Ignored,
 
             _ => DepositAccountHistoryEntry::Unknown(UnknownEntry {
                 tx_id: entry.values().transaction_id,
@@ -115,6 +259,14 @@ impl From<&DepositAccountHistoryEntry> for DepositAccountHistoryCursor {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
             },
+            DepositAccountHistoryEntry::WithdrawalFee(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::WithdrawalFeeRefund(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
             DepositAccountHistoryEntry::Disbursal(entry) => Self {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
@@ -123,6 +275,34 @@ impl From<&DepositAccountHistoryEntry> for DepositAccountHistoryCursor {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
             },
+            DepositAccountHistoryEntry::InterestCapitalization(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::TimeDepositOpening(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::TimeDepositSettlement(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::TransferOut(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::TransferIn(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::CancelledTransfer(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::ClosingBalanceSweep(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
             DepositAccountHistoryEntry::Unknown(entry) => Self {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
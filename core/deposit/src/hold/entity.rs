@@ -0,0 +1,252 @@
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{CalaTransactionId, DepositAccountId, HoldId, UsdCents};
+use audit::AuditInfo;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum HoldStatus {
+    Active,
+    Released,
+    Expired,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "HoldId")]
+pub enum HoldEvent {
+    Initialized {
+        id: HoldId,
+        ledger_tx_id: CalaTransactionId,
+        deposit_account_id: DepositAccountId,
+        amount: UsdCents,
+        reason: String,
+        reference: String,
+        expires_at: Option<DateTime<Utc>>,
+        audit_info: AuditInfo,
+    },
+    Released {
+        ledger_tx_id: CalaTransactionId,
+        status: HoldStatus,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(Debug)]
+pub struct HoldReleaseData {
+    pub ledger_tx_id: CalaTransactionId,
+    pub deposit_account_id: DepositAccountId,
+    pub amount: UsdCents,
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct Hold {
+    pub id: HoldId,
+    pub deposit_account_id: DepositAccountId,
+    pub amount: UsdCents,
+    pub reason: String,
+    pub reference: String,
+    #[builder(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+
+    events: EntityEvents<HoldEvent>,
+}
+
+impl Hold {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for hold")
+    }
+
+    pub fn status(&self) -> HoldStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|e| match e {
+                HoldEvent::Released { status, .. } => Some(*status),
+                _ => None,
+            })
+            .unwrap_or(HoldStatus::Active)
+    }
+
+    pub fn release(&mut self, audit_info: AuditInfo) -> Idempotent<HoldReleaseData> {
+        self.end(HoldStatus::Released, audit_info)
+    }
+
+    /// Releases the hold once its expiry has passed. Holds without an expiry
+    /// stay in place until released explicitly.
+    pub fn expire(
+        &mut self,
+        now: DateTime<Utc>,
+        audit_info: AuditInfo,
+    ) -> Idempotent<HoldReleaseData> {
+        if self.expires_at.is_none_or(|expires_at| expires_at > now) {
+            return Idempotent::Ignored;
+        }
+        self.end(HoldStatus::Expired, audit_info)
+    }
+
+    fn end(&mut self, status: HoldStatus, audit_info: AuditInfo) -> Idempotent<HoldReleaseData> {
+        idempotency_guard!(self.events.iter_all(), HoldEvent::Released { .. });
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(HoldEvent::Released {
+            ledger_tx_id,
+            status,
+            audit_info,
+        });
+        Idempotent::Executed(HoldReleaseData {
+            ledger_tx_id,
+            deposit_account_id: self.deposit_account_id,
+            amount: self.amount,
+        })
+    }
+}
+
+impl TryFromEvents<HoldEvent> for Hold {
+    fn try_from_events(events: EntityEvents<HoldEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = HoldBuilder::default();
+        for event in events.iter_all() {
+            if let HoldEvent::Initialized {
+                id,
+                deposit_account_id,
+                amount,
+                reason,
+                reference,
+                expires_at,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .deposit_account_id(*deposit_account_id)
+                    .amount(*amount)
+                    .reason(reason.clone())
+                    .reference(reference.clone())
+                    .expires_at(*expires_at)
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewHold {
+    #[builder(setter(into))]
+    pub(super) id: HoldId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) amount: UsdCents,
+    #[builder(setter(into))]
+    pub(super) reason: String,
+    #[builder(default)]
+    pub(super) expires_at: Option<DateTime<Utc>>,
+    reference: Option<String>,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewHold {
+    pub fn builder() -> NewHoldBuilder {
+        NewHoldBuilder::default()
+    }
+
+    pub(super) fn reference(&self) -> String {
+        match self.reference.as_deref() {
+            None => self.id.to_string(),
+            Some("") => self.id.to_string(),
+            Some(reference) => reference.to_string(),
+        }
+    }
+}
+
+impl NewHoldBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if self.amount.is_some_and(|amount| amount.is_zero()) {
+            return Err("Hold amount cannot be zero".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl IntoEvents<HoldEvent> for NewHold {
+    fn into_events(self) -> EntityEvents<HoldEvent> {
+        EntityEvents::init(
+            self.id,
+            [HoldEvent::Initialized {
+                reference: self.reference(),
+                id: self.id,
+                ledger_tx_id: self.id.into(),
+                deposit_account_id: self.deposit_account_id,
+                amount: self.amount,
+                reason: self.reason,
+                expires_at: self.expires_at,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn hold(expires_at: Option<DateTime<Utc>>) -> Hold {
+        let new_hold = NewHold::builder()
+            .id(HoldId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .amount(UsdCents::from(100))
+            .reason("court order")
+            .expires_at(expires_at)
+            .reference(None)
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        Hold::try_from_events(new_hold.into_events()).unwrap()
+    }
+
+    #[test]
+    fn release_is_idempotent() {
+        let mut hold = hold(None);
+        assert_eq!(hold.status(), HoldStatus::Active);
+        assert!(hold.release(dummy_audit_info()).did_execute());
+        assert_eq!(hold.status(), HoldStatus::Released);
+        assert!(hold.release(dummy_audit_info()).was_ignored());
+    }
+
+    #[test]
+    fn expires_only_after_expiry() {
+        let now = Utc::now();
+        let mut expiring = hold(Some(now));
+        assert!(
+            expiring
+                .expire(now - chrono::Duration::seconds(1), dummy_audit_info())
+                .was_ignored()
+        );
+        assert!(expiring.expire(now, dummy_audit_info()).did_execute());
+        assert_eq!(expiring.status(), HoldStatus::Expired);
+
+        let mut open_ended = hold(None);
+        assert!(open_ended.expire(now, dummy_audit_info()).was_ignored());
+    }
+}
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum HoldError {
+    #[error("HoldError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("HoldError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("HoldError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+}
+
+es_entity::from_es_entity_error!(HoldError);
@@ -0,0 +1,27 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::{DepositAccountId, HoldId};
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "Hold",
+    err = "HoldError",
+    columns(
+        deposit_account_id(ty = "DepositAccountId", list_for, update(persist = false)),
+        reference(ty = "String", create(accessor = "reference()"))
+    ),
+    tbl_prefix = "core"
+)]
+pub struct HoldRepo {
+    pool: PgPool,
+}
+
+impl HoldRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
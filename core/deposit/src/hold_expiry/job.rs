@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use chrono::Duration;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+
+use crate::{CoreDepositAction, CoreDepositObject};
+
+use super::ExpireHolds;
+
+#[derive(serde::Serialize)]
+pub struct HoldExpiryJobConfig<Perms> {
+    _phantom: std::marker::PhantomData<Perms>,
+}
+impl<Perms> HoldExpiryJobConfig<Perms> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Perms> JobConfig for HoldExpiryJobConfig<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    type Initializer = HoldExpiryInit<Perms>;
+}
+
+pub struct HoldExpiryInit<Perms>
+where
+    Perms: PermissionCheck,
+{
+    process: ExpireHolds<Perms>,
+}
+
+impl<Perms> HoldExpiryInit<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    pub fn new(process: &ExpireHolds<Perms>) -> Self {
+        Self {
+            process: process.clone(),
+        }
+    }
+}
+
+const HOLD_EXPIRY_JOB: JobType = JobType::new("deposit-hold-expiry");
+impl<Perms> JobInitializer for HoldExpiryInit<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        HOLD_EXPIRY_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(HoldExpiryJobRunner {
+            process: self.process.clone(),
+        }))
+    }
+}
+
+pub struct HoldExpiryJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+{
+    process: ExpireHolds<Perms>,
+}
+
+#[async_trait]
+impl<Perms> JobRunner for HoldExpiryJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let now = crate::time::now();
+        self.process.execute(now).await?;
+
+        let next_run = now + Duration::hours(1);
+        Ok(JobCompletion::RescheduleAt(next_run))
+    }
+}
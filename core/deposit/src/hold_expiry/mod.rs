@@ -0,0 +1,110 @@
+mod job;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+
+use crate::{
+    CoreDepositAction, CoreDepositObject,
+    error::CoreDepositError,
+    hold::{Hold, HoldRepo, HoldStatus},
+    ledger::DepositLedger,
+};
+
+pub use job::*;
+
+pub struct ExpireHolds<Perms>
+where
+    Perms: PermissionCheck,
+{
+    holds: HoldRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms> Clone for ExpireHolds<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fn clone(&self) -> Self {
+        Self {
+            holds: self.holds.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }
+    }
+}
+
+impl<Perms> ExpireHolds<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    pub fn new(holds: &HoldRepo, ledger: &DepositLedger, audit: &Perms::Audit) -> Self {
+        Self {
+            holds: holds.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+        }
+    }
+
+    /// Releases every active hold whose expiry has passed.
+    pub async fn execute(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), CoreDepositError> {
+        let mut has_next_page = true;
+        let mut after = None;
+        while has_next_page {
+            let mut holds = self
+                .holds
+                .list_by_id(
+                    es_entity::PaginatedQueryArgs { first: 50, after },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            (after, has_next_page) = (holds.end_cursor, holds.has_next_page);
+
+            for hold in holds.entities.iter_mut() {
+                if hold.status() != HoldStatus::Active
+                    || hold.expires_at.is_none_or(|expires_at| expires_at > now)
+                {
+                    continue;
+                }
+                self.expire(hold, now).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn expire(
+        &self,
+        hold: &mut Hold,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), CoreDepositError> {
+        let mut op = self.holds.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                op.tx(),
+                CoreDepositObject::hold(hold.id),
+                CoreDepositAction::HOLD_EXPIRE,
+            )
+            .await?;
+
+        let release = match hold.expire(now, audit_info) {
+            es_entity::Idempotent::Executed(release) => release,
+            es_entity::Idempotent::Ignored => return Ok(()),
+        };
+        self.holds.update_in_op(&mut op, hold).await?;
+        self.ledger
+            .release_hold(
+                op,
+                release.ledger_tx_id,
+                release.deposit_account_id,
+                release.amount,
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use chrono::Days;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{CoreDepositAction, CoreDepositEvent, CoreDepositObject};
+
+use super::AccrueDepositInterest;
+
+#[derive(serde::Serialize)]
+pub struct DepositInterestAccrualJobConfig<Perms, E> {
+    _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> DepositInterestAccrualJobConfig<Perms, E> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Perms, E> JobConfig for DepositInterestAccrualJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    type Initializer = DepositInterestAccrualInit<Perms, E>;
+}
+
+pub struct DepositInterestAccrualInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    process: AccrueDepositInterest<Perms, E>,
+}
+
+impl<Perms, E> DepositInterestAccrualInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(process: &AccrueDepositInterest<Perms, E>) -> Self {
+        Self {
+            process: process.clone(),
+        }
+    }
+}
+
+const DEPOSIT_INTEREST_ACCRUAL_JOB: JobType = JobType::new("deposit-interest-accrual");
+impl<Perms, E> JobInitializer for DepositInterestAccrualInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        DEPOSIT_INTEREST_ACCRUAL_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(DepositInterestAccrualJobRunner {
+            process: self.process.clone(),
+        }))
+    }
+}
+
+pub struct DepositInterestAccrualJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    process: AccrueDepositInterest<Perms, E>,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for DepositInterestAccrualJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        // Only completed days are accrued so that the end-of-day balance is final.
+        let today = crate::time::now().date_naive();
+        let yesterday = today - Days::new(1);
+        self.process.execute(yesterday).await?;
+
+        let next_run = (today + Days::new(1))
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight is a valid time")
+            .and_utc();
+        Ok(JobCompletion::RescheduleAt(next_run))
+    }
+}
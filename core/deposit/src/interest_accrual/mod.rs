@@ -0,0 +1,157 @@
+mod job;
+
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CoreDepositAction, CoreDepositObject,
+    account::{DepositAccount, DepositAccountRepo},
+    error::CoreDepositError,
+    event::CoreDepositEvent,
+    ledger::DepositLedger,
+    primitives::DepositProductId,
+    product::{DepositInterestTerms, DepositProductRepo},
+};
+
+pub use job::*;
+
+pub struct AccrueDepositInterest<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    accounts: DepositAccountRepo<E>,
+    products: DepositProductRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> Clone for AccrueDepositInterest<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            accounts: self.accounts.clone(),
+            products: self.products.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }
+    }
+}
+
+impl<Perms, E> AccrueDepositInterest<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        accounts: &DepositAccountRepo<E>,
+        products: &DepositProductRepo,
+        ledger: &DepositLedger,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            accounts: accounts.clone(),
+            products: products.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+        }
+    }
+
+    /// Accrues interest on every account with an interest bearing product for
+    /// all days up to and including `accrue_through`.
+    pub async fn execute(&self, accrue_through: NaiveDate) -> Result<(), CoreDepositError> {
+        let mut interest_terms: HashMap<DepositProductId, DepositInterestTerms> = HashMap::new();
+        let mut has_next_page = true;
+        let mut after = None;
+        while has_next_page {
+            let mut accounts = self
+                .accounts
+                .list_by_id(
+                    es_entity::PaginatedQueryArgs { first: 50, after },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            (after, has_next_page) = (accounts.end_cursor, accounts.has_next_page);
+
+            for account in accounts.entities.iter_mut() {
+                let Some(product_id) = account.deposit_product_id else {
+                    continue;
+                };
+                if account.status.is_inactive() || account.is_closed() {
+                    continue;
+                }
+                let terms = match interest_terms.get(&product_id) {
+                    Some(terms) => *terms,
+                    None => {
+                        let terms = self.products.find_by_id(product_id).await?.interest_terms;
+                        interest_terms.insert(product_id, terms);
+                        terms
+                    }
+                };
+                self.accrue_for_account(account, terms, accrue_through)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn accrue_for_account(
+        &self,
+        account: &mut DepositAccount,
+        terms: DepositInterestTerms,
+        accrue_through: NaiveDate,
+    ) -> Result<(), CoreDepositError> {
+        while let Some(mut date) = account
+            .next_interest_accrual_date()
+            .filter(|date| *date <= accrue_through)
+        {
+            let mut op = self.accounts.begin_op().await?;
+            let audit_info = self
+                .audit
+                .record_system_entry_in_tx(
+                    op.tx(),
+                    CoreDepositObject::deposit_account(account.id),
+                    CoreDepositAction::DEPOSIT_ACCOUNT_ACCRUE_INTEREST,
+                )
+                .await?;
+
+            // Accrue day by day until the end of a capitalization period so
+            // that each capitalization is posted in its own transaction.
+            let mut capitalization = None;
+            while date <= accrue_through && capitalization.is_none() {
+                let settled_balance = self
+                    .ledger
+                    .end_of_day_settled_balance(account.id, date)
+                    .await?;
+                let _ = account.accrue_interest(date, settled_balance, terms, audit_info.clone());
+                if terms.capitalization_frequency.is_period_end(date) {
+                    capitalization = match account.capitalize_interest(date, audit_info.clone()) {
+                        es_entity::Idempotent::Executed(data) => Some(data),
+                        es_entity::Idempotent::Ignored => None,
+                    };
+                }
+                date = date.succ_opt().expect("date out of range");
+            }
+
+            self.accounts.update_in_op(&mut op, account).await?;
+            match capitalization {
+                Some(capitalization) => {
+                    self.ledger
+                        .capitalize_interest(op, capitalization, account.id)
+                        .await?
+                }
+                None => op.commit().await?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -34,4 +34,20 @@ pub enum DepositLedgerError {
     NonAccountMemberFoundInAccountSet(String),
     #[error("DepositLedgerError - JournalIdMismatch: Account sets have wrong JournalId")]
     JournalIdMismatch,
+    #[error("DepositLedgerError - MissingInternalAccountSet: {0}")]
+    MissingInternalAccountSet(cala_ledger::AccountId),
+    #[error("DepositLedgerError - MissingLedgerAccount: {0}")]
+    MissingLedgerAccount(cala_ledger::AccountId),
+}
+
+impl DepositLedgerError {
+    /// The period whose withdrawal limit rejected the posting, if any.
+    pub fn withdrawal_limit_period(&self) -> Option<crate::WithdrawalLimitPeriod> {
+        match self {
+            Self::CalaLedger(cala_ledger::error::LedgerError::VelocityError(
+                cala_ledger::velocity::error::VelocityError::Enforcement(e),
+            )) => super::velocity::WithdrawalAmountLimit::period(e.limit_id),
+            _ => None,
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -16,9 +17,15 @@ use cala_ledger::{
 };
 
 use crate::{
-    DepositAccountBalance, DepositReversalData, LedgerOmnibusAccountIds, WithdrawalReversalData,
+    AccountFreezeScope, DepositAccountBalance, DepositReversalData, InterestCapitalizationData,
+    LedgerOmnibusAccountIds, TimeDepositSettlementData, TransferSettlementData,
+    WithdrawalFeeRefund, WithdrawalReversalData,
     chart_of_accounts_integration::ChartOfAccountsIntegrationConfig,
-    primitives::{CalaAccountId, CalaAccountSetId, DepositAccountType, UsdCents},
+    primitives::{
+        CalaAccountId, CalaAccountSetId, CalaTransactionId, CalaVelocityControlId,
+        DepositAccountType, TimeDepositId, UsdCents,
+    },
+    withdrawal_limit::{WithdrawalLimitPeriod, WithdrawalLimits},
 };
 
 use error::*;
@@ -45,8 +52,18 @@ pub const DEPOSIT_OMNIBUS_ACCOUNT_SET_NAME: &str = "Deposit Omnibus Account Set"
 pub const DEPOSIT_OMNIBUS_ACCOUNT_SET_REF: &str = "deposit-omnibus-account-set";
 pub const DEPOSIT_OMNIBUS_ACCOUNT_REF: &str = "deposit-omnibus-account";
 
+pub const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_NAME: &str = "Deposit Interest Expense Account Set";
+pub const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_REF: &str = "deposit-interest-expense-account-set";
+pub const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_REF: &str = "deposit-interest-expense-account";
+
+pub const DEPOSIT_FEE_INCOME_ACCOUNT_SET_NAME: &str = "Deposit Fee Income Account Set";
+pub const DEPOSIT_FEE_INCOME_ACCOUNT_SET_REF: &str = "deposit-fee-income-account-set";
+pub const DEPOSIT_FEE_INCOME_ACCOUNT_REF: &str = "deposit-fee-income-account";
+
 pub const DEPOSITS_VELOCITY_CONTROL_ID: uuid::Uuid =
     uuid::uuid!("00000000-0000-0000-0000-000000000001");
+pub const TIME_DEPOSITS_VELOCITY_CONTROL_ID: uuid::Uuid =
+    uuid::uuid!("00000000-0000-0000-0000-000000000003");
 
 #[derive(Clone, Copy)]
 pub struct InternalAccountSetDetails {
@@ -79,6 +96,19 @@ impl DepositAccountSets {
     fn account_set_id_for_config(&self) -> CalaAccountSetId {
         self.individual.id
     }
+
+    fn find(&self, id: CalaAccountSetId) -> Option<InternalAccountSetDetails> {
+        [
+            self.individual,
+            self.government_entity,
+            self.private_company,
+            self.bank,
+            self.financial_institution,
+            self.non_domiciled_individual,
+        ]
+        .into_iter()
+        .find(|details| details.id == id)
+    }
 }
 
 #[derive(Clone)]
@@ -87,8 +117,11 @@ pub struct DepositLedger {
     journal_id: JournalId,
     deposits_account_set: DepositAccountSets,
     deposit_omnibus_account_ids: LedgerOmnibusAccountIds,
+    interest_expense_account_ids: LedgerOmnibusAccountIds,
+    fee_income_account_ids: LedgerOmnibusAccountIds,
     usd: Currency,
     deposit_control_id: VelocityControlId,
+    time_deposit_control_id: VelocityControlId,
 }
 
 impl DepositLedger {
@@ -98,10 +131,21 @@ impl DepositLedger {
     ) -> Result<Self, DepositLedgerError> {
         templates::RecordDeposit::init(cala).await?;
         templates::InitiateWithdraw::init(cala).await?;
+        templates::InitiateWithdrawWithFee::init(cala).await?;
+        templates::RefundWithdrawalFee::init(cala).await?;
         templates::CancelWithdraw::init(cala).await?;
         templates::ConfirmWithdraw::init(cala).await?;
         templates::RevertWithdraw::init(cala).await?;
         templates::RevertDeposit::init(cala).await?;
+        templates::CapitalizeInterest::init(cala).await?;
+        templates::OpenTimeDeposit::init(cala).await?;
+        templates::SettleTimeDeposit::init(cala).await?;
+        templates::InitiateTransfer::init(cala).await?;
+        templates::ConfirmTransfer::init(cala).await?;
+        templates::CancelTransfer::init(cala).await?;
+        templates::PlaceHold::init(cala).await?;
+        templates::ReleaseHold::init(cala).await?;
+        templates::SweepClosedAccount::init(cala).await?;
 
         let deposits_normal_balance_type = DebitOrCredit::Credit;
 
@@ -169,19 +213,73 @@ impl DepositLedger {
         )
         .await?;
 
+        let interest_expense_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{DEPOSIT_INTEREST_EXPENSE_ACCOUNT_REF}"),
+            DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_NAME.to_string(),
+            DebitOrCredit::Debit,
+        )
+        .await?;
+
+        let fee_income_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{DEPOSIT_FEE_INCOME_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{DEPOSIT_FEE_INCOME_ACCOUNT_REF}"),
+            DEPOSIT_FEE_INCOME_ACCOUNT_SET_NAME.to_string(),
+            DebitOrCredit::Credit,
+        )
+        .await?;
+
         let overdraft_prevention_id = velocity::OverdraftPrevention::init(cala).await?;
+        let available_balance_enforcement_id =
+            velocity::AvailableBalanceEnforcement::init(cala).await?;
+        let debit_freeze_id = velocity::DebitFreeze::init(cala).await?;
+        let credit_freeze_id = velocity::CreditFreeze::init(cala).await?;
 
         let deposit_control_id = Self::create_deposit_control(cala).await?;
 
-        match cala
-            .velocities()
-            .add_limit_to_control(deposit_control_id, overdraft_prevention_id)
-            .await
-        {
-            Ok(_)
-            | Err(cala_ledger::velocity::error::VelocityError::LimitAlreadyAddedToControl) => {}
-            Err(e) => return Err(e.into()),
+        for limit_id in [
+            overdraft_prevention_id,
+            available_balance_enforcement_id,
+            debit_freeze_id,
+            credit_freeze_id,
+        ] {
+            match cala
+                .velocities()
+                .add_limit_to_control(deposit_control_id, limit_id)
+                .await
+            {
+                Ok(_)
+                | Err(cala_ledger::velocity::error::VelocityError::LimitAlreadyAddedToControl) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        // Attached per account through the versioned controls created in
+        // `apply_withdrawal_limits_in_op`.
+        for period in WithdrawalLimitPeriod::ALL {
+            velocity::WithdrawalAmountLimit::init(cala, period).await?;
+        }
+
+        let time_deposit_lock_id = velocity::TimeDepositLock::init(cala).await?;
+
+        let time_deposit_control_id = Self::create_time_deposit_control(cala).await?;
+
+        for limit_id in [overdraft_prevention_id, time_deposit_lock_id] {
+            match cala
+                .velocities()
+                .add_limit_to_control(time_deposit_control_id, limit_id)
+                .await
+            {
+                Ok(_)
+                | Err(cala_ledger::velocity::error::VelocityError::LimitAlreadyAddedToControl) => {}
+                Err(e) => return Err(e.into()),
+            }
         }
+
         Ok(Self {
             cala: cala.clone(),
             journal_id,
@@ -212,7 +310,10 @@ impl DepositLedger {
                 },
             },
             deposit_omnibus_account_ids,
+            interest_expense_account_ids,
+            fee_income_account_ids,
             deposit_control_id,
+            time_deposit_control_id,
             usd: Currency::USD,
         })
     }
@@ -365,6 +466,47 @@ impl DepositLedger {
         })
     }
 
+    /// Settled layer entries recorded on the account between `from` and
+    /// `until` (exclusive), oldest first.
+    pub async fn settled_entries_between(
+        &self,
+        id: impl Into<AccountId>,
+        from: chrono::DateTime<chrono::Utc>,
+        until: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<cala_ledger::entry::Entry>, DepositLedgerError> {
+        let id = id.into();
+        let mut entries = Vec::new();
+        let mut after = None;
+        loop {
+            let ret = self
+                .cala
+                .entries()
+                .list_for_account_id(
+                    id,
+                    es_entity::PaginatedQueryArgs { first: 100, after },
+                    es_entity::ListDirection::Descending,
+                )
+                .await?;
+            let mut reached_start = false;
+            for entry in ret.entities {
+                let created_at = entry.created_at();
+                if created_at < from {
+                    reached_start = true;
+                    break;
+                }
+                if created_at < until && entry.values().layer == cala_ledger::Layer::Settled {
+                    entries.push(entry);
+                }
+            }
+            if reached_start || !ret.has_next_page {
+                break;
+            }
+            after = ret.end_cursor;
+        }
+        entries.reverse();
+        Ok(entries)
+    }
+
     pub async fn record_deposit(
         &self,
         op: es_entity::DbOp<'_>,
@@ -390,27 +532,61 @@ impl DepositLedger {
         Ok(())
     }
 
+    /// Applies `withdrawal_limits` to the account first when they changed
+    /// since the last withdrawal.
     pub async fn initiate_withdrawal(
         &self,
         op: es_entity::DbOp<'_>,
         tx_id: impl Into<TransactionId>,
         amount: UsdCents,
+        fee: UsdCents,
         credit_account_id: impl Into<AccountId>,
+        withdrawal_limits: Option<(CalaVelocityControlId, WithdrawalLimits)>,
     ) -> Result<(), DepositLedgerError> {
         let tx_id = tx_id.into();
+        let credit_account_id = credit_account_id.into();
         let mut op = self.cala.ledger_operation_from_db_op(op);
 
-        let params = templates::InitiateWithdrawParams {
-            journal_id: self.journal_id,
-            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
-            credit_account_id: credit_account_id.into(),
-            amount: amount.to_usd(),
-            currency: self.usd,
-        };
-
-        self.cala
-            .post_transaction_in_op(&mut op, tx_id, templates::INITIATE_WITHDRAW_CODE, params)
+        if let Some((control_id, withdrawal_limits)) = withdrawal_limits {
+            self.apply_withdrawal_limits_in_op(
+                &mut op,
+                credit_account_id,
+                control_id,
+                withdrawal_limits,
+            )
             .await?;
+        }
+
+        if fee.is_zero() {
+            let params = templates::InitiateWithdrawParams {
+                journal_id: self.journal_id,
+                deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+                credit_account_id,
+                amount: amount.to_usd(),
+                currency: self.usd,
+            };
+            self.cala
+                .post_transaction_in_op(&mut op, tx_id, templates::INITIATE_WITHDRAW_CODE, params)
+                .await?;
+        } else {
+            let params = templates::InitiateWithdrawWithFeeParams {
+                journal_id: self.journal_id,
+                deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+                fee_income_account_id: self.fee_income_account_ids.account_id,
+                credit_account_id,
+                amount: amount.to_usd(),
+                fee_amount: fee.to_usd(),
+                currency: self.usd,
+            };
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    tx_id,
+                    templates::INITIATE_WITHDRAW_WITH_FEE_CODE,
+                    params,
+                )
+                .await?;
+        }
 
         op.commit().await?;
         Ok(())
@@ -441,6 +617,10 @@ impl DepositLedger {
                 params,
             )
             .await?;
+        if let Some(fee_refund) = reversal_data.fee_refund {
+            self.refund_withdrawal_fee_in_op(&mut op, fee_refund)
+                .await?;
+        }
         op.commit().await?;
 
         Ok(())
@@ -511,6 +691,7 @@ impl DepositLedger {
         tx_id: impl Into<TransactionId>,
         amount: UsdCents,
         credit_account_id: impl Into<AccountId>,
+        fee_refund: Option<WithdrawalFeeRefund>,
     ) -> Result<(), DepositLedgerError> {
         let tx_id = tx_id.into();
         let mut op = self.cala.ledger_operation_from_db_op(op);
@@ -526,10 +707,577 @@ impl DepositLedger {
         self.cala
             .post_transaction_in_op(&mut op, tx_id, templates::CANCEL_WITHDRAW_CODE, params)
             .await?;
+        if let Some(fee_refund) = fee_refund {
+            self.refund_withdrawal_fee_in_op(&mut op, fee_refund)
+                .await?;
+        }
+        op.commit().await?;
+        Ok(())
+    }
+
+    async fn refund_withdrawal_fee_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        fee_refund: WithdrawalFeeRefund,
+    ) -> Result<(), DepositLedgerError> {
+        let params = templates::RefundWithdrawalFeeParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: fee_refund.amount.to_usd(),
+            fee_income_account_id: self.fee_income_account_ids.account_id,
+            credit_account_id: fee_refund.credit_account_id.into(),
+        };
+
+        self.cala
+            .post_transaction_in_op(
+                op,
+                fee_refund.ledger_tx_id,
+                templates::REFUND_WITHDRAWAL_FEE_CODE,
+                params,
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn capitalize_interest(
+        &self,
+        op: es_entity::DbOp<'_>,
+        capitalization: InterestCapitalizationData,
+        credit_account_id: impl Into<AccountId>,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        let params = templates::CapitalizeInterestParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: capitalization.amount.to_usd(),
+            interest_expense_account_id: self.interest_expense_account_ids.account_id,
+            credit_account_id: credit_account_id.into(),
+            effective: capitalization.capitalized_on,
+        };
+
+        self.cala
+            .post_transaction_in_op(
+                &mut op,
+                capitalization.ledger_tx_id,
+                templates::CAPITALIZE_INTEREST_CODE,
+                params,
+            )
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn open_time_deposit(
+        &self,
+        op: es_entity::DbOp<'_>,
+        time_deposit_id: TimeDepositId,
+        reference: &str,
+        amount: UsdCents,
+        maturity_at: chrono::DateTime<chrono::Utc>,
+        deposit_account_id: impl Into<AccountId>,
+    ) -> Result<(), DepositLedgerError> {
+        let deposit_account_id = deposit_account_id.into();
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        self.create_time_deposit_account_in_op(
+            &mut op,
+            deposit_account_id,
+            time_deposit_id,
+            reference,
+            maturity_at,
+        )
+        .await?;
+
+        let params = templates::OpenTimeDepositParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: amount.to_usd(),
+            debit_account_id: deposit_account_id,
+            credit_account_id: time_deposit_id.into(),
+        };
+        self.cala
+            .post_transaction_in_op(
+                &mut op,
+                time_deposit_id.into(),
+                templates::OPEN_TIME_DEPOSIT_CODE,
+                params,
+            )
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    /// Pays the interest into the time deposit and moves principal and
+    /// interest either back to the deposit account or into the time deposit
+    /// it is rolled over into.
+    pub async fn settle_time_deposit(
+        &self,
+        op: es_entity::DbOp<'_>,
+        settlement: TimeDepositSettlementData,
+        rollover: Option<(&str, chrono::DateTime<chrono::Utc>)>,
+    ) -> Result<(), DepositLedgerError> {
+        let time_deposit_account_id = AccountId::from(settlement.time_deposit_id);
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        if !settlement.interest.is_zero() {
+            let params = templates::CapitalizeInterestParams {
+                journal_id: self.journal_id,
+                currency: self.usd,
+                amount: settlement.interest.to_usd(),
+                interest_expense_account_id: self.interest_expense_account_ids.account_id,
+                credit_account_id: time_deposit_account_id,
+                effective: settlement.effective,
+            };
+            self.cala
+                .post_transaction_in_op(
+                    &mut op,
+                    settlement.interest_tx_id,
+                    templates::CAPITALIZE_INTEREST_CODE,
+                    params,
+                )
+                .await?;
+        }
+
+        let credit_account_id = match (settlement.rolled_over_into, rollover) {
+            (Some(next_id), Some((reference, maturity_at))) => {
+                self.create_time_deposit_account_in_op(
+                    &mut op,
+                    time_deposit_account_id,
+                    next_id,
+                    reference,
+                    maturity_at,
+                )
+                .await?;
+                AccountId::from(next_id)
+            }
+            _ => AccountId::from(settlement.deposit_account_id),
+        };
+
+        let params = templates::SettleTimeDepositParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: settlement.payout.to_usd(),
+            debit_account_id: time_deposit_account_id,
+            credit_account_id,
+            effective: settlement.effective,
+        };
+        self.cala
+            .post_transaction_in_op(
+                &mut op,
+                settlement.settlement_tx_id,
+                templates::SETTLE_TIME_DEPOSIT_CODE,
+                params,
+            )
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    /// Holds the transfer amount on the sending account. When `settlement` is
+    /// given the transfer is settled in the same ledger operation.
+    pub async fn initiate_transfer(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        amount: UsdCents,
+        from_account_id: impl Into<AccountId>,
+        settlement: Option<TransferSettlementData>,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.initiate_transfer_in_op(
+            &mut op,
+            tx_id.into(),
+            amount,
+            from_account_id.into(),
+            settlement,
+        )
+        .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    async fn initiate_transfer_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        tx_id: TransactionId,
+        amount: UsdCents,
+        from_account_id: AccountId,
+        settlement: Option<TransferSettlementData>,
+    ) -> Result<(), DepositLedgerError> {
+        let params = templates::InitiateTransferParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: amount.to_usd(),
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            from_account_id,
+        };
+        self.cala
+            .post_transaction_in_op(op, tx_id, templates::INITIATE_TRANSFER_CODE, params)
+            .await?;
+
+        if let Some(settlement) = settlement {
+            self.settle_transfer_in_op(op, settlement).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn settle_transfer(
+        &self,
+        op: es_entity::DbOp<'_>,
+        settlement: TransferSettlementData,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+        self.settle_transfer_in_op(&mut op, settlement).await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    /// Releases the held amount to the receiving account, or back to the
+    /// sending account if the transfer was denied.
+    async fn settle_transfer_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        settlement: TransferSettlementData,
+    ) -> Result<(), DepositLedgerError> {
+        if settlement.approved {
+            let params = templates::ConfirmTransferParams {
+                journal_id: self.journal_id,
+                currency: self.usd,
+                amount: settlement.amount.to_usd(),
+                deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+                from_account_id: settlement.from_account_id.into(),
+                to_account_id: settlement.to_account_id.into(),
+            };
+            self.cala
+                .post_transaction_in_op(
+                    op,
+                    settlement.ledger_tx_id,
+                    templates::CONFIRM_TRANSFER_CODE,
+                    params,
+                )
+                .await?;
+        } else {
+            let params = templates::CancelTransferParams {
+                journal_id: self.journal_id,
+                currency: self.usd,
+                amount: settlement.amount.to_usd(),
+                deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+                from_account_id: settlement.from_account_id.into(),
+            };
+            self.cala
+                .post_transaction_in_op(
+                    op,
+                    settlement.ledger_tx_id,
+                    templates::CANCEL_TRANSFER_CODE,
+                    params,
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Creates the ledger account of a time deposit next to `sibling_account_id`
+    /// and locks it for debits until maturity.
+    async fn create_time_deposit_account_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        sibling_account_id: AccountId,
+        time_deposit_id: TimeDepositId,
+        reference: &str,
+        maturity_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), DepositLedgerError> {
+        let parent_account_set = self
+            .cala
+            .account_sets()
+            .find_where_member_in_op(op, sibling_account_id, Default::default())
+            .await?
+            .entities
+            .into_iter()
+            .find_map(|set| self.deposits_account_set.find(set.id()))
+            .ok_or(DepositLedgerError::MissingInternalAccountSet(
+                sibling_account_id,
+            ))?;
+
+        let name = format!("Time Deposit {time_deposit_id}");
+        self.create_account_in_op(
+            op,
+            time_deposit_id,
+            parent_account_set,
+            &format!("time-deposit:{reference}"),
+            &name,
+            &name,
+            None,
+        )
+        .await?;
+
+        let mut params = Params::default();
+        params.insert("locked_until", maturity_at);
+        self.cala
+            .velocities()
+            .attach_control_to_account_in_op(
+                op,
+                self.time_deposit_control_id,
+                time_deposit_id.into(),
+                params,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn place_hold(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        deposit_account_id: impl Into<AccountId>,
+        amount: UsdCents,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        let params = templates::PlaceHoldParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: amount.to_usd(),
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            deposit_account_id: deposit_account_id.into(),
+        };
+        self.cala
+            .post_transaction_in_op(&mut op, tx_id.into(), templates::PLACE_HOLD_CODE, params)
+            .await?;
+
         op.commit().await?;
         Ok(())
     }
 
+    pub async fn release_hold(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        deposit_account_id: impl Into<AccountId>,
+        amount: UsdCents,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        let params = templates::ReleaseHoldParams {
+            journal_id: self.journal_id,
+            currency: self.usd,
+            amount: amount.to_usd(),
+            deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+            deposit_account_id: deposit_account_id.into(),
+        };
+        self.cala
+            .post_transaction_in_op(&mut op, tx_id.into(), templates::RELEASE_HOLD_CODE, params)
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    /// Flags the ledger account so the freeze velocity limits reject
+    /// postings in the frozen direction.
+    pub async fn update_account_freeze(
+        &self,
+        op: es_entity::DbOp<'_>,
+        deposit_account_id: impl Into<AccountId>,
+        scope: Option<AccountFreezeScope>,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        self.update_account_metadata_in_op(&mut op, deposit_account_id.into(), |metadata| {
+            metadata.debits_frozen = scope.is_some_and(|scope| scope.debits_frozen());
+            metadata.credits_frozen = scope.is_some_and(|scope| scope.credits_frozen());
+        })
+        .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    /// Moves the remaining balance off the account and locks it. Cala does not
+    /// reject postings to locked accounts by itself, so both freeze controls
+    /// are switched on as well.
+    pub async fn close_account(
+        &self,
+        op: es_entity::DbOp<'_>,
+        deposit_account_id: impl Into<AccountId>,
+        sweep: Option<ClosingBalanceSweep>,
+    ) -> Result<(), DepositLedgerError> {
+        let deposit_account_id = deposit_account_id.into();
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        match sweep {
+            Some(ClosingBalanceSweep::Transfer {
+                transfer_tx_id,
+                settlement,
+            }) => {
+                self.initiate_transfer_in_op(
+                    &mut op,
+                    transfer_tx_id,
+                    settlement.amount,
+                    deposit_account_id,
+                    Some(settlement),
+                )
+                .await?;
+            }
+            Some(ClosingBalanceSweep::External {
+                ledger_tx_id,
+                amount,
+            }) => {
+                let params = templates::SweepClosedAccountParams {
+                    journal_id: self.journal_id,
+                    currency: self.usd,
+                    amount: amount.to_usd(),
+                    deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
+                    debit_account_id: deposit_account_id,
+                };
+                self.cala
+                    .post_transaction_in_op(
+                        &mut op,
+                        ledger_tx_id,
+                        templates::SWEEP_CLOSED_ACCOUNT_CODE,
+                        params,
+                    )
+                    .await?;
+            }
+            None => (),
+        }
+
+        self.update_account_in_op(
+            &mut op,
+            deposit_account_id,
+            Some(cala_ledger::Status::Locked),
+            |metadata| {
+                metadata.debits_frozen = true;
+                metadata.credits_frozen = true;
+            },
+        )
+        .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    /// Velocity limits are fixed once attached, so every change of limits
+    /// attaches a new control and points the account metadata at it. Controls
+    /// of earlier versions stop matching and their windows start over.
+    async fn apply_withdrawal_limits_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        deposit_account_id: AccountId,
+        control_id: CalaVelocityControlId,
+        withdrawal_limits: WithdrawalLimits,
+    ) -> Result<(), DepositLedgerError> {
+        let max_amounts = withdrawal_limits.max_amounts().collect::<Vec<_>>();
+        if !max_amounts.is_empty() {
+            let control = NewVelocityControl::builder()
+                .id(control_id)
+                .name(format!("Withdrawal Limits {deposit_account_id}"))
+                .description("Withdrawal limits of a single deposit account")
+                .condition(format!(
+                    "context.vars.account.metadata.withdrawal_limits_control_id == '{control_id}'"
+                ))
+                .build()
+                .expect("build control");
+            self.cala
+                .velocities()
+                .create_control_in_op(op, control)
+                .await?;
+
+            let mut params = Params::default();
+            for (period, max_amount) in max_amounts {
+                self.cala
+                    .velocities()
+                    .add_limit_to_control_in_op(
+                        op,
+                        control_id,
+                        velocity::WithdrawalAmountLimit::id(period),
+                    )
+                    .await?;
+                params.insert(
+                    velocity::WithdrawalAmountLimit::param_name(period),
+                    max_amount.to_usd(),
+                );
+            }
+            self.cala
+                .velocities()
+                .attach_control_to_account_in_op(op, control_id, deposit_account_id, params)
+                .await?;
+        }
+
+        self.update_account_metadata_in_op(op, deposit_account_id, |metadata| {
+            metadata.withdrawal_limits_control_id = Some(control_id);
+        })
+        .await
+    }
+
+    async fn update_account_metadata_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        deposit_account_id: AccountId,
+        f: impl FnOnce(&mut DepositAccountLedgerMetadata),
+    ) -> Result<(), DepositLedgerError> {
+        self.update_account_in_op(op, deposit_account_id, None, f)
+            .await
+    }
+
+    async fn update_account_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        deposit_account_id: AccountId,
+        status: Option<cala_ledger::Status>,
+        f: impl FnOnce(&mut DepositAccountLedgerMetadata),
+    ) -> Result<(), DepositLedgerError> {
+        let mut account = self
+            .cala
+            .accounts()
+            .find_all_in_op::<Account>(op, &[deposit_account_id])
+            .await?
+            .remove(&deposit_account_id)
+            .ok_or(DepositLedgerError::MissingLedgerAccount(deposit_account_id))?;
+        let mut metadata = account
+            .values()
+            .metadata
+            .clone()
+            .map(|metadata| {
+                serde_json::from_value(metadata).expect("Could not deserialize metadata")
+            })
+            .unwrap_or_else(|| DepositAccountLedgerMetadata::from(None));
+        f(&mut metadata);
+
+        let mut update = AccountUpdate::default();
+        update
+            .metadata(metadata)
+            .expect("Could not serialize metadata");
+        if let Some(status) = status {
+            update.status(status);
+        }
+        account.update(update);
+        self.cala.accounts().persist_in_op(op, &mut account).await?;
+
+        Ok(())
+    }
+
+    pub async fn end_of_day_settled_balance(
+        &self,
+        account_id: impl Into<AccountId>,
+        date: chrono::NaiveDate,
+    ) -> Result<UsdCents, DepositLedgerError> {
+        match self
+            .cala
+            .balances()
+            .effective()
+            .find_cumulative(self.journal_id, account_id.into(), self.usd, date)
+            .await
+        {
+            Ok(balance) => Ok(UsdCents::try_from_usd(balance.settled())?),
+            Err(cala_ledger::balance::error::BalanceError::NotFound(..)) => Ok(UsdCents::ZERO),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     pub async fn balance(
         &self,
         account_id: impl Into<AccountId>,
@@ -543,6 +1291,10 @@ impl DepositLedger {
             Ok(balances) => Ok(DepositAccountBalance {
                 settled: UsdCents::try_from_usd(balances.settled())?,
                 pending: UsdCents::try_from_usd(balances.pending())?,
+                held: UsdCents::try_from_usd(-balances.encumbrance())?,
+                available: UsdCents::try_from_usd(
+                    (balances.settled() + balances.encumbrance()).max(Decimal::ZERO),
+                )?,
             }),
             Err(cala_ledger::balance::error::BalanceError::NotFound(..)) => {
                 Ok(DepositAccountBalance::ZERO)
@@ -570,6 +1322,7 @@ impl DepositLedger {
             &deposit_account_reference,
             &deposit_account_name,
             &deposit_account_name,
+            Some(DepositAccountLedgerMetadata::from(None)),
         )
         .await?;
 
@@ -598,6 +1351,7 @@ impl DepositLedger {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn create_account_in_op(
         &self,
         op: &mut LedgerOperation<'_>,
@@ -606,18 +1360,24 @@ impl DepositLedger {
         reference: &str,
         name: &str,
         description: &str,
+        metadata: Option<DepositAccountLedgerMetadata>,
     ) -> Result<(), DepositLedgerError> {
         let id = id.into();
 
-        let new_ledger_account = NewAccount::builder()
+        let mut builder = NewAccount::builder();
+        builder
             .id(id)
             .external_id(reference)
             .name(name)
             .description(description)
             .code(id.to_string())
-            .normal_balance_type(parent_account_set.normal_balance_type)
-            .build()
-            .expect("Could not build new account");
+            .normal_balance_type(parent_account_set.normal_balance_type);
+        if let Some(metadata) = metadata {
+            builder
+                .metadata(metadata)
+                .expect("Could not serialize metadata");
+        }
+        let new_ledger_account = builder.build().expect("Could not build new account");
         let ledger_account = self
             .cala
             .accounts()
@@ -650,6 +1410,25 @@ impl DepositLedger {
         }
     }
 
+    pub async fn create_time_deposit_control(
+        cala: &CalaLedger,
+    ) -> Result<VelocityControlId, DepositLedgerError> {
+        let control = NewVelocityControl::builder()
+            .id(TIME_DEPOSITS_VELOCITY_CONTROL_ID)
+            .name("Time Deposit Control")
+            .description("Velocity Control for Time Deposits")
+            .build()
+            .expect("build control");
+
+        match cala.velocities().create_control(control).await {
+            Err(cala_ledger::velocity::error::VelocityError::ControlIdAlreadyExists) => {
+                Ok(TIME_DEPOSITS_VELOCITY_CONTROL_ID.into())
+            }
+            Err(e) => Err(e.into()),
+            Ok(control) => Ok(control.id()),
+        }
+    }
+
     pub async fn add_deposit_control_to_account(
         &self,
         op: &mut cala_ledger::LedgerOperation<'_>,
@@ -736,7 +1515,11 @@ impl DepositLedger {
     ) -> Result<(), DepositLedgerError> {
         let mut op = self.cala.begin_operation().await?;
 
-        let mut account_set_ids = vec![self.deposit_omnibus_account_ids.account_set_id];
+        let mut account_set_ids = vec![
+            self.deposit_omnibus_account_ids.account_set_id,
+            self.interest_expense_account_ids.account_set_id,
+            self.fee_income_account_ids.account_set_id,
+        ];
         account_set_ids.extend(self.deposits_account_set.account_set_ids());
         let mut account_sets = self
             .cala
@@ -748,6 +1531,8 @@ impl DepositLedger {
             config: _,
             audit_info: _,
             omnibus_parent_account_set_id,
+            interest_expense_parent_account_set_id,
+            fee_income_parent_account_set_id,
             individual_deposit_accounts_parent_account_set_id:
                 individual_deposit_parent_account_set_id,
             government_entity_deposit_accounts_parent_account_set_id:
@@ -771,6 +1556,26 @@ impl DepositLedger {
         )
         .await?;
 
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.interest_expense_account_ids.account_set_id,
+            *interest_expense_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.interest_expense_parent_account_set_id,
+        )
+        .await?;
+
+        self.attach_charts_account_set(
+            &mut op,
+            &mut account_sets,
+            self.fee_income_account_ids.account_set_id,
+            *fee_income_parent_account_set_id,
+            &charts_integration_meta,
+            |meta| meta.fee_income_parent_account_set_id,
+        )
+        .await?;
+
         self.attach_charts_account_set(
             &mut op,
             &mut account_sets,
@@ -837,12 +1642,47 @@ impl DepositLedger {
     }
 }
 
+/// How the remaining balance leaves an account that is being closed.
+pub enum ClosingBalanceSweep {
+    Transfer {
+        transfer_tx_id: CalaTransactionId,
+        settlement: TransferSettlementData,
+    },
+    External {
+        ledger_tx_id: CalaTransactionId,
+        amount: UsdCents,
+    },
+}
+
+/// Read by the freeze velocity limits, so both flags must always be present.
+/// The withdrawal limits control id is only read by controls attached after
+/// it has been set.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+struct DepositAccountLedgerMetadata {
+    debits_frozen: bool,
+    credits_frozen: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    withdrawal_limits_control_id: Option<CalaVelocityControlId>,
+}
+
+impl From<Option<AccountFreezeScope>> for DepositAccountLedgerMetadata {
+    fn from(scope: Option<AccountFreezeScope>) -> Self {
+        Self {
+            debits_frozen: scope.is_some_and(|scope| scope.debits_frozen()),
+            credits_frozen: scope.is_some_and(|scope| scope.credits_frozen()),
+            withdrawal_limits_control_id: None,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ChartOfAccountsIntegrationMeta {
     pub config: ChartOfAccountsIntegrationConfig,
     pub audit_info: AuditInfo,
 
     pub omnibus_parent_account_set_id: CalaAccountSetId,
+    pub interest_expense_parent_account_set_id: CalaAccountSetId,
+    pub fee_income_parent_account_set_id: CalaAccountSetId,
 
     pub individual_deposit_accounts_parent_account_set_id: CalaAccountSetId,
     pub government_entity_deposit_accounts_parent_account_set_id: CalaAccountSetId,
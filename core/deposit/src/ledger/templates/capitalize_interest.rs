@@ -0,0 +1,131 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const CAPITALIZE_INTEREST_CODE: &str = "CAPITALIZE_DEPOSIT_INTEREST";
+
+#[derive(Debug)]
+pub struct CapitalizeInterestParams {
+    pub journal_id: JournalId,
+    pub currency: Currency,
+    pub amount: Decimal,
+    pub interest_expense_account_id: CalaAccountId,
+    pub credit_account_id: CalaAccountId,
+    pub effective: NaiveDate,
+}
+
+impl CapitalizeInterestParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("interest_expense_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("credit_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<CapitalizeInterestParams> for Params {
+    fn from(
+        CapitalizeInterestParams {
+            journal_id,
+            currency,
+            amount,
+            interest_expense_account_id,
+            credit_account_id,
+            effective,
+        }: CapitalizeInterestParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("currency", currency);
+        params.insert("amount", amount);
+        params.insert("interest_expense_account_id", interest_expense_account_id);
+        params.insert("credit_account_id", credit_account_id);
+        params.insert("effective", effective);
+
+        params
+    }
+}
+
+pub struct CapitalizeInterest;
+
+impl CapitalizeInterest {
+    #[instrument(name = "ledger.capitalize_interest.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), DepositLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .description("'Capitalize accrued deposit interest'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'CAPITALIZE_DEPOSIT_INTEREST_DR'")
+                .currency("params.currency")
+                .account_id("params.interest_expense_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'CAPITALIZE_DEPOSIT_INTEREST_CR'")
+                .currency("params.currency")
+                .account_id("params.credit_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = CapitalizeInterestParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(CAPITALIZE_INTEREST_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const INITIATE_TRANSFER_CODE: &str = "INITIATE_TRANSFER";
+
+#[derive(Debug)]
+pub struct InitiateTransferParams {
+    pub journal_id: JournalId,
+    pub currency: Currency,
+    pub amount: Decimal,
+    pub deposit_omnibus_account_id: CalaAccountId,
+    pub from_account_id: CalaAccountId,
+}
+
+impl InitiateTransferParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("deposit_omnibus_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("from_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<InitiateTransferParams> for Params {
+    fn from(
+        InitiateTransferParams {
+            journal_id,
+            currency,
+            amount,
+            deposit_omnibus_account_id,
+            from_account_id,
+        }: InitiateTransferParams,
+    ) -> Self {
+        let mut params = Self::default();
+
+        params.insert("journal_id", journal_id);
+        params.insert("currency", currency);
+        params.insert("amount", amount);
+        params.insert("deposit_omnibus_account_id", deposit_omnibus_account_id);
+        params.insert("from_account_id", from_account_id);
+        params.insert("effective", crate::time::now().date_naive());
+
+        params
+    }
+}
+
+pub struct InitiateTransfer;
+
+impl InitiateTransfer {
+    #[instrument(name = "ledger.initiate_transfer.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), DepositLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .description("'Initiate a transfer between deposit accounts'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'INITIATE_TRANSFER_SETTLED_CR'")
+                .currency("params.currency")
+                .account_id("params.deposit_omnibus_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'INITIATE_TRANSFER_SETTLED_DR'")
+                .currency("params.currency")
+                .account_id("params.from_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'INITIATE_TRANSFER_PENDING_DR'")
+                .currency("params.currency")
+                .account_id("params.deposit_omnibus_account_id")
+                .direction("DEBIT")
+                .layer("PENDING")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'INITIATE_TRANSFER_PENDING_CR'")
+                .currency("params.currency")
+                .account_id("params.from_account_id")
+                .direction("CREDIT")
+                .layer("PENDING")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = InitiateTransferParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(INITIATE_TRANSFER_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -9,6 +9,7 @@ use cala_ledger::{
 use crate::{ledger::error::*, primitives::CalaAccountId};
 
 pub const INITIATE_WITHDRAW_CODE: &str = "INITIATE_WITHDRAW";
+pub const INITIATE_WITHDRAW_SETTLED_DR: &str = "INITIATE_WITHDRAW_SETTLED_DR";
 
 #[derive(Debug)]
 pub struct InitiateWithdrawParams {
@@ -82,15 +83,10 @@ impl From<InitiateWithdrawParams> for Params {
 pub struct InitiateWithdraw;
 
 impl InitiateWithdraw {
-    #[instrument(name = "ledger.initiate_withdraw.init", skip_all)]
-    pub async fn init(ledger: &CalaLedger) -> Result<(), DepositLedgerError> {
-        let tx_input = NewTxTemplateTransaction::builder()
-            .journal_id("params.journal_id")
-            .effective("params.effective")
-            .description("'Initiate a withdraw'")
-            .build()
-            .expect("Couldn't build TxInput");
-        let entries = vec![
+    /// Entries moving `params.amount` out of the account, shared with the
+    /// fee-bearing variant of the template.
+    pub(super) fn entries() -> Vec<NewTxTemplateEntry> {
+        vec![
             NewTxTemplateEntry::builder()
                 .entry_type("'INITIATE_WITHDRAW_SETTLED_CR'")
                 .currency("params.currency")
@@ -127,7 +123,18 @@ impl InitiateWithdraw {
                 .units("params.amount")
                 .build()
                 .expect("Couldn't build entry"),
-        ];
+        ]
+    }
+
+    #[instrument(name = "ledger.initiate_withdraw.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), DepositLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .description("'Initiate a withdraw'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = Self::entries();
 
         let params = InitiateWithdrawParams::defs();
         let template = NewTxTemplate::builder()
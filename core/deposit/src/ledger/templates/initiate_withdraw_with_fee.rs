@@ -0,0 +1,119 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+use super::{InitiateWithdraw, InitiateWithdrawParams};
+
+pub const INITIATE_WITHDRAW_WITH_FEE_CODE: &str = "INITIATE_WITHDRAW_WITH_FEE";
+
+#[derive(Debug)]
+pub struct InitiateWithdrawWithFeeParams {
+    pub journal_id: JournalId,
+    pub deposit_omnibus_account_id: CalaAccountId,
+    pub fee_income_account_id: CalaAccountId,
+    pub credit_account_id: CalaAccountId,
+    pub amount: Decimal,
+    pub fee_amount: Decimal,
+    pub currency: Currency,
+}
+
+impl InitiateWithdrawWithFeeParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        let mut defs = InitiateWithdrawParams::defs();
+        defs.extend([
+            NewParamDefinition::builder()
+                .name("fee_amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("fee_income_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+        ]);
+        defs
+    }
+}
+
+impl From<InitiateWithdrawWithFeeParams> for Params {
+    fn from(
+        InitiateWithdrawWithFeeParams {
+            journal_id,
+            deposit_omnibus_account_id,
+            fee_income_account_id,
+            credit_account_id,
+            amount,
+            fee_amount,
+            currency,
+        }: InitiateWithdrawWithFeeParams,
+    ) -> Self {
+        let mut params = Self::from(InitiateWithdrawParams {
+            journal_id,
+            deposit_omnibus_account_id,
+            credit_account_id,
+            amount,
+            currency,
+        });
+        params.insert("fee_amount", fee_amount);
+        params.insert("fee_income_account_id", fee_income_account_id);
+
+        params
+    }
+}
+
+pub struct InitiateWithdrawWithFee;
+
+impl InitiateWithdrawWithFee {
+    #[instrument(name = "ledger.initiate_withdraw_with_fee.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), DepositLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .description("'Initiate a withdraw with fee'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let mut entries = InitiateWithdraw::entries();
+        entries.extend([
+            NewTxTemplateEntry::builder()
+                .entry_type("'WITHDRAWAL_FEE_DR'")
+                .currency("params.currency")
+                .account_id("params.credit_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.fee_amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'WITHDRAWAL_FEE_CR'")
+                .currency("params.currency")
+                .account_id("params.fee_income_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.fee_amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ]);
+
+        let params = InitiateWithdrawWithFeeParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(INITIATE_WITHDRAW_WITH_FEE_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -1,13 +1,35 @@
+mod cancel_transfer;
 mod cancel_withdraw;
+mod capitalize_interest;
+mod confirm_transfer;
 mod confirm_withdraw;
+mod initiate_transfer;
 mod initiate_withdraw;
+mod initiate_withdraw_with_fee;
+mod open_time_deposit;
+mod place_hold;
 mod record_deposit;
+mod refund_withdrawal_fee;
+mod release_hold;
 mod revert_deposit;
 mod revert_withdraw;
+mod settle_time_deposit;
+mod sweep_closed_account;
 
+pub use cancel_transfer::*;
 pub use cancel_withdraw::*;
+pub use capitalize_interest::*;
+pub use confirm_transfer::*;
 pub use confirm_withdraw::*;
+pub use initiate_transfer::*;
 pub use initiate_withdraw::*;
+pub use initiate_withdraw_with_fee::*;
+pub use open_time_deposit::*;
+pub use place_hold::*;
 pub use record_deposit::*;
+pub use refund_withdrawal_fee::*;
+pub use release_hold::*;
 pub use revert_deposit::*;
 pub use revert_withdraw::*;
+pub use settle_time_deposit::*;
+pub use sweep_closed_account::*;
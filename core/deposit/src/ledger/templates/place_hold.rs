@@ -0,0 +1,129 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const PLACE_HOLD_CODE: &str = "PLACE_HOLD";
+
+#[derive(Debug)]
+pub struct PlaceHoldParams {
+    pub journal_id: JournalId,
+    pub currency: Currency,
+    pub amount: Decimal,
+    pub deposit_omnibus_account_id: CalaAccountId,
+    pub deposit_account_id: CalaAccountId,
+}
+
+impl PlaceHoldParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("deposit_omnibus_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("deposit_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<PlaceHoldParams> for Params {
+    fn from(
+        PlaceHoldParams {
+            journal_id,
+            currency,
+            amount,
+            deposit_omnibus_account_id,
+            deposit_account_id,
+        }: PlaceHoldParams,
+    ) -> Self {
+        let mut params = Self::default();
+
+        params.insert("journal_id", journal_id);
+        params.insert("currency", currency);
+        params.insert("amount", amount);
+        params.insert("deposit_omnibus_account_id", deposit_omnibus_account_id);
+        params.insert("deposit_account_id", deposit_account_id);
+        params.insert("effective", crate::time::now().date_naive());
+
+        params
+    }
+}
+
+pub struct PlaceHold;
+
+impl PlaceHold {
+    #[instrument(name = "ledger.place_hold.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), DepositLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .description("'Place a hold on a deposit account'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'PLACE_HOLD_ENCUMBRANCE_DR'")
+                .currency("params.currency")
+                .account_id("params.deposit_account_id")
+                .direction("DEBIT")
+                .layer("ENCUMBRANCE")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'PLACE_HOLD_ENCUMBRANCE_CR'")
+                .currency("params.currency")
+                .account_id("params.deposit_omnibus_account_id")
+                .direction("CREDIT")
+                .layer("ENCUMBRANCE")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = PlaceHoldParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(PLACE_HOLD_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const SETTLE_TIME_DEPOSIT_CODE: &str = "SETTLE_TIME_DEPOSIT";
+pub const SETTLE_TIME_DEPOSIT_DR: &str = "SETTLE_TIME_DEPOSIT_DR";
+
+#[derive(Debug)]
+pub struct SettleTimeDepositParams {
+    pub journal_id: JournalId,
+    pub currency: Currency,
+    pub amount: Decimal,
+    pub debit_account_id: CalaAccountId,
+    pub credit_account_id: CalaAccountId,
+    pub effective: NaiveDate,
+}
+
+impl SettleTimeDepositParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("debit_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("credit_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<SettleTimeDepositParams> for Params {
+    fn from(
+        SettleTimeDepositParams {
+            journal_id,
+            currency,
+            amount,
+            debit_account_id,
+            credit_account_id,
+            effective,
+        }: SettleTimeDepositParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("currency", currency);
+        params.insert("amount", amount);
+        params.insert("debit_account_id", debit_account_id);
+        params.insert("credit_account_id", credit_account_id);
+        params.insert("effective", effective);
+
+        params
+    }
+}
+
+pub struct SettleTimeDeposit;
+
+impl SettleTimeDeposit {
+    #[instrument(name = "ledger.settle_time_deposit.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), DepositLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .description("'Settle a time deposit'")
+            .build()
+            .expect("Couldn't build TxInput");
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'SETTLE_TIME_DEPOSIT_DR'")
+                .currency("params.currency")
+                .account_id("params.debit_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'SETTLE_TIME_DEPOSIT_CR'")
+                .currency("params.currency")
+                .account_id("params.credit_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = SettleTimeDepositParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(SETTLE_TIME_DEPOSIT_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
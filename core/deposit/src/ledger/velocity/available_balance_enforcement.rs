@@ -0,0 +1,47 @@
+use tracing::instrument;
+
+use cala_ledger::{velocity::*, *};
+
+use crate::ledger::error::*;
+
+pub struct AvailableBalanceEnforcement;
+
+const AVAILABLE_BALANCE_ENFORCEMENT_ID: uuid::Uuid =
+    uuid::uuid!("00000000-0000-0000-0000-000000000004");
+
+impl AvailableBalanceEnforcement {
+    #[instrument(name = "ledger.available_balance_enforcement.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<VelocityLimitId, DepositLedgerError> {
+        // Holds are recorded on the encumbrance layer. Ignoring pending entries
+        // keeps in-flight withdrawals from freeing up held funds.
+        let limit = NewVelocityLimit::builder()
+            .id(AVAILABLE_BALANCE_ENFORCEMENT_ID)
+            .name("Available Balance Enforcement")
+            .description("Prevent debits beyond the balance not covered by holds")
+            .window(vec![])
+            .condition("context.vars.entry.layer != PENDING")
+            .limit(
+                NewLimit::builder()
+                    .balance(vec![
+                        NewBalanceLimit::builder()
+                            .layer("ENCUMBRANCE")
+                            .amount("decimal('0.0')")
+                            .enforcement_direction("DEBIT")
+                            .build()
+                            .expect("balance limit"),
+                    ])
+                    .build()
+                    .expect("limit"),
+            )
+            .build()
+            .expect("velocity limit");
+
+        match ledger.velocities().create_limit(limit).await {
+            Err(cala_ledger::velocity::error::VelocityError::LimitIdAlreadyExists) => {
+                Ok(AVAILABLE_BALANCE_ENFORCEMENT_ID.into())
+            }
+            Err(e) => Err(e.into()),
+            Ok(limit) => Ok(limit.id()),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use tracing::instrument;
+
+use cala_ledger::{velocity::*, *};
+
+use crate::ledger::error::*;
+
+pub struct CreditFreeze;
+
+const CREDIT_FREEZE_ID: uuid::Uuid = uuid::uuid!("00000000-0000-0000-0000-000000000006");
+
+impl CreditFreeze {
+    #[instrument(name = "ledger.credit_freeze.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<VelocityLimitId, DepositLedgerError> {
+        // Only entries posted while the account is flagged as frozen count
+        // against the zero limit.
+        let limit = NewVelocityLimit::builder()
+            .id(CREDIT_FREEZE_ID)
+            .name("Credit Freeze")
+            .description("Prevent credits on a frozen deposit account")
+            .window(vec![])
+            .condition("context.vars.entry.direction == CREDIT && context.vars.entry.layer == SETTLED && context.vars.account.metadata.credits_frozen")
+            .limit(
+                NewLimit::builder()
+                    .balance(vec![
+                        NewBalanceLimit::builder()
+                            .layer("SETTLED")
+                            .amount("decimal('0.0')")
+                            .enforcement_direction("CREDIT")
+                            .build()
+                            .expect("balance limit"),
+                    ])
+                    .build()
+                    .expect("limit"),
+            )
+            .build()
+            .expect("velocity limit");
+
+        match ledger.velocities().create_limit(limit).await {
+            Err(cala_ledger::velocity::error::VelocityError::LimitIdAlreadyExists) => {
+                Ok(CREDIT_FREEZE_ID.into())
+            }
+            Err(e) => Err(e.into()),
+            Ok(limit) => Ok(limit.id()),
+        }
+    }
+}
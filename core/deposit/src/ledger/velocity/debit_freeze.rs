@@ -0,0 +1,46 @@
+use tracing::instrument;
+
+use cala_ledger::{velocity::*, *};
+
+use crate::ledger::error::*;
+
+pub struct DebitFreeze;
+
+const DEBIT_FREEZE_ID: uuid::Uuid = uuid::uuid!("00000000-0000-0000-0000-000000000005");
+
+impl DebitFreeze {
+    #[instrument(name = "ledger.debit_freeze.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<VelocityLimitId, DepositLedgerError> {
+        // Only entries posted while the account is flagged as frozen count
+        // against the zero limit.
+        let limit = NewVelocityLimit::builder()
+            .id(DEBIT_FREEZE_ID)
+            .name("Debit Freeze")
+            .description("Prevent debits on a frozen deposit account")
+            .window(vec![])
+            .condition("context.vars.entry.direction == DEBIT && context.vars.entry.layer == SETTLED && context.vars.account.metadata.debits_frozen")
+            .limit(
+                NewLimit::builder()
+                    .balance(vec![
+                        NewBalanceLimit::builder()
+                            .layer("SETTLED")
+                            .amount("decimal('0.0')")
+                            .enforcement_direction("DEBIT")
+                            .build()
+                            .expect("balance limit"),
+                    ])
+                    .build()
+                    .expect("limit"),
+            )
+            .build()
+            .expect("velocity limit");
+
+        match ledger.velocities().create_limit(limit).await {
+            Err(cala_ledger::velocity::error::VelocityError::LimitIdAlreadyExists) => {
+                Ok(DEBIT_FREEZE_ID.into())
+            }
+            Err(e) => Err(e.into()),
+            Ok(limit) => Ok(limit.id()),
+        }
+    }
+}
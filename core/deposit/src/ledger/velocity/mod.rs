@@ -1,3 +1,13 @@
+mod available_balance_enforcement;
+mod credit_freeze;
+mod debit_freeze;
 mod overdraft_prevention;
+mod time_deposit_lock;
+mod withdrawal_limit;
 
+pub use available_balance_enforcement::*;
+pub use credit_freeze::*;
+pub use debit_freeze::*;
 pub use overdraft_prevention::*;
+pub use time_deposit_lock::*;
+pub use withdrawal_limit::*;
@@ -0,0 +1,58 @@
+use tracing::instrument;
+
+use cala_ledger::{velocity::*, *};
+
+use crate::ledger::{error::*, templates::SETTLE_TIME_DEPOSIT_DR};
+
+pub struct TimeDepositLock;
+
+const TIME_DEPOSIT_LOCK_ID: uuid::Uuid = uuid::uuid!("00000000-0000-0000-0000-000000000003");
+
+impl TimeDepositLock {
+    #[instrument(name = "ledger.time_deposit_lock.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<VelocityLimitId, DepositLedgerError> {
+        let params = vec![
+            NewParamDefinition::builder()
+                .name("locked_until")
+                .r#type(ParamDataType::Timestamp)
+                .build()
+                .expect("param definition"),
+        ];
+
+        // Only the settlement of the time deposit itself may debit the account
+        // before maturity. Every other debit counts against a zero limit.
+        let limit = NewVelocityLimit::builder()
+            .id(TIME_DEPOSIT_LOCK_ID)
+            .name("Time Deposit Lock")
+            .description("Prevent debits on a time deposit until maturity")
+            .window(vec![])
+            .condition(format!(
+                "context.vars.entry.direction == DEBIT && context.vars.entry.entryType != '{SETTLE_TIME_DEPOSIT_DR}'"
+            ))
+            .params(params)
+            .limit(
+                NewLimit::builder()
+                    .balance(vec![
+                        NewBalanceLimit::builder()
+                            .layer("SETTLED")
+                            .amount("decimal('0.0')")
+                            .enforcement_direction("DEBIT")
+                            .end("params.locked_until")
+                            .build()
+                            .expect("balance limit"),
+                    ])
+                    .build()
+                    .expect("limit"),
+            )
+            .build()
+            .expect("velocity limit");
+
+        match ledger.velocities().create_limit(limit).await {
+            Err(cala_ledger::velocity::error::VelocityError::LimitIdAlreadyExists) => {
+                Ok(TIME_DEPOSIT_LOCK_ID.into())
+            }
+            Err(e) => Err(e.into()),
+            Ok(limit) => Ok(limit.id()),
+        }
+    }
+}
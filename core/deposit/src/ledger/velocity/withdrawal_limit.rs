@@ -0,0 +1,104 @@
+use tracing::instrument;
+
+use cala_ledger::{velocity::*, *};
+
+use crate::{
+    ledger::{error::*, templates::INITIATE_WITHDRAW_SETTLED_DR},
+    withdrawal_limit::WithdrawalLimitPeriod,
+};
+
+pub struct WithdrawalAmountLimit;
+
+const DAILY_WITHDRAWAL_LIMIT_ID: uuid::Uuid = uuid::uuid!("00000000-0000-0000-0000-000000000007");
+const WEEKLY_WITHDRAWAL_LIMIT_ID: uuid::Uuid = uuid::uuid!("00000000-0000-0000-0000-000000000008");
+const MONTHLY_WITHDRAWAL_LIMIT_ID: uuid::Uuid = uuid::uuid!("00000000-0000-0000-0000-000000000009");
+
+impl WithdrawalAmountLimit {
+    pub fn id(period: WithdrawalLimitPeriod) -> VelocityLimitId {
+        match period {
+            WithdrawalLimitPeriod::Daily => DAILY_WITHDRAWAL_LIMIT_ID,
+            WithdrawalLimitPeriod::Weekly => WEEKLY_WITHDRAWAL_LIMIT_ID,
+            WithdrawalLimitPeriod::Monthly => MONTHLY_WITHDRAWAL_LIMIT_ID,
+        }
+        .into()
+    }
+
+    pub fn period(limit_id: VelocityLimitId) -> Option<WithdrawalLimitPeriod> {
+        WithdrawalLimitPeriod::ALL
+            .into_iter()
+            .find(|period| Self::id(*period) == limit_id)
+    }
+
+    pub fn param_name(period: WithdrawalLimitPeriod) -> &'static str {
+        match period {
+            WithdrawalLimitPeriod::Daily => "daily_max_amount",
+            WithdrawalLimitPeriod::Weekly => "weekly_max_amount",
+            WithdrawalLimitPeriod::Monthly => "monthly_max_amount",
+        }
+    }
+
+    #[instrument(name = "ledger.withdrawal_amount_limit.init", skip_all)]
+    pub async fn init(
+        ledger: &CalaLedger,
+        period: WithdrawalLimitPeriod,
+    ) -> Result<VelocityLimitId, DepositLedgerError> {
+        let id = Self::id(period);
+        let param_name = Self::param_name(period);
+        // Windows are keyed by the UTC calendar day, ISO week or month the
+        // withdrawal was initiated in.
+        let window_format = match period {
+            WithdrawalLimitPeriod::Daily => "%Y-%m-%d",
+            WithdrawalLimitPeriod::Weekly => "%G-W%V",
+            WithdrawalLimitPeriod::Monthly => "%Y-%m",
+        };
+
+        let params = vec![
+            NewParamDefinition::builder()
+                .name(param_name)
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .expect("param definition"),
+        ];
+
+        let limit = NewVelocityLimit::builder()
+            .id(id)
+            .name(format!("{period:?} Withdrawal Limit"))
+            .description(format!(
+                "Cap the amount withdrawn from a deposit account per {period:?} window"
+            ))
+            .window(vec![
+                NewPartitionKey::builder()
+                    .alias("window")
+                    .value(format!(
+                        "context.vars.transaction.createdAt.format('{window_format}')"
+                    ))
+                    .build()
+                    .expect("partition key"),
+            ])
+            .condition(format!(
+                "context.vars.entry.entryType == '{INITIATE_WITHDRAW_SETTLED_DR}'"
+            ))
+            .params(params)
+            .limit(
+                NewLimit::builder()
+                    .balance(vec![
+                        NewBalanceLimit::builder()
+                            .layer("SETTLED")
+                            .amount(format!("params.{param_name}"))
+                            .enforcement_direction("DEBIT")
+                            .build()
+                            .expect("balance limit"),
+                    ])
+                    .build()
+                    .expect("limit"),
+            )
+            .build()
+            .expect("velocity limit");
+
+        match ledger.velocities().create_limit(limit).await {
+            Err(cala_ledger::velocity::error::VelocityError::LimitIdAlreadyExists) => Ok(id),
+            Err(e) => Err(e.into()),
+            Ok(limit) => Ok(limit.id()),
+        }
+    }
+}
@@ -2,19 +2,35 @@
 #![cfg_attr(feature = "fail-on-warnings", deny(clippy::all))]
 
 mod account;
+mod bank_statement;
+mod beneficiary;
 mod chart_of_accounts_integration;
+mod config;
 mod deposit;
 mod deposit_account_balance;
+mod dormancy;
 pub mod error;
 mod event;
 mod for_subject;
 mod history;
+mod hold;
+mod hold_expiry;
+mod interest_accrual;
 mod ledger;
+mod payment_batch;
 mod primitives;
 mod processes;
+mod product;
 mod publisher;
+mod statement;
+mod statement_generation;
 mod time;
+mod time_deposit;
+mod time_deposit_maturity;
+mod transfer;
 mod withdrawal;
+mod withdrawal_fee;
+mod withdrawal_limit;
 
 use deposit_account_cursor::DepositAccountsByCreatedAtCursor;
 use tracing::instrument;
@@ -23,35 +39,91 @@ use audit::AuditSvc;
 use authz::PermissionCheck;
 use cala_ledger::CalaLedger;
 use core_accounting::Chart;
+use document_storage::{DocumentStorage, GeneratedDocumentDownloadLink, ReferenceId};
 use governance::{Governance, GovernanceEvent};
 use job::Jobs;
 use outbox::{Outbox, OutboxEventMarker};
 use public_id::PublicIds;
 
-pub use account::DepositAccount;
 use account::*;
+pub use account::{AccountFreezeScope, BalanceSweep, BalanceSweepDestination, DepositAccount};
+use bank_statement::*;
+pub use bank_statement::{
+    BankStatementLine, BankStatementLineStatus, BankStatementLinesByCreatedAtCursor,
+};
+use beneficiary::*;
+pub use beneficiary::{Beneficiary, BeneficiaryBankAccount, BeneficiaryStatus};
 pub use chart_of_accounts_integration::{
     ChartOfAccountsIntegrationConfig, ChartOfAccountsIntegrationConfigBuilderError,
 };
+pub use config::*;
 use deposit::*;
 pub use deposit::{Deposit, DepositsByCreatedAtCursor};
 pub use deposit_account_balance::DepositAccountBalance;
+use dormancy::{DepositAccountDormancyInit, DepositAccountDormancyJobConfig, TrackDormancy};
 use error::*;
 pub use event::*;
 pub use for_subject::DepositsForSubject;
 pub use history::{DepositAccountHistoryCursor, DepositAccountHistoryEntry};
+use hold::*;
+pub use hold::{Hold, HoldStatus};
+use hold_expiry::{ExpireHolds, HoldExpiryInit, HoldExpiryJobConfig};
+use interest_accrual::{
+    AccrueDepositInterest, DepositInterestAccrualInit, DepositInterestAccrualJobConfig,
+};
 use ledger::*;
+use payment_batch::*;
+pub use payment_batch::{PaymentBatch, PaymentBatchesByCreatedAtCursor};
 pub use primitives::*;
 pub use processes::approval::APPROVE_WITHDRAWAL_PROCESS;
 use processes::approval::{ApproveWithdrawal, WithdrawApprovalInit, WithdrawApprovalJobConfig};
+pub use processes::beneficiary_approval::APPROVE_BENEFICIARY_PROCESS;
+use processes::beneficiary_approval::{
+    ApproveBeneficiary, BeneficiaryApprovalInit, BeneficiaryApprovalJobConfig,
+};
+pub use processes::transfer_approval::APPROVE_TRANSFER_PROCESS;
+use processes::transfer_approval::{
+    ApproveTransfer, TransferApprovalInit, TransferApprovalJobConfig,
+};
+use product::*;
+pub use product::{
+    CapitalizationFrequency, DepositInterestRate, DepositInterestTerms, DepositProduct,
+};
 use publisher::DepositPublisher;
+use statement::*;
+pub use statement::{DepositAccountStatement, StatementPeriod};
+use statement_generation::{
+    DepositAccountStatementInit, DepositAccountStatementJobConfig, GenerateStatements,
+};
+use time_deposit::*;
+pub use time_deposit::{
+    EarlyBreakPenaltyRate, TimeDeposit, TimeDepositDuration, TimeDepositMaturityInstruction,
+    TimeDepositStatus, TimeDepositTerms,
+};
+use time_deposit_maturity::{
+    MatureTimeDeposits, TimeDepositMaturityInit, TimeDepositMaturityJobConfig,
+};
+use transfer::*;
+pub use transfer::{Transfer, TransferStatus, TransfersByCreatedAtCursor};
 use withdrawal::*;
 pub use withdrawal::{Withdrawal, WithdrawalStatus, WithdrawalsByCreatedAtCursor};
+pub use withdrawal_fee::{WithdrawalFeeRate, WithdrawalFeeSchedule};
+pub use withdrawal_limit::{
+    WithdrawalLimit, WithdrawalLimitPeriod, WithdrawalLimitUsage, WithdrawalLimits,
+};
 
 #[cfg(feature = "json-schema")]
 pub mod event_schema {
     pub use crate::account::DepositAccountEvent;
+    pub use crate::bank_statement::BankStatementLineEvent;
+    pub use crate::beneficiary::BeneficiaryEvent;
     pub use crate::deposit::DepositEvent;
+    pub use crate::hold::HoldEvent;
+    pub use crate::payment_batch::PaymentBatchEvent;
+    pub use crate::product::DepositProductEvent;
+    pub use crate::statement::DepositAccountStatementEvent;
+    pub use crate::time_deposit::TimeDepositEvent;
+    pub use crate::transfer::TransferEvent;
     pub use crate::withdrawal::WithdrawalEvent;
 }
 
@@ -63,13 +135,25 @@ where
     accounts: DepositAccountRepo<E>,
     deposits: DepositRepo<E>,
     withdrawals: WithdrawalRepo<E>,
+    products: DepositProductRepo,
+    time_deposits: TimeDepositRepo,
+    transfers: TransferRepo<E>,
+    holds: HoldRepo,
+    statements: DepositAccountStatementRepo,
+    beneficiaries: BeneficiaryRepo,
+    bank_statement_lines: BankStatementLineRepo,
+    payment_batches: PaymentBatchRepo,
     approve_withdrawal: ApproveWithdrawal<Perms, E>,
+    approve_transfer: ApproveTransfer<Perms, E>,
+    approve_beneficiary: ApproveBeneficiary<Perms, E>,
     ledger: DepositLedger,
     cala: CalaLedger,
     authz: Perms,
     governance: Governance<Perms, E>,
     outbox: Outbox<E>,
     public_ids: PublicIds,
+    document_storage: DocumentStorage,
+    config: DepositConfig,
 }
 
 impl<Perms, E> Clone for CoreDeposit<Perms, E>
@@ -82,13 +166,25 @@ where
             accounts: self.accounts.clone(),
             deposits: self.deposits.clone(),
             withdrawals: self.withdrawals.clone(),
+            products: self.products.clone(),
+            time_deposits: self.time_deposits.clone(),
+            transfers: self.transfers.clone(),
+            holds: self.holds.clone(),
+            statements: self.statements.clone(),
+            beneficiaries: self.beneficiaries.clone(),
+            bank_statement_lines: self.bank_statement_lines.clone(),
+            payment_batches: self.payment_batches.clone(),
             ledger: self.ledger.clone(),
             cala: self.cala.clone(),
             authz: self.authz.clone(),
             governance: self.governance.clone(),
             approve_withdrawal: self.approve_withdrawal.clone(),
+            approve_transfer: self.approve_transfer.clone(),
+            approve_beneficiary: self.approve_beneficiary.clone(),
             outbox: self.outbox.clone(),
             public_ids: self.public_ids.clone(),
+            document_storage: self.document_storage.clone(),
+            config: self.config.clone(),
         }
     }
 }
@@ -105,6 +201,7 @@ where
     #[allow(clippy::too_many_arguments)]
     pub async fn init(
         pool: &sqlx::PgPool,
+        config: DepositConfig,
         authz: &Perms,
         outbox: &Outbox<E>,
         governance: &Governance<Perms, E>,
@@ -112,11 +209,20 @@ where
         cala: &CalaLedger,
         journal_id: CalaJournalId,
         public_ids: &PublicIds,
+        document_storage: DocumentStorage,
     ) -> Result<Self, CoreDepositError> {
         let publisher = DepositPublisher::new(outbox);
         let accounts = DepositAccountRepo::new(pool, &publisher);
         let deposits = DepositRepo::new(pool, &publisher);
         let withdrawals = WithdrawalRepo::new(pool, &publisher);
+        let products = DepositProductRepo::new(pool);
+        let time_deposits = TimeDepositRepo::new(pool);
+        let transfers = TransferRepo::new(pool, &publisher);
+        let holds = HoldRepo::new(pool);
+        let statements = DepositAccountStatementRepo::new(pool);
+        let beneficiaries = BeneficiaryRepo::new(pool);
+        let bank_statement_lines = BankStatementLineRepo::new(pool);
+        let payment_batches = PaymentBatchRepo::new(pool);
         let ledger = DepositLedger::init(cala, journal_id).await?;
 
         let approve_withdrawal = ApproveWithdrawal::new(&withdrawals, authz.audit(), governance);
@@ -127,6 +233,67 @@ where
         )
         .await?;
 
+        let approve_transfer = ApproveTransfer::new(&transfers, authz.audit(), governance, &ledger);
+        jobs.add_initializer_and_spawn_unique(
+            TransferApprovalInit::new(outbox, &approve_transfer),
+            TransferApprovalJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+
+        let approve_beneficiary = ApproveBeneficiary::new(
+            &beneficiaries,
+            authz.audit(),
+            governance,
+            chrono::Duration::hours(config.beneficiary_cooling_off_period_hours.into()),
+        );
+        jobs.add_initializer_and_spawn_unique(
+            BeneficiaryApprovalInit::new(outbox, &approve_beneficiary),
+            BeneficiaryApprovalJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+
+        let accrue_interest =
+            AccrueDepositInterest::new(&accounts, &products, &ledger, authz.audit());
+        jobs.add_initializer_and_spawn_unique(
+            DepositInterestAccrualInit::new(&accrue_interest),
+            DepositInterestAccrualJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+
+        let mature_time_deposits = MatureTimeDeposits::new(&time_deposits, &ledger, authz.audit());
+        jobs.add_initializer_and_spawn_unique(
+            TimeDepositMaturityInit::new(&mature_time_deposits),
+            TimeDepositMaturityJobConfig::<Perms>::new(),
+        )
+        .await?;
+
+        let expire_holds = ExpireHolds::new(&holds, &ledger, authz.audit());
+        jobs.add_initializer_and_spawn_unique(
+            HoldExpiryInit::new(&expire_holds),
+            HoldExpiryJobConfig::<Perms>::new(),
+        )
+        .await?;
+
+        let generate_statements = GenerateStatements::new(
+            &accounts,
+            &statements,
+            &ledger,
+            &document_storage,
+            authz.audit(),
+        );
+        jobs.add_initializer_and_spawn_unique(
+            DepositAccountStatementInit::new(&generate_statements),
+            DepositAccountStatementJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+
+        let track_dormancy = TrackDormancy::new(&accounts, &ledger, authz.audit(), &config);
+        jobs.add_initializer_and_spawn_unique(
+            DepositAccountDormancyInit::new(&track_dormancy),
+            DepositAccountDormancyJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+
         match governance.init_policy(APPROVE_WITHDRAWAL_PROCESS).await {
             Err(governance::error::GovernanceError::PolicyError(
                 governance::policy_error::PolicyError::DuplicateApprovalProcessType,
@@ -135,17 +302,45 @@ where
             _ => (),
         }
 
+        match governance.init_policy(APPROVE_TRANSFER_PROCESS).await {
+            Err(governance::error::GovernanceError::PolicyError(
+                governance::policy_error::PolicyError::DuplicateApprovalProcessType,
+            )) => (),
+            Err(e) => return Err(e.into()),
+            _ => (),
+        }
+
+        match governance.init_policy(APPROVE_BENEFICIARY_PROCESS).await {
+            Err(governance::error::GovernanceError::PolicyError(
+                governance::policy_error::PolicyError::DuplicateApprovalProcessType,
+            )) => (),
+            Err(e) => return Err(e.into()),
+            _ => (),
+        }
+
         let res = Self {
             accounts,
             deposits,
             withdrawals,
+            products,
+            time_deposits,
+            transfers,
+            holds,
+            statements,
+            beneficiaries,
+            bank_statement_lines,
+            payment_batches,
             authz: authz.clone(),
             outbox: outbox.clone(),
             governance: governance.clone(),
             cala: cala.clone(),
             approve_withdrawal,
+            approve_transfer,
+            approve_beneficiary,
             ledger,
             public_ids: public_ids.clone(),
+            document_storage,
+            config,
         };
         Ok(res)
     }
@@ -166,7 +361,11 @@ where
             &self.accounts,
             &self.deposits,
             &self.withdrawals,
+            &self.statements,
+            &self.beneficiaries,
             &self.ledger,
+            &self.governance,
+            &self.document_storage,
             &self.authz,
         ))
     }
@@ -363,6 +562,7 @@ where
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
         amount: UsdCents,
+        beneficiary_id: Option<BeneficiaryId>,
         reference: Option<String>,
     ) -> Result<Withdrawal, CoreDepositError> {
         let deposit_account_id = deposit_account_id.into();
@@ -374,12 +574,36 @@ where
                 CoreDepositAction::WITHDRAWAL_INITIATE,
             )
             .await?;
-        self.check_account_active(deposit_account_id).await?;
+        let mut account = self.accounts.find_by_id(deposit_account_id).await?;
+        if account.is_closed() {
+            return Err(CoreDepositError::DepositAccountClosed);
+        }
+        if account.status.is_inactive() {
+            return Err(CoreDepositError::DepositAccountNotActive);
+        }
+        if account.is_dormant() {
+            return Err(CoreDepositError::DepositAccountDormant);
+        }
+        if let Some(beneficiary_id) = beneficiary_id {
+            self.check_beneficiary_usable(deposit_account_id, beneficiary_id)
+                .await?;
+        }
+        let withdrawal_limits = self.effective_withdrawal_limits(&account).await?;
+        for usage in self
+            .withdrawal_limit_usage_for(deposit_account_id, withdrawal_limits)
+            .await?
+        {
+            usage.check(amount)?;
+        }
+        let fee = self.withdrawal_fee_for(&account, amount).await?;
+
         let withdrawal_id = WithdrawalId::new();
         let new_withdrawal = NewWithdrawal::builder()
             .id(withdrawal_id)
             .deposit_account_id(deposit_account_id)
             .amount(amount)
+            .fee(fee)
+            .beneficiary_id(beneficiary_id)
             .approval_process_id(withdrawal_id)
             .reference(reference)
             .audit_info(audit_info)
@@ -399,9 +623,28 @@ where
             .create_in_op(&mut op, new_withdrawal)
             .await?;
 
+        let limits_update = match account.apply_withdrawal_limits(withdrawal_limits) {
+            es_entity::Idempotent::Executed(control_id) => {
+                self.accounts.update_in_op(&mut op, &mut account).await?;
+                Some((control_id, withdrawal_limits))
+            }
+            es_entity::Idempotent::Ignored => None,
+        };
+
         self.ledger
-            .initiate_withdrawal(op, withdrawal_id, amount, deposit_account_id)
-            .await?;
+            .initiate_withdrawal(
+                op,
+                withdrawal_id,
+                amount,
+                fee,
+                deposit_account_id,
+                limits_update,
+            )
+            .await
+            .map_err(|e| match e.withdrawal_limit_period() {
+                Some(period) => CoreDepositError::WithdrawalLimitExceeded(period),
+                None => e.into(),
+            })?;
         Ok(withdrawal)
     }
 
@@ -529,12 +772,18 @@ where
         self.check_account_active(withdrawal.deposit_account_id)
             .await?;
         let mut op = self.withdrawals.begin_op().await?;
-        let tx_id = withdrawal.cancel(audit_info)?;
+        let (tx_id, fee_refund) = withdrawal.cancel(audit_info)?;
         self.withdrawals
             .update_in_op(&mut op, &mut withdrawal)
             .await?;
         self.ledger
-            .cancel_withdrawal(op, tx_id, withdrawal.amount, withdrawal.deposit_account_id)
+            .cancel_withdrawal(
+                op,
+                tx_id,
+                withdrawal.amount,
+                withdrawal.deposit_account_id,
+                fee_refund,
+            )
             .await?;
         Ok(withdrawal)
     }
@@ -779,103 +1028,1614 @@ where
             .await?)
     }
 
-    pub async fn get_chart_of_accounts_integration_config(
+    #[instrument(name = "deposit.create_deposit_product", skip(self), err)]
+    pub async fn create_deposit_product(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
-    ) -> Result<Option<ChartOfAccountsIntegrationConfig>, CoreDepositError> {
-        self.authz
+        name: String,
+        interest_terms: DepositInterestTerms,
+    ) -> Result<DepositProduct, CoreDepositError> {
+        let audit_info = self
+            .authz
             .enforce_permission(
                 sub,
-                CoreDepositObject::chart_of_accounts_integration(),
-                CoreDepositAction::CHART_OF_ACCOUNTS_INTEGRATION_CONFIG_READ,
+                CoreDepositObject::all_deposit_products(),
+                CoreDepositAction::DEPOSIT_PRODUCT_CREATE,
             )
             .await?;
-        Ok(self
-            .ledger
-            .get_chart_of_accounts_integration_config()
-            .await?)
+
+        let new_product = NewDepositProduct::builder()
+            .id(DepositProductId::new())
+            .name(name)
+            .interest_terms(interest_terms)
+            .audit_info(audit_info)
+            .build()
+            .expect("Could not build new deposit product");
+
+        Ok(self.products.create(new_product).await?)
     }
 
-    pub async fn set_chart_of_accounts_integration_config(
+    #[instrument(
+        name = "deposit.update_deposit_product_interest_terms",
+        skip(self),
+        err
+    )]
+    pub async fn update_deposit_product_interest_terms(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
-        chart: &Chart,
-        config: ChartOfAccountsIntegrationConfig,
-    ) -> Result<ChartOfAccountsIntegrationConfig, CoreDepositError> {
-        if chart.id != config.chart_of_accounts_id {
-            return Err(CoreDepositError::ChartIdMismatch);
-        }
+        id: impl Into<DepositProductId> + std::fmt::Debug,
+        interest_terms: DepositInterestTerms,
+    ) -> Result<DepositProduct, CoreDepositError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_product(id),
+                CoreDepositAction::DEPOSIT_PRODUCT_UPDATE,
+            )
+            .await?;
 
-        if self
-            .ledger
-            .get_chart_of_accounts_integration_config()
-            .await?
-            .is_some()
+        let mut product = self.products.find_by_id(id).await?;
+        if product
+            .update_interest_terms(interest_terms, audit_info)
+            .did_execute()
         {
-            return Err(CoreDepositError::DepositConfigAlreadyExists);
+            self.products.update(&mut product).await?;
         }
 
-        let individual_deposit_accounts_parent_account_set_id = chart.account_set_id_from_code(
-            &config.chart_of_accounts_individual_deposit_accounts_parent_code,
-        )?;
-        let government_entity_deposit_accounts_parent_account_set_id = chart
-            .account_set_id_from_code(
-                &config.chart_of_accounts_government_entity_deposit_accounts_parent_code,
-            )?;
-        let private_company_deposit_accounts_parent_account_set_id = chart
-            .account_set_id_from_code(
-                &config.chart_of_account_private_company_deposit_accounts_parent_code,
-            )?;
-        let bank_deposit_accounts_parent_account_set_id = chart
-            .account_set_id_from_code(&config.chart_of_account_bank_deposit_accounts_parent_code)?;
-        let financial_institution_deposit_accounts_parent_account_set_id = chart
-            .account_set_id_from_code(
-                &config.chart_of_account_financial_institution_deposit_accounts_parent_code,
-            )?;
-        let non_domiciled_individual_deposit_accounts_parent_account_set_id = chart
-            .account_set_id_from_code(
-                &config.chart_of_account_non_domiciled_individual_deposit_accounts_parent_code,
-            )?;
+        Ok(product)
+    }
 
-        let omnibus_parent_account_set_id =
-            chart.account_set_id_from_code(&config.chart_of_accounts_omnibus_parent_code)?;
+    #[instrument(
+        name = "deposit.update_deposit_product_withdrawal_limits",
+        skip(self),
+        err
+    )]
+    pub async fn update_deposit_product_withdrawal_limits(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<DepositProductId> + std::fmt::Debug,
+        withdrawal_limits: WithdrawalLimits,
+    ) -> Result<DepositProduct, CoreDepositError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_product(id),
+                CoreDepositAction::DEPOSIT_PRODUCT_UPDATE,
+            )
+            .await?;
 
+        let mut product = self.products.find_by_id(id).await?;
+        if product
+            .update_withdrawal_limits(withdrawal_limits, audit_info)
+            .did_execute()
+        {
+            self.products.update(&mut product).await?;
+        }
+
+        Ok(product)
+    }
+
+    #[instrument(
+        name = "deposit.update_deposit_product_withdrawal_fee_schedule",
+        skip(self),
+        err
+    )]
+    pub async fn update_deposit_product_withdrawal_fee_schedule(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<DepositProductId> + std::fmt::Debug,
+        withdrawal_fee_schedule: Option<WithdrawalFeeSchedule>,
+    ) -> Result<DepositProduct, CoreDepositError> {
+        let id = id.into();
         let audit_info = self
             .authz
             .enforce_permission(
                 sub,
-                CoreDepositObject::chart_of_accounts_integration(),
-                CoreDepositAction::CHART_OF_ACCOUNTS_INTEGRATION_CONFIG_UPDATE,
+                CoreDepositObject::deposit_product(id),
+                CoreDepositAction::DEPOSIT_PRODUCT_UPDATE,
             )
             .await?;
 
-        let charts_integration_meta = ChartOfAccountsIntegrationMeta {
-            audit_info,
-            config: config.clone(),
-            omnibus_parent_account_set_id,
-            individual_deposit_accounts_parent_account_set_id,
-            government_entity_deposit_accounts_parent_account_set_id,
-            private_company_deposit_accounts_parent_account_set_id,
-            bank_deposit_accounts_parent_account_set_id,
-            financial_institution_deposit_accounts_parent_account_set_id,
-            non_domiciled_individual_deposit_accounts_parent_account_set_id,
-        };
+        let mut product = self.products.find_by_id(id).await?;
+        if product
+            .update_withdrawal_fee_schedule(withdrawal_fee_schedule, audit_info)?
+            .did_execute()
+        {
+            self.products.update(&mut product).await?;
+        }
 
-        self.ledger
-            .attach_chart_of_accounts_account_sets(charts_integration_meta)
+        Ok(product)
+    }
+
+    #[instrument(name = "deposit.find_deposit_product_by_id", skip(self), err)]
+    pub async fn find_deposit_product_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<DepositProductId> + std::fmt::Debug,
+    ) -> Result<Option<DepositProduct>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_product(id),
+                CoreDepositAction::DEPOSIT_PRODUCT_READ,
+            )
             .await?;
 
-        Ok(config)
+        match self.products.find_by_id(id).await {
+            Ok(product) => Ok(Some(product)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
     }
 
-    async fn check_account_active(
+    #[instrument(name = "deposit.list_deposit_products", skip(self), err)]
+    pub async fn list_deposit_products(
         &self,
-        deposit_account_id: DepositAccountId,
-    ) -> Result<(), CoreDepositError> {
-        let account = self.accounts.find_by_id(deposit_account_id).await?;
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    ) -> Result<Vec<DepositProduct>, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_products(),
+                CoreDepositAction::DEPOSIT_PRODUCT_LIST,
+            )
+            .await?;
+
+        Ok(self
+            .products
+            .list_by_name(Default::default(), es_entity::ListDirection::Ascending)
+            .await?
+            .entities)
+    }
+
+    #[instrument(name = "deposit.find_all_deposit_products", skip(self), err)]
+    pub async fn find_all_deposit_products<T: From<DepositProduct>>(
+        &self,
+        ids: &[DepositProductId],
+    ) -> Result<std::collections::HashMap<DepositProductId, T>, CoreDepositError> {
+        Ok(self.products.find_all(ids).await?)
+    }
+
+    /// Interest starts accruing on the end-of-day balance of the day the
+    /// product is assigned.
+    #[instrument(name = "deposit.assign_product_to_account", skip(self), err)]
+    pub async fn assign_product_to_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        product_id: impl Into<DepositProductId> + std::fmt::Debug,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        let product_id = product_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_ASSIGN_PRODUCT,
+            )
+            .await?;
+
+        let product = self.products.find_by_id(product_id).await?;
+        let mut account = self.accounts.find_by_id(account_id).await?;
         if account.status.is_inactive() {
             return Err(CoreDepositError::DepositAccountNotActive);
         }
+
+        if account
+            .assign_product(product.id, time::now().date_naive(), audit_info)
+            .did_execute()
+        {
+            self.accounts.update(&mut account).await?;
+        }
+
+        Ok(account)
+    }
+
+    /// Moves `amount` out of the deposit account into a new time deposit that
+    /// cannot be debited until it matures.
+    #[instrument(name = "deposit.open_time_deposit", skip(self), err)]
+    pub async fn open_time_deposit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: UsdCents,
+        terms: TimeDepositTerms,
+        maturity_instruction: TimeDepositMaturityInstruction,
+        reference: Option<String>,
+    ) -> Result<TimeDeposit, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_time_deposits(),
+                CoreDepositAction::TIME_DEPOSIT_OPEN,
+            )
+            .await?;
+        self.check_account_active(deposit_account_id).await?;
+
+        let time_deposit_id = TimeDepositId::new();
+        let new_time_deposit = NewTimeDeposit::builder()
+            .id(time_deposit_id)
+            .ledger_transaction_id(time_deposit_id)
+            .deposit_account_id(deposit_account_id)
+            .amount(amount)
+            .terms(terms)
+            .maturity_instruction(maturity_instruction)
+            .opened_at(time::now())
+            .reference(reference)
+            .audit_info(audit_info)
+            .build()?;
+
+        let mut op = self.time_deposits.begin_op().await?;
+        let time_deposit = self
+            .time_deposits
+            .create_in_op(&mut op, new_time_deposit)
+            .await?;
+        self.ledger
+            .open_time_deposit(
+                op,
+                time_deposit_id,
+                &time_deposit.reference,
+                amount,
+                time_deposit.maturity_at,
+                deposit_account_id,
+            )
+            .await?;
+        Ok(time_deposit)
+    }
+
+    /// Settles a time deposit before maturity, forfeiting part of the interest
+    /// earned so far as set by its early break penalty.
+    #[instrument(name = "deposit.break_time_deposit", skip(self), err)]
+    pub async fn break_time_deposit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        time_deposit_id: impl Into<TimeDepositId> + std::fmt::Debug,
+    ) -> Result<TimeDeposit, CoreDepositError> {
+        let id = time_deposit_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::time_deposit(id),
+                CoreDepositAction::TIME_DEPOSIT_BREAK,
+            )
+            .await?;
+
+        let mut time_deposit = self.time_deposits.find_by_id(id).await?;
+        self.check_account_active(time_deposit.deposit_account_id)
+            .await?;
+
+        if let es_entity::Idempotent::Executed(settlement) =
+            time_deposit.break_early(time::now(), audit_info)?
+        {
+            let mut op = self.time_deposits.begin_op().await?;
+            self.time_deposits
+                .update_in_op(&mut op, &mut time_deposit)
+                .await?;
+            self.ledger
+                .settle_time_deposit(op, settlement, None)
+                .await?;
+        }
+
+        Ok(time_deposit)
+    }
+
+    #[instrument(name = "deposit.find_time_deposit_by_id", skip(self), err)]
+    pub async fn find_time_deposit_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<TimeDepositId> + std::fmt::Debug,
+    ) -> Result<Option<TimeDeposit>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::time_deposit(id),
+                CoreDepositAction::TIME_DEPOSIT_READ,
+            )
+            .await?;
+
+        match self.time_deposits.find_by_id(id).await {
+            Ok(time_deposit) => Ok(Some(time_deposit)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.list_time_deposits_for_account", skip(self), err)]
+    pub async fn list_time_deposits_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<TimeDeposit>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_time_deposits(),
+                CoreDepositAction::TIME_DEPOSIT_LIST,
+            )
+            .await?;
+        Ok(self
+            .time_deposits
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    #[instrument(name = "deposit.find_all_time_deposits", skip(self), err)]
+    pub async fn find_all_time_deposits<T: From<TimeDeposit>>(
+        &self,
+        ids: &[TimeDepositId],
+    ) -> Result<std::collections::HashMap<TimeDepositId, T>, CoreDepositError> {
+        Ok(self.time_deposits.find_all(ids).await?)
+    }
+
+    /// Moves funds between two deposit accounts. Transfers above the
+    /// configured threshold stay pending until the approval process concludes.
+    #[instrument(name = "deposit.initiate_transfer", skip(self), err)]
+    pub async fn initiate_transfer(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        from_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        to_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: UsdCents,
+        reference: Option<String>,
+    ) -> Result<Transfer, CoreDepositError> {
+        let from_account_id = from_account_id.into();
+        let to_account_id = to_account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_transfers(),
+                CoreDepositAction::TRANSFER_INITIATE,
+            )
+            .await?;
+        let from_account = self.check_account_active(from_account_id).await?;
+        if from_account.is_dormant() {
+            return Err(CoreDepositError::DepositAccountDormant);
+        }
+        self.check_account_active(to_account_id).await?;
+
+        let transfer_id = TransferId::new();
+        let requires_approval = amount > self.config.transfer_approval_threshold;
+        let new_transfer = NewTransfer::builder()
+            .id(transfer_id)
+            .from_account_id(from_account_id)
+            .to_account_id(to_account_id)
+            .amount(amount)
+            .approval_process_id(requires_approval.then(|| transfer_id.into()))
+            .reference(reference)
+            .audit_info(audit_info.clone())
+            .build()?;
+
+        let mut op = self.transfers.begin_op().await?;
+        if requires_approval {
+            self.governance
+                .start_process(
+                    &mut op,
+                    transfer_id,
+                    transfer_id.to_string(),
+                    APPROVE_TRANSFER_PROCESS,
+                )
+                .await?;
+        }
+        let mut transfer = self.transfers.create_in_op(&mut op, new_transfer).await?;
+
+        let settlement = if requires_approval {
+            None
+        } else {
+            let settlement = transfer
+                .confirm(audit_info)
+                .expect("newly created transfer should not be settled");
+            self.transfers.update_in_op(&mut op, &mut transfer).await?;
+            Some(settlement)
+        };
+
+        self.ledger
+            .initiate_transfer(op, transfer_id, amount, from_account_id, settlement)
+            .await?;
+        Ok(transfer)
+    }
+
+    #[instrument(name = "deposit.find_transfer_by_id", skip(self), err)]
+    pub async fn find_transfer_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<TransferId> + std::fmt::Debug,
+    ) -> Result<Option<Transfer>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::transfer(id),
+                CoreDepositAction::TRANSFER_READ,
+            )
+            .await?;
+
+        match self.transfers.find_by_id(id).await {
+            Ok(transfer) => Ok(Some(transfer)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(
+        name = "deposit.ensure_up_to_date_transfer_status",
+        skip(self, transfer),
+        err
+    )]
+    pub async fn ensure_up_to_date_transfer_status(
+        &self,
+        transfer: &Transfer,
+    ) -> Result<Option<Transfer>, CoreDepositError> {
+        Ok(self.approve_transfer.execute_from_svc(transfer).await?)
+    }
+
+    /// Lists transfers sent from or received by the account, newest first.
+    #[instrument(name = "deposit.list_transfers_for_account", skip(self), err)]
+    pub async fn list_transfers_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<Transfer>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_transfers(),
+                CoreDepositAction::TRANSFER_LIST,
+            )
+            .await?;
+        let mut transfers = self
+            .transfers
+            .list_for_from_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities;
+        transfers.extend(
+            self.transfers
+                .list_for_to_account_id_by_created_at(
+                    account_id,
+                    Default::default(),
+                    es_entity::ListDirection::Descending,
+                )
+                .await?
+                .entities,
+        );
+        transfers.sort_by_key(|t| std::cmp::Reverse(t.created_at()));
+        Ok(transfers)
+    }
+
+    #[instrument(name = "deposit.find_all_transfers", skip(self), err)]
+    pub async fn find_all_transfers<T: From<Transfer>>(
+        &self,
+        ids: &[TransferId],
+    ) -> Result<std::collections::HashMap<TransferId, T>, CoreDepositError> {
+        Ok(self.transfers.find_all(ids).await?)
+    }
+
+    /// Blocks postings on the account in the given direction until it is
+    /// unfrozen. Holds can still be placed and released while frozen.
+    #[instrument(name = "deposit.freeze_account", skip(self), err)]
+    pub async fn freeze_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        scope: AccountFreezeScope,
+        reason: String,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_FREEZE,
+            )
+            .await?;
+
+        let mut account = self.accounts.find_by_id(account_id).await?;
+        if account.freeze(scope, reason, audit_info).did_execute() {
+            let mut op = self.accounts.begin_op().await?;
+            self.accounts.update_in_op(&mut op, &mut account).await?;
+            self.ledger
+                .update_account_freeze(op, account_id, Some(scope))
+                .await?;
+        }
+
+        Ok(account)
+    }
+
+    #[instrument(name = "deposit.unfreeze_account", skip(self), err)]
+    pub async fn unfreeze_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_UNFREEZE,
+            )
+            .await?;
+
+        let mut account = self.accounts.find_by_id(account_id).await?;
+        if account.unfreeze(audit_info).did_execute() {
+            let mut op = self.accounts.begin_op().await?;
+            self.accounts.update_in_op(&mut op, &mut account).await?;
+            self.ledger
+                .update_account_freeze(op, account_id, None)
+                .await?;
+        }
+
+        Ok(account)
+    }
+
+    /// Lifts dormancy once the account holder got back in touch, allowing
+    /// withdrawals again. The reactivation counts as customer activity.
+    #[instrument(name = "deposit.reactivate_account", skip(self), err)]
+    pub async fn reactivate_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_REACTIVATE,
+            )
+            .await?;
+
+        let mut account = self.accounts.find_by_id(account_id).await?;
+        if account
+            .reactivate(crate::time::now(), audit_info)
+            .did_execute()
+        {
+            self.accounts.update(&mut account).await?;
+        }
+
+        Ok(account)
+    }
+
+    /// Closes the account for good. Any remaining balance is swept to
+    /// `sweep_destination`, which is required unless the account is empty.
+    /// The ledger account is locked so nothing can be posted to it anymore.
+    #[instrument(name = "deposit.close_account", skip(self), err)]
+    pub async fn close_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        sweep_destination: Option<BalanceSweepDestination>,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_CLOSE,
+            )
+            .await?;
+
+        let mut account = self.accounts.find_by_id(account_id).await?;
+        if account.is_closed() {
+            return Ok(account);
+        }
+        self.ensure_no_pending_withdrawals(account_id).await?;
+        let balance = self.ledger.balance(account_id).await?;
+        if !balance.held.is_zero() || balance.pending != balance.settled {
+            return Err(CoreDepositError::DepositAccountHasUnsettledFunds);
+        }
+
+        let mut op = self.accounts.begin_op().await?;
+        let (balance_sweep, ledger_sweep) = match sweep_destination {
+            _ if balance.settled.is_zero() => (None, None),
+            None => return Err(CoreDepositError::DepositAccountHasBalance),
+            Some(destination @ BalanceSweepDestination::DepositAccount { deposit_account_id }) => {
+                self.check_account_active(deposit_account_id).await?;
+                let transfer_id = TransferId::new();
+                let new_transfer = NewTransfer::builder()
+                    .id(transfer_id)
+                    .from_account_id(account_id)
+                    .to_account_id(deposit_account_id)
+                    .amount(balance.settled)
+                    .reference(Some(format!("closure:{account_id}")))
+                    .audit_info(audit_info.clone())
+                    .build()?;
+                let mut transfer = self.transfers.create_in_op(&mut op, new_transfer).await?;
+                let settlement = transfer
+                    .confirm(audit_info.clone())
+                    .expect("newly created transfer should not be settled");
+                self.transfers.update_in_op(&mut op, &mut transfer).await?;
+                (
+                    Some(BalanceSweep {
+                        destination,
+                        amount: balance.settled,
+                        ledger_tx_id: transfer_id.into(),
+                    }),
+                    Some(ClosingBalanceSweep::Transfer {
+                        transfer_tx_id: transfer_id.into(),
+                        settlement,
+                    }),
+                )
+            }
+            Some(destination @ BalanceSweepDestination::ExternalBeneficiary { .. }) => {
+                let ledger_tx_id = CalaTransactionId::new();
+                (
+                    Some(BalanceSweep {
+                        destination,
+                        amount: balance.settled,
+                        ledger_tx_id,
+                    }),
+                    Some(ClosingBalanceSweep::External {
+                        ledger_tx_id,
+                        amount: balance.settled,
+                    }),
+                )
+            }
+        };
+
+        let _ = account.close(balance_sweep, audit_info);
+        self.accounts.update_in_op(&mut op, &mut account).await?;
+        self.ledger
+            .close_account(op, account_id, ledger_sweep)
+            .await?;
+
+        Ok(account)
+    }
+
+    /// Overrides the withdrawal limits of the account's deposit product.
+    /// Passing `None` falls back to the product limits again. The ledger
+    /// picks up the change with the next withdrawal.
+    #[instrument(name = "deposit.update_account_withdrawal_limits", skip(self), err)]
+    pub async fn update_account_withdrawal_limits(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        withdrawal_limits: Option<WithdrawalLimits>,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_UPDATE_WITHDRAWAL_LIMITS,
+            )
+            .await?;
+
+        let mut account = self.accounts.find_by_id(account_id).await?;
+        if account
+            .override_withdrawal_limits(withdrawal_limits, audit_info)
+            .did_execute()
+        {
+            self.accounts.update(&mut account).await?;
+        }
+
+        Ok(account)
+    }
+
+    /// How much of each withdrawal limit of the account has been used up in
+    /// the current period.
+    #[instrument(name = "deposit.account_withdrawal_limit_usage", skip(self), err)]
+    pub async fn account_withdrawal_limit_usage(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<WithdrawalLimitUsage>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_READ_BALANCE,
+            )
+            .await?;
+
+        let account = self.accounts.find_by_id(account_id).await?;
+        let withdrawal_limits = self.effective_withdrawal_limits(&account).await?;
+        self.withdrawal_limit_usage_for(account_id, withdrawal_limits)
+            .await
+    }
+
+    /// Reserves `amount` of the available balance. Debits that would reach
+    /// into held funds are rejected by the ledger until the hold is released
+    /// or expires.
+    #[instrument(name = "deposit.place_hold", skip(self), err)]
+    pub async fn place_hold(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: UsdCents,
+        reason: String,
+        expires_at: Option<chrono::DateTime<chrono::Utc>>,
+        reference: Option<String>,
+    ) -> Result<Hold, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_holds(),
+                CoreDepositAction::HOLD_PLACE,
+            )
+            .await?;
+        self.accounts.find_by_id(deposit_account_id).await?;
+
+        let hold_id = HoldId::new();
+        let new_hold = NewHold::builder()
+            .id(hold_id)
+            .deposit_account_id(deposit_account_id)
+            .amount(amount)
+            .reason(reason)
+            .expires_at(expires_at)
+            .reference(reference)
+            .audit_info(audit_info)
+            .build()?;
+
+        let mut op = self.holds.begin_op().await?;
+        let hold = self.holds.create_in_op(&mut op, new_hold).await?;
+        self.ledger
+            .place_hold(op, hold_id, deposit_account_id, amount)
+            .await?;
+        Ok(hold)
+    }
+
+    #[instrument(name = "deposit.release_hold", skip(self), err)]
+    pub async fn release_hold(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        hold_id: impl Into<HoldId> + std::fmt::Debug,
+    ) -> Result<Hold, CoreDepositError> {
+        let id = hold_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::hold(id),
+                CoreDepositAction::HOLD_RELEASE,
+            )
+            .await?;
+
+        let mut hold = self.holds.find_by_id(id).await?;
+        if let es_entity::Idempotent::Executed(release) = hold.release(audit_info) {
+            let mut op = self.holds.begin_op().await?;
+            self.holds.update_in_op(&mut op, &mut hold).await?;
+            self.ledger
+                .release_hold(
+                    op,
+                    release.ledger_tx_id,
+                    release.deposit_account_id,
+                    release.amount,
+                )
+                .await?;
+        }
+
+        Ok(hold)
+    }
+
+    #[instrument(name = "deposit.find_hold_by_id", skip(self), err)]
+    pub async fn find_hold_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<HoldId> + std::fmt::Debug,
+    ) -> Result<Option<Hold>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::hold(id),
+                CoreDepositAction::HOLD_READ,
+            )
+            .await?;
+
+        match self.holds.find_by_id(id).await {
+            Ok(hold) => Ok(Some(hold)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.list_holds_for_account", skip(self), err)]
+    pub async fn list_holds_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<Hold>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_holds(),
+                CoreDepositAction::HOLD_LIST,
+            )
+            .await?;
+        Ok(self
+            .holds
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    #[instrument(name = "deposit.find_all_holds", skip(self), err)]
+    pub async fn find_all_holds<T: From<Hold>>(
+        &self,
+        ids: &[HoldId],
+    ) -> Result<std::collections::HashMap<HoldId, T>, CoreDepositError> {
+        Ok(self.holds.find_all(ids).await?)
+    }
+
+    #[instrument(name = "deposit.list_statements_for_account", skip(self), err)]
+    pub async fn list_statements_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<DepositAccountStatement>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_account_statements(),
+                CoreDepositAction::DEPOSIT_ACCOUNT_STATEMENT_LIST,
+            )
+            .await?;
+        Ok(self
+            .statements
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    #[instrument(name = "deposit.generate_statement_download_link", skip(self), err)]
+    pub async fn generate_statement_download_link(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        statement_id: impl Into<DepositAccountStatementId> + std::fmt::Debug,
+    ) -> Result<GeneratedDocumentDownloadLink, CoreDepositError> {
+        let statement_id = statement_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account_statement(statement_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_STATEMENT_GENERATE_DOWNLOAD_LINK,
+            )
+            .await?;
+        let statement = self.statements.find_by_id(statement_id).await?;
+        Ok(self
+            .document_storage
+            .generate_download_link(audit_info, statement.document_id)
+            .await?)
+    }
+
+    #[instrument(name = "deposit.find_beneficiary_by_id", skip(self), err)]
+    pub async fn find_beneficiary_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<BeneficiaryId> + std::fmt::Debug,
+    ) -> Result<Option<Beneficiary>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::beneficiary(id),
+                CoreDepositAction::BENEFICIARY_READ,
+            )
+            .await?;
+
+        match self.beneficiaries.find_by_id(id).await {
+            Ok(beneficiary) => Ok(Some(beneficiary)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.list_beneficiaries_for_account", skip(self), err)]
+    pub async fn list_beneficiaries_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<Beneficiary>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_beneficiaries(),
+                CoreDepositAction::BENEFICIARY_LIST,
+            )
+            .await?;
+        Ok(self
+            .beneficiaries
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    /// Removes a beneficiary so no further withdrawals can be paid out to it,
+    /// e.g. when staff suspect the details were registered fraudulently.
+    #[instrument(name = "deposit.remove_beneficiary", skip(self), err)]
+    pub async fn remove_beneficiary(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<BeneficiaryId> + std::fmt::Debug,
+    ) -> Result<Beneficiary, CoreDepositError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::beneficiary(id),
+                CoreDepositAction::BENEFICIARY_REMOVE,
+            )
+            .await?;
+        let mut beneficiary = self.beneficiaries.find_by_id(id).await?;
+        if beneficiary.remove(audit_info).did_execute() {
+            self.beneficiaries.update(&mut beneficiary).await?;
+        }
+        Ok(beneficiary)
+    }
+
+    #[instrument(
+        name = "deposit.ensure_up_to_date_beneficiary_status",
+        skip(self, beneficiary),
+        err
+    )]
+    pub async fn ensure_up_to_date_beneficiary_status(
+        &self,
+        beneficiary: &Beneficiary,
+    ) -> Result<Option<Beneficiary>, CoreDepositError> {
+        Ok(self
+            .approve_beneficiary
+            .execute_from_svc(beneficiary)
+            .await?)
+    }
+
+    #[instrument(name = "deposit.find_all_beneficiaries", skip(self), err)]
+    pub async fn find_all_beneficiaries<T: From<Beneficiary>>(
+        &self,
+        ids: &[BeneficiaryId],
+    ) -> Result<std::collections::HashMap<BeneficiaryId, T>, CoreDepositError> {
+        Ok(self.beneficiaries.find_all(ids).await?)
+    }
+
+    /// Imports the booked credits of a camt.053 statement or camt.054
+    /// notification. Lines already imported are skipped, lines matching an
+    /// active deposit account are recorded as deposits right away and the
+    /// rest are left unmatched for manual assignment.
+    #[instrument(name = "deposit.import_bank_statement", skip(self, xml), err)]
+    pub async fn import_bank_statement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        xml: &str,
+    ) -> Result<Vec<BankStatementLine>, CoreDepositError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_bank_statement_lines(),
+                CoreDepositAction::BANK_STATEMENT_LINE_IMPORT,
+            )
+            .await?;
+        let credit_lines = parse_credit_lines(xml)?;
+
+        let mut imported = vec![];
+        for credit_line in credit_lines {
+            match self
+                .bank_statement_lines
+                .find_by_reference(credit_line.reference.clone())
+                .await
+            {
+                Ok(_) => continue,
+                Err(e) if e.was_not_found() => (),
+                Err(e) => return Err(e.into()),
+            }
+
+            let new_line = NewBankStatementLine::builder()
+                .id(BankStatementLineId::new())
+                .line(credit_line)
+                .audit_info(audit_info.clone())
+                .build()?;
+            let mut op = self.bank_statement_lines.begin_op().await?;
+            let mut line = self
+                .bank_statement_lines
+                .create_in_op(&mut op, new_line)
+                .await?;
+            match self.match_bank_statement_line(&line).await? {
+                Some(account) => {
+                    self.record_bank_statement_deposit(
+                        op,
+                        &mut line,
+                        account.id,
+                        true,
+                        audit_info.clone(),
+                    )
+                    .await?
+                }
+                None => op.commit().await?,
+            }
+            imported.push(line);
+        }
+        Ok(imported)
+    }
+
+    #[instrument(name = "deposit.assign_bank_statement_line", skip(self), err)]
+    pub async fn assign_bank_statement_line(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        bank_statement_line_id: impl Into<BankStatementLineId> + std::fmt::Debug,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<BankStatementLine, CoreDepositError> {
+        let id = bank_statement_line_id.into();
+        let deposit_account_id = deposit_account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::bank_statement_line(id),
+                CoreDepositAction::BANK_STATEMENT_LINE_ASSIGN,
+            )
+            .await?;
+        self.check_account_active(deposit_account_id).await?;
+
+        let mut line = self.bank_statement_lines.find_by_id(id).await?;
+        let op = self.bank_statement_lines.begin_op().await?;
+        self.record_bank_statement_deposit(op, &mut line, deposit_account_id, false, audit_info)
+            .await?;
+        Ok(line)
+    }
+
+    #[instrument(name = "deposit.find_bank_statement_line_by_id", skip(self), err)]
+    pub async fn find_bank_statement_line_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<BankStatementLineId> + std::fmt::Debug,
+    ) -> Result<Option<BankStatementLine>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::bank_statement_line(id),
+                CoreDepositAction::BANK_STATEMENT_LINE_READ,
+            )
+            .await?;
+
+        match self.bank_statement_lines.find_by_id(id).await {
+            Ok(line) => Ok(Some(line)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.list_bank_statement_lines", skip(self), err)]
+    pub async fn list_bank_statement_lines(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        status: BankStatementLineStatus,
+        query: es_entity::PaginatedQueryArgs<BankStatementLinesByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<BankStatementLine, BankStatementLinesByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_bank_statement_lines(),
+                CoreDepositAction::BANK_STATEMENT_LINE_LIST,
+            )
+            .await?;
+        Ok(self
+            .bank_statement_lines
+            .list_for_status_by_created_at(status, query, es_entity::ListDirection::Descending)
+            .await?)
+    }
+
+    #[instrument(name = "deposit.find_all_bank_statement_lines", skip(self), err)]
+    pub async fn find_all_bank_statement_lines<T: From<BankStatementLine>>(
+        &self,
+        ids: &[BankStatementLineId],
+    ) -> Result<std::collections::HashMap<BankStatementLineId, T>, CoreDepositError> {
+        Ok(self.bank_statement_lines.find_all(ids).await?)
+    }
+
+    /// Writes confirmed withdrawals to beneficiaries that have not been paid
+    /// out yet into a pain.001 file for the bank. Each withdrawal is marked
+    /// with the batch in the same transaction, so a concurrent run fails
+    /// instead of paying it twice.
+    #[instrument(name = "deposit.generate_payment_batch", skip(self), err)]
+    pub async fn generate_payment_batch(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    ) -> Result<PaymentBatch, CoreDepositError> {
+        let payment_initiation = self
+            .config
+            .payment_initiation
+            .as_ref()
+            .ok_or(CoreDepositError::PaymentInitiationNotConfigured)?;
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_payment_batches(),
+                CoreDepositAction::PAYMENT_BATCH_GENERATE,
+            )
+            .await?;
+
+        let mut withdrawals = self
+            .withdrawals
+            .list_unbatched_confirmed(payment_initiation.max_transactions_per_file)
+            .await?;
+        if withdrawals.is_empty() {
+            return Err(CoreDepositError::NoWithdrawalsToPay);
+        }
+        let beneficiary_ids = withdrawals
+            .iter()
+            .filter_map(|withdrawal| withdrawal.beneficiary_id)
+            .collect::<Vec<_>>();
+        let beneficiaries: std::collections::HashMap<BeneficiaryId, Beneficiary> =
+            self.beneficiaries.find_all(&beneficiary_ids).await?;
+        let transfers = withdrawals
+            .iter()
+            .map(|withdrawal| {
+                let beneficiary = withdrawal
+                    .beneficiary_id
+                    .and_then(|id| beneficiaries.get(&id))
+                    .ok_or(withdrawal::error::WithdrawalError::MissingBeneficiary(
+                        withdrawal.id,
+                    ))?;
+                Ok(CreditTransfer {
+                    end_to_end_id: uuid::Uuid::from(withdrawal.id).simple().to_string(),
+                    amount: withdrawal.amount,
+                    creditor: beneficiary.bank_account.clone(),
+                    remittance_information: withdrawal.reference.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, CoreDepositError>>()?;
+
+        let payment_batch_id = PaymentBatchId::new();
+        let now = crate::time::now();
+        let initiation = CreditTransferInitiation {
+            message_id: payment_batch::message_id(payment_batch_id),
+            created_at: now,
+            requested_execution_date: now.date_naive(),
+            debtor: payment_initiation,
+            transfers: &transfers,
+        };
+        let xml = initiation.to_xml();
+
+        let mut op = self.payment_batches.begin_op().await?;
+        let mut document = self
+            .document_storage
+            .create_in_op(
+                audit_info.clone(),
+                format!("pain001-{}.xml", initiation.message_id),
+                "application/xml",
+                ReferenceId::from(uuid::Uuid::from(payment_batch_id)),
+                WITHDRAWAL_PAYMENT_FILE_DOCUMENT,
+                &mut op,
+            )
+            .await?;
+
+        for withdrawal in withdrawals.iter_mut() {
+            withdrawal.include_in_payment_batch(payment_batch_id, audit_info.clone())?;
+            self.withdrawals.update_in_op(&mut op, withdrawal).await?;
+        }
+
+        let new_batch = NewPaymentBatch::builder()
+            .id(payment_batch_id)
+            .withdrawal_ids(withdrawals.iter().map(|withdrawal| withdrawal.id).collect())
+            .total_amount(initiation.control_sum())
+            .requested_execution_date(initiation.requested_execution_date)
+            .document_id(document.id)
+            .audit_info(audit_info)
+            .build()?;
+        let batch = self
+            .payment_batches
+            .create_in_op(&mut op, new_batch)
+            .await?;
+
+        self.document_storage
+            .upload_in_op(xml.into_bytes(), &mut document, &mut op)
+            .await?;
+        op.commit().await?;
+        Ok(batch)
+    }
+
+    #[instrument(name = "deposit.find_payment_batch_by_id", skip(self), err)]
+    pub async fn find_payment_batch_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<PaymentBatchId> + std::fmt::Debug,
+    ) -> Result<Option<PaymentBatch>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::payment_batch(id),
+                CoreDepositAction::PAYMENT_BATCH_READ,
+            )
+            .await?;
+
+        match self.payment_batches.find_by_id(id).await {
+            Ok(batch) => Ok(Some(batch)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.list_payment_batches", skip(self), err)]
+    pub async fn list_payment_batches(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<PaymentBatchesByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<PaymentBatch, PaymentBatchesByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_payment_batches(),
+                CoreDepositAction::PAYMENT_BATCH_LIST,
+            )
+            .await?;
+        Ok(self
+            .payment_batches
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await?)
+    }
+
+    #[instrument(name = "deposit.find_all_payment_batches", skip(self), err)]
+    pub async fn find_all_payment_batches<T: From<PaymentBatch>>(
+        &self,
+        ids: &[PaymentBatchId],
+    ) -> Result<std::collections::HashMap<PaymentBatchId, T>, CoreDepositError> {
+        Ok(self.payment_batches.find_all(ids).await?)
+    }
+
+    #[instrument(name = "deposit.generate_payment_file_download_link", skip(self), err)]
+    pub async fn generate_payment_file_download_link(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        payment_batch_id: impl Into<PaymentBatchId> + std::fmt::Debug,
+    ) -> Result<GeneratedDocumentDownloadLink, CoreDepositError> {
+        let payment_batch_id = payment_batch_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::payment_batch(payment_batch_id),
+                CoreDepositAction::PAYMENT_BATCH_GENERATE_DOWNLOAD_LINK,
+            )
+            .await?;
+        let batch = self.payment_batches.find_by_id(payment_batch_id).await?;
+        Ok(self
+            .document_storage
+            .generate_download_link(audit_info, batch.document_id)
+            .await?)
+    }
+
+    pub async fn get_chart_of_accounts_integration_config(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+    ) -> Result<Option<ChartOfAccountsIntegrationConfig>, CoreDepositError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::chart_of_accounts_integration(),
+                CoreDepositAction::CHART_OF_ACCOUNTS_INTEGRATION_CONFIG_READ,
+            )
+            .await?;
+        Ok(self
+            .ledger
+            .get_chart_of_accounts_integration_config()
+            .await?)
+    }
+
+    pub async fn set_chart_of_accounts_integration_config(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart: &Chart,
+        config: ChartOfAccountsIntegrationConfig,
+    ) -> Result<ChartOfAccountsIntegrationConfig, CoreDepositError> {
+        if chart.id != config.chart_of_accounts_id {
+            return Err(CoreDepositError::ChartIdMismatch);
+        }
+
+        if self
+            .ledger
+            .get_chart_of_accounts_integration_config()
+            .await?
+            .is_some()
+        {
+            return Err(CoreDepositError::DepositConfigAlreadyExists);
+        }
+
+        let individual_deposit_accounts_parent_account_set_id = chart.account_set_id_from_code(
+            &config.chart_of_accounts_individual_deposit_accounts_parent_code,
+        )?;
+        let government_entity_deposit_accounts_parent_account_set_id = chart
+            .account_set_id_from_code(
+                &config.chart_of_accounts_government_entity_deposit_accounts_parent_code,
+            )?;
+        let private_company_deposit_accounts_parent_account_set_id = chart
+            .account_set_id_from_code(
+                &config.chart_of_account_private_company_deposit_accounts_parent_code,
+            )?;
+        let bank_deposit_accounts_parent_account_set_id = chart
+            .account_set_id_from_code(&config.chart_of_account_bank_deposit_accounts_parent_code)?;
+        let financial_institution_deposit_accounts_parent_account_set_id = chart
+            .account_set_id_from_code(
+                &config.chart_of_account_financial_institution_deposit_accounts_parent_code,
+            )?;
+        let non_domiciled_individual_deposit_accounts_parent_account_set_id = chart
+            .account_set_id_from_code(
+                &config.chart_of_account_non_domiciled_individual_deposit_accounts_parent_code,
+            )?;
+
+        let omnibus_parent_account_set_id =
+            chart.account_set_id_from_code(&config.chart_of_accounts_omnibus_parent_code)?;
+        let interest_expense_parent_account_set_id = chart
+            .account_set_id_from_code(&config.chart_of_accounts_interest_expense_parent_code)?;
+        let fee_income_parent_account_set_id =
+            chart.account_set_id_from_code(&config.chart_of_accounts_fee_income_parent_code)?;
+
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::chart_of_accounts_integration(),
+                CoreDepositAction::CHART_OF_ACCOUNTS_INTEGRATION_CONFIG_UPDATE,
+            )
+            .await?;
+
+        let charts_integration_meta = ChartOfAccountsIntegrationMeta {
+            audit_info,
+            config: config.clone(),
+            omnibus_parent_account_set_id,
+            interest_expense_parent_account_set_id,
+            fee_income_parent_account_set_id,
+            individual_deposit_accounts_parent_account_set_id,
+            government_entity_deposit_accounts_parent_account_set_id,
+            private_company_deposit_accounts_parent_account_set_id,
+            bank_deposit_accounts_parent_account_set_id,
+            financial_institution_deposit_accounts_parent_account_set_id,
+            non_domiciled_individual_deposit_accounts_parent_account_set_id,
+        };
+
+        self.ledger
+            .attach_chart_of_accounts_account_sets(charts_integration_meta)
+            .await?;
+
+        Ok(config)
+    }
+
+    async fn effective_withdrawal_limits(
+        &self,
+        account: &DepositAccount,
+    ) -> Result<WithdrawalLimits, CoreDepositError> {
+        if let Some(withdrawal_limits) = account.withdrawal_limits_override() {
+            return Ok(withdrawal_limits);
+        }
+        match account.deposit_product_id {
+            Some(product_id) => Ok(self
+                .products
+                .find_by_id(product_id)
+                .await?
+                .withdrawal_limits),
+            None => Ok(WithdrawalLimits::default()),
+        }
+    }
+
+    async fn withdrawal_fee_for(
+        &self,
+        account: &DepositAccount,
+        amount: UsdCents,
+    ) -> Result<UsdCents, CoreDepositError> {
+        match account.deposit_product_id {
+            Some(product_id) => Ok(self
+                .products
+                .find_by_id(product_id)
+                .await?
+                .withdrawal_fee_for(amount)),
+            None => Ok(UsdCents::ZERO),
+        }
+    }
+
+    async fn withdrawal_limit_usage_for(
+        &self,
+        account_id: DepositAccountId,
+        withdrawal_limits: WithdrawalLimits,
+    ) -> Result<Vec<WithdrawalLimitUsage>, CoreDepositError> {
+        let now = time::now();
+        let earliest_window_start = WithdrawalLimitPeriod::ALL
+            .into_iter()
+            .map(|period| period.window_start(now))
+            .min()
+            .expect("at least one period");
+
+        let mut withdrawals = Vec::new();
+        let mut has_next_page = true;
+        let mut after = None;
+        while has_next_page {
+            let page = self
+                .withdrawals
+                .list_for_deposit_account_id_by_created_at(
+                    account_id,
+                    es_entity::PaginatedQueryArgs { first: 50, after },
+                    es_entity::ListDirection::Descending,
+                )
+                .await?;
+            (after, has_next_page) = (page.end_cursor, page.has_next_page);
+
+            for withdrawal in page.entities {
+                if withdrawal.created_at() < earliest_window_start {
+                    has_next_page = false;
+                    break;
+                }
+                withdrawals.push((withdrawal.created_at(), withdrawal.amount));
+            }
+        }
+
+        Ok(withdrawal_limits.usage(now, &withdrawals))
+    }
+
+    async fn ensure_no_pending_withdrawals(
+        &self,
+        deposit_account_id: DepositAccountId,
+    ) -> Result<(), CoreDepositError> {
+        let mut has_next_page = true;
+        let mut after = None;
+        while has_next_page {
+            let withdrawals = self
+                .withdrawals
+                .list_for_deposit_account_id_by_created_at(
+                    deposit_account_id,
+                    es_entity::PaginatedQueryArgs { first: 100, after },
+                    es_entity::ListDirection::Descending,
+                )
+                .await?;
+            (after, has_next_page) = (withdrawals.end_cursor, withdrawals.has_next_page);
+            if withdrawals.entities.iter().any(|withdrawal| {
+                matches!(
+                    withdrawal.status(),
+                    WithdrawalStatus::PendingApproval | WithdrawalStatus::PendingConfirmation
+                )
+            }) {
+                return Err(CoreDepositError::DepositAccountHasPendingWithdrawals);
+            }
+        }
+        Ok(())
+    }
+
+    async fn check_beneficiary_usable(
+        &self,
+        deposit_account_id: DepositAccountId,
+        beneficiary_id: BeneficiaryId,
+    ) -> Result<(), CoreDepositError> {
+        let beneficiary = self.beneficiaries.find_by_id(beneficiary_id).await?;
+        if beneficiary.deposit_account_id != deposit_account_id {
+            return Err(CoreDepositError::BeneficiaryAccountMismatch);
+        }
+        let beneficiary = self
+            .approve_beneficiary
+            .execute_from_svc(&beneficiary)
+            .await?
+            .unwrap_or(beneficiary);
+        match beneficiary.status() {
+            BeneficiaryStatus::Active => Ok(()),
+            status => Err(CoreDepositError::BeneficiaryNotUsable(status)),
+        }
+    }
+
+    async fn check_account_active(
+        &self,
+        deposit_account_id: DepositAccountId,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account = self.accounts.find_by_id(deposit_account_id).await?;
+        if account.is_closed() {
+            return Err(CoreDepositError::DepositAccountClosed);
+        }
+        if account.status.is_inactive() {
+            return Err(CoreDepositError::DepositAccountNotActive);
+        }
+        Ok(account)
+    }
+
+    /// Finds the active deposit account a bank statement line was sent to.
+    /// A candidate pointing at an inactive account stops the search so the
+    /// line is reviewed manually instead of credited elsewhere.
+    async fn match_bank_statement_line(
+        &self,
+        line: &BankStatementLine,
+    ) -> Result<Option<DepositAccount>, CoreDepositError> {
+        for candidate in line.match_candidates() {
+            match self
+                .accounts
+                .find_by_public_id(public_id::PublicId::new(candidate))
+                .await
+            {
+                Ok(account) if account.is_closed() || account.status.is_inactive() => {
+                    return Ok(None);
+                }
+                Ok(account) => return Ok(Some(account)),
+                Err(e) if e.was_not_found() => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(None)
+    }
+
+    async fn record_bank_statement_deposit(
+        &self,
+        mut op: es_entity::DbOp<'_>,
+        line: &mut BankStatementLine,
+        deposit_account_id: DepositAccountId,
+        matched_on_import: bool,
+        audit_info: audit::AuditInfo,
+    ) -> Result<(), CoreDepositError> {
+        let deposit_id = DepositId::new();
+        if line
+            .record_deposit(
+                deposit_id,
+                deposit_account_id,
+                matched_on_import,
+                audit_info.clone(),
+            )
+            .was_ignored()
+        {
+            return Err(CoreDepositError::BankStatementLineAlreadyRecorded);
+        }
+        let new_deposit = NewDeposit::builder()
+            .id(deposit_id)
+            .ledger_transaction_id(deposit_id)
+            .deposit_account_id(deposit_account_id)
+            .amount(line.amount)
+            .reference(Some(line.reference.clone()))
+            .audit_info(audit_info)
+            .build()?;
+
+        self.deposits.create_in_op(&mut op, new_deposit).await?;
+        self.bank_statement_lines
+            .update_in_op(&mut op, line)
+            .await?;
+        self.ledger
+            .record_deposit(op, deposit_id, line.amount, deposit_account_id)
+            .await?;
         Ok(())
     }
 }
@@ -0,0 +1,116 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use derive_builder::Builder;
+use document_storage::DocumentId;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{PaymentBatchId, UsdCents, WithdrawalId};
+use audit::AuditInfo;
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "PaymentBatchId")]
+pub enum PaymentBatchEvent {
+    Initialized {
+        id: PaymentBatchId,
+        withdrawal_ids: Vec<WithdrawalId>,
+        total_amount: UsdCents,
+        requested_execution_date: NaiveDate,
+        document_id: DocumentId,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct PaymentBatch {
+    pub id: PaymentBatchId,
+    pub withdrawal_ids: Vec<WithdrawalId>,
+    pub total_amount: UsdCents,
+    pub requested_execution_date: NaiveDate,
+    /// The pain.001 file handed to the bank.
+    pub document_id: DocumentId,
+
+    events: EntityEvents<PaymentBatchEvent>,
+}
+
+impl PaymentBatch {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for payment batch")
+    }
+
+    /// `MsgId` of the payment file the bank reports back against.
+    pub fn message_id(&self) -> String {
+        message_id(self.id)
+    }
+}
+
+pub(crate) fn message_id(id: PaymentBatchId) -> String {
+    uuid::Uuid::from(id).simple().to_string()
+}
+
+impl TryFromEvents<PaymentBatchEvent> for PaymentBatch {
+    fn try_from_events(events: EntityEvents<PaymentBatchEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = PaymentBatchBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                PaymentBatchEvent::Initialized {
+                    id,
+                    withdrawal_ids,
+                    total_amount,
+                    requested_execution_date,
+                    document_id,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .withdrawal_ids(withdrawal_ids.clone())
+                        .total_amount(*total_amount)
+                        .requested_execution_date(*requested_execution_date)
+                        .document_id(*document_id)
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewPaymentBatch {
+    #[builder(setter(into))]
+    pub(super) id: PaymentBatchId,
+    pub(super) withdrawal_ids: Vec<WithdrawalId>,
+    pub(super) total_amount: UsdCents,
+    pub(super) requested_execution_date: NaiveDate,
+    pub(super) document_id: DocumentId,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewPaymentBatch {
+    pub fn builder() -> NewPaymentBatchBuilder {
+        NewPaymentBatchBuilder::default()
+    }
+}
+
+impl IntoEvents<PaymentBatchEvent> for NewPaymentBatch {
+    fn into_events(self) -> EntityEvents<PaymentBatchEvent> {
+        EntityEvents::init(
+            self.id,
+            [PaymentBatchEvent::Initialized {
+                id: self.id,
+                withdrawal_ids: self.withdrawal_ids,
+                total_amount: self.total_amount,
+                requested_execution_date: self.requested_execution_date,
+                document_id: self.document_id,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
@@ -0,0 +1,17 @@
+mod entity;
+pub mod error;
+mod pain001;
+mod repo;
+
+pub use entity::PaymentBatch;
+#[cfg(feature = "json-schema")]
+pub use entity::PaymentBatchEvent;
+pub(crate) use entity::*;
+pub(crate) use pain001::*;
+pub use repo::payment_batch_cursor::PaymentBatchesByCreatedAtCursor;
+pub(crate) use repo::*;
+
+use document_storage::DocumentType;
+
+pub const WITHDRAWAL_PAYMENT_FILE_DOCUMENT: DocumentType =
+    DocumentType::new("withdrawal_payment_file");
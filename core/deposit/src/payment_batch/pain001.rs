@@ -0,0 +1,320 @@
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+
+use crate::{beneficiary::BeneficiaryBankAccount, config::PaymentInitiationConfig, primitives::*};
+
+const PAIN_001_NAMESPACE: &str = "urn:iso:std:iso:20022:tech:xsd:pain.001.001.09";
+const USD: &str = "USD";
+const MAX_ID_LENGTH: usize = 35;
+const MAX_TEXT_LENGTH: usize = 140;
+
+/// A single wire to a withdrawal beneficiary.
+#[derive(Debug, Clone)]
+pub struct CreditTransfer {
+    /// Identifies the withdrawal end to end, echoed back by the bank in its
+    /// camt.054 debit notifications.
+    pub end_to_end_id: String,
+    pub amount: UsdCents,
+    pub creditor: BeneficiaryBankAccount,
+    pub remittance_information: String,
+}
+
+/// A pain.001 customer credit transfer initiation paying out all transfers
+/// from the bank's own account in a single payment information block.
+pub struct CreditTransferInitiation<'a> {
+    pub message_id: String,
+    pub created_at: DateTime<Utc>,
+    pub requested_execution_date: NaiveDate,
+    pub debtor: &'a PaymentInitiationConfig,
+    pub transfers: &'a [CreditTransfer],
+}
+
+impl CreditTransferInitiation<'_> {
+    pub fn control_sum(&self) -> UsdCents {
+        self.transfers
+            .iter()
+            .fold(UsdCents::ZERO, |sum, transfer| sum + transfer.amount)
+    }
+
+    pub fn to_xml(&self) -> String {
+        let number_of_transactions = self.transfers.len().to_string();
+        let control_sum = amount(self.control_sum());
+
+        let mut xml = XmlWriter::default();
+        xml.declaration();
+        xml.open_with_attr("Document", "xmlns", PAIN_001_NAMESPACE);
+        xml.open("CstmrCdtTrfInitn");
+
+        xml.open("GrpHdr");
+        xml.element("MsgId", &truncate(&self.message_id, MAX_ID_LENGTH));
+        xml.element(
+            "CreDtTm",
+            &self.created_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+        );
+        xml.element("NbOfTxs", &number_of_transactions);
+        xml.element("CtrlSum", &control_sum);
+        xml.open("InitgPty");
+        xml.element("Nm", &truncate(&self.debtor.debtor_name, MAX_TEXT_LENGTH));
+        xml.close("InitgPty");
+        xml.close("GrpHdr");
+
+        xml.open("PmtInf");
+        xml.element("PmtInfId", &truncate(&self.message_id, MAX_ID_LENGTH));
+        xml.element("PmtMtd", "TRF");
+        xml.element("NbOfTxs", &number_of_transactions);
+        xml.element("CtrlSum", &control_sum);
+        xml.open("ReqdExctnDt");
+        xml.element("Dt", &self.requested_execution_date.to_string());
+        xml.close("ReqdExctnDt");
+        xml.open("Dbtr");
+        xml.element("Nm", &truncate(&self.debtor.debtor_name, MAX_TEXT_LENGTH));
+        xml.close("Dbtr");
+        xml.open("DbtrAcct");
+        account_identification(&mut xml, &self.debtor.debtor_account);
+        xml.element("Ccy", USD);
+        xml.close("DbtrAcct");
+        xml.open("DbtrAgt");
+        xml.open("FinInstnId");
+        xml.element("BICFI", self.debtor.debtor_agent_bic.trim());
+        xml.close("FinInstnId");
+        xml.close("DbtrAgt");
+
+        for transfer in self.transfers {
+            xml.open("CdtTrfTxInf");
+            xml.open("PmtId");
+            xml.element(
+                "EndToEndId",
+                &truncate(&transfer.end_to_end_id, MAX_ID_LENGTH),
+            );
+            xml.close("PmtId");
+            xml.open("Amt");
+            xml.element_with_attr("InstdAmt", "Ccy", USD, &amount(transfer.amount));
+            xml.close("Amt");
+            xml.open("CdtrAgt");
+            xml.open("FinInstnId");
+            xml.element("BICFI", transfer.creditor.bank_identifier.trim());
+            xml.element(
+                "Nm",
+                &truncate(&transfer.creditor.bank_name, MAX_TEXT_LENGTH),
+            );
+            xml.close("FinInstnId");
+            xml.close("CdtrAgt");
+            xml.open("Cdtr");
+            xml.element(
+                "Nm",
+                &truncate(&transfer.creditor.account_holder_name, MAX_TEXT_LENGTH),
+            );
+            xml.close("Cdtr");
+            xml.open("CdtrAcct");
+            account_identification(&mut xml, &transfer.creditor.account_number);
+            xml.close("CdtrAcct");
+            xml.open("RmtInf");
+            xml.element(
+                "Ustrd",
+                &truncate(&transfer.remittance_information, MAX_TEXT_LENGTH),
+            );
+            xml.close("RmtInf");
+            xml.close("CdtTrfTxInf");
+        }
+
+        xml.close("PmtInf");
+        xml.close("CstmrCdtTrfInitn");
+        xml.close("Document");
+        xml.finish()
+    }
+}
+
+/// Writes an IBAN when the account number is one and falls back to a
+/// domestic account number otherwise.
+fn account_identification(xml: &mut XmlWriter, account_number: &str) {
+    let compact = account_number
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_uppercase();
+    xml.open("Id");
+    if is_iban(&compact) {
+        xml.element("IBAN", &compact);
+    } else {
+        xml.open("Othr");
+        xml.element("Id", &truncate(account_number.trim(), 34));
+        xml.close("Othr");
+    }
+    xml.close("Id");
+}
+
+fn is_iban(account_number: &str) -> bool {
+    let bytes = account_number.as_bytes();
+    (15..=34).contains(&bytes.len())
+        && bytes[..2].iter().all(u8::is_ascii_uppercase)
+        && bytes[2..4].iter().all(u8::is_ascii_digit)
+        && bytes.iter().all(u8::is_ascii_alphanumeric)
+}
+
+fn amount(amount: UsdCents) -> String {
+    format!("{:.2}", amount.to_usd())
+}
+
+fn truncate(value: &str, max_chars: usize) -> String {
+    value.trim().chars().take(max_chars).collect()
+}
+
+#[derive(Default)]
+struct XmlWriter {
+    out: String,
+    depth: usize,
+}
+
+impl XmlWriter {
+    fn declaration(&mut self) {
+        self.out
+            .push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    }
+
+    fn open(&mut self, tag: &str) {
+        self.indent();
+        self.out.push_str(&format!("<{tag}>\n"));
+        self.depth += 1;
+    }
+
+    fn open_with_attr(&mut self, tag: &str, attr: &str, value: &str) {
+        self.indent();
+        self.out
+            .push_str(&format!("<{tag} {attr}=\"{}\">\n", escape(value)));
+        self.depth += 1;
+    }
+
+    fn close(&mut self, tag: &str) {
+        self.depth -= 1;
+        self.indent();
+        self.out.push_str(&format!("</{tag}>\n"));
+    }
+
+    fn element(&mut self, tag: &str, text: &str) {
+        self.indent();
+        self.out
+            .push_str(&format!("<{tag}>{}</{tag}>\n", escape(text)));
+    }
+
+    fn element_with_attr(&mut self, tag: &str, attr: &str, value: &str, text: &str) {
+        self.indent();
+        self.out.push_str(&format!(
+            "<{tag} {attr}=\"{}\">{}</{tag}>\n",
+            escape(value),
+            escape(text)
+        ));
+    }
+
+    fn indent(&mut self) {
+        for _ in 0..self.depth {
+            self.out.push_str("  ");
+        }
+    }
+
+    fn finish(self) -> String {
+        self.out
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn debtor() -> PaymentInitiationConfig {
+        PaymentInitiationConfig {
+            debtor_name: "Lana Bank".to_string(),
+            debtor_account: "123456789".to_string(),
+            debtor_agent_bic: "LANAUS33".to_string(),
+            max_transactions_per_file: 500,
+        }
+    }
+
+    fn transfer(account_number: &str, amount: u64) -> CreditTransfer {
+        CreditTransfer {
+            end_to_end_id: "9f1c4a2b7d3e4f5a8b6c1d2e3f4a5b6c".to_string(),
+            amount: UsdCents::from(amount),
+            creditor: BeneficiaryBankAccount {
+                account_holder_name: "Smith & Sons <Ltd>".to_string(),
+                account_number: account_number.to_string(),
+                bank_name: "Receiving Bank".to_string(),
+                bank_identifier: "RCVBDEFF".to_string(),
+            },
+            remittance_information: "WD-1".to_string(),
+        }
+    }
+
+    fn text<'a>(node: roxmltree::Node<'a, 'a>, path: &[&str]) -> Option<&'a str> {
+        let mut node = node;
+        for name in path {
+            node = node.children().find(|n| n.tag_name().name() == *name)?;
+        }
+        node.text()
+    }
+
+    #[test]
+    fn writes_group_header_and_transfers() {
+        let debtor = debtor();
+        let transfers = [
+            transfer("DE89 3704 0044 0532 0130 00", 150_000),
+            transfer("000123456", 2_550),
+        ];
+        let initiation = CreditTransferInitiation {
+            message_id: "MSG-1".to_string(),
+            created_at: "2024-03-01T10:00:00Z".parse().unwrap(),
+            requested_execution_date: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            debtor: &debtor,
+            transfers: &transfers,
+        };
+        assert_eq!(initiation.control_sum(), UsdCents::from(152_550));
+
+        let xml = initiation.to_xml();
+        let doc = roxmltree::Document::parse(&xml).unwrap();
+        let root = doc.root_element();
+        assert_eq!(root.tag_name().namespace(), Some(PAIN_001_NAMESPACE));
+
+        let initn = root.first_element_child().unwrap();
+        assert_eq!(text(initn, &["GrpHdr", "NbOfTxs"]), Some("2"));
+        assert_eq!(text(initn, &["GrpHdr", "CtrlSum"]), Some("1525.50"));
+        assert_eq!(
+            text(initn, &["PmtInf", "DbtrAcct", "Id", "Othr", "Id"]),
+            Some("123456789")
+        );
+
+        let txs = initn
+            .descendants()
+            .filter(|n| n.tag_name().name() == "CdtTrfTxInf")
+            .collect::<Vec<_>>();
+        assert_eq!(txs.len(), 2);
+        assert_eq!(text(txs[0], &["Amt", "InstdAmt"]), Some("1500.00"));
+        assert_eq!(
+            text(txs[0], &["CdtrAcct", "Id", "IBAN"]),
+            Some("DE89370400440532013000")
+        );
+        assert_eq!(text(txs[0], &["Cdtr", "Nm"]), Some("Smith & Sons <Ltd>"));
+        assert_eq!(
+            text(txs[1], &["CdtrAcct", "Id", "Othr", "Id"]),
+            Some("000123456")
+        );
+    }
+
+    #[test]
+    fn iban_detection() {
+        assert!(is_iban("DE89370400440532013000"));
+        assert!(!is_iban("000123456"));
+        assert!(!is_iban("12DE370400440532013000"));
+    }
+}
@@ -0,0 +1,23 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::PaymentBatchId;
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "PaymentBatch",
+    err = "PaymentBatchError",
+    tbl_prefix = "core"
+)]
+pub struct PaymentBatchRepo {
+    pool: PgPool,
+}
+
+impl PaymentBatchRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
@@ -14,6 +14,7 @@ pub use public_id::PublicId;
 pub use cala_ledger::primitives::{
     AccountId as CalaAccountId, AccountSetId as CalaAccountSetId, EntryId as CalaEntryId,
     JournalId as CalaJournalId, TransactionId as CalaTransactionId,
+    VelocityControlId as CalaVelocityControlId,
 };
 
 es_entity::entity_id! {
@@ -21,6 +22,14 @@ es_entity::entity_id! {
     DepositAccountId,
     WithdrawalId,
     ChartOfAccountsIntegrationConfigId,
+    DepositProductId,
+    TimeDepositId,
+    TransferId,
+    HoldId,
+    DepositAccountStatementId,
+    BeneficiaryId,
+    BankStatementLineId,
+    PaymentBatchId,
     DepositId;
 
     DepositAccountHolderId => core_customer::CustomerId,
@@ -28,7 +37,13 @@ es_entity::entity_id! {
     DepositAccountId => public_id::PublicIdTargetId,
     DepositId => CalaTransactionId,
     WithdrawalId => CalaTransactionId,
-    WithdrawalId => ApprovalProcessId
+    WithdrawalId => ApprovalProcessId,
+    TimeDepositId => CalaAccountId,
+    TimeDepositId => CalaTransactionId,
+    TransferId => ApprovalProcessId,
+    TransferId => CalaTransactionId,
+    HoldId => CalaTransactionId,
+    BeneficiaryId => ApprovalProcessId
 }
 
 pub use core_customer::AccountStatus;
@@ -37,8 +52,16 @@ pub use core_money::UsdCents;
 pub type DepositAccountAllOrOne = AllOrOne<DepositAccountId>;
 pub type DepositAccountByHolderAllOrOne = AllOrOne<DepositAccountHolderId>;
 pub type DepositAllOrOne = AllOrOne<DepositId>;
+pub type DepositProductAllOrOne = AllOrOne<DepositProductId>;
 pub type ChartOfAccountsIntegrationConfigAllOrOne = AllOrOne<ChartOfAccountsIntegrationConfigId>;
 pub type WithdrawalAllOrOne = AllOrOne<WithdrawalId>;
+pub type TimeDepositAllOrOne = AllOrOne<TimeDepositId>;
+pub type TransferAllOrOne = AllOrOne<TransferId>;
+pub type HoldAllOrOne = AllOrOne<HoldId>;
+pub type DepositAccountStatementAllOrOne = AllOrOne<DepositAccountStatementId>;
+pub type BeneficiaryAllOrOne = AllOrOne<BeneficiaryId>;
+pub type BankStatementLineAllOrOne = AllOrOne<BankStatementLineId>;
+pub type PaymentBatchAllOrOne = AllOrOne<PaymentBatchId>;
 
 pub const PERMISSION_SET_DEPOSIT_VIEWER: &str = "deposit_viewer";
 pub const PERMISSION_SET_DEPOSIT_WRITER: &str = "deposit_writer";
@@ -60,6 +83,14 @@ pub enum CoreDepositObject {
     Deposit(DepositAllOrOne),
     ChartOfAccountsIntegrationConfig(ChartOfAccountsIntegrationConfigAllOrOne),
     Withdrawal(WithdrawalAllOrOne),
+    DepositProduct(DepositProductAllOrOne),
+    TimeDeposit(TimeDepositAllOrOne),
+    Transfer(TransferAllOrOne),
+    Hold(HoldAllOrOne),
+    DepositAccountStatement(DepositAccountStatementAllOrOne),
+    Beneficiary(BeneficiaryAllOrOne),
+    BankStatementLine(BankStatementLineAllOrOne),
+    PaymentBatch(PaymentBatchAllOrOne),
 }
 
 impl CoreDepositObject {
@@ -90,6 +121,70 @@ impl CoreDepositObject {
     pub fn chart_of_accounts_integration() -> Self {
         CoreDepositObject::ChartOfAccountsIntegrationConfig(AllOrOne::All)
     }
+
+    pub fn all_deposit_products() -> Self {
+        CoreDepositObject::DepositProduct(AllOrOne::All)
+    }
+
+    pub fn deposit_product(id: DepositProductId) -> Self {
+        CoreDepositObject::DepositProduct(AllOrOne::ById(id))
+    }
+
+    pub fn all_time_deposits() -> Self {
+        CoreDepositObject::TimeDeposit(AllOrOne::All)
+    }
+
+    pub fn time_deposit(id: TimeDepositId) -> Self {
+        CoreDepositObject::TimeDeposit(AllOrOne::ById(id))
+    }
+
+    pub fn all_transfers() -> Self {
+        CoreDepositObject::Transfer(AllOrOne::All)
+    }
+
+    pub fn transfer(id: TransferId) -> Self {
+        CoreDepositObject::Transfer(AllOrOne::ById(id))
+    }
+
+    pub fn all_holds() -> Self {
+        CoreDepositObject::Hold(AllOrOne::All)
+    }
+
+    pub fn hold(id: HoldId) -> Self {
+        CoreDepositObject::Hold(AllOrOne::ById(id))
+    }
+
+    pub fn all_deposit_account_statements() -> Self {
+        CoreDepositObject::DepositAccountStatement(AllOrOne::All)
+    }
+
+    pub fn deposit_account_statement(id: DepositAccountStatementId) -> Self {
+        CoreDepositObject::DepositAccountStatement(AllOrOne::ById(id))
+    }
+
+    pub fn all_beneficiaries() -> Self {
+        CoreDepositObject::Beneficiary(AllOrOne::All)
+    }
+
+    pub fn beneficiary(id: BeneficiaryId) -> Self {
+        CoreDepositObject::Beneficiary(AllOrOne::ById(id))
+    }
+
+    pub fn all_bank_statement_lines() -> Self {
+        CoreDepositObject::BankStatementLine(AllOrOne::All)
+    }
+
+    pub fn bank_statement_line(id: BankStatementLineId) -> Self {
+        CoreDepositObject::BankStatementLine(AllOrOne::ById(id))
+    }
+
+    pub fn all_payment_batches() -> Self {
+        CoreDepositObject::PaymentBatch(AllOrOne::All)
+    }
+
+    pub fn payment_batch(id: PaymentBatchId) -> Self {
+        CoreDepositObject::PaymentBatch(AllOrOne::ById(id))
+    }
 }
 
 impl Display for CoreDepositObject {
@@ -101,6 +196,14 @@ impl Display for CoreDepositObject {
             Deposit(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             Withdrawal(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             ChartOfAccountsIntegrationConfig(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            DepositProduct(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            TimeDeposit(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            Transfer(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            Hold(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            DepositAccountStatement(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            Beneficiary(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            BankStatementLine(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            PaymentBatch(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
         }
     }
 }
@@ -136,6 +239,54 @@ impl FromStr for CoreDepositObject {
                     .map_err(|_| "could not parse CoreDepositObject")?;
                 CoreDepositObject::ChartOfAccountsIntegrationConfig(obj_ref)
             }
+            DepositProduct => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::DepositProduct(obj_ref)
+            }
+            TimeDeposit => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::TimeDeposit(obj_ref)
+            }
+            Transfer => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::Transfer(obj_ref)
+            }
+            Hold => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::Hold(obj_ref)
+            }
+            DepositAccountStatement => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::DepositAccountStatement(obj_ref)
+            }
+            Beneficiary => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::Beneficiary(obj_ref)
+            }
+            BankStatementLine => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::BankStatementLine(obj_ref)
+            }
+            PaymentBatch => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::PaymentBatch(obj_ref)
+            }
         };
         Ok(res)
     }
@@ -149,6 +300,14 @@ pub enum CoreDepositAction {
     Deposit(DepositAction),
     ChartOfAccountsIntegrationConfig(ChartOfAccountsIntegrationConfigAction),
     Withdrawal(WithdrawalAction),
+    DepositProduct(DepositProductAction),
+    TimeDeposit(TimeDepositAction),
+    Transfer(TransferAction),
+    Hold(HoldAction),
+    DepositAccountStatement(DepositAccountStatementAction),
+    Beneficiary(BeneficiaryAction),
+    BankStatementLine(BankStatementLineAction),
+    PaymentBatch(PaymentBatchAction),
 }
 
 impl CoreDepositAction {
@@ -162,6 +321,22 @@ impl CoreDepositAction {
         CoreDepositAction::DepositAccount(DepositAccountAction::Read);
     pub const DEPOSIT_ACCOUNT_LIST: Self =
         CoreDepositAction::DepositAccount(DepositAccountAction::List);
+    pub const DEPOSIT_ACCOUNT_ASSIGN_PRODUCT: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::AssignProduct);
+    pub const DEPOSIT_ACCOUNT_ACCRUE_INTEREST: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::AccrueInterest);
+    pub const DEPOSIT_ACCOUNT_FREEZE: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::Freeze);
+    pub const DEPOSIT_ACCOUNT_UNFREEZE: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::Unfreeze);
+    pub const DEPOSIT_ACCOUNT_UPDATE_WITHDRAWAL_LIMITS: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::UpdateWithdrawalLimits);
+    pub const DEPOSIT_ACCOUNT_UPDATE_DORMANCY: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::UpdateDormancy);
+    pub const DEPOSIT_ACCOUNT_REACTIVATE: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::Reactivate);
+    pub const DEPOSIT_ACCOUNT_CLOSE: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::Close);
 
     pub const DEPOSIT_CREATE: Self = CoreDepositAction::Deposit(DepositAction::Create);
     pub const DEPOSIT_READ: Self = CoreDepositAction::Deposit(DepositAction::Read);
@@ -186,6 +361,67 @@ impl CoreDepositAction {
     pub const WITHDRAWAL_LIST: Self = CoreDepositAction::Withdrawal(WithdrawalAction::List);
     pub const WITHDRAWAL_REVERT: Self = CoreDepositAction::Withdrawal(WithdrawalAction::Revert);
 
+    pub const DEPOSIT_PRODUCT_CREATE: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::Create);
+    pub const DEPOSIT_PRODUCT_UPDATE: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::Update);
+    pub const DEPOSIT_PRODUCT_READ: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::Read);
+    pub const DEPOSIT_PRODUCT_LIST: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::List);
+
+    pub const TIME_DEPOSIT_OPEN: Self = CoreDepositAction::TimeDeposit(TimeDepositAction::Open);
+    pub const TIME_DEPOSIT_READ: Self = CoreDepositAction::TimeDeposit(TimeDepositAction::Read);
+    pub const TIME_DEPOSIT_LIST: Self = CoreDepositAction::TimeDeposit(TimeDepositAction::List);
+    pub const TIME_DEPOSIT_MATURE: Self = CoreDepositAction::TimeDeposit(TimeDepositAction::Mature);
+    pub const TIME_DEPOSIT_BREAK: Self = CoreDepositAction::TimeDeposit(TimeDepositAction::Break);
+
+    pub const TRANSFER_INITIATE: Self = CoreDepositAction::Transfer(TransferAction::Initiate);
+    pub const TRANSFER_CONCLUDE_APPROVAL_PROCESS: Self =
+        CoreDepositAction::Transfer(TransferAction::ConcludeApprovalProcess);
+    pub const TRANSFER_READ: Self = CoreDepositAction::Transfer(TransferAction::Read);
+    pub const TRANSFER_LIST: Self = CoreDepositAction::Transfer(TransferAction::List);
+
+    pub const HOLD_PLACE: Self = CoreDepositAction::Hold(HoldAction::Place);
+    pub const HOLD_RELEASE: Self = CoreDepositAction::Hold(HoldAction::Release);
+    pub const HOLD_EXPIRE: Self = CoreDepositAction::Hold(HoldAction::Expire);
+    pub const HOLD_READ: Self = CoreDepositAction::Hold(HoldAction::Read);
+    pub const HOLD_LIST: Self = CoreDepositAction::Hold(HoldAction::List);
+
+    pub const DEPOSIT_ACCOUNT_STATEMENT_GENERATE: Self =
+        CoreDepositAction::DepositAccountStatement(DepositAccountStatementAction::Generate);
+    pub const DEPOSIT_ACCOUNT_STATEMENT_LIST: Self =
+        CoreDepositAction::DepositAccountStatement(DepositAccountStatementAction::List);
+    pub const DEPOSIT_ACCOUNT_STATEMENT_GENERATE_DOWNLOAD_LINK: Self =
+        CoreDepositAction::DepositAccountStatement(
+            DepositAccountStatementAction::GenerateDownloadLink,
+        );
+
+    pub const BENEFICIARY_REGISTER: Self =
+        CoreDepositAction::Beneficiary(BeneficiaryAction::Register);
+    pub const BENEFICIARY_UPDATE: Self = CoreDepositAction::Beneficiary(BeneficiaryAction::Update);
+    pub const BENEFICIARY_REMOVE: Self = CoreDepositAction::Beneficiary(BeneficiaryAction::Remove);
+    pub const BENEFICIARY_CONCLUDE_APPROVAL_PROCESS: Self =
+        CoreDepositAction::Beneficiary(BeneficiaryAction::ConcludeApprovalProcess);
+    pub const BENEFICIARY_READ: Self = CoreDepositAction::Beneficiary(BeneficiaryAction::Read);
+    pub const BENEFICIARY_LIST: Self = CoreDepositAction::Beneficiary(BeneficiaryAction::List);
+
+    pub const BANK_STATEMENT_LINE_IMPORT: Self =
+        CoreDepositAction::BankStatementLine(BankStatementLineAction::Import);
+    pub const BANK_STATEMENT_LINE_ASSIGN: Self =
+        CoreDepositAction::BankStatementLine(BankStatementLineAction::Assign);
+    pub const BANK_STATEMENT_LINE_READ: Self =
+        CoreDepositAction::BankStatementLine(BankStatementLineAction::Read);
+    pub const BANK_STATEMENT_LINE_LIST: Self =
+        CoreDepositAction::BankStatementLine(BankStatementLineAction::List);
+
+    pub const PAYMENT_BATCH_GENERATE: Self =
+        CoreDepositAction::PaymentBatch(PaymentBatchAction::Generate);
+    pub const PAYMENT_BATCH_READ: Self = CoreDepositAction::PaymentBatch(PaymentBatchAction::Read);
+    pub const PAYMENT_BATCH_LIST: Self = CoreDepositAction::PaymentBatch(PaymentBatchAction::List);
+    pub const PAYMENT_BATCH_GENERATE_DOWNLOAD_LINK: Self =
+        CoreDepositAction::PaymentBatch(PaymentBatchAction::GenerateDownloadLink);
+
     pub fn entities() -> Vec<(
         CoreDepositActionDiscriminants,
         Vec<ActionDescription<NoPath>>,
@@ -202,6 +438,14 @@ impl CoreDepositAction {
                     ChartOfAccountsIntegrationConfigAction::describe()
                 }
                 Withdrawal => WithdrawalAction::describe(),
+                DepositProduct => DepositProductAction::describe(),
+                TimeDeposit => TimeDepositAction::describe(),
+                Transfer => TransferAction::describe(),
+                Hold => HoldAction::describe(),
+                DepositAccountStatement => DepositAccountStatementAction::describe(),
+                Beneficiary => BeneficiaryAction::describe(),
+                BankStatementLine => BankStatementLineAction::describe(),
+                PaymentBatch => PaymentBatchAction::describe(),
             };
 
             result.push((*entity, actions));
@@ -219,6 +463,14 @@ impl Display for CoreDepositAction {
             Deposit(action) => action.fmt(f),
             ChartOfAccountsIntegrationConfig(action) => action.fmt(f),
             Withdrawal(action) => action.fmt(f),
+            DepositProduct(action) => action.fmt(f),
+            TimeDeposit(action) => action.fmt(f),
+            Transfer(action) => action.fmt(f),
+            Hold(action) => action.fmt(f),
+            DepositAccountStatement(action) => action.fmt(f),
+            Beneficiary(action) => action.fmt(f),
+            BankStatementLine(action) => action.fmt(f),
+            PaymentBatch(action) => action.fmt(f),
         }
     }
 }
@@ -236,6 +488,18 @@ impl FromStr for CoreDepositAction {
                 CoreDepositAction::from(action.parse::<ChartOfAccountsIntegrationConfigAction>()?)
             }
             Withdrawal => CoreDepositAction::from(action.parse::<WithdrawalAction>()?),
+            DepositProduct => CoreDepositAction::from(action.parse::<DepositProductAction>()?),
+            TimeDeposit => CoreDepositAction::from(action.parse::<TimeDepositAction>()?),
+            Transfer => CoreDepositAction::from(action.parse::<TransferAction>()?),
+            Hold => CoreDepositAction::from(action.parse::<HoldAction>()?),
+            DepositAccountStatement => {
+                CoreDepositAction::from(action.parse::<DepositAccountStatementAction>()?)
+            }
+            Beneficiary => CoreDepositAction::from(action.parse::<BeneficiaryAction>()?),
+            BankStatementLine => {
+                CoreDepositAction::from(action.parse::<BankStatementLineAction>()?)
+            }
+            PaymentBatch => CoreDepositAction::from(action.parse::<PaymentBatchAction>()?),
         };
 
         Ok(res)
@@ -251,6 +515,14 @@ pub enum DepositAccountAction {
     ReadTxHistory,
     Read,
     List,
+    AssignProduct,
+    AccrueInterest,
+    Freeze,
+    Unfreeze,
+    UpdateWithdrawalLimits,
+    UpdateDormancy,
+    Reactivate,
+    Close,
 }
 
 impl DepositAccountAction {
@@ -279,6 +551,24 @@ impl DepositAccountAction {
                     variant,
                     &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
                 ),
+                Self::AssignProduct => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER])
+                }
+                Self::AccrueInterest => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER])
+                }
+                Self::Freeze => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Unfreeze => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::UpdateWithdrawalLimits => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER])
+                }
+                Self::UpdateDormancy => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER])
+                }
+                Self::Reactivate => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER])
+                }
+                Self::Close => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
             };
             res.push(action_description);
         }
@@ -411,6 +701,205 @@ impl From<ChartOfAccountsIntegrationConfigAction> for CoreDepositAction {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum DepositProductAction {
+    Create,
+    Update,
+    Read,
+    List,
+}
+
+impl DepositProductAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Create => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Update => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_VIEWER, PERMISSION_SET_DEPOSIT_WRITER],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
+                ),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<DepositProductAction> for CoreDepositAction {
+    fn from(action: DepositProductAction) -> Self {
+        CoreDepositAction::DepositProduct(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum TimeDepositAction {
+    Open,
+    Read,
+    List,
+    Mature,
+    Break,
+}
+
+impl TimeDepositAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Open => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_VIEWER, PERMISSION_SET_DEPOSIT_WRITER],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
+                ),
+                Self::Mature => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Break => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<TimeDepositAction> for CoreDepositAction {
+    fn from(action: TimeDepositAction) -> Self {
+        CoreDepositAction::TimeDeposit(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum TransferAction {
+    Initiate,
+    ConcludeApprovalProcess,
+    Read,
+    List,
+}
+
+impl TransferAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Initiate => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::ConcludeApprovalProcess => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER])
+                }
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_VIEWER, PERMISSION_SET_DEPOSIT_WRITER],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
+                ),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<TransferAction> for CoreDepositAction {
+    fn from(action: TransferAction) -> Self {
+        CoreDepositAction::Transfer(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum HoldAction {
+    Place,
+    Release,
+    Expire,
+    Read,
+    List,
+}
+
+impl HoldAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Place => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Release => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Expire => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_VIEWER, PERMISSION_SET_DEPOSIT_WRITER],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
+                ),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<HoldAction> for CoreDepositAction {
+    fn from(action: HoldAction) -> Self {
+        CoreDepositAction::Hold(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum DepositAccountStatementAction {
+    Generate,
+    List,
+    GenerateDownloadLink,
+}
+
+impl DepositAccountStatementAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Generate => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
+                ),
+                Self::GenerateDownloadLink => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
+                ),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<DepositAccountStatementAction> for CoreDepositAction {
+    fn from(action: DepositAccountStatementAction) -> Self {
+        CoreDepositAction::DepositAccountStatement(action)
+    }
+}
+
 pub enum DepositAccountType {
     Individual,
     GovernmentEntity,
@@ -441,3 +930,142 @@ pub enum DepositStatus {
     Confirmed,
     Reverted,
 }
+
+/// Where an account stands in the dormancy lifecycle. Variants are ordered
+/// by how long the account has gone without customer activity.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DormancyStatus {
+    Active,
+    Dormant,
+    /// Unclaimed for long enough that the balance has to be reported as
+    /// unclaimed property.
+    EscheatmentDue,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum BeneficiaryAction {
+    Register,
+    Update,
+    Remove,
+    ConcludeApprovalProcess,
+    Read,
+    List,
+}
+
+impl BeneficiaryAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Register => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Update => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Remove => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::ConcludeApprovalProcess => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER])
+                }
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_VIEWER, PERMISSION_SET_DEPOSIT_WRITER],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
+                ),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<BeneficiaryAction> for CoreDepositAction {
+    fn from(action: BeneficiaryAction) -> Self {
+        CoreDepositAction::Beneficiary(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum BankStatementLineAction {
+    Import,
+    Assign,
+    Read,
+    List,
+}
+
+impl BankStatementLineAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Import => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Assign => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_VIEWER, PERMISSION_SET_DEPOSIT_WRITER],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
+                ),
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<BankStatementLineAction> for CoreDepositAction {
+    fn from(action: BankStatementLineAction) -> Self {
+        CoreDepositAction::BankStatementLine(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum PaymentBatchAction {
+    Generate,
+    Read,
+    List,
+    GenerateDownloadLink,
+}
+
+impl PaymentBatchAction {
+    pub fn describe() -> Vec<ActionDescription<NoPath>> {
+        let mut res = vec![];
+
+        for variant in <Self as strum::VariantArray>::VARIANTS {
+            let action_description = match variant {
+                Self::Generate => ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER]),
+                Self::Read => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_VIEWER, PERMISSION_SET_DEPOSIT_WRITER],
+                ),
+                Self::List => ActionDescription::new(
+                    variant,
+                    &[PERMISSION_SET_DEPOSIT_WRITER, PERMISSION_SET_DEPOSIT_VIEWER],
+                ),
+                Self::GenerateDownloadLink => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_DEPOSIT_WRITER])
+                }
+            };
+            res.push(action_description);
+        }
+
+        res
+    }
+}
+
+impl From<PaymentBatchAction> for CoreDepositAction {
+    fn from(action: PaymentBatchAction) -> Self {
+        CoreDepositAction::PaymentBatch(action)
+    }
+}
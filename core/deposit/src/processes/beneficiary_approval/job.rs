@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use authz::PermissionCheck;
+use futures::StreamExt;
+
+use audit::AuditSvc;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::{Outbox, OutboxEventMarker};
+
+use crate::{CoreDepositAction, CoreDepositEvent, CoreDepositObject};
+
+use super::ApproveBeneficiary;
+
+#[derive(serde::Serialize)]
+pub struct BeneficiaryApprovalJobConfig<Perms, E> {
+    _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> BeneficiaryApprovalJobConfig<Perms, E> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Perms, E> JobConfig for BeneficiaryApprovalJobConfig<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+{
+    type Initializer = BeneficiaryApprovalInit<Perms, E>;
+}
+
+pub struct BeneficiaryApprovalInit<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+{
+    outbox: Outbox<E>,
+    process: ApproveBeneficiary<Perms, E>,
+}
+
+impl<Perms, E> BeneficiaryApprovalInit<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+{
+    pub fn new(outbox: &Outbox<E>, process: &ApproveBeneficiary<Perms, E>) -> Self {
+        Self {
+            process: process.clone(),
+            outbox: outbox.clone(),
+        }
+    }
+}
+
+const BENEFICIARY_APPROVE_JOB: JobType = JobType::new("beneficiary-approval");
+impl<Perms, E> JobInitializer for BeneficiaryApprovalInit<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        BENEFICIARY_APPROVE_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(BeneficiaryApprovalJobRunner {
+            outbox: self.outbox.clone(),
+            process: self.process.clone(),
+        }))
+    }
+
+    fn retry_on_error_settings() -> RetrySettings
+    where
+        Self: Sized,
+    {
+        RetrySettings::repeat_indefinitely()
+    }
+}
+
+#[derive(Default, Clone, Copy, serde::Deserialize, serde::Serialize)]
+struct BeneficiaryApprovalJobData {
+    sequence: outbox::EventSequence,
+}
+
+pub struct BeneficiaryApprovalJobRunner<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+{
+    outbox: Outbox<E>,
+    process: ApproveBeneficiary<Perms, E>,
+}
+#[async_trait]
+impl<Perms, E> JobRunner for BeneficiaryApprovalJobRunner<Perms, E>
+where
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+{
+    #[allow(clippy::single_match)]
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<BeneficiaryApprovalJobData>()?
+            .unwrap_or_default();
+        let mut stream = self.outbox.listen_persisted(Some(state.sequence)).await?;
+
+        while let Some(message) = stream.next().await {
+            match message.as_ref().as_event() {
+                Some(GovernanceEvent::ApprovalProcessConcluded {
+                    id,
+                    approved,
+                    process_type,
+                    ..
+                }) if process_type == &super::APPROVE_BENEFICIARY_PROCESS => {
+                    self.process.execute(*id, *approved).await?;
+                    state.sequence = message.sequence;
+                    current_job.update_execution_state(state).await?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(JobCompletion::RescheduleNow)
+    }
+}
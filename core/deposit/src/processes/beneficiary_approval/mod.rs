@@ -0,0 +1,142 @@
+mod job;
+
+use authz::PermissionCheck;
+use governance::{
+    ApprovalProcess, ApprovalProcessStatus, ApprovalProcessType, GovernanceAction, GovernanceEvent,
+    GovernanceObject,
+};
+use tracing::instrument;
+
+use audit::AuditSvc;
+use governance::Governance;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    BeneficiaryAction, CoreDepositAction, CoreDepositObject,
+    beneficiary::{Beneficiary, BeneficiaryRepo},
+    event::CoreDepositEvent,
+    primitives::BeneficiaryId,
+};
+
+use super::error::ProcessError;
+
+pub use job::*;
+
+pub const APPROVE_BENEFICIARY_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("beneficiary");
+
+pub struct ApproveBeneficiary<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    repo: BeneficiaryRepo,
+    audit: Perms::Audit,
+    governance: Governance<Perms, E>,
+    cooling_off: chrono::Duration,
+}
+impl<Perms, E> Clone for ApproveBeneficiary<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            audit: self.audit.clone(),
+            governance: self.governance.clone(),
+            cooling_off: self.cooling_off,
+        }
+    }
+}
+
+impl<Perms, E> ApproveBeneficiary<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        repo: &BeneficiaryRepo,
+        audit: &Perms::Audit,
+        governance: &Governance<Perms, E>,
+        cooling_off: chrono::Duration,
+    ) -> Self {
+        Self {
+            repo: repo.clone(),
+            audit: audit.clone(),
+            governance: governance.clone(),
+            cooling_off,
+        }
+    }
+
+    pub async fn execute_from_svc(
+        &self,
+        beneficiary: &Beneficiary,
+    ) -> Result<Option<Beneficiary>, ProcessError> {
+        if beneficiary.is_approved_or_denied().is_some() {
+            return Ok(None);
+        }
+
+        let process: ApprovalProcess = self
+            .governance
+            .find_all_approval_processes(&[beneficiary.approval_process_id])
+            .await?
+            .remove(&beneficiary.approval_process_id)
+            .expect("approval process not found");
+
+        let res = match process.status() {
+            ApprovalProcessStatus::Approved => Some(self.execute(beneficiary.id, true).await?),
+            ApprovalProcessStatus::Denied => Some(self.execute(beneficiary.id, false).await?),
+            _ => None,
+        };
+        Ok(res)
+    }
+
+    /// Concludes the approval of a beneficiary. An approved change removes the
+    /// beneficiary it replaces so only the new details can be paid out to.
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    #[instrument(name = "deposit.approve_beneficiary", skip(self))]
+    pub async fn execute(
+        &self,
+        id: impl es_entity::RetryableInto<BeneficiaryId>,
+        approved: bool,
+    ) -> Result<Beneficiary, ProcessError> {
+        let id = id.into();
+        let mut beneficiary = self.repo.find_by_id(id).await?;
+        if beneficiary.is_approved_or_denied().is_some() {
+            return Ok(beneficiary);
+        }
+        let mut db = self.repo.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreDepositObject::beneficiary(id),
+                CoreDepositAction::Beneficiary(BeneficiaryAction::ConcludeApprovalProcess),
+            )
+            .await?;
+        if beneficiary
+            .approval_process_concluded(
+                approved,
+                crate::time::now(),
+                self.cooling_off,
+                audit_info.clone(),
+            )
+            .did_execute()
+        {
+            self.repo.update_in_op(&mut db, &mut beneficiary).await?;
+            if let (true, Some(replaced_id)) = (approved, beneficiary.replaces) {
+                let mut replaced = self.repo.find_by_id(replaced_id).await?;
+                if replaced.remove(audit_info).did_execute() {
+                    self.repo.update_in_op(&mut db, &mut replaced).await?;
+                }
+            }
+            db.commit().await?;
+        }
+        Ok(beneficiary)
+    }
+}
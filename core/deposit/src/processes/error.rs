@@ -10,6 +10,12 @@ pub enum ProcessError {
     Sqlx(#[from] sqlx::Error),
     #[error("ProcessError - WithdrawalError: {0}")]
     WithdrawalError(#[from] crate::withdrawal::error::WithdrawalError),
+    #[error("ProcessError - BeneficiaryError: {0}")]
+    BeneficiaryError(#[from] crate::beneficiary::error::BeneficiaryError),
+    #[error("ProcessError - TransferError: {0}")]
+    TransferError(#[from] crate::transfer::error::TransferError),
+    #[error("ProcessError - DepositLedgerError: {0}")]
+    DepositLedgerError(#[from] crate::ledger::error::DepositLedgerError),
     #[error("ProcessError - AuditError: {0}")]
     AuditError(#[from] audit::error::AuditError),
 }
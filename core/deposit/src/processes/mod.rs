@@ -1,2 +1,4 @@
 pub mod approval;
+pub mod beneficiary_approval;
 pub mod error;
+pub mod transfer_approval;
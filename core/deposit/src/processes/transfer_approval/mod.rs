@@ -0,0 +1,131 @@
+mod job;
+
+use authz::PermissionCheck;
+use governance::{
+    ApprovalProcess, ApprovalProcessStatus, ApprovalProcessType, GovernanceAction, GovernanceEvent,
+    GovernanceObject,
+};
+use tracing::instrument;
+
+use audit::AuditSvc;
+use governance::Governance;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CoreDepositAction, CoreDepositObject, TransferAction,
+    event::CoreDepositEvent,
+    ledger::DepositLedger,
+    primitives::TransferId,
+    transfer::{Transfer, repo::TransferRepo},
+};
+
+use super::error::ProcessError;
+
+pub use job::*;
+
+pub const APPROVE_TRANSFER_PROCESS: ApprovalProcessType = ApprovalProcessType::new("transfer");
+
+pub struct ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    repo: TransferRepo<E>,
+    audit: Perms::Audit,
+    governance: Governance<Perms, E>,
+    ledger: DepositLedger,
+}
+impl<Perms, E> Clone for ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            audit: self.audit.clone(),
+            governance: self.governance.clone(),
+            ledger: self.ledger.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        repo: &TransferRepo<E>,
+        audit: &Perms::Audit,
+        governance: &Governance<Perms, E>,
+        ledger: &DepositLedger,
+    ) -> Self {
+        Self {
+            repo: repo.clone(),
+            audit: audit.clone(),
+            governance: governance.clone(),
+            ledger: ledger.clone(),
+        }
+    }
+
+    pub async fn execute_from_svc(
+        &self,
+        transfer: &Transfer,
+    ) -> Result<Option<Transfer>, ProcessError> {
+        let Some(approval_process_id) = transfer.approval_process_id else {
+            return Ok(None);
+        };
+        if transfer.is_approved_or_denied().is_some() {
+            return Ok(None);
+        }
+
+        let process: ApprovalProcess = self
+            .governance
+            .find_all_approval_processes(&[approval_process_id])
+            .await?
+            .remove(&approval_process_id)
+            .expect("approval process not found");
+
+        let res = match process.status() {
+            ApprovalProcessStatus::Approved => Some(self.execute(transfer.id, true).await?),
+            ApprovalProcessStatus::Denied => Some(self.execute(transfer.id, false).await?),
+            _ => None,
+        };
+        Ok(res)
+    }
+
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    #[instrument(name = "deposit.approve_transfer", skip(self))]
+    pub async fn execute(
+        &self,
+        id: impl es_entity::RetryableInto<TransferId>,
+        approved: bool,
+    ) -> Result<Transfer, ProcessError> {
+        let id = id.into();
+        let mut transfer = self.repo.find_by_id(id).await?;
+        if transfer.is_approved_or_denied().is_some() {
+            return Ok(transfer);
+        }
+        let mut db = self.repo.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                db.tx(),
+                CoreDepositObject::transfer(id),
+                CoreDepositAction::Transfer(TransferAction::ConcludeApprovalProcess),
+            )
+            .await?;
+        if let es_entity::Idempotent::Executed(settlement) =
+            transfer.approval_process_concluded(approved, audit_info)
+        {
+            self.repo.update_in_op(&mut db, &mut transfer).await?;
+            self.ledger.settle_transfer(db, settlement).await?;
+        }
+        Ok(transfer)
+    }
+}
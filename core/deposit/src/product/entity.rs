@@ -0,0 +1,185 @@
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use audit::AuditInfo;
+
+use crate::{
+    primitives::*, withdrawal_fee::WithdrawalFeeSchedule, withdrawal_limit::WithdrawalLimits,
+};
+
+use super::{error::DepositProductError, value::DepositInterestTerms};
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "DepositProductId")]
+pub enum DepositProductEvent {
+    Initialized {
+        id: DepositProductId,
+        name: String,
+        interest_terms: DepositInterestTerms,
+        audit_info: AuditInfo,
+    },
+    InterestTermsUpdated {
+        interest_terms: DepositInterestTerms,
+        audit_info: AuditInfo,
+    },
+    WithdrawalLimitsUpdated {
+        withdrawal_limits: WithdrawalLimits,
+        audit_info: AuditInfo,
+    },
+    WithdrawalFeeScheduleUpdated {
+        withdrawal_fee_schedule: Option<WithdrawalFeeSchedule>,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct DepositProduct {
+    pub id: DepositProductId,
+    pub name: String,
+    pub interest_terms: DepositInterestTerms,
+    #[builder(default)]
+    pub withdrawal_limits: WithdrawalLimits,
+    #[builder(default)]
+    pub withdrawal_fee_schedule: Option<WithdrawalFeeSchedule>,
+
+    events: EntityEvents<DepositProductEvent>,
+}
+
+impl DepositProduct {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("Deposit Product has never been persisted")
+    }
+
+    pub fn update_interest_terms(
+        &mut self,
+        interest_terms: DepositInterestTerms,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.interest_terms == interest_terms {
+            return Idempotent::Ignored;
+        }
+        self.events.push(DepositProductEvent::InterestTermsUpdated {
+            interest_terms,
+            audit_info,
+        });
+        self.interest_terms = interest_terms;
+        Idempotent::Executed(())
+    }
+
+    pub fn update_withdrawal_limits(
+        &mut self,
+        withdrawal_limits: WithdrawalLimits,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        if self.withdrawal_limits == withdrawal_limits {
+            return Idempotent::Ignored;
+        }
+        self.events
+            .push(DepositProductEvent::WithdrawalLimitsUpdated {
+                withdrawal_limits,
+                audit_info,
+            });
+        self.withdrawal_limits = withdrawal_limits;
+        Idempotent::Executed(())
+    }
+
+    pub fn update_withdrawal_fee_schedule(
+        &mut self,
+        withdrawal_fee_schedule: Option<WithdrawalFeeSchedule>,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, DepositProductError> {
+        if withdrawal_fee_schedule.is_some_and(|schedule| !schedule.is_valid()) {
+            return Err(DepositProductError::InvalidWithdrawalFeeSchedule);
+        }
+        if self.withdrawal_fee_schedule == withdrawal_fee_schedule {
+            return Ok(Idempotent::Ignored);
+        }
+        self.events
+            .push(DepositProductEvent::WithdrawalFeeScheduleUpdated {
+                withdrawal_fee_schedule,
+                audit_info,
+            });
+        self.withdrawal_fee_schedule = withdrawal_fee_schedule;
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub fn withdrawal_fee_for(&self, amount: UsdCents) -> UsdCents {
+        self.withdrawal_fee_schedule
+            .map(|schedule| schedule.fee_for(amount))
+            .unwrap_or(UsdCents::ZERO)
+    }
+}
+
+impl TryFromEvents<DepositProductEvent> for DepositProduct {
+    fn try_from_events(events: EntityEvents<DepositProductEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = DepositProductBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                DepositProductEvent::Initialized {
+                    id,
+                    name,
+                    interest_terms,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .name(name.to_string())
+                        .interest_terms(*interest_terms)
+                }
+                DepositProductEvent::InterestTermsUpdated { interest_terms, .. } => {
+                    builder = builder.interest_terms(*interest_terms);
+                }
+                DepositProductEvent::WithdrawalLimitsUpdated {
+                    withdrawal_limits, ..
+                } => {
+                    builder = builder.withdrawal_limits(*withdrawal_limits);
+                }
+                DepositProductEvent::WithdrawalFeeScheduleUpdated {
+                    withdrawal_fee_schedule,
+                    ..
+                } => {
+                    builder = builder.withdrawal_fee_schedule(*withdrawal_fee_schedule);
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewDepositProduct {
+    #[builder(setter(into))]
+    pub(super) id: DepositProductId,
+    pub(super) name: String,
+    pub(super) interest_terms: DepositInterestTerms,
+    pub audit_info: AuditInfo,
+}
+
+impl NewDepositProduct {
+    pub fn builder() -> NewDepositProductBuilder {
+        NewDepositProductBuilder::default()
+    }
+}
+
+impl IntoEvents<DepositProductEvent> for NewDepositProduct {
+    fn into_events(self) -> EntityEvents<DepositProductEvent> {
+        EntityEvents::init(
+            self.id,
+            [DepositProductEvent::Initialized {
+                id: self.id,
+                name: self.name,
+                interest_terms: self.interest_terms,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
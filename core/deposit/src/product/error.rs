@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum DepositProductError {
+    #[error("DepositProductError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("DepositProductError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("DepositProductError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("DepositProductError - InvalidWithdrawalFeeSchedule")]
+    InvalidWithdrawalFeeSchedule,
+}
+
+es_entity::from_es_entity_error!(DepositProductError);
@@ -0,0 +1,11 @@
+mod entity;
+pub mod error;
+mod repo;
+mod value;
+
+pub use entity::DepositProduct;
+#[cfg(feature = "json-schema")]
+pub use entity::DepositProductEvent;
+pub(crate) use entity::*;
+pub(crate) use repo::*;
+pub use value::*;
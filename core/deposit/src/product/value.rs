@@ -0,0 +1,102 @@
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+use crate::primitives::UsdCents;
+
+const NUMBER_OF_DAYS_IN_YEAR: u64 = 365;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(transparent)]
+pub struct DepositInterestRate(Decimal);
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(DepositInterestRate);
+
+impl DepositInterestRate {
+    /// Interest earned by `balance` over a single day, in USD and without
+    /// rounding so that sub-cent amounts keep accumulating between
+    /// capitalizations.
+    pub fn daily_interest(&self, balance: UsdCents) -> Decimal {
+        balance.to_usd() * self.0 / Decimal::ONE_HUNDRED / Decimal::from(NUMBER_OF_DAYS_IN_YEAR)
+    }
+}
+
+impl From<Decimal> for DepositInterestRate {
+    fn from(value: Decimal) -> Self {
+        DepositInterestRate(value)
+    }
+}
+
+impl From<DepositInterestRate> for Decimal {
+    fn from(value: DepositInterestRate) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum CapitalizationFrequency {
+    Monthly,
+    Quarterly,
+    Annually,
+}
+
+impl CapitalizationFrequency {
+    pub fn is_period_end(&self, date: NaiveDate) -> bool {
+        let is_month_end = date
+            .succ_opt()
+            .is_none_or(|next_day| next_day.month() != date.month());
+        match self {
+            CapitalizationFrequency::Monthly => is_month_end,
+            CapitalizationFrequency::Quarterly => {
+                is_month_end && matches!(date.month(), 3 | 6 | 9 | 12)
+            }
+            CapitalizationFrequency::Annually => is_month_end && date.month() == 12,
+        }
+    }
+}
+
+/// Interest accrues daily on the end-of-day settled balance and is credited
+/// to the account at the end of every capitalization period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct DepositInterestTerms {
+    pub annual_rate: DepositInterestRate,
+    pub capitalization_frequency: CapitalizationFrequency,
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn daily_interest_keeps_sub_cent_precision() {
+        let rate = DepositInterestRate::from(dec!(3.65));
+        assert_eq!(rate.daily_interest(UsdCents::from(100_000)), dec!(0.1));
+        assert_eq!(rate.daily_interest(UsdCents::from(10)), dec!(0.00001));
+    }
+
+    #[test]
+    fn period_end_by_frequency() {
+        assert!(CapitalizationFrequency::Monthly.is_period_end(date(2024, 2, 29)));
+        assert!(!CapitalizationFrequency::Monthly.is_period_end(date(2024, 2, 28)));
+
+        assert!(CapitalizationFrequency::Quarterly.is_period_end(date(2024, 6, 30)));
+        assert!(!CapitalizationFrequency::Quarterly.is_period_end(date(2024, 5, 31)));
+
+        assert!(CapitalizationFrequency::Annually.is_period_end(date(2024, 12, 31)));
+        assert!(!CapitalizationFrequency::Annually.is_period_end(date(2024, 11, 30)));
+    }
+}
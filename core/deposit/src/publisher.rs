@@ -4,6 +4,7 @@ use super::event::CoreDepositEvent;
 use crate::{
     account::{DepositAccount, DepositAccountEvent, error::DepositAccountError},
     deposit::{Deposit, DepositEvent, error::DepositError},
+    transfer::{Transfer, TransferEvent, error::TransferError},
     withdrawal::{Withdrawal, WithdrawalEvent, error::WithdrawalError},
 };
 
@@ -48,6 +49,25 @@ where
                     id: entity.id,
                     account_holder_id: entity.account_holder_id,
                 }),
+                Closed { .. } => Some(CoreDepositEvent::DepositAccountClosed {
+                    id: entity.id,
+                    account_holder_id: entity.account_holder_id,
+                }),
+                DormancyStatusUpdated { status, .. } => {
+                    Some(CoreDepositEvent::DepositAccountDormancyStatusUpdated {
+                        id: entity.id,
+                        status: *status,
+                    })
+                }
+                InterestCapitalized {
+                    amount,
+                    capitalized_on,
+                    ..
+                } => Some(CoreDepositEvent::DepositAccountInterestCapitalized {
+                    id: entity.id,
+                    amount: *amount,
+                    capitalized_on: *capitalized_on,
+                }),
                 _ => None,
             })
             .collect::<Vec<_>>();
@@ -106,4 +126,40 @@ where
             .await?;
         Ok(())
     }
+
+    pub async fn publish_transfer(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        entity: &Transfer,
+        new_events: es_entity::LastPersisted<'_, TransferEvent>,
+    ) -> Result<(), TransferError> {
+        use TransferEvent::*;
+        let publish_events = new_events
+            .filter_map(|event| match &event.event {
+                Initialized { .. } => Some(CoreDepositEvent::TransferInitiated {
+                    id: entity.id,
+                    from_account_id: entity.from_account_id,
+                    to_account_id: entity.to_account_id,
+                    amount: entity.amount,
+                }),
+                Confirmed { .. } => Some(CoreDepositEvent::TransferConfirmed {
+                    id: entity.id,
+                    from_account_id: entity.from_account_id,
+                    to_account_id: entity.to_account_id,
+                    amount: entity.amount,
+                }),
+                Cancelled { .. } => Some(CoreDepositEvent::TransferCancelled {
+                    id: entity.id,
+                    from_account_id: entity.from_account_id,
+                    to_account_id: entity.to_account_id,
+                    amount: entity.amount,
+                }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        self.outbox
+            .publish_all_persisted(db.tx(), publish_events)
+            .await?;
+        Ok(())
+    }
 }
@@ -0,0 +1,188 @@
+use chrono::{DateTime, Datelike, Days, NaiveDate, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use document_storage::DocumentId;
+use es_entity::*;
+
+use crate::primitives::{DepositAccountId, DepositAccountStatementId, UsdCents};
+use audit::AuditInfo;
+
+/// Calendar month covered by a statement. Both ends are inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct StatementPeriod {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl StatementPeriod {
+    pub fn month_of(date: NaiveDate) -> Self {
+        let start = date.with_day(1).expect("first day of month");
+        let next_month_start = start
+            .checked_add_months(chrono::Months::new(1))
+            .expect("next month should exist");
+        Self {
+            start,
+            end: next_month_start - Days::new(1),
+        }
+    }
+
+    /// The last full month before `date`.
+    pub fn month_before(date: NaiveDate) -> Self {
+        Self::month_of(Self::month_of(date).start - Days::new(1))
+    }
+
+    pub fn next(&self) -> Self {
+        Self::month_of(self.end + Days::new(1))
+    }
+
+    pub fn label(&self) -> String {
+        self.start.format("%Y-%m").to_string()
+    }
+}
+
+pub(crate) fn statement_reference(
+    deposit_account_id: DepositAccountId,
+    period: StatementPeriod,
+) -> String {
+    format!("{deposit_account_id}:{}", period.label())
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "DepositAccountStatementId")]
+pub enum DepositAccountStatementEvent {
+    Initialized {
+        id: DepositAccountStatementId,
+        deposit_account_id: DepositAccountId,
+        reference: String,
+        period: StatementPeriod,
+        opening_balance: UsdCents,
+        closing_balance: UsdCents,
+        document_id: DocumentId,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct DepositAccountStatement {
+    pub id: DepositAccountStatementId,
+    pub deposit_account_id: DepositAccountId,
+    pub reference: String,
+    pub period: StatementPeriod,
+    pub opening_balance: UsdCents,
+    pub closing_balance: UsdCents,
+    pub document_id: DocumentId,
+
+    events: EntityEvents<DepositAccountStatementEvent>,
+}
+
+impl DepositAccountStatement {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for deposit account statement")
+    }
+}
+
+impl TryFromEvents<DepositAccountStatementEvent> for DepositAccountStatement {
+    fn try_from_events(
+        events: EntityEvents<DepositAccountStatementEvent>,
+    ) -> Result<Self, EsEntityError> {
+        let mut builder = DepositAccountStatementBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                DepositAccountStatementEvent::Initialized {
+                    id,
+                    deposit_account_id,
+                    reference,
+                    period,
+                    opening_balance,
+                    closing_balance,
+                    document_id,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .deposit_account_id(*deposit_account_id)
+                        .reference(reference.clone())
+                        .period(*period)
+                        .opening_balance(*opening_balance)
+                        .closing_balance(*closing_balance)
+                        .document_id(*document_id)
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewDepositAccountStatement {
+    #[builder(setter(into))]
+    pub(super) id: DepositAccountStatementId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    pub(super) period: StatementPeriod,
+    pub(super) opening_balance: UsdCents,
+    pub(super) closing_balance: UsdCents,
+    #[builder(setter(into))]
+    pub(super) document_id: DocumentId,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewDepositAccountStatement {
+    pub fn builder() -> NewDepositAccountStatementBuilder {
+        NewDepositAccountStatementBuilder::default()
+    }
+
+    pub(super) fn reference(&self) -> String {
+        statement_reference(self.deposit_account_id, self.period)
+    }
+}
+
+impl IntoEvents<DepositAccountStatementEvent> for NewDepositAccountStatement {
+    fn into_events(self) -> EntityEvents<DepositAccountStatementEvent> {
+        EntityEvents::init(
+            self.id,
+            [DepositAccountStatementEvent::Initialized {
+                reference: self.reference(),
+                id: self.id,
+                deposit_account_id: self.deposit_account_id,
+                period: self.period,
+                opening_balance: self.opening_balance,
+                closing_balance: self.closing_balance,
+                document_id: self.document_id,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn month_before_covers_previous_calendar_month() {
+        let period = StatementPeriod::month_before(date(2024, 3, 1));
+        assert_eq!(period.start, date(2024, 2, 1));
+        assert_eq!(period.end, date(2024, 2, 29));
+        assert_eq!(period.label(), "2024-02");
+
+        let period = StatementPeriod::month_before(date(2024, 1, 15));
+        assert_eq!(period.start, date(2023, 12, 1));
+        assert_eq!(period.end, date(2023, 12, 31));
+        assert_eq!(period.next(), StatementPeriod::month_of(date(2024, 1, 31)));
+    }
+}
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum DepositAccountStatementError {
+    #[error("DepositAccountStatementError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("DepositAccountStatementError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("DepositAccountStatementError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+}
+
+es_entity::from_es_entity_error!(DepositAccountStatementError);
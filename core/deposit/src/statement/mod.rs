@@ -0,0 +1,9 @@
+mod entity;
+pub mod error;
+mod repo;
+
+#[cfg(feature = "json-schema")]
+pub use entity::DepositAccountStatementEvent;
+pub(crate) use entity::*;
+pub use entity::{DepositAccountStatement, StatementPeriod};
+pub(crate) use repo::*;
@@ -0,0 +1,134 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+
+use cala_ledger::DebitOrCredit;
+
+use crate::{
+    history::DepositAccountHistoryEntry,
+    primitives::{CalaTransactionId, UsdCents},
+    statement::StatementPeriod,
+};
+
+pub(super) struct StatementEntry {
+    pub recorded_at: DateTime<Utc>,
+    pub tx_id: CalaTransactionId,
+    pub description: &'static str,
+    pub direction: DebitOrCredit,
+    pub amount: UsdCents,
+}
+
+impl StatementEntry {
+    /// Returns `None` for entries that are not shown in the account history.
+    pub fn from_ledger_entry(entry: cala_ledger::entry::Entry) -> Option<Self> {
+        let recorded_at = entry.created_at();
+        let tx_id = entry.values().transaction_id;
+        let direction = entry.values().direction;
+        let amount =
+            UsdCents::try_from_usd(entry.values().units).expect("entry amount should be positive");
+        let description = describe(&DepositAccountHistoryEntry::from(entry))?;
+        Some(Self {
+            recorded_at,
+            tx_id,
+            description,
+            direction,
+            amount,
+        })
+    }
+}
+
+fn describe(entry: &DepositAccountHistoryEntry) -> Option<&'static str> {
+    use DepositAccountHistoryEntry::*;
+    let description = match entry {
+        Deposit(_) => "Deposit",
+        Withdrawal(_) => "Withdrawal",
+        CancelledWithdrawal(_) => "Withdrawal cancelled",
+        WithdrawalFee(_) => "Withdrawal fee",
+        WithdrawalFeeRefund(_) => "Withdrawal fee refunded",
+        Disbursal(_) => "Credit facility disbursal",
+        Payment(_) => "Credit facility payment",
+        InterestCapitalization(_) => "Interest",
+        TimeDepositOpening(_) => "Fixed-term deposit opened",
+        TimeDepositSettlement(_) => "Fixed-term deposit settled",
+        TransferOut(_) => "Transfer out",
+        TransferIn(_) => "Transfer in",
+        CancelledTransfer(_) => "Transfer cancelled",
+        ClosingBalanceSweep(_) => "Closing balance swept",
+        Unknown(_) => "Other",
+        Ignored => return None,
+    };
+    Some(description)
+}
+
+#[derive(Serialize)]
+pub(super) struct StatementLine {
+    date: String,
+    description: &'static str,
+    reference: String,
+    debit: String,
+    credit: String,
+}
+
+#[derive(Serialize)]
+pub(super) struct StatementData {
+    account_name: String,
+    public_id: String,
+    account_id: String,
+    period_start: String,
+    period_end: String,
+    opening_balance: String,
+    closing_balance: String,
+    total_credits: String,
+    total_debits: String,
+    lines: Vec<StatementLine>,
+    generated_on: String,
+}
+
+impl StatementData {
+    pub fn new(
+        account: &crate::DepositAccount,
+        period: StatementPeriod,
+        opening_balance: UsdCents,
+        closing_balance: UsdCents,
+        entries: &[StatementEntry],
+        generated_on: NaiveDate,
+    ) -> Self {
+        let mut total_credits = UsdCents::ZERO;
+        let mut total_debits = UsdCents::ZERO;
+        let lines = entries
+            .iter()
+            .map(|entry| {
+                let (debit, credit) = match entry.direction {
+                    DebitOrCredit::Debit => {
+                        total_debits += entry.amount;
+                        (entry.amount.formatted_usd(), String::new())
+                    }
+                    DebitOrCredit::Credit => {
+                        total_credits += entry.amount;
+                        (String::new(), entry.amount.formatted_usd())
+                    }
+                };
+                StatementLine {
+                    date: entry.recorded_at.date_naive().to_string(),
+                    description: entry.description,
+                    reference: entry.tx_id.to_string(),
+                    debit,
+                    credit,
+                }
+            })
+            .collect();
+
+        Self {
+            account_name: account.name.clone(),
+            public_id: account.public_id.to_string(),
+            account_id: account.id.to_string(),
+            period_start: period.start.to_string(),
+            period_end: period.end.to_string(),
+            opening_balance: opening_balance.formatted_usd(),
+            closing_balance: closing_balance.formatted_usd(),
+            total_credits: total_credits.formatted_usd(),
+            total_debits: total_debits.formatted_usd(),
+            lines,
+            generated_on: generated_on.to_string(),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{CoreDepositAction, CoreDepositEvent, CoreDepositObject, statement::StatementPeriod};
+
+use super::GenerateStatements;
+
+#[derive(serde::Serialize)]
+pub struct DepositAccountStatementJobConfig<Perms, E> {
+    _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> DepositAccountStatementJobConfig<Perms, E> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Perms, E> JobConfig for DepositAccountStatementJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    type Initializer = DepositAccountStatementInit<Perms, E>;
+}
+
+pub struct DepositAccountStatementInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    process: GenerateStatements<Perms, E>,
+}
+
+impl<Perms, E> DepositAccountStatementInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(process: &GenerateStatements<Perms, E>) -> Self {
+        Self {
+            process: process.clone(),
+        }
+    }
+}
+
+const DEPOSIT_ACCOUNT_STATEMENT_JOB: JobType = JobType::new("deposit-account-statements");
+impl<Perms, E> JobInitializer for DepositAccountStatementInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        DEPOSIT_ACCOUNT_STATEMENT_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(DepositAccountStatementJobRunner {
+            process: self.process.clone(),
+        }))
+    }
+}
+
+pub struct DepositAccountStatementJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    process: GenerateStatements<Perms, E>,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for DepositAccountStatementJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let today = crate::time::now().date_naive();
+        self.process.execute(today).await?;
+
+        let next_run = StatementPeriod::month_of(today)
+            .next()
+            .start
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight is a valid time")
+            .and_utc();
+        Ok(JobCompletion::RescheduleAt(next_run))
+    }
+}
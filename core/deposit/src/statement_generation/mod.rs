@@ -0,0 +1,200 @@
+mod data;
+mod job;
+
+use chrono::{Days, NaiveDate, NaiveTime};
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use document_storage::{DocumentStorage, DocumentType, ReferenceId};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CoreDepositAction, CoreDepositObject,
+    account::{DepositAccount, DepositAccountRepo},
+    error::CoreDepositError,
+    event::CoreDepositEvent,
+    ledger::DepositLedger,
+    primitives::DepositAccountStatementId,
+    statement::{
+        DepositAccountStatementRepo, NewDepositAccountStatement, StatementPeriod,
+        statement_reference,
+    },
+};
+
+use data::{StatementData, StatementEntry};
+pub use job::*;
+
+pub const DEPOSIT_ACCOUNT_STATEMENT_DOCUMENT: DocumentType =
+    DocumentType::new("deposit_account_statement");
+const STATEMENT_TEMPLATE: &str = include_str!("templates/deposit_account_statement.md.hbs");
+
+pub struct GenerateStatements<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    accounts: DepositAccountRepo<E>,
+    statements: DepositAccountStatementRepo,
+    ledger: DepositLedger,
+    document_storage: DocumentStorage,
+    renderer: rendering::Renderer,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> Clone for GenerateStatements<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            accounts: self.accounts.clone(),
+            statements: self.statements.clone(),
+            ledger: self.ledger.clone(),
+            document_storage: self.document_storage.clone(),
+            renderer: self.renderer.clone(),
+            audit: self.audit.clone(),
+        }
+    }
+}
+
+impl<Perms, E> GenerateStatements<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        accounts: &DepositAccountRepo<E>,
+        statements: &DepositAccountStatementRepo,
+        ledger: &DepositLedger,
+        document_storage: &DocumentStorage,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            accounts: accounts.clone(),
+            statements: statements.clone(),
+            ledger: ledger.clone(),
+            document_storage: document_storage.clone(),
+            renderer: rendering::Renderer::new(),
+            audit: audit.clone(),
+        }
+    }
+
+    /// Generates the statement for the last full month before `today` for
+    /// every account that existed by the end of that month. Accounts that
+    /// already have a statement for the period are skipped.
+    pub async fn execute(&self, today: NaiveDate) -> Result<(), CoreDepositError> {
+        let period = StatementPeriod::month_before(today);
+        let mut has_next_page = true;
+        let mut after = None;
+        while has_next_page {
+            let accounts = self
+                .accounts
+                .list_by_id(
+                    es_entity::PaginatedQueryArgs { first: 50, after },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            (after, has_next_page) = (accounts.end_cursor, accounts.has_next_page);
+
+            for account in accounts.entities.iter() {
+                if account.created_at().date_naive() > period.end {
+                    continue;
+                }
+                match self
+                    .statements
+                    .find_by_reference(statement_reference(account.id, period))
+                    .await
+                {
+                    Ok(_) => continue,
+                    Err(e) if e.was_not_found() => (),
+                    Err(e) => return Err(e.into()),
+                }
+                self.generate(account, period, today).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn generate(
+        &self,
+        account: &DepositAccount,
+        period: StatementPeriod,
+        today: NaiveDate,
+    ) -> Result<(), CoreDepositError> {
+        let opening_balance = self
+            .ledger
+            .end_of_day_settled_balance(account.id, period.start - Days::new(1))
+            .await?;
+        let closing_balance = self
+            .ledger
+            .end_of_day_settled_balance(account.id, period.end)
+            .await?;
+        let entries: Vec<_> = self
+            .ledger
+            .settled_entries_between(
+                account.id,
+                period.start.and_time(NaiveTime::MIN).and_utc(),
+                period.next().start.and_time(NaiveTime::MIN).and_utc(),
+            )
+            .await?
+            .into_iter()
+            .filter_map(StatementEntry::from_ledger_entry)
+            .collect();
+
+        let data = StatementData::new(
+            account,
+            period,
+            opening_balance,
+            closing_balance,
+            &entries,
+            today,
+        );
+        let markdown = self
+            .renderer
+            .render_template_to_markdown(STATEMENT_TEMPLATE, &data)?;
+        let pdf = self.renderer.markdown_to_pdf(&markdown)?;
+
+        let statement_id = DepositAccountStatementId::new();
+        let mut op = self.statements.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                op.tx(),
+                CoreDepositObject::deposit_account_statement(statement_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_STATEMENT_GENERATE,
+            )
+            .await?;
+
+        let mut document = self
+            .document_storage
+            .create_in_op(
+                audit_info.clone(),
+                format!("statement-{}-{}.pdf", account.public_id, period.label()),
+                "application/pdf",
+                ReferenceId::from(uuid::Uuid::from(statement_id)),
+                DEPOSIT_ACCOUNT_STATEMENT_DOCUMENT,
+                &mut op,
+            )
+            .await?;
+
+        let new_statement = NewDepositAccountStatement::builder()
+            .id(statement_id)
+            .deposit_account_id(account.id)
+            .period(period)
+            .opening_balance(opening_balance)
+            .closing_balance(closing_balance)
+            .document_id(document.id)
+            .audit_info(audit_info)
+            .build()?;
+        self.statements.create_in_op(&mut op, new_statement).await?;
+
+        self.document_storage
+            .upload_in_op(pdf, &mut document, &mut op)
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,409 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::primitives::{CalaTransactionId, DepositAccountId, TimeDepositId, UsdCents};
+
+use super::{error::TimeDepositError, value::*};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TimeDepositStatus {
+    Active,
+    Matured,
+    Broken,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "TimeDepositId")]
+pub enum TimeDepositEvent {
+    Initialized {
+        id: TimeDepositId,
+        ledger_tx_id: CalaTransactionId,
+        deposit_account_id: DepositAccountId,
+        amount: UsdCents,
+        terms: TimeDepositTerms,
+        maturity_instruction: TimeDepositMaturityInstruction,
+        opened_at: DateTime<Utc>,
+        maturity_at: DateTime<Utc>,
+        rolled_over_from: Option<TimeDepositId>,
+        reference: String,
+        audit_info: AuditInfo,
+    },
+    Matured {
+        interest_tx_id: CalaTransactionId,
+        settlement_tx_id: CalaTransactionId,
+        interest: UsdCents,
+        rolled_over_into: Option<TimeDepositId>,
+        audit_info: AuditInfo,
+    },
+    Broken {
+        interest_tx_id: CalaTransactionId,
+        settlement_tx_id: CalaTransactionId,
+        interest: UsdCents,
+        penalty: UsdCents,
+        audit_info: AuditInfo,
+    },
+}
+
+pub struct TimeDepositSettlementData {
+    pub interest_tx_id: CalaTransactionId,
+    pub settlement_tx_id: CalaTransactionId,
+    pub time_deposit_id: TimeDepositId,
+    pub deposit_account_id: DepositAccountId,
+    pub interest: UsdCents,
+    pub payout: UsdCents,
+    pub effective: NaiveDate,
+    pub rolled_over_into: Option<TimeDepositId>,
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct TimeDeposit {
+    pub id: TimeDepositId,
+    pub deposit_account_id: DepositAccountId,
+    pub amount: UsdCents,
+    pub terms: TimeDepositTerms,
+    pub maturity_instruction: TimeDepositMaturityInstruction,
+    pub opened_at: DateTime<Utc>,
+    pub maturity_at: DateTime<Utc>,
+    pub reference: String,
+    events: EntityEvents<TimeDepositEvent>,
+}
+
+impl TimeDeposit {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for time deposit")
+    }
+
+    pub fn status(&self) -> TimeDepositStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .map(|event| match event {
+                TimeDepositEvent::Initialized { .. } => TimeDepositStatus::Active,
+                TimeDepositEvent::Matured { .. } => TimeDepositStatus::Matured,
+                TimeDepositEvent::Broken { .. } => TimeDepositStatus::Broken,
+            })
+            .next()
+            .expect("status should always exist")
+    }
+
+    pub fn interest_paid(&self) -> Option<UsdCents> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            TimeDepositEvent::Matured { interest, .. } => Some(*interest),
+            TimeDepositEvent::Broken { interest, .. } => Some(*interest),
+            _ => None,
+        })
+    }
+
+    pub fn rolled_over_into(&self) -> Option<TimeDepositId> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            TimeDepositEvent::Matured {
+                rolled_over_into, ..
+            } => *rolled_over_into,
+            _ => None,
+        })
+    }
+
+    /// Interest the deposit would pay if held until maturity.
+    pub fn interest_at_maturity(&self) -> UsdCents {
+        self.interest_earned_until(self.maturity_at)
+    }
+
+    fn interest_earned_until(&self, until: DateTime<Utc>) -> UsdCents {
+        let until = until.min(self.maturity_at);
+        let days = (until.date_naive() - self.opened_at.date_naive())
+            .num_days()
+            .max(0);
+        self.terms
+            .interest_for_days(self.amount, u32::try_from(days).unwrap_or(u32::MAX))
+    }
+
+    pub fn mature(
+        &mut self,
+        now: DateTime<Utc>,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<TimeDepositSettlementData>, TimeDepositError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            TimeDepositEvent::Matured { .. }
+        );
+        if self.status() == TimeDepositStatus::Broken {
+            return Err(TimeDepositError::AlreadyBroken(self.id));
+        }
+        if now < self.maturity_at {
+            return Err(TimeDepositError::NotYetMatured(self.id));
+        }
+
+        let interest = self.interest_at_maturity();
+        let rolled_over_into = match self.maturity_instruction {
+            TimeDepositMaturityInstruction::Rollover => Some(TimeDepositId::new()),
+            TimeDepositMaturityInstruction::Sweep => None,
+        };
+        let data = TimeDepositSettlementData {
+            interest_tx_id: CalaTransactionId::new(),
+            settlement_tx_id: CalaTransactionId::new(),
+            time_deposit_id: self.id,
+            deposit_account_id: self.deposit_account_id,
+            interest,
+            payout: self.amount + interest,
+            effective: self.maturity_at.date_naive(),
+            rolled_over_into,
+        };
+        self.events.push(TimeDepositEvent::Matured {
+            interest_tx_id: data.interest_tx_id,
+            settlement_tx_id: data.settlement_tx_id,
+            interest,
+            rolled_over_into,
+            audit_info,
+        });
+        Ok(Idempotent::Executed(data))
+    }
+
+    /// Settles the deposit before maturity. The penalty is deducted from the
+    /// interest earned so far, the principal is always returned in full.
+    pub fn break_early(
+        &mut self,
+        now: DateTime<Utc>,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<TimeDepositSettlementData>, TimeDepositError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            TimeDepositEvent::Broken { .. }
+        );
+        if self.status() == TimeDepositStatus::Matured || now >= self.maturity_at {
+            return Err(TimeDepositError::AlreadyMatured(self.id));
+        }
+
+        let earned = self.interest_earned_until(now);
+        let penalty = self.terms.early_break_penalty.penalty(earned);
+        let interest = earned - penalty;
+        let data = TimeDepositSettlementData {
+            interest_tx_id: CalaTransactionId::new(),
+            settlement_tx_id: CalaTransactionId::new(),
+            time_deposit_id: self.id,
+            deposit_account_id: self.deposit_account_id,
+            interest,
+            payout: self.amount + interest,
+            effective: now.date_naive(),
+            rolled_over_into: None,
+        };
+        self.events.push(TimeDepositEvent::Broken {
+            interest_tx_id: data.interest_tx_id,
+            settlement_tx_id: data.settlement_tx_id,
+            interest,
+            penalty,
+            audit_info,
+        });
+        Ok(Idempotent::Executed(data))
+    }
+}
+
+impl TryFromEvents<TimeDepositEvent> for TimeDeposit {
+    fn try_from_events(events: EntityEvents<TimeDepositEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = TimeDepositBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                TimeDepositEvent::Initialized {
+                    id,
+                    deposit_account_id,
+                    amount,
+                    terms,
+                    maturity_instruction,
+                    opened_at,
+                    maturity_at,
+                    reference,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .deposit_account_id(*deposit_account_id)
+                        .amount(*amount)
+                        .terms(*terms)
+                        .maturity_instruction(*maturity_instruction)
+                        .opened_at(*opened_at)
+                        .maturity_at(*maturity_at)
+                        .reference(reference.clone());
+                }
+                TimeDepositEvent::Matured { .. } => {}
+                TimeDepositEvent::Broken { .. } => {}
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewTimeDeposit {
+    #[builder(setter(into))]
+    pub(super) id: TimeDepositId,
+    #[builder(setter(into))]
+    pub(super) ledger_transaction_id: CalaTransactionId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) amount: UsdCents,
+    pub(super) terms: TimeDepositTerms,
+    pub(super) maturity_instruction: TimeDepositMaturityInstruction,
+    pub(super) opened_at: DateTime<Utc>,
+    #[builder(default)]
+    pub(super) rolled_over_from: Option<TimeDepositId>,
+    #[builder(default)]
+    reference: Option<String>,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewTimeDeposit {
+    pub fn builder() -> NewTimeDepositBuilder {
+        NewTimeDepositBuilder::default()
+    }
+
+    pub(super) fn reference(&self) -> String {
+        match self.reference.as_deref() {
+            None => self.id.to_string(),
+            Some("") => self.id.to_string(),
+            Some(reference) => reference.to_string(),
+        }
+    }
+
+    pub fn maturity_at(&self) -> DateTime<Utc> {
+        self.terms.duration.maturity_date(self.opened_at)
+    }
+}
+
+impl NewTimeDepositBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.amount {
+            Some(amount) if amount.is_zero() => {
+                Err("Time deposit amount cannot be zero".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl IntoEvents<TimeDepositEvent> for NewTimeDeposit {
+    fn into_events(self) -> EntityEvents<TimeDepositEvent> {
+        EntityEvents::init(
+            self.id,
+            [TimeDepositEvent::Initialized {
+                reference: self.reference(),
+                maturity_at: self.maturity_at(),
+                id: self.id,
+                ledger_tx_id: self.ledger_transaction_id,
+                deposit_account_id: self.deposit_account_id,
+                amount: self.amount,
+                terms: self.terms,
+                maturity_instruction: self.maturity_instruction,
+                opened_at: self.opened_at,
+                rolled_over_from: self.rolled_over_from,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    use crate::product::DepositInterestRate;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn opened_at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()
+    }
+
+    fn time_deposit(maturity_instruction: TimeDepositMaturityInstruction) -> TimeDeposit {
+        let new_time_deposit = NewTimeDeposit::builder()
+            .id(TimeDepositId::new())
+            .ledger_transaction_id(CalaTransactionId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .amount(UsdCents::from(1_000_000))
+            .terms(TimeDepositTerms {
+                annual_rate: DepositInterestRate::from(dec!(3.65)),
+                duration: TimeDepositDuration::Months(12),
+                early_break_penalty: EarlyBreakPenaltyRate::from(dec!(50)),
+            })
+            .maturity_instruction(maturity_instruction)
+            .opened_at(opened_at())
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        TimeDeposit::try_from_events(new_time_deposit.into_events()).unwrap()
+    }
+
+    #[test]
+    fn cannot_mature_before_maturity_date() {
+        let mut time_deposit = time_deposit(TimeDepositMaturityInstruction::Sweep);
+        let res = time_deposit.mature(opened_at(), dummy_audit_info());
+        assert!(matches!(res, Err(TimeDepositError::NotYetMatured(_))));
+    }
+
+    #[test]
+    fn matures_with_full_interest() {
+        let mut time_deposit = time_deposit(TimeDepositMaturityInstruction::Sweep);
+        let data = time_deposit
+            .mature(time_deposit.maturity_at, dummy_audit_info())
+            .unwrap()
+            .unwrap();
+        assert_eq!(data.interest, UsdCents::from(36_500));
+        assert_eq!(data.payout, UsdCents::from(1_036_500));
+        assert_eq!(data.rolled_over_into, None);
+        assert_eq!(time_deposit.status(), TimeDepositStatus::Matured);
+
+        let res = time_deposit.mature(time_deposit.maturity_at, dummy_audit_info());
+        assert!(matches!(res, Ok(Idempotent::Ignored)));
+    }
+
+    #[test]
+    fn rollover_allocates_next_time_deposit() {
+        let mut time_deposit = time_deposit(TimeDepositMaturityInstruction::Rollover);
+        let data = time_deposit
+            .mature(time_deposit.maturity_at, dummy_audit_info())
+            .unwrap()
+            .unwrap();
+        assert!(data.rolled_over_into.is_some());
+        assert_eq!(time_deposit.rolled_over_into(), data.rolled_over_into);
+    }
+
+    #[test]
+    fn early_break_deducts_penalty_from_interest() {
+        let mut time_deposit = time_deposit(TimeDepositMaturityInstruction::Sweep);
+        let broken_at = opened_at() + chrono::Days::new(100);
+        let data = time_deposit
+            .break_early(broken_at, dummy_audit_info())
+            .unwrap()
+            .unwrap();
+        assert_eq!(data.interest, UsdCents::from(5_000));
+        assert_eq!(data.payout, UsdCents::from(1_005_000));
+        assert_eq!(time_deposit.status(), TimeDepositStatus::Broken);
+
+        let res = time_deposit.mature(time_deposit.maturity_at, dummy_audit_info());
+        assert!(matches!(res, Err(TimeDepositError::AlreadyBroken(_))));
+    }
+}
@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+use crate::primitives::TimeDepositId;
+
+#[derive(Error, Debug)]
+pub enum TimeDepositError {
+    #[error("TimeDepositError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("TimeDepositError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("TimeDepositError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("TimeDepositError - NotYetMatured: {0}")]
+    NotYetMatured(TimeDepositId),
+    #[error("TimeDepositError - AlreadyMatured: {0}")]
+    AlreadyMatured(TimeDepositId),
+    #[error("TimeDepositError - AlreadyBroken: {0}")]
+    AlreadyBroken(TimeDepositId),
+}
+
+es_entity::from_es_entity_error!(TimeDepositError);
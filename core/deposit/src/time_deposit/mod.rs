@@ -0,0 +1,11 @@
+mod entity;
+pub mod error;
+mod repo;
+mod value;
+
+#[cfg(feature = "json-schema")]
+pub use entity::TimeDepositEvent;
+pub(crate) use entity::*;
+pub use entity::{TimeDeposit, TimeDepositStatus};
+pub(crate) use repo::*;
+pub use value::*;
@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::{Decimal, RoundingStrategy, prelude::ToPrimitive};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+use crate::{primitives::UsdCents, product::DepositInterestRate};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum TimeDepositDuration {
+    Months(u32),
+}
+
+impl TimeDepositDuration {
+    pub fn maturity_date(&self, start_date: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            TimeDepositDuration::Months(months) => start_date
+                .checked_add_months(chrono::Months::new(*months))
+                .expect("should return a maturity date"),
+        }
+    }
+}
+
+/// Share of the interest earned so far that is forfeited when a time deposit
+/// is broken before maturity, in percent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(transparent)]
+pub struct EarlyBreakPenaltyRate(Decimal);
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(EarlyBreakPenaltyRate);
+
+impl EarlyBreakPenaltyRate {
+    pub fn penalty(&self, interest: UsdCents) -> UsdCents {
+        let cents = (Decimal::from(interest.into_inner()) * self.0 / Decimal::ONE_HUNDRED)
+            .round_dp_with_strategy(0, RoundingStrategy::ToZero)
+            .to_u64()
+            .unwrap_or(0);
+        UsdCents::from(cents.min(interest.into_inner()))
+    }
+}
+
+impl From<Decimal> for EarlyBreakPenaltyRate {
+    fn from(value: Decimal) -> Self {
+        EarlyBreakPenaltyRate(value)
+    }
+}
+
+impl From<EarlyBreakPenaltyRate> for Decimal {
+    fn from(value: EarlyBreakPenaltyRate) -> Self {
+        value.0
+    }
+}
+
+/// Simple interest on the principal is paid out at maturity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct TimeDepositTerms {
+    pub annual_rate: DepositInterestRate,
+    pub duration: TimeDepositDuration,
+    pub early_break_penalty: EarlyBreakPenaltyRate,
+}
+
+impl TimeDepositTerms {
+    /// Interest earned by `principal` over `days`, truncated to whole cents.
+    pub fn interest_for_days(&self, principal: UsdCents, days: u32) -> UsdCents {
+        let cents = (self.annual_rate.daily_interest(principal)
+            * Decimal::from(days)
+            * Decimal::ONE_HUNDRED)
+            .round_dp_with_strategy(0, RoundingStrategy::ToZero)
+            .to_u64()
+            .unwrap_or(0);
+        UsdCents::from(cents)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TimeDepositMaturityInstruction {
+    /// Principal and interest are credited to the on-demand deposit account.
+    Sweep,
+    /// Principal and interest are placed in a new time deposit on the same terms.
+    Rollover,
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn interest_is_truncated_to_cents() {
+        let terms = TimeDepositTerms {
+            annual_rate: DepositInterestRate::from(dec!(3.65)),
+            duration: TimeDepositDuration::Months(12),
+            early_break_penalty: EarlyBreakPenaltyRate::from(dec!(50)),
+        };
+        assert_eq!(
+            terms.interest_for_days(UsdCents::from(100_000), 365),
+            UsdCents::from(3650)
+        );
+        assert_eq!(
+            terms.interest_for_days(UsdCents::from(1_000), 1),
+            UsdCents::ZERO
+        );
+    }
+
+    #[test]
+    fn penalty_never_exceeds_interest() {
+        let penalty = EarlyBreakPenaltyRate::from(dec!(150));
+        assert_eq!(penalty.penalty(UsdCents::from(101)), UsdCents::from(101));
+        let penalty = EarlyBreakPenaltyRate::from(dec!(50));
+        assert_eq!(penalty.penalty(UsdCents::from(101)), UsdCents::from(50));
+    }
+}
@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+use chrono::Days;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use job::*;
+
+use crate::{CoreDepositAction, CoreDepositObject};
+
+use super::MatureTimeDeposits;
+
+#[derive(serde::Serialize)]
+pub struct TimeDepositMaturityJobConfig<Perms> {
+    _phantom: std::marker::PhantomData<Perms>,
+}
+impl<Perms> TimeDepositMaturityJobConfig<Perms> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Perms> JobConfig for TimeDepositMaturityJobConfig<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    type Initializer = TimeDepositMaturityInit<Perms>;
+}
+
+pub struct TimeDepositMaturityInit<Perms>
+where
+    Perms: PermissionCheck,
+{
+    process: MatureTimeDeposits<Perms>,
+}
+
+impl<Perms> TimeDepositMaturityInit<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    pub fn new(process: &MatureTimeDeposits<Perms>) -> Self {
+        Self {
+            process: process.clone(),
+        }
+    }
+}
+
+const TIME_DEPOSIT_MATURITY_JOB: JobType = JobType::new("time-deposit-maturity");
+impl<Perms> JobInitializer for TimeDepositMaturityInit<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        TIME_DEPOSIT_MATURITY_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(TimeDepositMaturityJobRunner {
+            process: self.process.clone(),
+        }))
+    }
+}
+
+pub struct TimeDepositMaturityJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+{
+    process: MatureTimeDeposits<Perms>,
+}
+
+#[async_trait]
+impl<Perms> JobRunner for TimeDepositMaturityJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let now = crate::time::now();
+        self.process.execute(now).await?;
+
+        let next_run = (now.date_naive() + Days::new(1))
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight is a valid time")
+            .and_utc();
+        Ok(JobCompletion::RescheduleAt(next_run))
+    }
+}
@@ -0,0 +1,141 @@
+mod job;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+
+use crate::{
+    CoreDepositAction, CoreDepositObject,
+    error::CoreDepositError,
+    ledger::DepositLedger,
+    time_deposit::{NewTimeDeposit, TimeDeposit, TimeDepositRepo, TimeDepositStatus},
+};
+
+pub use job::*;
+
+pub struct MatureTimeDeposits<Perms>
+where
+    Perms: PermissionCheck,
+{
+    time_deposits: TimeDepositRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms> Clone for MatureTimeDeposits<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fn clone(&self) -> Self {
+        Self {
+            time_deposits: self.time_deposits.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }
+    }
+}
+
+impl<Perms> MatureTimeDeposits<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    pub fn new(
+        time_deposits: &TimeDepositRepo,
+        ledger: &DepositLedger,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            time_deposits: time_deposits.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+        }
+    }
+
+    /// Settles every active time deposit that has reached its maturity date.
+    pub async fn execute(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), CoreDepositError> {
+        let mut has_next_page = true;
+        let mut after = None;
+        while has_next_page {
+            let mut time_deposits = self
+                .time_deposits
+                .list_by_id(
+                    es_entity::PaginatedQueryArgs { first: 50, after },
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+            (after, has_next_page) = (time_deposits.end_cursor, time_deposits.has_next_page);
+
+            for time_deposit in time_deposits.entities.iter_mut() {
+                if time_deposit.status() != TimeDepositStatus::Active
+                    || time_deposit.maturity_at > now
+                {
+                    continue;
+                }
+                self.mature(time_deposit, now).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn mature(
+        &self,
+        time_deposit: &mut TimeDeposit,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), CoreDepositError> {
+        let mut op = self.time_deposits.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                op.tx(),
+                CoreDepositObject::time_deposit(time_deposit.id),
+                CoreDepositAction::TIME_DEPOSIT_MATURE,
+            )
+            .await?;
+
+        let settlement = match time_deposit.mature(now, audit_info.clone())? {
+            es_entity::Idempotent::Executed(settlement) => settlement,
+            es_entity::Idempotent::Ignored => return Ok(()),
+        };
+        self.time_deposits
+            .update_in_op(&mut op, time_deposit)
+            .await?;
+
+        let rolled_over = match settlement.rolled_over_into {
+            Some(next_id) => {
+                let new_time_deposit = NewTimeDeposit::builder()
+                    .id(next_id)
+                    .ledger_transaction_id(settlement.settlement_tx_id)
+                    .deposit_account_id(time_deposit.deposit_account_id)
+                    .amount(settlement.payout)
+                    .terms(time_deposit.terms)
+                    .maturity_instruction(time_deposit.maturity_instruction)
+                    .opened_at(time_deposit.maturity_at)
+                    .rolled_over_from(Some(time_deposit.id))
+                    .audit_info(audit_info)
+                    .build()
+                    .expect("Could not build rolled over time deposit");
+                Some(
+                    self.time_deposits
+                        .create_in_op(&mut op, new_time_deposit)
+                        .await?,
+                )
+            }
+            None => None,
+        };
+
+        self.ledger
+            .settle_time_deposit(
+                op,
+                settlement,
+                rolled_over
+                    .as_ref()
+                    .map(|next| (next.reference.as_str(), next.maturity_at)),
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,329 @@
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{
+    ApprovalProcessId, CalaTransactionId, DepositAccountId, TransferId, UsdCents,
+};
+use audit::AuditInfo;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum TransferStatus {
+    PendingApproval,
+    Confirmed,
+    Denied,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "TransferId")]
+pub enum TransferEvent {
+    Initialized {
+        id: TransferId,
+        ledger_tx_id: CalaTransactionId,
+        from_account_id: DepositAccountId,
+        to_account_id: DepositAccountId,
+        amount: UsdCents,
+        reference: String,
+        approval_process_id: Option<ApprovalProcessId>,
+        status: TransferStatus,
+        audit_info: AuditInfo,
+    },
+    ApprovalProcessConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        status: TransferStatus,
+        audit_info: AuditInfo,
+    },
+    Confirmed {
+        ledger_tx_id: CalaTransactionId,
+        status: TransferStatus,
+        audit_info: AuditInfo,
+    },
+    Cancelled {
+        ledger_tx_id: CalaTransactionId,
+        status: TransferStatus,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(Debug)]
+pub struct TransferSettlementData {
+    pub ledger_tx_id: CalaTransactionId,
+    pub approved: bool,
+    pub from_account_id: DepositAccountId,
+    pub to_account_id: DepositAccountId,
+    pub amount: UsdCents,
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct Transfer {
+    pub id: TransferId,
+    pub from_account_id: DepositAccountId,
+    pub to_account_id: DepositAccountId,
+    pub reference: String,
+    pub amount: UsdCents,
+    #[builder(default)]
+    pub approval_process_id: Option<ApprovalProcessId>,
+
+    events: EntityEvents<TransferEvent>,
+}
+
+impl Transfer {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for transfer")
+    }
+
+    pub fn status(&self) -> TransferStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .map(|e| match e {
+                TransferEvent::Initialized { status, .. } => *status,
+                TransferEvent::ApprovalProcessConcluded { status, .. } => *status,
+                TransferEvent::Confirmed { status, .. } => *status,
+                TransferEvent::Cancelled { status, .. } => *status,
+            })
+            .next()
+            .expect("status should always exist")
+    }
+
+    pub fn is_approved_or_denied(&self) -> Option<bool> {
+        self.events.iter_all().find_map(|e| match e {
+            TransferEvent::ApprovalProcessConcluded { approved, .. } => Some(*approved),
+            _ => None,
+        })
+    }
+
+    /// Completes a transfer that did not need governance approval.
+    pub fn confirm(&mut self, audit_info: AuditInfo) -> Idempotent<TransferSettlementData> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            TransferEvent::Confirmed { .. } | TransferEvent::Cancelled { .. }
+        );
+        self.settle(true, audit_info)
+    }
+
+    /// Releases the held funds to the recipient when approved or back to the
+    /// sender when denied.
+    pub fn approval_process_concluded(
+        &mut self,
+        approved: bool,
+        audit_info: AuditInfo,
+    ) -> Idempotent<TransferSettlementData> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            TransferEvent::ApprovalProcessConcluded { .. }
+        );
+        let status = if approved {
+            TransferStatus::Confirmed
+        } else {
+            TransferStatus::Denied
+        };
+        self.events.push(TransferEvent::ApprovalProcessConcluded {
+            approval_process_id: self.id.into(),
+            approved,
+            status,
+            audit_info: audit_info.clone(),
+        });
+        self.settle(approved, audit_info)
+    }
+
+    fn settle(
+        &mut self,
+        approved: bool,
+        audit_info: AuditInfo,
+    ) -> Idempotent<TransferSettlementData> {
+        let ledger_tx_id = CalaTransactionId::new();
+        if approved {
+            self.events.push(TransferEvent::Confirmed {
+                ledger_tx_id,
+                status: TransferStatus::Confirmed,
+                audit_info,
+            });
+        } else {
+            self.events.push(TransferEvent::Cancelled {
+                ledger_tx_id,
+                status: TransferStatus::Denied,
+                audit_info,
+            });
+        }
+        Idempotent::Executed(TransferSettlementData {
+            ledger_tx_id,
+            approved,
+            from_account_id: self.from_account_id,
+            to_account_id: self.to_account_id,
+            amount: self.amount,
+        })
+    }
+}
+
+impl TryFromEvents<TransferEvent> for Transfer {
+    fn try_from_events(events: EntityEvents<TransferEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = TransferBuilder::default();
+        for event in events.iter_all() {
+            if let TransferEvent::Initialized {
+                id,
+                reference,
+                from_account_id,
+                to_account_id,
+                amount,
+                approval_process_id,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .from_account_id(*from_account_id)
+                    .to_account_id(*to_account_id)
+                    .amount(*amount)
+                    .reference(reference.clone())
+                    .approval_process_id(*approval_process_id)
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewTransfer {
+    #[builder(setter(into))]
+    pub(super) id: TransferId,
+    #[builder(setter(into))]
+    pub(super) from_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) to_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) amount: UsdCents,
+    #[builder(default)]
+    pub(super) approval_process_id: Option<ApprovalProcessId>,
+    reference: Option<String>,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewTransfer {
+    pub fn builder() -> NewTransferBuilder {
+        NewTransferBuilder::default()
+    }
+
+    pub(super) fn reference(&self) -> String {
+        match self.reference.as_deref() {
+            None => self.id.to_string(),
+            Some("") => self.id.to_string(),
+            Some(reference) => reference.to_string(),
+        }
+    }
+}
+
+impl NewTransferBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if self.amount.is_some_and(|amount| amount.is_zero()) {
+            return Err("Transfer amount cannot be zero".to_string());
+        }
+        match (self.from_account_id, self.to_account_id) {
+            (Some(from), Some(to)) if from == to => {
+                Err("Cannot transfer to the same account".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl IntoEvents<TransferEvent> for NewTransfer {
+    fn into_events(self) -> EntityEvents<TransferEvent> {
+        EntityEvents::init(
+            self.id,
+            [TransferEvent::Initialized {
+                reference: self.reference(),
+                id: self.id,
+                ledger_tx_id: self.id.into(),
+                from_account_id: self.from_account_id,
+                to_account_id: self.to_account_id,
+                amount: self.amount,
+                approval_process_id: self.approval_process_id,
+                status: TransferStatus::PendingApproval,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn transfer(approval_process_id: Option<ApprovalProcessId>) -> Transfer {
+        let new_transfer = NewTransfer::builder()
+            .id(TransferId::new())
+            .from_account_id(DepositAccountId::new())
+            .to_account_id(DepositAccountId::new())
+            .amount(UsdCents::from(100))
+            .approval_process_id(approval_process_id)
+            .reference(None)
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        Transfer::try_from_events(new_transfer.into_events()).unwrap()
+    }
+
+    #[test]
+    fn errors_when_transferring_to_same_account() {
+        let account_id = DepositAccountId::new();
+        let new_transfer = NewTransfer::builder()
+            .id(TransferId::new())
+            .from_account_id(account_id)
+            .to_account_id(account_id)
+            .amount(UsdCents::from(100))
+            .reference(None)
+            .audit_info(dummy_audit_info())
+            .build();
+
+        assert!(matches!(
+            new_transfer,
+            Err(NewTransferBuilderError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn confirm_is_idempotent() {
+        let mut transfer = transfer(None);
+        assert!(transfer.confirm(dummy_audit_info()).did_execute());
+        assert_eq!(transfer.status(), TransferStatus::Confirmed);
+        assert!(transfer.confirm(dummy_audit_info()).was_ignored());
+    }
+
+    #[test]
+    fn denied_transfer_is_cancelled() {
+        let mut transfer = transfer(Some(ApprovalProcessId::new()));
+        let data = transfer
+            .approval_process_concluded(false, dummy_audit_info())
+            .unwrap();
+        assert!(!data.approved);
+        assert_eq!(transfer.status(), TransferStatus::Denied);
+        assert!(
+            transfer
+                .approval_process_concluded(true, dummy_audit_info())
+                .was_ignored()
+        );
+    }
+}
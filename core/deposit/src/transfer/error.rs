@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TransferError {
+    #[error("TransferError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("TransferError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("TransferError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("TransferError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+}
+
+es_entity::from_es_entity_error!(TransferError);
@@ -6,13 +6,16 @@ use serde::{Deserialize, Serialize};
 use es_entity::*;
 
 use crate::primitives::{
-    ApprovalProcessId, CalaTransactionId, DepositAccountId, UsdCents, WithdrawalId,
+    ApprovalProcessId, BeneficiaryId, CalaTransactionId, DepositAccountId, PaymentBatchId,
+    UsdCents, WithdrawalId,
 };
 use audit::AuditInfo;
 
 use super::error::WithdrawalError;
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(
+    Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, strum::Display, strum::EnumString,
+)]
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 pub enum WithdrawalStatus {
@@ -34,6 +37,10 @@ pub enum WithdrawalEvent {
         ledger_tx_id: CalaTransactionId,
         deposit_account_id: DepositAccountId,
         amount: UsdCents,
+        #[serde(default)]
+        fee: UsdCents,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        beneficiary_id: Option<BeneficiaryId>,
         reference: String,
         approval_process_id: ApprovalProcessId,
         status: WithdrawalStatus,
@@ -52,14 +59,22 @@ pub enum WithdrawalEvent {
     },
     Cancelled {
         ledger_tx_id: CalaTransactionId,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fee_refund_tx_id: Option<CalaTransactionId>,
         status: WithdrawalStatus,
         audit_info: AuditInfo,
     },
     Reverted {
         ledger_tx_id: CalaTransactionId,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fee_refund_tx_id: Option<CalaTransactionId>,
         status: WithdrawalStatus,
         audit_info: AuditInfo,
     },
+    IncludedInPaymentBatch {
+        payment_batch_id: PaymentBatchId,
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -69,9 +84,15 @@ pub struct Withdrawal {
     pub deposit_account_id: DepositAccountId,
     pub reference: String,
     pub amount: UsdCents,
+    pub fee: UsdCents,
+    #[builder(default)]
+    pub beneficiary_id: Option<BeneficiaryId>,
     pub approval_process_id: ApprovalProcessId,
     #[builder(setter(strip_option), default)]
     pub cancelled_tx_id: Option<CalaTransactionId>,
+    /// The payment file the withdrawal was sent to the bank with.
+    #[builder(setter(strip_option), default)]
+    pub payment_batch_id: Option<PaymentBatchId>,
 
     events: EntityEvents<WithdrawalEvent>,
 }
@@ -83,6 +104,14 @@ pub struct WithdrawalReversalData {
     pub amount: UsdCents,
     pub correlation_id: String,
     pub external_id: String,
+    pub fee_refund: Option<WithdrawalFeeRefund>,
+}
+
+#[derive(Debug)]
+pub struct WithdrawalFeeRefund {
+    pub ledger_tx_id: CalaTransactionId,
+    pub credit_account_id: DepositAccountId,
+    pub amount: UsdCents,
 }
 
 impl Withdrawal {
@@ -136,9 +165,11 @@ impl Withdrawal {
         }
 
         let ledger_tx_id = CalaTransactionId::new();
+        let fee_refund = self.new_fee_refund();
 
         self.events.push(WithdrawalEvent::Reverted {
             ledger_tx_id,
+            fee_refund_tx_id: fee_refund.as_ref().map(|refund| refund.ledger_tx_id),
             status: WithdrawalStatus::Reverted,
             audit_info,
         });
@@ -149,10 +180,14 @@ impl Withdrawal {
             credit_account_id: self.deposit_account_id,
             correlation_id: self.id.to_string(),
             external_id: format!("lana:withdraw:{}:reverted", self.id),
+            fee_refund,
         }))
     }
 
-    pub fn cancel(&mut self, audit_info: AuditInfo) -> Result<CalaTransactionId, WithdrawalError> {
+    pub fn cancel(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<(CalaTransactionId, Option<WithdrawalFeeRefund>), WithdrawalError> {
         if self.is_confirmed() {
             return Err(WithdrawalError::AlreadyConfirmed(self.id));
         }
@@ -162,14 +197,53 @@ impl Withdrawal {
         }
 
         let ledger_tx_id = CalaTransactionId::new();
+        let fee_refund = self.new_fee_refund();
         self.events.push(WithdrawalEvent::Cancelled {
             ledger_tx_id,
+            fee_refund_tx_id: fee_refund.as_ref().map(|refund| refund.ledger_tx_id),
             status: WithdrawalStatus::Cancelled,
             audit_info,
         });
         self.cancelled_tx_id = Some(ledger_tx_id);
 
-        Ok(ledger_tx_id)
+        Ok((ledger_tx_id, fee_refund))
+    }
+
+    /// Marks the withdrawal as paid out through a payment file so it cannot
+    /// be included in another one.
+    pub fn include_in_payment_batch(
+        &mut self,
+        payment_batch_id: PaymentBatchId,
+        audit_info: AuditInfo,
+    ) -> Result<(), WithdrawalError> {
+        if self.payment_batch_id.is_some() {
+            return Err(WithdrawalError::AlreadyInPaymentBatch(self.id));
+        }
+        if self.status() != WithdrawalStatus::Confirmed {
+            return Err(WithdrawalError::NotConfirmed(self.id));
+        }
+        if self.beneficiary_id.is_none() {
+            return Err(WithdrawalError::MissingBeneficiary(self.id));
+        }
+
+        self.events.push(WithdrawalEvent::IncludedInPaymentBatch {
+            payment_batch_id,
+            audit_info,
+        });
+        self.payment_batch_id = Some(payment_batch_id);
+
+        Ok(())
+    }
+
+    fn new_fee_refund(&self) -> Option<WithdrawalFeeRefund> {
+        if self.fee.is_zero() {
+            return None;
+        }
+        Some(WithdrawalFeeRefund {
+            ledger_tx_id: CalaTransactionId::new(),
+            credit_account_id: self.deposit_account_id,
+            amount: self.fee,
+        })
     }
 
     fn is_confirmed(&self) -> bool {
@@ -199,14 +273,14 @@ impl Withdrawal {
         self.events
             .iter_all()
             .rev()
-            .map(|e| match e {
-                WithdrawalEvent::Confirmed { status, .. } => *status,
-                WithdrawalEvent::Cancelled { status, .. } => *status,
-                WithdrawalEvent::Reverted { status, .. } => *status,
-                WithdrawalEvent::ApprovalProcessConcluded { status, .. } => *status,
-                WithdrawalEvent::Initialized { status, .. } => *status,
+            .find_map(|e| match e {
+                WithdrawalEvent::Confirmed { status, .. } => Some(*status),
+                WithdrawalEvent::Cancelled { status, .. } => Some(*status),
+                WithdrawalEvent::Reverted { status, .. } => Some(*status),
+                WithdrawalEvent::ApprovalProcessConcluded { status, .. } => Some(*status),
+                WithdrawalEvent::Initialized { status, .. } => Some(*status),
+                WithdrawalEvent::IncludedInPaymentBatch { .. } => None,
             })
-            .next()
             .expect("status should always exist")
     }
 
@@ -244,6 +318,8 @@ impl TryFromEvents<WithdrawalEvent> for Withdrawal {
                     reference,
                     deposit_account_id,
                     amount,
+                    fee,
+                    beneficiary_id,
                     approval_process_id,
                     ..
                 } => {
@@ -251,12 +327,17 @@ impl TryFromEvents<WithdrawalEvent> for Withdrawal {
                         .id(*id)
                         .deposit_account_id(*deposit_account_id)
                         .amount(*amount)
+                        .fee(*fee)
+                        .beneficiary_id(*beneficiary_id)
                         .reference(reference.clone())
                         .approval_process_id(*approval_process_id)
                 }
                 WithdrawalEvent::Cancelled { ledger_tx_id, .. } => {
                     builder = builder.cancelled_tx_id(*ledger_tx_id)
                 }
+                WithdrawalEvent::IncludedInPaymentBatch {
+                    payment_batch_id, ..
+                } => builder = builder.payment_batch_id(*payment_batch_id),
                 _ => (),
             }
         }
@@ -273,6 +354,10 @@ pub struct NewWithdrawal {
     pub(super) deposit_account_id: DepositAccountId,
     #[builder(setter(into))]
     pub(super) amount: UsdCents,
+    #[builder(setter(into), default)]
+    pub(super) fee: UsdCents,
+    #[builder(default)]
+    pub(super) beneficiary_id: Option<BeneficiaryId>,
     #[builder(setter(into))]
     pub(super) approval_process_id: ApprovalProcessId,
     reference: Option<String>,
@@ -292,6 +377,10 @@ impl NewWithdrawal {
             Some(reference) => reference.to_string(),
         }
     }
+
+    pub(super) fn status(&self) -> WithdrawalStatus {
+        WithdrawalStatus::PendingApproval
+    }
 }
 
 impl NewWithdrawalBuilder {
@@ -313,8 +402,10 @@ impl IntoEvents<WithdrawalEvent> for NewWithdrawal {
                 ledger_tx_id: self.id.into(),
                 deposit_account_id: self.deposit_account_id,
                 amount: self.amount,
+                fee: self.fee,
+                beneficiary_id: self.beneficiary_id,
                 approval_process_id: self.approval_process_id,
-                status: WithdrawalStatus::PendingApproval,
+                status: self.status(),
                 audit_info: self.audit_info,
             }],
         )
@@ -386,6 +477,7 @@ mod test {
             .id(WithdrawalId::new())
             .deposit_account_id(DepositAccountId::new())
             .amount(UsdCents::ONE)
+            .beneficiary_id(Some(BeneficiaryId::new()))
             .reference(None)
             .approval_process_id(ApprovalProcessId::new())
             .audit_info(dummy_audit_info())
@@ -442,6 +534,27 @@ mod test {
         assert!(result.was_ignored());
     }
 
+    #[test]
+    fn fee_is_refunded_on_cancel() {
+        let new_withdrawal = NewWithdrawal::builder()
+            .id(WithdrawalId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .amount(UsdCents::from(10_000))
+            .fee(UsdCents::from(150))
+            .reference(None)
+            .approval_process_id(ApprovalProcessId::new())
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+
+        let mut withdrawal = Withdrawal::try_from_events(new_withdrawal.into_events()).unwrap();
+        let (_, fee_refund) = withdrawal.cancel(dummy_audit_info()).unwrap();
+
+        let fee_refund = fee_refund.expect("fee should be refunded");
+        assert_eq!(fee_refund.amount, UsdCents::from(150));
+        assert_eq!(fee_refund.credit_account_id, withdrawal.deposit_account_id);
+    }
+
     #[test]
     fn cannot_revert_unconfirmed_withdrawal() {
         let new_withdrawal = NewWithdrawal::builder()
@@ -463,4 +576,31 @@ mod test {
 
         assert!(matches!(result, Err(WithdrawalError::NotConfirmed(_))));
     }
+
+    #[test]
+    fn confirmed_withdrawal_is_included_in_one_payment_batch() {
+        let mut withdrawal = create_confirmed_withdrawal();
+        let payment_batch_id = PaymentBatchId::new();
+
+        withdrawal
+            .include_in_payment_batch(payment_batch_id, dummy_audit_info())
+            .unwrap();
+        assert_eq!(withdrawal.payment_batch_id, Some(payment_batch_id));
+        assert_eq!(withdrawal.status(), WithdrawalStatus::Confirmed);
+
+        let result = withdrawal.include_in_payment_batch(PaymentBatchId::new(), dummy_audit_info());
+        assert!(matches!(
+            result,
+            Err(WithdrawalError::AlreadyInPaymentBatch(_))
+        ));
+    }
+
+    #[test]
+    fn reverted_withdrawal_cannot_be_included_in_payment_batch() {
+        let mut withdrawal = create_confirmed_withdrawal();
+        let _ = withdrawal.revert(dummy_audit_info()).unwrap();
+
+        let result = withdrawal.include_in_payment_batch(PaymentBatchId::new(), dummy_audit_info());
+        assert!(matches!(result, Err(WithdrawalError::NotConfirmed(_))));
+    }
 }
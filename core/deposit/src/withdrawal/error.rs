@@ -20,6 +20,10 @@ pub enum WithdrawalError {
     AuditError(#[from] audit::error::AuditError),
     #[error("WithdrawalError - NotConfirmed: {0}")]
     NotConfirmed(WithdrawalId),
+    #[error("WithdrawalError - AlreadyInPaymentBatch: {0}")]
+    AlreadyInPaymentBatch(WithdrawalId),
+    #[error("WithdrawalError - MissingBeneficiary: {0}")]
+    MissingBeneficiary(WithdrawalId),
 }
 
 es_entity::from_es_entity_error!(WithdrawalError);
@@ -5,7 +5,10 @@ use outbox::OutboxEventMarker;
 
 use crate::{
     event::CoreDepositEvent,
-    primitives::{ApprovalProcessId, CalaTransactionId, DepositAccountId, WithdrawalId},
+    primitives::{
+        ApprovalProcessId, BeneficiaryId, CalaTransactionId, DepositAccountId, PaymentBatchId,
+        WithdrawalId,
+    },
     publisher::DepositPublisher,
 };
 
@@ -18,7 +21,14 @@ use super::{entity::*, error::*};
     columns(
         deposit_account_id(ty = "DepositAccountId", list_for, update(persist = false)),
         approval_process_id(ty = "ApprovalProcessId", update(persist = false)),
+        beneficiary_id(ty = "Option<BeneficiaryId>", update(persist = false)),
+        status(
+            ty = "WithdrawalStatus",
+            create(accessor = "status()"),
+            update(accessor = "status()")
+        ),
         cancelled_tx_id(ty = "Option<CalaTransactionId>", create(persist = false)),
+        payment_batch_id(ty = "Option<PaymentBatchId>", create(persist = false)),
         reference(ty = "String", create(accessor = "reference()"))
     ),
     tbl_prefix = "core",
@@ -66,4 +76,66 @@ where
             .publish_withdrawal(db, entity, new_events)
             .await
     }
+
+    /// Confirmed withdrawals to a beneficiary that have not been written to a
+    /// payment file yet, oldest first.
+    pub async fn list_unbatched_confirmed(
+        &self,
+        first: usize,
+    ) -> Result<Vec<Withdrawal>, WithdrawalError> {
+        let (entities, _) = es_query!(
+            "core",
+            self.pool(),
+            r#"
+                SELECT created_at, id FROM core_withdrawals
+                WHERE status = $1
+                  AND payment_batch_id IS NULL
+                  AND beneficiary_id IS NOT NULL
+                ORDER BY created_at, id
+                LIMIT $2"#,
+            WithdrawalStatus::Confirmed as WithdrawalStatus,
+            (first + 1) as i64,
+        )
+        .fetch_n(first)
+        .await?;
+        Ok(entities)
+    }
+}
+
+mod withdrawal_status_sqlx {
+    use sqlx::{Type, postgres::*};
+
+    use super::WithdrawalStatus;
+
+    impl Type<Postgres> for WithdrawalStatus {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for WithdrawalStatus {
+        fn encode_by_ref(
+            &self,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            <String as sqlx::Encode<'_, Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for WithdrawalStatus {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(s.parse().map_err(|e: strum::ParseError| Box::new(e))?)
+        }
+    }
+
+    impl PgHasArrayType for WithdrawalStatus {
+        fn array_type_info() -> PgTypeInfo {
+            <String as sqlx::postgres::PgHasArrayType>::array_type_info()
+        }
+    }
 }
@@ -0,0 +1,121 @@
+use rust_decimal::{Decimal, RoundingStrategy, prelude::ToPrimitive};
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::UsdCents;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WithdrawalFeeRate {
+    Flat {
+        amount: UsdCents,
+    },
+    /// Percentage of the withdrawn amount, e.g. `0.5` for 0.5%.
+    Percentage {
+        rate: Decimal,
+    },
+}
+
+/// Fee charged on every withdrawal initiated from an account of the product.
+/// The computed fee is clamped to `min_fee` / `max_fee` when they are set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct WithdrawalFeeSchedule {
+    pub rate: WithdrawalFeeRate,
+    pub min_fee: Option<UsdCents>,
+    pub max_fee: Option<UsdCents>,
+}
+
+impl WithdrawalFeeSchedule {
+    pub fn fee_for(&self, amount: UsdCents) -> UsdCents {
+        let fee = match self.rate {
+            WithdrawalFeeRate::Flat { amount } => amount,
+            WithdrawalFeeRate::Percentage { rate } => {
+                let cents = (Decimal::from(amount.into_inner()) * rate / Decimal::ONE_HUNDRED)
+                    .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero);
+                UsdCents::from(cents.to_u64().unwrap_or(0))
+            }
+        };
+        let fee = match self.min_fee {
+            Some(min_fee) if fee < min_fee => min_fee,
+            _ => fee,
+        };
+        match self.max_fee {
+            Some(max_fee) if fee > max_fee => max_fee,
+            _ => fee,
+        }
+    }
+
+    pub(crate) fn is_valid(&self) -> bool {
+        let rate_is_valid = match self.rate {
+            WithdrawalFeeRate::Flat { .. } => true,
+            WithdrawalFeeRate::Percentage { rate } => {
+                rate >= Decimal::ZERO && rate <= Decimal::ONE_HUNDRED
+            }
+        };
+        let bounds_are_valid = match (self.min_fee, self.max_fee) {
+            (Some(min_fee), Some(max_fee)) => min_fee <= max_fee,
+            _ => true,
+        };
+        rate_is_valid && bounds_are_valid
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn percentage_fee_is_rounded_and_clamped() {
+        let schedule = WithdrawalFeeSchedule {
+            rate: WithdrawalFeeRate::Percentage { rate: dec!(0.5) },
+            min_fee: Some(UsdCents::from(100)),
+            max_fee: Some(UsdCents::from(2_500)),
+        };
+
+        assert_eq!(
+            schedule.fee_for(UsdCents::from(10_000)),
+            UsdCents::from(100)
+        );
+        assert_eq!(
+            schedule.fee_for(UsdCents::from(100_100)),
+            UsdCents::from(501)
+        );
+        assert_eq!(
+            schedule.fee_for(UsdCents::from(1_000_000)),
+            UsdCents::from(2_500)
+        );
+    }
+
+    #[test]
+    fn flat_fee_ignores_amount() {
+        let schedule = WithdrawalFeeSchedule {
+            rate: WithdrawalFeeRate::Flat {
+                amount: UsdCents::from(250),
+            },
+            min_fee: None,
+            max_fee: None,
+        };
+
+        assert_eq!(schedule.fee_for(UsdCents::ONE), UsdCents::from(250));
+        assert_eq!(
+            schedule.fee_for(UsdCents::from(99_999)),
+            UsdCents::from(250)
+        );
+    }
+
+    #[test]
+    fn rejects_inverted_bounds() {
+        let schedule = WithdrawalFeeSchedule {
+            rate: WithdrawalFeeRate::Percentage { rate: dec!(1) },
+            min_fee: Some(UsdCents::from(500)),
+            max_fee: Some(UsdCents::from(100)),
+        };
+
+        assert!(!schedule.is_valid());
+    }
+}
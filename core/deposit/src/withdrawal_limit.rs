@@ -0,0 +1,205 @@
+use chrono::{DateTime, Datelike, Days, NaiveTime, Utc};
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::CoreDepositError, primitives::UsdCents};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum WithdrawalLimitPeriod {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl WithdrawalLimitPeriod {
+    pub const ALL: [Self; 3] = [Self::Daily, Self::Weekly, Self::Monthly];
+
+    /// Start of the UTC calendar window containing `now`. Weeks start on
+    /// Monday to line up with the ISO weeks the ledger windows are keyed by.
+    pub fn window_start(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let today = now.date_naive();
+        let first_day = match self {
+            Self::Daily => today,
+            Self::Weekly => today - Days::new(u64::from(today.weekday().num_days_from_monday())),
+            Self::Monthly => today.with_day(1).expect("first day of month"),
+        };
+        first_day.and_time(NaiveTime::MIN).and_utc()
+    }
+}
+
+/// Caps on the withdrawals initiated within a single period. Unset caps are
+/// not enforced.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct WithdrawalLimit {
+    pub max_amount: Option<UsdCents>,
+    pub max_count: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct WithdrawalLimits {
+    pub daily: WithdrawalLimit,
+    pub weekly: WithdrawalLimit,
+    pub monthly: WithdrawalLimit,
+}
+
+impl WithdrawalLimits {
+    pub fn for_period(&self, period: WithdrawalLimitPeriod) -> WithdrawalLimit {
+        match period {
+            WithdrawalLimitPeriod::Daily => self.daily,
+            WithdrawalLimitPeriod::Weekly => self.weekly,
+            WithdrawalLimitPeriod::Monthly => self.monthly,
+        }
+    }
+
+    pub(crate) fn max_amounts(&self) -> impl Iterator<Item = (WithdrawalLimitPeriod, UsdCents)> {
+        WithdrawalLimitPeriod::ALL
+            .into_iter()
+            .filter_map(|period| Some((period, self.for_period(period).max_amount?)))
+    }
+
+    /// Usage of every period, counting each withdrawal by the time it was
+    /// initiated. Withdrawals that were later cancelled still count.
+    pub(crate) fn usage(
+        &self,
+        now: DateTime<Utc>,
+        withdrawals: &[(DateTime<Utc>, UsdCents)],
+    ) -> Vec<WithdrawalLimitUsage> {
+        WithdrawalLimitPeriod::ALL
+            .into_iter()
+            .map(|period| {
+                let window_start = period.window_start(now);
+                let (used_amount, used_count) = withdrawals
+                    .iter()
+                    .filter(|(initiated_at, _)| *initiated_at >= window_start)
+                    .fold((UsdCents::ZERO, 0), |(total, count), (_, amount)| {
+                        (total + *amount, count + 1)
+                    });
+                WithdrawalLimitUsage {
+                    period,
+                    limit: self.for_period(period),
+                    used_amount,
+                    used_count,
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WithdrawalLimitUsage {
+    pub period: WithdrawalLimitPeriod,
+    pub limit: WithdrawalLimit,
+    pub used_amount: UsdCents,
+    pub used_count: u32,
+}
+
+impl WithdrawalLimitUsage {
+    pub fn remaining_amount(&self) -> Option<UsdCents> {
+        self.limit.max_amount.map(|max| {
+            if max > self.used_amount {
+                max - self.used_amount
+            } else {
+                UsdCents::ZERO
+            }
+        })
+    }
+
+    pub fn remaining_count(&self) -> Option<u32> {
+        self.limit
+            .max_count
+            .map(|max| max.saturating_sub(self.used_count))
+    }
+
+    pub(crate) fn check(&self, amount: UsdCents) -> Result<(), CoreDepositError> {
+        if self.remaining_count() == Some(0) {
+            return Err(CoreDepositError::WithdrawalCountLimitExceeded(self.period));
+        }
+        if self
+            .remaining_amount()
+            .is_some_and(|remaining| amount > remaining)
+        {
+            return Err(CoreDepositError::WithdrawalLimitExceeded(self.period));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn at(year: i32, month: u32, day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn window_starts_at_calendar_boundaries() {
+        // 2024-05-16 is a Thursday
+        let now = at(2024, 5, 16, 15);
+        assert_eq!(
+            WithdrawalLimitPeriod::Daily.window_start(now),
+            at(2024, 5, 16, 0)
+        );
+        assert_eq!(
+            WithdrawalLimitPeriod::Weekly.window_start(now),
+            at(2024, 5, 13, 0)
+        );
+        assert_eq!(
+            WithdrawalLimitPeriod::Monthly.window_start(now),
+            at(2024, 5, 1, 0)
+        );
+    }
+
+    #[test]
+    fn check_against_remaining_limit() {
+        let limits = WithdrawalLimits {
+            daily: WithdrawalLimit {
+                max_amount: Some(UsdCents::from(1_000)),
+                max_count: None,
+            },
+            weekly: WithdrawalLimit {
+                max_amount: None,
+                max_count: Some(2),
+            },
+            monthly: WithdrawalLimit::default(),
+        };
+        let now = at(2024, 5, 16, 15);
+        let withdrawals = [
+            (at(2024, 5, 16, 9), UsdCents::from(600)),
+            (at(2024, 5, 14, 9), UsdCents::from(900)),
+        ];
+
+        let usage = limits.usage(now, &withdrawals);
+        let daily = usage[0];
+        assert_eq!(daily.used_amount, UsdCents::from(600));
+        assert_eq!(daily.remaining_amount(), Some(UsdCents::from(400)));
+        assert!(daily.check(UsdCents::from(400)).is_ok());
+        assert!(matches!(
+            daily.check(UsdCents::from(401)),
+            Err(CoreDepositError::WithdrawalLimitExceeded(
+                WithdrawalLimitPeriod::Daily
+            ))
+        ));
+
+        let weekly = usage[1];
+        assert_eq!(weekly.remaining_count(), Some(0));
+        assert!(matches!(
+            weekly.check(UsdCents::from(1)),
+            Err(CoreDepositError::WithdrawalCountLimitExceeded(
+                WithdrawalLimitPeriod::Weekly
+            ))
+        ));
+
+        let monthly = usage[2];
+        assert_eq!(monthly.used_count, 2);
+        assert_eq!(monthly.remaining_amount(), None);
+    }
+}
@@ -28,8 +28,11 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
     let journal_id = helpers::init_journal(&cala).await?;
     let public_ids = public_id::PublicIds::new(&pool);
 
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = DocumentStorage::new(&pool, &storage);
     let deposit = CoreDeposit::init(
         &pool,
+        DepositConfig::default(),
         &authz,
         &outbox,
         &governance,
@@ -37,11 +40,10 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         &cala,
         journal_id,
         &public_ids,
+        document_storage.clone(),
     )
     .await?;
 
-    let storage = Storage::new(&StorageConfig::default());
-    let document_storage = DocumentStorage::new(&pool, &storage);
     let accounting = CoreAccounting::new(&pool, &authz, &cala, journal_id, document_storage, &jobs);
     let chart_ref = format!("ref-{:08}", rand::rng().random_range(0..10000));
     let chart = accounting
@@ -50,6 +52,8 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         .await?;
     let import = r#"
         2,Omnibus Parent
+        8,Interest Expense
+        9,Fee Income
         1,Individual Deposit Accounts
         7,Government Entity Deposit Accounts
         3,Private Company Deposit Accounts
@@ -78,6 +82,8 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
             ChartOfAccountsIntegrationConfig::builder()
                 .chart_of_accounts_id(chart_id)
                 .chart_of_accounts_omnibus_parent_code("2".parse().unwrap())
+                .chart_of_accounts_interest_expense_parent_code("8".parse().unwrap())
+                .chart_of_accounts_fee_income_parent_code("9".parse().unwrap())
                 .chart_of_accounts_individual_deposit_accounts_parent_code("1".parse().unwrap())
                 .chart_of_accounts_government_entity_deposit_accounts_parent_code(
                     "7".parse().unwrap(),
@@ -110,6 +116,8 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
 
     let import = r#"
         2,Other Omnibus Parent
+        8,Other Interest Expense
+        9,Other Fee Income
         1,Other Individual Deposit Accounts
         7,Other Government Entity Deposit 
         3,Other Private Company Deposit Accounts
@@ -131,6 +139,8 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
             ChartOfAccountsIntegrationConfig::builder()
                 .chart_of_accounts_id(chart_id)
                 .chart_of_accounts_omnibus_parent_code("2".parse().unwrap())
+                .chart_of_accounts_interest_expense_parent_code("8".parse().unwrap())
+                .chart_of_accounts_fee_income_parent_code("9".parse().unwrap())
                 .chart_of_accounts_individual_deposit_accounts_parent_code("1".parse().unwrap())
                 .chart_of_accounts_government_entity_deposit_accounts_parent_code(
                     "7".parse().unwrap(),
@@ -4,7 +4,9 @@ use rust_decimal_macros::dec;
 
 use authz::dummy::DummySubject;
 use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{Storage, config::StorageConfig};
 use core_deposit::*;
+use document_storage::DocumentStorage;
 use helpers::{action, event, object};
 
 #[tokio::test]
@@ -25,8 +27,12 @@ async fn deposit() -> anyhow::Result<()> {
     let journal_id = helpers::init_journal(&cala).await?;
     let public_ids = public_id::PublicIds::new(&pool);
 
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = DocumentStorage::new(&pool, &storage);
+
     let deposit = CoreDeposit::init(
         &pool,
+        DepositConfig::default(),
         &authz,
         &outbox,
         &governance,
@@ -34,6 +40,7 @@ async fn deposit() -> anyhow::Result<()> {
         &cala,
         journal_id,
         &public_ids,
+        document_storage,
     )
     .await?;
 
@@ -84,8 +91,12 @@ async fn revert_deposit() -> anyhow::Result<()> {
     let journal_id = helpers::init_journal(&cala).await?;
     let public_ids = public_id::PublicIds::new(&pool);
 
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = DocumentStorage::new(&pool, &storage);
+
     let deposit = CoreDeposit::init(
         &pool,
+        DepositConfig::default(),
         &authz,
         &outbox,
         &governance,
@@ -93,6 +104,7 @@ async fn revert_deposit() -> anyhow::Result<()> {
         &cala,
         journal_id,
         &public_ids,
+        document_storage,
     )
     .await?;
 
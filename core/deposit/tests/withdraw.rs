@@ -4,7 +4,9 @@ use rust_decimal_macros::dec;
 
 use authz::dummy::DummySubject;
 use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use cloud_storage::{Storage, config::StorageConfig};
 use core_deposit::*;
+use document_storage::DocumentStorage;
 
 use helpers::{action, event, object};
 
@@ -26,8 +28,12 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
     let journal_id = helpers::init_journal(&cala).await?;
     let public_ids = public_id::PublicIds::new(&pool);
 
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = DocumentStorage::new(&pool, &storage);
+
     let deposit = CoreDeposit::init(
         &pool,
+        DepositConfig::default(),
         &authz,
         &outbox,
         &governance,
@@ -35,6 +41,7 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
         &cala,
         journal_id,
         &public_ids,
+        document_storage,
     )
     .await?;
 
@@ -57,7 +64,7 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
     // overdraw
     let withdrawal_amount = UsdCents::try_from_usd(dec!(5000000)).unwrap();
     let withdrawal = deposit
-        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None)
+        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None, None)
         .await;
     assert!(matches!(
         withdrawal,
@@ -67,7 +74,7 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
     let withdrawal_amount = UsdCents::try_from_usd(dec!(500000)).unwrap();
 
     let withdrawal = deposit
-        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None)
+        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None, None)
         .await?;
 
     let balance = deposit.account_balance(&DummySubject, account.id).await?;
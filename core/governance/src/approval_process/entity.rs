@@ -36,6 +36,9 @@ pub enum ApprovalProcessEvent {
         approved: bool,
         audit_info: AuditInfo,
     },
+    Cancelled {
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -112,7 +115,7 @@ impl ApprovalProcess {
     ) -> Idempotent<(bool, Option<String>)> {
         idempotency_guard!(
             self.events.iter_all(),
-            ApprovalProcessEvent::Concluded { .. },
+            ApprovalProcessEvent::Concluded { .. } | ApprovalProcessEvent::Cancelled { .. },
         );
         if let Some(approved) =
             self.rules
@@ -145,6 +148,9 @@ impl ApprovalProcess {
                 ApprovalProcessEvent::Concluded {
                     approved: false, ..
                 } => return ApprovalProcessStatus::Denied,
+                ApprovalProcessEvent::Cancelled { .. } => {
+                    return ApprovalProcessStatus::Cancelled;
+                }
                 _ => {}
             }
         }
@@ -160,7 +166,7 @@ impl ApprovalProcess {
         use ApprovalProcessEvent::*;
         idempotency_guard!(
             self.events.iter_all(),
-            Concluded {..},
+            Concluded {..} | Cancelled {..},
             Approved {approver_id: id, ..} | Denied {denier_id: id,..} if id == &approver_id,
         );
 
@@ -186,7 +192,7 @@ impl ApprovalProcess {
         use ApprovalProcessEvent::*;
         idempotency_guard!(
             self.events.iter_all(),
-            Concluded {..},
+            Concluded {..} | Cancelled {..},
             Approved {approver_id: id, ..} | Denied {denier_id: id,..} if id == &denier_id,
         );
 
@@ -203,6 +209,16 @@ impl ApprovalProcess {
         Idempotent::Executed(())
     }
 
+    pub(crate) fn cancel(&mut self, audit_info: AuditInfo) -> Idempotent<()> {
+        use ApprovalProcessEvent::*;
+        idempotency_guard!(self.events.iter_all(), Concluded { .. } | Cancelled { .. });
+
+        self.events
+            .push(ApprovalProcessEvent::Cancelled { audit_info });
+
+        Idempotent::Executed(())
+    }
+
     pub fn approvers(&self) -> HashSet<CommitteeMemberId> {
         self.events
             .iter_all()
@@ -245,6 +261,7 @@ impl TryFromEvents<ApprovalProcessEvent> for ApprovalProcess {
                 ApprovalProcessEvent::Approved { .. } => {}
                 ApprovalProcessEvent::Denied { .. } => {}
                 ApprovalProcessEvent::Concluded { .. } => {}
+                ApprovalProcessEvent::Cancelled { .. } => {}
             }
         }
         builder.events(events).build()
@@ -469,4 +486,40 @@ mod tests {
                 .was_ignored()
         );
     }
+
+    #[test]
+    fn cancel_blocks_votes_and_conclusion() {
+        let mut process =
+            ApprovalProcess::try_from_events(init_events(ApprovalRules::CommitteeThreshold {
+                threshold: 1,
+                committee_id: CommitteeId::new(),
+            }))
+            .expect("Could not build approval process");
+        assert!(process.cancel(dummy_audit_info()).did_execute());
+        assert!(process.cancel(dummy_audit_info()).was_ignored());
+        assert_eq!(process.status(), ApprovalProcessStatus::Cancelled);
+
+        let approver = CommitteeMemberId::new();
+        let eligible: HashSet<_> = [approver].iter().copied().collect();
+        assert!(
+            process
+                .approve(&eligible, approver, dummy_audit_info())
+                .was_ignored()
+        );
+        assert!(
+            process
+                .check_concluded(eligible, dummy_audit_info())
+                .was_ignored()
+        );
+    }
+
+    #[test]
+    fn cancel_already_concluded() {
+        let mut process =
+            ApprovalProcess::try_from_events(init_events(ApprovalRules::SystemAutoApprove))
+                .expect("Could not build approval process");
+        let _ = process.check_concluded(HashSet::new(), dummy_audit_info());
+        assert!(process.cancel(dummy_audit_info()).was_ignored());
+        assert_eq!(process.status(), ApprovalProcessStatus::Approved);
+    }
 }
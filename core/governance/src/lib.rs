@@ -227,6 +227,29 @@ where
         Ok(process)
     }
 
+    #[instrument(name = "governance.cancel_process_in_op", skip(self, db), err)]
+    pub async fn cancel_process_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        process_id: impl Into<ApprovalProcessId> + std::fmt::Debug,
+    ) -> Result<ApprovalProcess, GovernanceError> {
+        let process_id = process_id.into();
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db.tx(),
+                GovernanceObject::approval_process(process_id),
+                GovernanceAction::APPROVAL_PROCESS_CANCEL,
+            )
+            .await?;
+        let mut process = self.process_repo.find_by_id(process_id).await?;
+        if process.cancel(audit_info).did_execute() {
+            self.process_repo.update_in_op(db, &mut process).await?;
+        }
+        Ok(process)
+    }
+
     #[instrument(name = "governance.approve_process", skip(self), err)]
     pub async fn approve_process(
         &self,
@@ -10,6 +10,7 @@ pub enum ApprovalProcessStatus {
     Approved,
     Denied,
     InProgress,
+    Cancelled,
 }
 
 impl ApprovalProcessStatus {
@@ -77,6 +78,8 @@ impl GovernanceAction {
         GovernanceAction::ApprovalProcess(ApprovalProcessAction::Deny);
     pub const APPROVAL_PROCESS_CONCLUDE: Self =
         GovernanceAction::ApprovalProcess(ApprovalProcessAction::Conclude);
+    pub const APPROVAL_PROCESS_CANCEL: Self =
+        GovernanceAction::ApprovalProcess(ApprovalProcessAction::Cancel);
 
     pub fn entities() -> Vec<(
         GovernanceActionDiscriminants,
@@ -225,6 +228,7 @@ pub enum ApprovalProcessAction {
     Approve,
     Deny,
     Conclude,
+    Cancel,
 }
 
 impl ApprovalProcessAction {
@@ -257,6 +261,9 @@ impl ApprovalProcessAction {
                 Self::Conclude => {
                     ActionDescription::new(variant, &[PERMISSION_SET_GOVERNANCE_WRITER])
                 }
+                Self::Cancel => {
+                    ActionDescription::new(variant, &[PERMISSION_SET_GOVERNANCE_WRITER])
+                }
             };
             res.push(action_description);
         }
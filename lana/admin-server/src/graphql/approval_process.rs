@@ -3,8 +3,8 @@ use async_graphql::*;
 use crate::primitives::*;
 
 use super::{
-    access::User, approval_rules::*, credit_facility::*, loader::LanaDataLoader, policy::*,
-    withdrawal::*,
+    access::User, approval_rules::*, beneficiary::*, credit_facility::*, loader::LanaDataLoader,
+    policy::*, transfer::*, withdrawal::*,
 };
 
 pub use lana_app::governance::{
@@ -141,7 +141,32 @@ impl ApprovalProcess {
                     .expect("withdrawal not found");
                 Ok(ApprovalProcessTarget::Withdrawal(withdrawal))
             }
-            ApprovalProcessType::CreditFacilityApproval => {
+            ApprovalProcessType::TransferApproval => {
+                let transfer = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<TransferId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("transfer not found");
+                Ok(ApprovalProcessTarget::Transfer(transfer))
+            }
+            ApprovalProcessType::BeneficiaryApproval => {
+                let beneficiary = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<BeneficiaryId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("beneficiary not found");
+                Ok(ApprovalProcessTarget::Beneficiary(beneficiary))
+            }
+            ApprovalProcessType::CreditFacilityApproval
+            | ApprovalProcessType::ObligationReinstatementApproval => {
                 let credit_facility = loader
                     .load_one(
                         self.entity
@@ -173,18 +198,27 @@ impl ApprovalProcess {
 #[allow(clippy::enum_variant_names)]
 pub enum ApprovalProcessType {
     WithdrawalApproval,
+    TransferApproval,
+    BeneficiaryApproval,
     CreditFacilityApproval,
     DisbursalApproval,
+    ObligationReinstatementApproval,
 }
 
 impl From<&DomainApprovalProcessType> for ApprovalProcessType {
     fn from(process_type: &DomainApprovalProcessType) -> Self {
         if process_type == &lana_app::governance::APPROVE_WITHDRAWAL_PROCESS {
             Self::WithdrawalApproval
+        } else if process_type == &lana_app::governance::APPROVE_TRANSFER_PROCESS {
+            Self::TransferApproval
+        } else if process_type == &lana_app::governance::APPROVE_BENEFICIARY_PROCESS {
+            Self::BeneficiaryApproval
         } else if process_type == &lana_app::governance::APPROVE_CREDIT_FACILITY_PROCESS {
             Self::CreditFacilityApproval
         } else if process_type == &lana_app::governance::APPROVE_DISBURSAL_PROCESS {
             Self::DisbursalApproval
+        } else if process_type == &lana_app::governance::APPROVE_OBLIGATION_REINSTATEMENT_PROCESS {
+            Self::ObligationReinstatementApproval
         } else {
             panic!("Unknown approval process type: {process_type:?}");
         }
@@ -219,6 +253,8 @@ impl ApprovalProcessVoter {
 #[derive(async_graphql::Union)]
 pub(super) enum ApprovalProcessTarget {
     Withdrawal(Withdrawal),
+    Transfer(Transfer),
+    Beneficiary(Beneficiary),
     CreditFacility(CreditFacility),
     CreditFacilityDisbursal(CreditFacilityDisbursal),
 }
@@ -0,0 +1,97 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{deposit::Deposit, deposit_account::DepositAccount, loader::LanaDataLoader};
+
+pub use lana_app::deposit::{
+    BankStatementLine as DomainBankStatementLine, BankStatementLineStatus,
+    BankStatementLinesByCreatedAtCursor,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct BankStatementLine {
+    id: ID,
+    bank_statement_line_id: UUID,
+    statement_id: String,
+    reference: String,
+    amount: UsdCents,
+    booked_on: Option<Date>,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainBankStatementLine>,
+}
+
+impl From<DomainBankStatementLine> for BankStatementLine {
+    fn from(line: DomainBankStatementLine) -> Self {
+        BankStatementLine {
+            id: line.id.to_global_id(),
+            bank_statement_line_id: UUID::from(line.id),
+            statement_id: line.statement_id.clone(),
+            reference: line.reference.clone(),
+            amount: line.amount,
+            booked_on: line.booked_on.map(Into::into),
+            created_at: line.created_at().into(),
+            entity: Arc::new(line),
+        }
+    }
+}
+
+#[ComplexObject]
+impl BankStatementLine {
+    async fn debtor_name(&self) -> Option<&str> {
+        self.entity.debtor_name.as_deref()
+    }
+
+    async fn creditor_account(&self) -> Option<&str> {
+        self.entity.creditor_account.as_deref()
+    }
+
+    async fn creditor_reference(&self) -> Option<&str> {
+        self.entity.creditor_reference.as_deref()
+    }
+
+    async fn remittance_information(&self) -> Option<&str> {
+        self.entity.remittance_information.as_deref()
+    }
+
+    async fn status(&self) -> BankStatementLineStatus {
+        self.entity.status()
+    }
+
+    async fn deposit(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Deposit>> {
+        let Some(deposit_id) = self.entity.deposit_id() else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(deposit_id).await?)
+    }
+
+    async fn account(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<DepositAccount>> {
+        let Some(account_id) = self.entity.deposit_account_id() else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(account_id).await?)
+    }
+}
+
+#[derive(InputObject)]
+pub struct BankStatementImportInput {
+    pub file: Upload,
+}
+
+#[derive(SimpleObject)]
+pub struct BankStatementImportPayload {
+    /// Lines imported from the file. Lines already imported before are left out.
+    pub bank_statement_lines: Vec<BankStatementLine>,
+}
+
+#[derive(InputObject)]
+pub struct BankStatementLineAssignInput {
+    pub bank_statement_line_id: UUID,
+    pub deposit_account_id: UUID,
+}
+crate::mutation_payload! { BankStatementLineAssignPayload, bank_statement_line: BankStatementLine }
@@ -0,0 +1,94 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{
+    approval_process::ApprovalProcess, deposit_account::DepositAccount, loader::LanaDataLoader,
+};
+
+pub use lana_app::deposit::{Beneficiary as DomainBeneficiary, BeneficiaryStatus};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct Beneficiary {
+    id: ID,
+    beneficiary_id: UUID,
+    deposit_account_id: UUID,
+    replaces_beneficiary_id: Option<UUID>,
+    approval_process_id: UUID,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainBeneficiary>,
+}
+
+impl From<DomainBeneficiary> for Beneficiary {
+    fn from(beneficiary: DomainBeneficiary) -> Self {
+        Beneficiary {
+            id: beneficiary.id.to_global_id(),
+            beneficiary_id: UUID::from(beneficiary.id),
+            deposit_account_id: beneficiary.deposit_account_id.into(),
+            replaces_beneficiary_id: beneficiary.replaces.map(UUID::from),
+            approval_process_id: beneficiary.approval_process_id.into(),
+            created_at: beneficiary.created_at().into(),
+            entity: Arc::new(beneficiary),
+        }
+    }
+}
+
+#[ComplexObject]
+impl Beneficiary {
+    async fn account_holder_name(&self) -> &str {
+        &self.entity.bank_account.account_holder_name
+    }
+
+    async fn account_number(&self) -> &str {
+        &self.entity.bank_account.account_number
+    }
+
+    async fn bank_name(&self) -> &str {
+        &self.entity.bank_account.bank_name
+    }
+
+    async fn bank_identifier(&self) -> &str {
+        &self.entity.bank_account.bank_identifier
+    }
+
+    async fn usable_from(&self) -> Option<Timestamp> {
+        self.entity.usable_from().map(Into::into)
+    }
+
+    async fn status(&self, ctx: &Context<'_>) -> async_graphql::Result<BeneficiaryStatus> {
+        let (app, _) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(app
+            .deposits()
+            .ensure_up_to_date_beneficiary_status(&self.entity)
+            .await?
+            .map(|b| b.status())
+            .unwrap_or_else(|| self.entity.status()))
+    }
+
+    async fn approval_process(&self, ctx: &Context<'_>) -> async_graphql::Result<ApprovalProcess> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let process = loader
+            .load_one(self.entity.approval_process_id)
+            .await?
+            .expect("process not found");
+        Ok(process)
+    }
+
+    async fn account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.deposit_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+}
+
+#[derive(InputObject)]
+pub struct BeneficiaryRemoveInput {
+    pub beneficiary_id: UUID,
+}
+crate::mutation_payload! { BeneficiaryRemovePayload, beneficiary: Beneficiary }
@@ -10,11 +10,14 @@ pub struct CreditModuleConfig {
     chart_of_account_facility_omnibus_parent_code: Option<String>,
     chart_of_account_collateral_omnibus_parent_code: Option<String>,
     chart_of_account_in_liquidation_omnibus_parent_code: Option<String>,
+    chart_of_account_disbursal_settlement_omnibus_parent_code: Option<String>,
+    chart_of_account_participation_principal_omnibus_parent_code: Option<String>,
     chart_of_account_facility_parent_code: Option<String>,
     chart_of_account_collateral_parent_code: Option<String>,
     chart_of_account_in_liquidation_parent_code: Option<String>,
     chart_of_account_interest_income_parent_code: Option<String>,
     chart_of_account_fee_income_parent_code: Option<String>,
+    chart_of_account_participation_payable_parent_code: Option<String>,
 
     chart_of_account_short_term_individual_disbursed_receivable_parent_code: Option<String>,
     chart_of_account_short_term_government_entity_disbursed_receivable_parent_code: Option<String>,
@@ -92,6 +95,16 @@ impl From<DomainChartOfAccountsIntegrationConfig> for CreditModuleConfig {
                     .chart_of_account_in_liquidation_omnibus_parent_code
                     .to_string(),
             ),
+            chart_of_account_disbursal_settlement_omnibus_parent_code: Some(
+                values
+                    .chart_of_account_disbursal_settlement_omnibus_parent_code
+                    .to_string(),
+            ),
+            chart_of_account_participation_principal_omnibus_parent_code: Some(
+                values
+                    .chart_of_account_participation_principal_omnibus_parent_code
+                    .to_string(),
+            ),
             chart_of_account_facility_parent_code: Some(
                 values.chart_of_account_facility_parent_code.to_string(),
             ),
@@ -109,6 +122,11 @@ impl From<DomainChartOfAccountsIntegrationConfig> for CreditModuleConfig {
             chart_of_account_fee_income_parent_code: Some(
                 values.chart_of_account_fee_income_parent_code.to_string(),
             ),
+            chart_of_account_participation_payable_parent_code: Some(
+                values
+                    .chart_of_account_participation_payable_parent_code
+                    .to_string(),
+            ),
 
             chart_of_account_short_term_individual_disbursed_receivable_parent_code: Some(
                 values
@@ -300,11 +318,14 @@ pub struct CreditModuleConfigureInput {
     pub chart_of_account_facility_omnibus_parent_code: String,
     pub chart_of_account_collateral_omnibus_parent_code: String,
     pub chart_of_account_in_liquidation_omnibus_parent_code: String,
+    pub chart_of_account_disbursal_settlement_omnibus_parent_code: String,
+    pub chart_of_account_participation_principal_omnibus_parent_code: String,
     pub chart_of_account_facility_parent_code: String,
     pub chart_of_account_collateral_parent_code: String,
     pub chart_of_account_in_liquidation_parent_code: String,
     pub chart_of_account_interest_income_parent_code: String,
     pub chart_of_account_fee_income_parent_code: String,
+    pub chart_of_account_participation_payable_parent_code: String,
 
     pub chart_of_account_short_term_individual_disbursed_receivable_parent_code: String,
     pub chart_of_account_short_term_government_entity_disbursed_receivable_parent_code: String,
@@ -6,7 +6,9 @@ use crate::{
     primitives::*,
 };
 pub use lana_app::{
-    credit::{Disbursal as DomainDisbursal, DisbursalsCursor},
+    credit::{
+        Disbursal as DomainDisbursal, DisbursalDestination, DisbursalsCursor, ExternalBeneficiary,
+    },
     public_id::PublicId,
 };
 
@@ -59,6 +61,15 @@ impl CreditFacilityDisbursal {
             .unwrap_or_else(|| self.entity.status()))
     }
 
+    async fn external_beneficiary(&self) -> Option<DisbursalExternalBeneficiary> {
+        match &self.entity.destination {
+            DisbursalDestination::ExternalAccount { beneficiary } => {
+                Some(DisbursalExternalBeneficiary::from(beneficiary.clone()))
+            }
+            DisbursalDestination::DepositAccount => None,
+        }
+    }
+
     async fn approval_process(&self, ctx: &Context<'_>) -> async_graphql::Result<ApprovalProcess> {
         let loader = ctx.data_unchecked::<LanaDataLoader>();
         let process = loader
@@ -69,9 +80,63 @@ impl CreditFacilityDisbursal {
     }
 }
 
+#[derive(SimpleObject, Clone)]
+pub struct DisbursalExternalBeneficiary {
+    name: String,
+    bank_name: String,
+    account_number: String,
+    routing_number: Option<String>,
+}
+
+impl From<ExternalBeneficiary> for DisbursalExternalBeneficiary {
+    fn from(beneficiary: ExternalBeneficiary) -> Self {
+        Self {
+            name: beneficiary.name,
+            bank_name: beneficiary.bank_name,
+            account_number: beneficiary.account_number,
+            routing_number: beneficiary.routing_number,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct DisbursalExternalBeneficiaryInput {
+    pub name: String,
+    pub bank_name: String,
+    pub account_number: String,
+    pub routing_number: Option<String>,
+}
+
+impl From<DisbursalExternalBeneficiaryInput> for DisbursalDestination {
+    fn from(input: DisbursalExternalBeneficiaryInput) -> Self {
+        DisbursalDestination::ExternalAccount {
+            beneficiary: ExternalBeneficiary {
+                name: input.name,
+                bank_name: input.bank_name,
+                account_number: input.account_number,
+                routing_number: input.routing_number,
+            },
+        }
+    }
+}
+
 #[derive(InputObject)]
 pub struct CreditFacilityDisbursalInitiateInput {
     pub credit_facility_id: UUID,
     pub amount: UsdCents,
+    pub external_beneficiary: Option<DisbursalExternalBeneficiaryInput>,
 }
 crate::mutation_payload! { CreditFacilityDisbursalInitiatePayload, disbursal: CreditFacilityDisbursal }
+
+#[derive(InputObject)]
+pub struct CreditFacilityDisbursalSettlementConfirmInput {
+    pub disbursal_id: UUID,
+}
+crate::mutation_payload! { CreditFacilityDisbursalSettlementConfirmPayload, disbursal: CreditFacilityDisbursal }
+
+#[derive(InputObject)]
+pub struct CreditFacilityDisbursalSettlementFailInput {
+    pub disbursal_id: UUID,
+    pub reason: String,
+}
+crate::mutation_payload! { CreditFacilityDisbursalSettlementFailPayload, disbursal: CreditFacilityDisbursal }
@@ -11,7 +11,9 @@ pub enum CreditFacilityHistoryEntry {
     Collateralization(CreditFacilityCollateralizationUpdated),
     Disbursal(CreditFacilityDisbursalExecuted),
     Interest(CreditFacilityInterestAccrued),
+    InterestAdjustment(CreditFacilityInterestAdjusted),
     ReservedForLiquidation(CreditFacilityLiquidationAmountReserved),
+    Reinstatement(CreditFacilityObligationReinstated),
 }
 
 #[derive(SimpleObject)]
@@ -67,6 +69,14 @@ pub struct CreditFacilityInterestAccrued {
     pub days: u32,
 }
 
+#[derive(SimpleObject)]
+pub struct CreditFacilityInterestAdjusted {
+    pub cents: UsdCents,
+    pub recorded_at: Timestamp,
+    pub effective: Date,
+    pub tx_id: UUID,
+}
+
 #[derive(SimpleObject)]
 pub struct CreditFacilityLiquidationAmountReserved {
     pub cents: UsdCents,
@@ -75,6 +85,14 @@ pub struct CreditFacilityLiquidationAmountReserved {
     pub tx_id: UUID,
 }
 
+#[derive(SimpleObject)]
+pub struct CreditFacilityObligationReinstated {
+    pub cents: UsdCents,
+    pub recorded_at: Timestamp,
+    pub effective: Date,
+    pub tx_id: UUID,
+}
+
 impl From<lana_app::credit::CreditFacilityHistoryEntry> for CreditFacilityHistoryEntry {
     fn from(transaction: lana_app::credit::CreditFacilityHistoryEntry) -> Self {
         match transaction {
@@ -96,9 +114,15 @@ impl From<lana_app::credit::CreditFacilityHistoryEntry> for CreditFacilityHistor
             lana_app::credit::CreditFacilityHistoryEntry::Interest(interest) => {
                 CreditFacilityHistoryEntry::Interest(interest.into())
             }
+            lana_app::credit::CreditFacilityHistoryEntry::InterestAdjustment(adjustment) => {
+                CreditFacilityHistoryEntry::InterestAdjustment(adjustment.into())
+            }
             lana_app::credit::CreditFacilityHistoryEntry::ReservedForLiquidation(liquidation) => {
                 CreditFacilityHistoryEntry::ReservedForLiquidation(liquidation.into())
             }
+            lana_app::credit::CreditFacilityHistoryEntry::Reinstatement(reinstatement) => {
+                CreditFacilityHistoryEntry::Reinstatement(reinstatement.into())
+            }
         }
     }
 }
@@ -174,6 +198,17 @@ impl From<lana_app::credit::InterestAccrualsPosted> for CreditFacilityInterestAc
     }
 }
 
+impl From<lana_app::credit::InterestAccrualAdjusted> for CreditFacilityInterestAdjusted {
+    fn from(adjustment: lana_app::credit::InterestAccrualAdjusted) -> Self {
+        Self {
+            cents: adjustment.cents,
+            recorded_at: adjustment.recorded_at.into(),
+            effective: adjustment.effective.into(),
+            tx_id: UUID::from(adjustment.tx_id),
+        }
+    }
+}
+
 impl From<lana_app::credit::ObligationMovedToLiquidation>
     for CreditFacilityLiquidationAmountReserved
 {
@@ -186,3 +221,14 @@ impl From<lana_app::credit::ObligationMovedToLiquidation>
         }
     }
 }
+
+impl From<lana_app::credit::DefaultedObligationReinstated> for CreditFacilityObligationReinstated {
+    fn from(reinstatement: lana_app::credit::DefaultedObligationReinstated) -> Self {
+        Self {
+            cents: reinstatement.cents,
+            recorded_at: reinstatement.recorded_at.into(),
+            effective: reinstatement.effective.into(),
+            tx_id: UUID::from(reinstatement.tx_id),
+        }
+    }
+}
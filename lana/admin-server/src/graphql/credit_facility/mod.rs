@@ -3,8 +3,10 @@ mod collateral;
 pub(super) mod disbursal;
 mod error;
 mod history;
+mod participation;
 pub(super) mod payment_allocation;
 mod repayment;
+mod underwriting;
 
 use async_graphql::*;
 
@@ -12,7 +14,7 @@ use crate::primitives::*;
 
 use super::{
     approval_process::*, custody::Wallet, customer::*, loader::LanaDataLoader,
-    primitives::SortDirection, terms::*,
+    primitives::SortDirection, terms::*, terms_template::TermsTemplate,
 };
 pub use lana_app::{
     credit::{
@@ -30,7 +32,9 @@ pub use collateral::*;
 pub use disbursal::*;
 pub use error::*;
 pub use history::*;
+pub use participation::*;
 pub use repayment::*;
+pub use underwriting::*;
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -78,10 +82,33 @@ impl CreditFacility {
         Ok(app.credit().can_be_completed(&self.entity).await?)
     }
 
+    async fn days_past_due(&self) -> u32 {
+        self.entity.days_past_due()
+    }
+
+    async fn aging_bucket(&self) -> AgingBucket {
+        self.entity.aging_bucket()
+    }
+
     async fn credit_facility_terms(&self) -> TermValues {
         self.entity.terms.into()
     }
 
+    async fn terms_template(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<TermsTemplate>> {
+        let Some(terms_template) = self.entity.terms_template else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(terms_template.terms_template_id).await?)
+    }
+
+    async fn terms_template_version(&self) -> Option<u32> {
+        self.entity.terms_template.map(|t| t.version)
+    }
+
     async fn status(&self, ctx: &Context<'_>) -> async_graphql::Result<CreditFacilityStatus> {
         let (app, _) = crate::app_and_sub_from_ctx!(ctx);
         Ok(app
@@ -113,6 +140,21 @@ impl CreditFacility {
         Ok(app.credit().repayment_plan(sub, self.entity.id).await?)
     }
 
+    async fn participations(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<CreditFacilityParticipation>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(app
+            .credit()
+            .participations()
+            .list_for_credit_facility(sub, self.entity.id)
+            .await?
+            .into_iter()
+            .map(CreditFacilityParticipation::from)
+            .collect())
+    }
+
     async fn disbursals(
         &self,
         ctx: &Context<'_>,
@@ -187,6 +229,36 @@ impl CreditFacility {
         Ok(app.credit().subject_can_complete(sub, false).await.is_ok())
     }
 
+    async fn subject_can_cancel(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(!self.entity.is_activated()
+            && !self.entity.is_cancelled()
+            && app.credit().subject_can_cancel(sub, false).await.is_ok())
+    }
+
+    async fn subject_can_request_reinstatement(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<bool> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(app
+            .credit()
+            .subject_can_request_reinstatement(sub, false)
+            .await
+            .is_ok())
+    }
+
+    async fn cancellation_reason(&self) -> Option<&str> {
+        self.entity.cancellation_reason()
+    }
+
+    async fn underwriting_decision(&self) -> Option<UnderwritingDecision> {
+        self.entity
+            .underwriting
+            .clone()
+            .map(UnderwritingDecision::from)
+    }
+
     async fn customer(&self, ctx: &Context<'_>) -> async_graphql::Result<Customer> {
         let loader = ctx.data_unchecked::<LanaDataLoader>();
         let customer = loader
@@ -231,6 +303,16 @@ pub struct CreditFacilityCreateInput {
 }
 crate::mutation_payload! { CreditFacilityCreatePayload, credit_facility: CreditFacility }
 
+#[derive(InputObject)]
+pub struct CreditFacilityCreateFromTermsTemplateInput {
+    pub customer_id: UUID,
+    pub disbursal_credit_account_id: UUID,
+    pub facility: UsdCents,
+    pub terms_template_id: UUID,
+    pub custodian_id: Option<UUID>,
+}
+crate::mutation_payload! { CreditFacilityCreateFromTermsTemplatePayload, credit_facility: CreditFacility }
+
 #[derive(InputObject)]
 pub struct CreditFacilityCollateralUpdateInput {
     pub credit_facility_id: UUID,
@@ -253,6 +335,19 @@ pub struct CreditFacilityCompleteInput {
 }
 crate::mutation_payload! { CreditFacilityCompletePayload, credit_facility: CreditFacility }
 
+#[derive(InputObject)]
+pub struct CreditFacilityCancelInput {
+    pub credit_facility_id: UUID,
+    pub reason: String,
+}
+crate::mutation_payload! { CreditFacilityCancelPayload, credit_facility: CreditFacility }
+
+#[derive(InputObject)]
+pub struct CreditFacilityReinstatementRequestInput {
+    pub credit_facility_id: UUID,
+}
+crate::mutation_payload! { CreditFacilityReinstatementRequestPayload, credit_facility: CreditFacility }
+
 #[derive(async_graphql::Enum, Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum CreditFacilitiesSortBy {
     #[default]
@@ -0,0 +1,87 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+pub use lana_app::credit::{
+    Participation as DomainParticipation,
+    ParticipationStatementEntry as DomainParticipationStatementEntry,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct CreditFacilityParticipation {
+    id: ID,
+    participation_id: UUID,
+    credit_facility_id: UUID,
+    partner_name: String,
+    share: ParticipationSharePct,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(crate) entity: Arc<DomainParticipation>,
+}
+
+impl From<DomainParticipation> for CreditFacilityParticipation {
+    fn from(participation: DomainParticipation) -> Self {
+        Self {
+            id: participation.id.to_global_id(),
+            participation_id: UUID::from(participation.id),
+            credit_facility_id: UUID::from(participation.credit_facility_id),
+            partner_name: participation.partner_name.clone(),
+            share: participation.share,
+            created_at: participation.created_at().into(),
+            entity: Arc::new(participation),
+        }
+    }
+}
+
+#[ComplexObject]
+impl CreditFacilityParticipation {
+    async fn total_interest_share(&self) -> UsdCents {
+        self.entity
+            .total_partner_amount(ParticipationShareType::Interest)
+    }
+
+    async fn total_principal_share(&self) -> UsdCents {
+        self.entity
+            .total_partner_amount(ParticipationShareType::Principal)
+    }
+
+    async fn statement(&self) -> Vec<CreditFacilityParticipationStatementEntry> {
+        self.entity
+            .statement()
+            .into_iter()
+            .map(CreditFacilityParticipationStatementEntry::from)
+            .collect()
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct CreditFacilityParticipationStatementEntry {
+    tx_id: UUID,
+    share_type: ParticipationShareType,
+    facility_amount: UsdCents,
+    partner_amount: UsdCents,
+    effective: Date,
+    recorded_at: Timestamp,
+}
+
+impl From<DomainParticipationStatementEntry> for CreditFacilityParticipationStatementEntry {
+    fn from(entry: DomainParticipationStatementEntry) -> Self {
+        Self {
+            tx_id: UUID::from(entry.ledger_tx_id),
+            share_type: entry.share_type,
+            facility_amount: entry.facility_amount,
+            partner_amount: entry.partner_amount,
+            effective: entry.effective.into(),
+            recorded_at: entry.recorded_at.into(),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreditFacilityParticipationCreateInput {
+    pub credit_facility_id: UUID,
+    pub partner_name: String,
+    pub share: ParticipationSharePct,
+}
+crate::mutation_payload! { CreditFacilityParticipationCreatePayload, participation: CreditFacilityParticipation }
@@ -0,0 +1,38 @@
+use async_graphql::*;
+
+pub use lana_app::credit::{
+    UnderwritingDecision as DomainUnderwritingDecision,
+    UnderwritingFinding as DomainUnderwritingFinding, UnderwritingOutcome, UnderwritingRule,
+};
+
+#[derive(SimpleObject)]
+pub struct UnderwritingDecision {
+    outcome: UnderwritingOutcome,
+    findings: Vec<UnderwritingFinding>,
+}
+
+#[derive(SimpleObject)]
+pub struct UnderwritingFinding {
+    rule: UnderwritingRule,
+    passed: bool,
+    reason: String,
+}
+
+impl From<DomainUnderwritingDecision> for UnderwritingDecision {
+    fn from(decision: DomainUnderwritingDecision) -> Self {
+        Self {
+            outcome: decision.outcome,
+            findings: decision.findings.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<DomainUnderwritingFinding> for UnderwritingFinding {
+    fn from(finding: DomainUnderwritingFinding) -> Self {
+        Self {
+            rule: finding.rule,
+            passed: finding.passed,
+            reason: finding.reason,
+        }
+    }
+}
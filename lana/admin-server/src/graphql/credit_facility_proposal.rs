@@ -0,0 +1,111 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{
+    credit_facility::CreditFacility, customer::Customer, loader::LanaDataLoader, terms::TermValues,
+    terms_template::TermsTemplate,
+};
+
+pub use lana_app::{
+    credit::{
+        CreditFacilityProposal as DomainCreditFacilityProposal,
+        CreditFacilityProposalsByCreatedAtCursor,
+    },
+    primitives::CreditFacilityProposalStatus,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct CreditFacilityProposal {
+    id: ID,
+    credit_facility_proposal_id: UUID,
+    amount: UsdCents,
+    status: CreditFacilityProposalStatus,
+    terms_template_version: u32,
+    rejection_reason: Option<String>,
+    created_at: Timestamp,
+    concluded_at: Option<Timestamp>,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainCreditFacilityProposal>,
+}
+
+impl From<DomainCreditFacilityProposal> for CreditFacilityProposal {
+    fn from(proposal: DomainCreditFacilityProposal) -> Self {
+        Self {
+            id: proposal.id.to_global_id(),
+            credit_facility_proposal_id: UUID::from(proposal.id),
+            amount: proposal.amount,
+            status: proposal.status,
+            terms_template_version: proposal.terms_template.version,
+            rejection_reason: proposal.rejection_reason.clone(),
+            created_at: proposal.created_at().into(),
+            concluded_at: proposal.concluded_at().map(Into::into),
+            entity: Arc::new(proposal),
+        }
+    }
+}
+
+#[ComplexObject]
+impl CreditFacilityProposal {
+    async fn terms(&self) -> TermValues {
+        self.entity.terms.into()
+    }
+
+    async fn customer(&self, ctx: &Context<'_>) -> async_graphql::Result<Customer> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let customer = loader
+            .load_one(self.entity.customer_id)
+            .await?
+            .expect("customer not found");
+        Ok(customer)
+    }
+
+    async fn terms_template(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<TermsTemplate>> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader
+            .load_one(self.entity.terms_template.terms_template_id)
+            .await?)
+    }
+
+    async fn credit_facility(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<CreditFacility>> {
+        let Some(credit_facility_id) = self.entity.credit_facility_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(credit_facility_id).await?)
+    }
+
+    async fn subject_can_convert(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(self.entity.is_pending_review()
+            && app
+                .credit()
+                .proposals()
+                .subject_can_convert(sub, false)
+                .await
+                .is_ok())
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreditFacilityProposalConvertInput {
+    pub credit_facility_proposal_id: UUID,
+    pub disbursal_credit_account_id: UUID,
+    pub custodian_id: Option<UUID>,
+}
+crate::mutation_payload! { CreditFacilityProposalConvertPayload, credit_facility: CreditFacility }
+
+#[derive(InputObject)]
+pub struct CreditFacilityProposalRejectInput {
+    pub credit_facility_proposal_id: UUID,
+    pub reason: String,
+}
+crate::mutation_payload! { CreditFacilityProposalRejectPayload, credit_facility_proposal: CreditFacilityProposal }
@@ -1,6 +1,8 @@
 use async_graphql::*;
 
-use lana_app::dashboard::DashboardValues;
+use lana_app::dashboard::{
+    AgingTile as DomainAgingTile, DashboardValues, PortfolioAgingTiles as DomainPortfolioAgingTiles,
+};
 
 use crate::primitives::{Satoshis, UsdCents};
 
@@ -10,6 +12,7 @@ pub struct Dashboard {
     pending_facilities: u32,
     total_disbursed: UsdCents,
     total_collateral: Satoshis,
+    portfolio_aging: DashboardPortfolioAging,
 }
 
 impl From<DashboardValues> for Dashboard {
@@ -19,6 +22,43 @@ impl From<DashboardValues> for Dashboard {
             pending_facilities: values.pending_facilities,
             total_disbursed: values.total_disbursed,
             total_collateral: values.total_collateral,
+            portfolio_aging: values.portfolio_aging.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct DashboardAgingTile {
+    facilities: u32,
+    outstanding: UsdCents,
+}
+
+impl From<DomainAgingTile> for DashboardAgingTile {
+    fn from(tile: DomainAgingTile) -> Self {
+        Self {
+            facilities: tile.facilities,
+            outstanding: tile.outstanding,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct DashboardPortfolioAging {
+    current: DashboardAgingTile,
+    days_1_to_30: DashboardAgingTile,
+    days_31_to_60: DashboardAgingTile,
+    days_61_to_90: DashboardAgingTile,
+    over_90_days: DashboardAgingTile,
+}
+
+impl From<DomainPortfolioAgingTiles> for DashboardPortfolioAging {
+    fn from(tiles: DomainPortfolioAgingTiles) -> Self {
+        Self {
+            current: tiles.current.into(),
+            days_1_to_30: tiles.days_1_to_30.into(),
+            days_31_to_60: tiles.days_31_to_60.into(),
+            days_61_to_90: tiles.days_61_to_90.into(),
+            over_90_days: tiles.over_90_days.into(),
         }
     }
 }
@@ -4,7 +4,12 @@ use crate::primitives::*;
 
 use super::loader::LanaDataLoader;
 
-pub use super::deposit_account::DepositAccount;
+pub use super::deposit_account::{
+    BalanceSweepDestination, DepositAccount, DepositAccountCloseInput, DepositAccountClosePayload,
+    DepositAccountFreezeInput, DepositAccountFreezePayload, DepositAccountReactivateInput,
+    DepositAccountReactivatePayload, DepositAccountUnfreezeInput, DepositAccountUnfreezePayload,
+    DepositAccountWithdrawalLimitsUpdateInput, DepositAccountWithdrawalLimitsUpdatePayload,
+};
 
 pub use lana_app::deposit::{Deposit as DomainDeposit, DepositStatus, DepositsByCreatedAtCursor};
 
@@ -3,11 +3,16 @@ use async_graphql::{connection::*, *};
 use crate::primitives::*;
 
 pub use lana_app::deposit::{
-    DepositAccount as DomainDepositAccount, DepositAccountHistoryCursor,
-    DepositAccountHistoryEntry as DomainDepositAccountHistoryEntry,
+    AccountFreezeScope, BalanceSweepDestination, DepositAccount as DomainDepositAccount,
+    DepositAccountHistoryCursor, DepositAccountHistoryEntry as DomainDepositAccountHistoryEntry,
+    DormancyStatus,
 };
 
-use super::{customer::Customer, deposit::*, deposit_account_history::*, withdrawal::*};
+use super::{
+    beneficiary::*, customer::Customer, deposit::*, deposit_account_history::*,
+    deposit_account_statement::*, deposit_product::*, hold::*, loader::LanaDataLoader,
+    primitives::Decimal, time_deposit::*, transfer::*, withdrawal::*, withdrawal_limit::*,
+};
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -38,6 +43,8 @@ impl From<DomainDepositAccount> for DepositAccount {
 pub struct DepositAccountBalance {
     settled: UsdCents,
     pending: UsdCents,
+    held: UsdCents,
+    available: UsdCents,
 }
 
 impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
@@ -45,6 +52,8 @@ impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
         Self {
             settled: balance.settled,
             pending: balance.pending,
+            held: balance.held,
+            available: balance.available,
         }
     }
 }
@@ -73,6 +82,91 @@ impl DepositAccount {
         Ok(withdrawals.into_iter().map(Withdrawal::from).collect())
     }
 
+    async fn time_deposits(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<TimeDeposit>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let time_deposits = app
+            .deposits()
+            .list_time_deposits_for_account(sub, self.entity.id)
+            .await?;
+        Ok(time_deposits.into_iter().map(TimeDeposit::from).collect())
+    }
+
+    async fn transfers(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Transfer>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let transfers = app
+            .deposits()
+            .list_transfers_for_account(sub, self.entity.id)
+            .await?;
+        Ok(transfers.into_iter().map(Transfer::from).collect())
+    }
+
+    async fn holds(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Hold>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let holds = app
+            .deposits()
+            .list_holds_for_account(sub, self.entity.id)
+            .await?;
+        Ok(holds.into_iter().map(Hold::from).collect())
+    }
+
+    async fn beneficiaries(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Beneficiary>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let beneficiaries = app
+            .deposits()
+            .list_beneficiaries_for_account(sub, self.entity.id)
+            .await?;
+        Ok(beneficiaries.into_iter().map(Beneficiary::from).collect())
+    }
+
+    async fn statements(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<DepositAccountStatement>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let statements = app
+            .deposits()
+            .list_statements_for_account(sub, self.entity.id)
+            .await?;
+        Ok(statements
+            .into_iter()
+            .map(DepositAccountStatement::from)
+            .collect())
+    }
+
+    async fn freeze_scope(&self) -> Option<AccountFreezeScope> {
+        self.entity.freeze_scope()
+    }
+
+    async fn closed_at(&self) -> Option<Timestamp> {
+        self.entity.closed_at().map(Timestamp::from)
+    }
+
+    async fn dormancy_status(&self) -> DormancyStatus {
+        self.entity.dormancy_status()
+    }
+
+    async fn last_customer_activity_at(&self) -> Option<Timestamp> {
+        self.entity.last_customer_activity_at().map(Timestamp::from)
+    }
+
+    async fn withdrawal_limits_override(&self) -> Option<WithdrawalLimits> {
+        self.entity
+            .withdrawal_limits_override()
+            .map(WithdrawalLimits::from)
+    }
+
+    async fn withdrawal_limit_usage(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<WithdrawalLimitUsage>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let usage = app
+            .deposits()
+            .account_withdrawal_limit_usage(sub, self.entity.id)
+            .await?;
+        Ok(usage.into_iter().map(WithdrawalLimitUsage::from).collect())
+    }
+
     async fn history(
         &self,
         ctx: &Context<'_>,
@@ -123,6 +217,21 @@ impl DepositAccount {
         Ok(DepositAccountBalance::from(balance))
     }
 
+    async fn deposit_product(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<DepositProduct>> {
+        let Some(product_id) = self.entity.deposit_product_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(product_id).await?)
+    }
+
+    async fn accrued_interest(&self) -> Decimal {
+        self.entity.accrued_interest().into()
+    }
+
     async fn customer(&self, ctx: &Context<'_>) -> async_graphql::Result<Customer> {
         let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
         let customer = app
@@ -134,3 +243,60 @@ impl DepositAccount {
         Ok(Customer::from(customer))
     }
 }
+
+#[derive(InputObject)]
+pub struct DepositAccountFreezeInput {
+    pub deposit_account_id: UUID,
+    pub scope: AccountFreezeScope,
+    pub reason: String,
+}
+crate::mutation_payload! { DepositAccountFreezePayload, account: DepositAccount }
+
+#[derive(InputObject)]
+pub struct DepositAccountUnfreezeInput {
+    pub deposit_account_id: UUID,
+}
+crate::mutation_payload! { DepositAccountUnfreezePayload, account: DepositAccount }
+
+#[derive(InputObject)]
+pub struct DepositAccountReactivateInput {
+    pub deposit_account_id: UUID,
+}
+crate::mutation_payload! { DepositAccountReactivatePayload, account: DepositAccount }
+
+#[derive(OneofObject)]
+pub enum BalanceSweepDestinationInput {
+    DepositAccountId(UUID),
+    ExternalBeneficiary(String),
+}
+
+impl From<BalanceSweepDestinationInput> for BalanceSweepDestination {
+    fn from(input: BalanceSweepDestinationInput) -> Self {
+        match input {
+            BalanceSweepDestinationInput::DepositAccountId(id) => {
+                BalanceSweepDestination::DepositAccount {
+                    deposit_account_id: id.into(),
+                }
+            }
+            BalanceSweepDestinationInput::ExternalBeneficiary(beneficiary) => {
+                BalanceSweepDestination::ExternalBeneficiary { beneficiary }
+            }
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct DepositAccountCloseInput {
+    pub deposit_account_id: UUID,
+    /// Where to move the remaining balance. Required unless the account is empty.
+    pub sweep_to: Option<BalanceSweepDestinationInput>,
+}
+crate::mutation_payload! { DepositAccountClosePayload, account: DepositAccount }
+
+#[derive(InputObject)]
+pub struct DepositAccountWithdrawalLimitsUpdateInput {
+    pub deposit_account_id: UUID,
+    /// Leave empty to fall back to the limits of the deposit product.
+    pub withdrawal_limits: Option<WithdrawalLimitsInput>,
+}
+crate::mutation_payload! { DepositAccountWithdrawalLimitsUpdatePayload, account: DepositAccount }
@@ -0,0 +1,56 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::{
+    deposit::DepositAccountStatement as DomainDepositAccountStatement,
+    document::GeneratedDocumentDownloadLink,
+};
+
+#[derive(SimpleObject, Clone)]
+pub struct DepositAccountStatement {
+    id: ID,
+    deposit_account_statement_id: UUID,
+    deposit_account_id: UUID,
+    period_start: Date,
+    period_end: Date,
+    opening_balance: UsdCents,
+    closing_balance: UsdCents,
+    created_at: Timestamp,
+}
+
+impl From<DomainDepositAccountStatement> for DepositAccountStatement {
+    fn from(statement: DomainDepositAccountStatement) -> Self {
+        DepositAccountStatement {
+            id: statement.id.to_global_id(),
+            deposit_account_statement_id: UUID::from(statement.id),
+            deposit_account_id: statement.deposit_account_id.into(),
+            period_start: statement.period.start.into(),
+            period_end: statement.period.end.into(),
+            opening_balance: statement.opening_balance,
+            closing_balance: statement.closing_balance,
+            created_at: statement.created_at().into(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct DepositAccountStatementDownloadLink {
+    pub url: String,
+    pub document_id: UUID,
+}
+
+impl From<GeneratedDocumentDownloadLink> for DepositAccountStatementDownloadLink {
+    fn from(result: GeneratedDocumentDownloadLink) -> Self {
+        Self {
+            url: result.link,
+            document_id: UUID::from(result.document_id),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct DepositAccountStatementDownloadLinkGenerateInput {
+    pub deposit_account_statement_id: UUID,
+}
+crate::mutation_payload! { DepositAccountStatementDownloadLinkGeneratePayload, link: DepositAccountStatementDownloadLink }
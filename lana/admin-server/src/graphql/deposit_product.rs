@@ -0,0 +1,102 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{deposit::DepositAccount, withdrawal_fee::*, withdrawal_limit::*};
+
+pub use lana_app::deposit::{
+    CapitalizationFrequency, DepositInterestRate,
+    DepositInterestTerms as DomainDepositInterestTerms, DepositProduct as DomainDepositProduct,
+};
+
+#[derive(SimpleObject, Clone)]
+pub struct DepositInterestTerms {
+    annual_rate: DepositInterestRate,
+    capitalization_frequency: CapitalizationFrequency,
+}
+
+impl From<DomainDepositInterestTerms> for DepositInterestTerms {
+    fn from(terms: DomainDepositInterestTerms) -> Self {
+        Self {
+            annual_rate: terms.annual_rate,
+            capitalization_frequency: terms.capitalization_frequency,
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct DepositProduct {
+    id: ID,
+    deposit_product_id: UUID,
+    interest_terms: DepositInterestTerms,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainDepositProduct>,
+}
+
+impl From<DomainDepositProduct> for DepositProduct {
+    fn from(product: DomainDepositProduct) -> Self {
+        Self {
+            id: product.id.to_global_id(),
+            deposit_product_id: product.id.into(),
+            interest_terms: product.interest_terms.into(),
+            created_at: product.created_at().into(),
+
+            entity: Arc::new(product),
+        }
+    }
+}
+
+#[ComplexObject]
+impl DepositProduct {
+    async fn name(&self) -> &str {
+        &self.entity.name
+    }
+
+    async fn withdrawal_limits(&self) -> WithdrawalLimits {
+        self.entity.withdrawal_limits.into()
+    }
+
+    async fn withdrawal_fee_schedule(&self) -> Option<WithdrawalFeeSchedule> {
+        self.entity.withdrawal_fee_schedule.map(Into::into)
+    }
+}
+
+#[derive(InputObject)]
+pub struct DepositProductCreateInput {
+    pub name: String,
+    pub annual_rate: DepositInterestRate,
+    pub capitalization_frequency: CapitalizationFrequency,
+}
+crate::mutation_payload! { DepositProductCreatePayload, deposit_product: DepositProduct }
+
+#[derive(InputObject)]
+pub struct DepositProductInterestTermsUpdateInput {
+    pub deposit_product_id: UUID,
+    pub annual_rate: DepositInterestRate,
+    pub capitalization_frequency: CapitalizationFrequency,
+}
+crate::mutation_payload! { DepositProductInterestTermsUpdatePayload, deposit_product: DepositProduct }
+
+#[derive(InputObject)]
+pub struct DepositProductWithdrawalLimitsUpdateInput {
+    pub deposit_product_id: UUID,
+    pub withdrawal_limits: WithdrawalLimitsInput,
+}
+crate::mutation_payload! { DepositProductWithdrawalLimitsUpdatePayload, deposit_product: DepositProduct }
+
+#[derive(InputObject)]
+pub struct DepositProductWithdrawalFeeScheduleUpdateInput {
+    pub deposit_product_id: UUID,
+    pub withdrawal_fee_schedule: Option<WithdrawalFeeScheduleInput>,
+}
+crate::mutation_payload! { DepositProductWithdrawalFeeScheduleUpdatePayload, deposit_product: DepositProduct }
+
+#[derive(InputObject)]
+pub struct DepositAccountProductAssignInput {
+    pub deposit_account_id: UUID,
+    pub deposit_product_id: UUID,
+}
+crate::mutation_payload! { DepositAccountProductAssignPayload, account: DepositAccount }
@@ -0,0 +1,75 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{deposit_account::DepositAccount, loader::LanaDataLoader};
+
+pub use lana_app::deposit::{Hold as DomainHold, HoldStatus};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct Hold {
+    id: ID,
+    hold_id: UUID,
+    deposit_account_id: UUID,
+    amount: UsdCents,
+    expires_at: Option<Timestamp>,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainHold>,
+}
+
+impl From<DomainHold> for Hold {
+    fn from(hold: DomainHold) -> Self {
+        Hold {
+            id: hold.id.to_global_id(),
+            hold_id: UUID::from(hold.id),
+            deposit_account_id: hold.deposit_account_id.into(),
+            amount: hold.amount,
+            expires_at: hold.expires_at.map(Into::into),
+            created_at: hold.created_at().into(),
+            entity: Arc::new(hold),
+        }
+    }
+}
+
+#[ComplexObject]
+impl Hold {
+    async fn reason(&self) -> &str {
+        &self.entity.reason
+    }
+
+    async fn reference(&self) -> &str {
+        &self.entity.reference
+    }
+
+    async fn status(&self) -> HoldStatus {
+        self.entity.status()
+    }
+
+    async fn account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.deposit_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+}
+
+#[derive(InputObject)]
+pub struct HoldPlaceInput {
+    pub deposit_account_id: UUID,
+    pub amount: UsdCents,
+    pub reason: String,
+    pub expires_at: Option<Timestamp>,
+    pub reference: Option<String>,
+}
+crate::mutation_payload! { HoldPlacePayload, hold: Hold }
+
+#[derive(InputObject)]
+pub struct HoldReleaseInput {
+    pub hold_id: UUID,
+}
+crate::mutation_payload! { HoldReleasePayload, hold: Hold }
@@ -22,9 +22,10 @@ use lana_app::{
 use crate::primitives::*;
 
 use super::{
-    access::*, accounting::*, approval_process::*, committee::*, credit_facility::*, custody::*,
-    customer::*, deposit::*, deposit_account::*, document::*, policy::*, reports::*,
-    terms_template::*, withdrawal::*,
+    access::*, accounting::*, approval_process::*, bank_statement::*, beneficiary::*, committee::*,
+    credit_facility::*, credit_facility_proposal::*, custody::*, customer::*, deposit::*,
+    deposit_account::*, deposit_product::*, document::*, hold::*, payment_batch::*, policy::*,
+    reports::*, terms_template::*, time_deposit::*, transfer::*, withdrawal::*,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -258,6 +259,115 @@ impl Loader<DepositAccountId> for LanaLoader {
     }
 }
 
+impl Loader<TimeDepositId> for LanaLoader {
+    type Value = TimeDeposit;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[TimeDepositId],
+    ) -> Result<HashMap<TimeDepositId, TimeDeposit>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_time_deposits(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<HoldId> for LanaLoader {
+    type Value = Hold;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(&self, keys: &[HoldId]) -> Result<HashMap<HoldId, Hold>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_holds(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<BeneficiaryId> for LanaLoader {
+    type Value = Beneficiary;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[BeneficiaryId],
+    ) -> Result<HashMap<BeneficiaryId, Beneficiary>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_beneficiaries(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<BankStatementLineId> for LanaLoader {
+    type Value = BankStatementLine;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[BankStatementLineId],
+    ) -> Result<HashMap<BankStatementLineId, BankStatementLine>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_bank_statement_lines(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<PaymentBatchId> for LanaLoader {
+    type Value = PaymentBatch;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[PaymentBatchId],
+    ) -> Result<HashMap<PaymentBatchId, PaymentBatch>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_payment_batches(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<TransferId> for LanaLoader {
+    type Value = Transfer;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[TransferId],
+    ) -> Result<HashMap<TransferId, Transfer>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_transfers(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<DepositProductId> for LanaLoader {
+    type Value = DepositProduct;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[DepositProductId],
+    ) -> Result<HashMap<DepositProductId, DepositProduct>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_deposit_products(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<LedgerTransactionId> for LanaLoader {
     type Value = LedgerTransaction;
     type Error = Arc<LedgerTransactionError>;
@@ -326,6 +436,40 @@ impl Loader<CreditFacilityId> for LanaLoader {
     }
 }
 
+impl Loader<CreditFacilityProposalId> for LanaLoader {
+    type Value = CreditFacilityProposal;
+    type Error = Arc<lana_app::credit::CreditFacilityProposalError>;
+
+    async fn load(
+        &self,
+        keys: &[CreditFacilityProposalId],
+    ) -> Result<HashMap<CreditFacilityProposalId, CreditFacilityProposal>, Self::Error> {
+        self.app
+            .credit()
+            .proposals()
+            .find_all(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<ParticipationId> for LanaLoader {
+    type Value = CreditFacilityParticipation;
+    type Error = Arc<lana_app::credit::ParticipationError>;
+
+    async fn load(
+        &self,
+        keys: &[ParticipationId],
+    ) -> Result<HashMap<ParticipationId, CreditFacilityParticipation>, Self::Error> {
+        self.app
+            .credit()
+            .participations()
+            .find_all(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<CollateralId> for LanaLoader {
     type Value = Collateral;
     type Error = Arc<lana_app::credit::error::CoreCreditError>;
@@ -0,0 +1,79 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+pub use lana_app::{
+    credit::{LoanTape as DomainLoanTape, LoanTapeFormat},
+    document::{Document as DomainDocument, DocumentStatus, GeneratedDocumentDownloadLink},
+};
+
+#[derive(SimpleObject, Clone)]
+pub struct LoanTape {
+    loan_tape_id: UUID,
+    as_of: Date,
+    documents: Vec<LoanTapeDocument>,
+}
+
+impl From<DomainLoanTape> for LoanTape {
+    fn from(loan_tape: DomainLoanTape) -> Self {
+        Self {
+            loan_tape_id: UUID::from(loan_tape.id),
+            as_of: loan_tape.as_of.into(),
+            documents: loan_tape
+                .documents
+                .into_iter()
+                .map(LoanTapeDocument::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct LoanTapeDocument {
+    document_id: UUID,
+    loan_tape_id: UUID,
+    format: LoanTapeFormat,
+    filename: String,
+    status: DocumentStatus,
+    created_at: Timestamp,
+}
+
+impl From<DomainDocument> for LoanTapeDocument {
+    fn from(document: DomainDocument) -> Self {
+        Self {
+            document_id: UUID::from(document.id),
+            loan_tape_id: UUID::from(document.reference_id),
+            format: LoanTapeFormat::from_content_type(&document.content_type)
+                .expect("loan tape document has a known content type"),
+            created_at: document.created_at().into(),
+            filename: document.filename,
+            status: document.status,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct LoanTapeDownloadLink {
+    pub url: String,
+    pub document_id: UUID,
+}
+
+impl From<GeneratedDocumentDownloadLink> for LoanTapeDownloadLink {
+    fn from(result: GeneratedDocumentDownloadLink) -> Self {
+        Self {
+            url: result.link,
+            document_id: UUID::from(result.document_id),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct LoanTapeGenerateInput {
+    pub as_of: Date,
+}
+crate::mutation_payload! { LoanTapeGeneratePayload, loan_tape: LoanTape }
+
+#[derive(InputObject)]
+pub struct LoanTapeDownloadLinkGenerateInput {
+    pub document_id: UUID,
+}
+crate::mutation_payload! { LoanTapeDownloadLinkGeneratePayload, link: LoanTapeDownloadLink }
@@ -4,28 +4,43 @@ mod approval_rules;
 mod audit;
 mod authenticated_subject;
 mod balance_sheet_config;
+mod bank_statement;
+mod beneficiary;
 mod committee;
 mod contract_creation;
 mod credit_config;
 mod credit_facility;
+mod credit_facility_proposal;
 mod custody;
 mod customer;
 mod dashboard;
 mod deposit;
 mod deposit_account;
 mod deposit_account_history;
+mod deposit_account_statement;
 mod deposit_config;
+mod deposit_product;
 mod document;
+mod hold;
 mod loader;
+mod loan_tape;
+mod payment_batch;
+mod portfolio_aging;
 mod price;
+mod price_shock_stress_test;
 mod primitives;
 mod profit_and_loss_config;
 mod public_id;
 mod reports;
 mod sumsub;
 mod terms;
+mod terms_simulation;
 mod terms_template;
+mod time_deposit;
+mod transfer;
 mod withdrawal;
+mod withdrawal_fee;
+mod withdrawal_limit;
 #[macro_use]
 pub mod macros;
 mod access;
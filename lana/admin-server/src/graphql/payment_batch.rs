@@ -0,0 +1,77 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{loader::LanaDataLoader, withdrawal::Withdrawal};
+
+pub use lana_app::{
+    deposit::{PaymentBatch as DomainPaymentBatch, PaymentBatchesByCreatedAtCursor},
+    document::GeneratedDocumentDownloadLink,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct PaymentBatch {
+    id: ID,
+    payment_batch_id: UUID,
+    message_id: String,
+    total_amount: UsdCents,
+    requested_execution_date: Date,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainPaymentBatch>,
+}
+
+impl From<DomainPaymentBatch> for PaymentBatch {
+    fn from(batch: DomainPaymentBatch) -> Self {
+        PaymentBatch {
+            id: batch.id.to_global_id(),
+            payment_batch_id: UUID::from(batch.id),
+            message_id: batch.message_id(),
+            total_amount: batch.total_amount,
+            requested_execution_date: batch.requested_execution_date.into(),
+            created_at: batch.created_at().into(),
+            entity: Arc::new(batch),
+        }
+    }
+}
+
+#[ComplexObject]
+impl PaymentBatch {
+    async fn withdrawals(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Withdrawal>> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let mut withdrawals = loader
+            .load_many(self.entity.withdrawal_ids.iter().copied())
+            .await?;
+        Ok(self
+            .entity
+            .withdrawal_ids
+            .iter()
+            .filter_map(|id| withdrawals.remove(id))
+            .collect())
+    }
+}
+
+crate::mutation_payload! { PaymentBatchGeneratePayload, payment_batch: PaymentBatch }
+
+#[derive(SimpleObject)]
+pub struct PaymentBatchDownloadLink {
+    pub url: String,
+    pub document_id: UUID,
+}
+
+impl From<GeneratedDocumentDownloadLink> for PaymentBatchDownloadLink {
+    fn from(result: GeneratedDocumentDownloadLink) -> Self {
+        Self {
+            url: result.link,
+            document_id: UUID::from(result.document_id),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct PaymentBatchDownloadLinkGenerateInput {
+    pub payment_batch_id: UUID,
+}
+crate::mutation_payload! { PaymentBatchDownloadLinkGeneratePayload, link: PaymentBatchDownloadLink }
@@ -0,0 +1,51 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::primitives::Decimal;
+
+pub use lana_app::credit::{
+    PortfolioAging as DomainPortfolioAging, PortfolioAgingBucket as DomainPortfolioAgingBucket,
+};
+
+#[derive(InputObject)]
+pub struct PortfolioAgingInput {
+    pub as_of: Option<Date>,
+}
+
+#[derive(SimpleObject)]
+pub struct PortfolioAgingBucket {
+    bucket: AgingBucket,
+    facilities: u32,
+    outstanding: UsdCents,
+    portfolio_at_risk_pct: Decimal,
+}
+
+#[derive(SimpleObject)]
+pub struct PortfolioAging {
+    as_of: Date,
+    facilities: u32,
+    total_outstanding: UsdCents,
+    buckets: Vec<PortfolioAgingBucket>,
+}
+
+impl From<DomainPortfolioAging> for PortfolioAging {
+    fn from(aging: DomainPortfolioAging) -> Self {
+        let buckets = aging
+            .buckets
+            .iter()
+            .map(|bucket: &DomainPortfolioAgingBucket| PortfolioAgingBucket {
+                bucket: bucket.bucket,
+                facilities: bucket.facilities,
+                outstanding: bucket.outstanding,
+                portfolio_at_risk_pct: aging.portfolio_at_risk_pct(bucket.bucket).into(),
+            })
+            .collect();
+        Self {
+            as_of: aging.as_of.into(),
+            facilities: aging.facilities,
+            total_outstanding: aging.total_outstanding,
+            buckets,
+        }
+    }
+}
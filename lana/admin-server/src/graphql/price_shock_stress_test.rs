@@ -0,0 +1,79 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::primitives::Decimal;
+
+pub use lana_app::credit::{
+    PriceShockScenario as DomainPriceShockScenario,
+    PriceShockStressTest as DomainPriceShockStressTest, StressedExposure as DomainStressedExposure,
+};
+
+#[derive(InputObject)]
+pub struct PriceShockStressTestInput {
+    #[graphql(default)]
+    pub shocks_pct: Vec<Decimal>,
+}
+
+#[derive(SimpleObject)]
+pub struct StressedExposure {
+    facilities: u32,
+    outstanding: UsdCents,
+}
+
+impl From<DomainStressedExposure> for StressedExposure {
+    fn from(exposure: DomainStressedExposure) -> Self {
+        Self {
+            facilities: exposure.facilities,
+            outstanding: exposure.outstanding,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct PriceShockScenario {
+    shock_pct: Decimal,
+    usd_cents_per_btc: UsdCents,
+    under_margin_call: StressedExposure,
+    under_liquidation: StressedExposure,
+    moved_to_margin_call: StressedExposure,
+    moved_to_liquidation: StressedExposure,
+    expected_shortfall: UsdCents,
+}
+
+impl From<DomainPriceShockScenario> for PriceShockScenario {
+    fn from(scenario: DomainPriceShockScenario) -> Self {
+        Self {
+            shock_pct: scenario.shock_pct.into(),
+            usd_cents_per_btc: scenario.price.into_inner(),
+            under_margin_call: scenario.under_margin_call.into(),
+            under_liquidation: scenario.under_liquidation.into(),
+            moved_to_margin_call: scenario.moved_to_margin_call.into(),
+            moved_to_liquidation: scenario.moved_to_liquidation.into(),
+            expected_shortfall: scenario.expected_shortfall,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct PriceShockStressTest {
+    facilities: u32,
+    total_outstanding: UsdCents,
+    baseline: PriceShockScenario,
+    scenarios: Vec<PriceShockScenario>,
+}
+
+impl From<DomainPriceShockStressTest> for PriceShockStressTest {
+    fn from(stress_test: DomainPriceShockStressTest) -> Self {
+        Self {
+            facilities: stress_test.facilities,
+            total_outstanding: stress_test.total_outstanding,
+            baseline: stress_test.baseline.into(),
+            scenarios: stress_test
+                .scenarios
+                .into_iter()
+                .map(PriceShockScenario::from)
+                .collect(),
+        }
+    }
+}
@@ -13,10 +13,12 @@ use crate::primitives::*;
 
 use super::{
     access::*, accounting::*, approval_process::*, audit::*, authenticated_subject::*,
-    balance_sheet_config::*, committee::*, contract_creation::*, credit_config::*,
-    credit_facility::*, custody::*, customer::*, dashboard::*, deposit::*, deposit_config::*,
-    document::*, loader::*, policy::*, price::*, profit_and_loss_config::*, public_id::*,
-    reports::*, sumsub::*, terms_template::*, withdrawal::*,
+    balance_sheet_config::*, bank_statement::*, beneficiary::*, committee::*, contract_creation::*,
+    credit_config::*, credit_facility::*, credit_facility_proposal::*, custody::*, customer::*,
+    dashboard::*, deposit::*, deposit_account_statement::*, deposit_config::*, deposit_product::*,
+    document::*, hold::*, loader::*, loan_tape::*, payment_batch::*, policy::*, portfolio_aging::*,
+    price::*, price_shock_stress_test::*, profit_and_loss_config::*, public_id::*, reports::*,
+    sumsub::*, terms_simulation::*, terms_template::*, time_deposit::*, transfer::*, withdrawal::*,
 };
 
 pub struct Query;
@@ -200,6 +202,38 @@ impl Query {
         )
     }
 
+    async fn payment_batch(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<PaymentBatch>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            PaymentBatch,
+            ctx,
+            app.deposits().find_payment_batch_by_id(sub, id)
+        )
+    }
+
+    async fn payment_batches(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<PaymentBatchesByCreatedAtCursor, PaymentBatch, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            PaymentBatchesByCreatedAtCursor,
+            PaymentBatch,
+            ctx,
+            after,
+            first,
+            |query| app.deposits().list_payment_batches(sub, query)
+        )
+    }
+
     async fn deposit(&self, ctx: &Context<'_>, id: UUID) -> async_graphql::Result<Option<Deposit>> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         maybe_fetch_one!(Deposit, ctx, app.deposits().find_deposit_by_id(sub, id))
@@ -249,6 +283,158 @@ impl Query {
         )
     }
 
+    async fn credit_facility_proposal(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<CreditFacilityProposal>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            CreditFacilityProposal,
+            ctx,
+            app.credit().proposals().find_by_id(sub, id)
+        )
+    }
+
+    async fn credit_facility_participation(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<CreditFacilityParticipation>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            CreditFacilityParticipation,
+            ctx,
+            app.credit().participations().find_by_id(sub, id)
+        )
+    }
+
+    async fn credit_facility_proposals(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+        status: Option<CreditFacilityProposalStatus>,
+    ) -> async_graphql::Result<
+        Connection<
+            CreditFacilityProposalsByCreatedAtCursor,
+            CreditFacilityProposal,
+            EmptyFields,
+            EmptyFields,
+        >,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            CreditFacilityProposalsByCreatedAtCursor,
+            CreditFacilityProposal,
+            ctx,
+            after,
+            first,
+            |query| app.credit().proposals().list(sub, query, status)
+        )
+    }
+
+    async fn deposit_product(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<DepositProduct>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            DepositProduct,
+            ctx,
+            app.deposits().find_deposit_product_by_id(sub, id)
+        )
+    }
+
+    async fn deposit_products(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<DepositProduct>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let products = app.deposits().list_deposit_products(sub).await?;
+        Ok(products.into_iter().map(DepositProduct::from).collect())
+    }
+
+    async fn time_deposit(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<TimeDeposit>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            TimeDeposit,
+            ctx,
+            app.deposits().find_time_deposit_by_id(sub, id)
+        )
+    }
+
+    async fn hold(&self, ctx: &Context<'_>, id: UUID) -> async_graphql::Result<Option<Hold>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(Hold, ctx, app.deposits().find_hold_by_id(sub, id))
+    }
+
+    async fn beneficiary(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<Beneficiary>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            Beneficiary,
+            ctx,
+            app.deposits().find_beneficiary_by_id(sub, id)
+        )
+    }
+
+    async fn bank_statement_line(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<BankStatementLine>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            BankStatementLine,
+            ctx,
+            app.deposits().find_bank_statement_line_by_id(sub, id)
+        )
+    }
+
+    async fn bank_statement_lines(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+        #[graphql(default_with = "BankStatementLineStatus::Unmatched")]
+        status: BankStatementLineStatus,
+    ) -> async_graphql::Result<
+        Connection<
+            BankStatementLinesByCreatedAtCursor,
+            BankStatementLine,
+            EmptyFields,
+            EmptyFields,
+        >,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            BankStatementLinesByCreatedAtCursor,
+            BankStatementLine,
+            ctx,
+            after,
+            first,
+            |query| app.deposits().list_bank_statement_lines(sub, status, query)
+        )
+    }
+
+    async fn transfer(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<Transfer>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(Transfer, ctx, app.deposits().find_transfer_by_id(sub, id))
+    }
+
     async fn terms_templates(
         &self,
         ctx: &Context<'_>,
@@ -261,6 +447,110 @@ impl Query {
             .collect())
     }
 
+    async fn credit_facilities_for_terms_template(
+        &self,
+        ctx: &Context<'_>,
+        terms_template_id: UUID,
+        version: u32,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<CreditFacilitiesCursor, CreditFacility, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_combo_cursor!(
+            CreditFacilitiesCursor,
+            CreditFacility,
+            DomainCreditFacilitiesSortBy::CreatedAt,
+            ctx,
+            after,
+            first,
+            |query| app.credit().facilities().list_for_terms_template_version(
+                sub,
+                TermsTemplateId::from(terms_template_id),
+                version,
+                query
+            )
+        )
+    }
+
+    async fn price_shock_stress_test(
+        &self,
+        ctx: &Context<'_>,
+        input: PriceShockStressTestInput,
+    ) -> async_graphql::Result<PriceShockStressTest> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let stress_test = app
+            .credit()
+            .price_shock_stress_test(sub, input.shocks_pct.into_iter().map(Into::into).collect())
+            .await?;
+        Ok(PriceShockStressTest::from(stress_test))
+    }
+
+    async fn portfolio_aging(
+        &self,
+        ctx: &Context<'_>,
+        input: PortfolioAgingInput,
+    ) -> async_graphql::Result<PortfolioAging> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let aging = app
+            .credit()
+            .portfolio_aging(sub, input.as_of.map(Date::into_inner))
+            .await?;
+        Ok(PortfolioAging::from(aging))
+    }
+
+    async fn terms_simulation(
+        &self,
+        ctx: &Context<'_>,
+        input: TermsSimulationInput,
+    ) -> async_graphql::Result<TermsSimulation> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let TermsSimulationInput {
+            terms,
+            amount,
+            collateral,
+            start_date,
+            price_path,
+        } = input;
+
+        let term_values = lana_app::terms::TermValues::builder()
+            .annual_rate(terms.annual_rate)
+            .accrual_interval(terms.accrual_interval)
+            .accrual_cycle_interval(terms.accrual_cycle_interval)
+            .one_time_fee_rate(terms.one_time_fee_rate)
+            .duration(terms.duration)
+            .interest_due_duration_from_accrual(terms.interest_due_duration_from_accrual)
+            .obligation_overdue_duration_from_due(terms.obligation_overdue_duration_from_due)
+            .obligation_liquidation_duration_from_due(
+                terms.obligation_liquidation_duration_from_due,
+            )
+            .liquidation_cvl(terms.liquidation_cvl)
+            .margin_call_cvl(terms.margin_call_cvl)
+            .initial_cvl(terms.initial_cvl)
+            .interest_capitalization(terms.interest_capitalization)
+            .build()?;
+
+        let starts_at = start_date.map(|date| {
+            date.into_inner()
+                .and_hms_opt(0, 0, 0)
+                .expect("midnight is a valid time")
+                .and_utc()
+        });
+        let simulation = app
+            .credit()
+            .simulate_terms(
+                sub,
+                term_values,
+                amount,
+                collateral,
+                starts_at,
+                price_path.into_iter().map(Into::into).collect(),
+            )
+            .await?;
+        Ok(TermsSimulation::from(simulation))
+    }
+
     async fn credit_facility(
         &self,
         ctx: &Context<'_>,
@@ -864,6 +1154,20 @@ impl Query {
         Ok(latest)
     }
 
+    async fn loan_tape_documents(
+        &self,
+        ctx: &Context<'_>,
+        loan_tape_id: UUID,
+    ) -> async_graphql::Result<Vec<LoanTapeDocument>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let documents = app
+            .credit()
+            .loan_tapes()
+            .list_documents(sub, loan_tape_id)
+            .await?;
+        Ok(documents.into_iter().map(LoanTapeDocument::from).collect())
+    }
+
     async fn report_runs(
         &self,
         ctx: &Context<'_>,
@@ -1132,6 +1436,14 @@ impl Mutation {
             .chart_of_accounts_omnibus_parent_code(
                 input.chart_of_accounts_omnibus_parent_code.parse()?,
             )
+            .chart_of_accounts_interest_expense_parent_code(
+                input
+                    .chart_of_accounts_interest_expense_parent_code
+                    .parse()?,
+            )
+            .chart_of_accounts_fee_income_parent_code(
+                input.chart_of_accounts_fee_income_parent_code.parse()?,
+            )
             .build()?;
         let config = app
             .deposits()
@@ -1189,87 +1501,467 @@ impl Mutation {
         )
     }
 
-    pub async fn withdrawal_initiate(
+    pub async fn deposit_product_create(
         &self,
         ctx: &Context<'_>,
-        input: WithdrawalInitiateInput,
-    ) -> async_graphql::Result<WithdrawalInitiatePayload> {
+        input: DepositProductCreateInput,
+    ) -> async_graphql::Result<DepositProductCreatePayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let interest_terms = DomainDepositInterestTerms {
+            annual_rate: input.annual_rate,
+            capitalization_frequency: input.capitalization_frequency,
+        };
+
         exec_mutation!(
-            WithdrawalInitiatePayload,
-            Withdrawal,
+            DepositProductCreatePayload,
+            DepositProduct,
             ctx,
-            app.deposits().initiate_withdrawal(
+            app.deposits()
+                .create_deposit_product(sub, input.name, interest_terms)
+        )
+    }
+
+    pub async fn deposit_product_interest_terms_update(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositProductInterestTermsUpdateInput,
+    ) -> async_graphql::Result<DepositProductInterestTermsUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let interest_terms = DomainDepositInterestTerms {
+            annual_rate: input.annual_rate,
+            capitalization_frequency: input.capitalization_frequency,
+        };
+
+        exec_mutation!(
+            DepositProductInterestTermsUpdatePayload,
+            DepositProduct,
+            ctx,
+            app.deposits().update_deposit_product_interest_terms(
                 sub,
-                input.deposit_account_id,
-                input.amount,
-                input.reference
+                input.deposit_product_id,
+                interest_terms
             )
         )
     }
 
-    pub async fn withdrawal_confirm(
+    pub async fn deposit_product_withdrawal_limits_update(
         &self,
         ctx: &Context<'_>,
-        input: WithdrawalConfirmInput,
-    ) -> async_graphql::Result<WithdrawalConfirmPayload> {
+        input: DepositProductWithdrawalLimitsUpdateInput,
+    ) -> async_graphql::Result<DepositProductWithdrawalLimitsUpdatePayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
 
         exec_mutation!(
-            WithdrawalConfirmPayload,
-            Withdrawal,
+            DepositProductWithdrawalLimitsUpdatePayload,
+            DepositProduct,
             ctx,
-            app.deposits().confirm_withdrawal(sub, input.withdrawal_id)
+            app.deposits().update_deposit_product_withdrawal_limits(
+                sub,
+                input.deposit_product_id,
+                input.withdrawal_limits.into()
+            )
         )
     }
 
-    pub async fn withdrawal_cancel(
+    pub async fn deposit_product_withdrawal_fee_schedule_update(
         &self,
         ctx: &Context<'_>,
-        input: WithdrawalCancelInput,
-    ) -> async_graphql::Result<WithdrawalCancelPayload> {
+        input: DepositProductWithdrawalFeeScheduleUpdateInput,
+    ) -> async_graphql::Result<DepositProductWithdrawalFeeScheduleUpdatePayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
+
         exec_mutation!(
-            WithdrawalCancelPayload,
-            Withdrawal,
+            DepositProductWithdrawalFeeScheduleUpdatePayload,
+            DepositProduct,
             ctx,
-            app.deposits().cancel_withdrawal(sub, input.withdrawal_id)
+            app.deposits()
+                .update_deposit_product_withdrawal_fee_schedule(
+                    sub,
+                    input.deposit_product_id,
+                    input.withdrawal_fee_schedule.map(Into::into)
+                )
         )
     }
 
-    pub async fn withdrawal_revert(
+    pub async fn deposit_account_product_assign(
         &self,
         ctx: &Context<'_>,
-        input: WithdrawalRevertInput,
-    ) -> async_graphql::Result<WithdrawalRevertPayload> {
+        input: DepositAccountProductAssignInput,
+    ) -> async_graphql::Result<DepositAccountProductAssignPayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
+
         exec_mutation!(
-            WithdrawalRevertPayload,
-            Withdrawal,
+            DepositAccountProductAssignPayload,
+            DepositAccount,
             ctx,
-            app.deposits().revert_withdrawal(sub, input.withdrawal_id)
+            app.deposits().assign_product_to_account(
+                sub,
+                input.deposit_account_id,
+                input.deposit_product_id
+            )
         )
     }
 
-    pub async fn deposit_revert(
+    pub async fn time_deposit_open(
         &self,
         ctx: &Context<'_>,
-        input: DepositRevertInput,
-    ) -> async_graphql::Result<DepositRevertPayload> {
+        input: TimeDepositOpenInput,
+    ) -> async_graphql::Result<TimeDepositOpenPayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let terms = DomainTimeDepositTerms {
+            annual_rate: input.annual_rate,
+            duration: TimeDepositDuration::Months(input.duration_months),
+            early_break_penalty: input.early_break_penalty,
+        };
         exec_mutation!(
-            DepositRevertPayload,
-            Deposit,
+            TimeDepositOpenPayload,
+            TimeDeposit,
             ctx,
-            app.deposits().revert_deposit(sub, input.deposit_id)
+            app.deposits().open_time_deposit(
+                sub,
+                input.deposit_account_id,
+                input.amount,
+                terms,
+                input.maturity_instruction,
+                input.reference
+            )
         )
     }
 
-    async fn terms_template_create(
+    pub async fn time_deposit_break(
         &self,
         ctx: &Context<'_>,
-        input: TermsTemplateCreateInput,
-    ) -> async_graphql::Result<TermsTemplateCreatePayload> {
+        input: TimeDepositBreakInput,
+    ) -> async_graphql::Result<TimeDepositBreakPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            TimeDepositBreakPayload,
+            TimeDeposit,
+            ctx,
+            app.deposits()
+                .break_time_deposit(sub, input.time_deposit_id)
+        )
+    }
+
+    pub async fn transfer_initiate(
+        &self,
+        ctx: &Context<'_>,
+        input: TransferInitiateInput,
+    ) -> async_graphql::Result<TransferInitiatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            TransferInitiatePayload,
+            Transfer,
+            ctx,
+            app.deposits().initiate_transfer(
+                sub,
+                input.from_account_id,
+                input.to_account_id,
+                input.amount,
+                input.reference
+            )
+        )
+    }
+
+    pub async fn deposit_account_freeze(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountFreezeInput,
+    ) -> async_graphql::Result<DepositAccountFreezePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountFreezePayload,
+            DepositAccount,
+            ctx,
+            app.deposits()
+                .freeze_account(sub, input.deposit_account_id, input.scope, input.reason)
+        )
+    }
+
+    pub async fn deposit_account_unfreeze(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountUnfreezeInput,
+    ) -> async_graphql::Result<DepositAccountUnfreezePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountUnfreezePayload,
+            DepositAccount,
+            ctx,
+            app.deposits()
+                .unfreeze_account(sub, input.deposit_account_id)
+        )
+    }
+
+    pub async fn deposit_account_reactivate(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountReactivateInput,
+    ) -> async_graphql::Result<DepositAccountReactivatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountReactivatePayload,
+            DepositAccount,
+            ctx,
+            app.deposits()
+                .reactivate_account(sub, input.deposit_account_id)
+        )
+    }
+
+    pub async fn deposit_account_close(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountCloseInput,
+    ) -> async_graphql::Result<DepositAccountClosePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountClosePayload,
+            DepositAccount,
+            ctx,
+            app.deposits().close_account(
+                sub,
+                input.deposit_account_id,
+                input.sweep_to.map(BalanceSweepDestination::from)
+            )
+        )
+    }
+
+    pub async fn deposit_account_withdrawal_limits_update(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountWithdrawalLimitsUpdateInput,
+    ) -> async_graphql::Result<DepositAccountWithdrawalLimitsUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountWithdrawalLimitsUpdatePayload,
+            DepositAccount,
+            ctx,
+            app.deposits().update_account_withdrawal_limits(
+                sub,
+                input.deposit_account_id,
+                input.withdrawal_limits.map(Into::into)
+            )
+        )
+    }
+
+    pub async fn hold_place(
+        &self,
+        ctx: &Context<'_>,
+        input: HoldPlaceInput,
+    ) -> async_graphql::Result<HoldPlacePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            HoldPlacePayload,
+            Hold,
+            ctx,
+            app.deposits().place_hold(
+                sub,
+                input.deposit_account_id,
+                input.amount,
+                input.reason,
+                input.expires_at.map(|ts| ts.into_inner()),
+                input.reference
+            )
+        )
+    }
+
+    pub async fn hold_release(
+        &self,
+        ctx: &Context<'_>,
+        input: HoldReleaseInput,
+    ) -> async_graphql::Result<HoldReleasePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            HoldReleasePayload,
+            Hold,
+            ctx,
+            app.deposits().release_hold(sub, input.hold_id)
+        )
+    }
+
+    pub async fn beneficiary_remove(
+        &self,
+        ctx: &Context<'_>,
+        input: BeneficiaryRemoveInput,
+    ) -> async_graphql::Result<BeneficiaryRemovePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            BeneficiaryRemovePayload,
+            Beneficiary,
+            ctx,
+            app.deposits().remove_beneficiary(sub, input.beneficiary_id)
+        )
+    }
+
+    pub async fn bank_statement_import(
+        &self,
+        ctx: &Context<'_>,
+        input: BankStatementImportInput,
+    ) -> async_graphql::Result<BankStatementImportPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let mut file = input.file.value(ctx)?.content;
+        let mut data = String::new();
+        file.read_to_string(&mut data)?;
+        let lines = app.deposits().import_bank_statement(sub, &data).await?;
+
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let mut bank_statement_lines = Vec::with_capacity(lines.len());
+        for line in lines {
+            let line = BankStatementLine::from(line);
+            loader.feed_one(line.entity.id, line.clone()).await;
+            bank_statement_lines.push(line);
+        }
+        Ok(BankStatementImportPayload {
+            bank_statement_lines,
+        })
+    }
+
+    pub async fn bank_statement_line_assign(
+        &self,
+        ctx: &Context<'_>,
+        input: BankStatementLineAssignInput,
+    ) -> async_graphql::Result<BankStatementLineAssignPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            BankStatementLineAssignPayload,
+            BankStatementLine,
+            ctx,
+            app.deposits().assign_bank_statement_line(
+                sub,
+                input.bank_statement_line_id,
+                input.deposit_account_id
+            )
+        )
+    }
+
+    pub async fn deposit_account_statement_download_link_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountStatementDownloadLinkGenerateInput,
+    ) -> async_graphql::Result<DepositAccountStatementDownloadLinkGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let link = app
+            .deposits()
+            .generate_statement_download_link(sub, input.deposit_account_statement_id)
+            .await?;
+        Ok(DepositAccountStatementDownloadLinkGeneratePayload::from(
+            DepositAccountStatementDownloadLink::from(link),
+        ))
+    }
+
+    pub async fn withdrawal_initiate(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalInitiateInput,
+    ) -> async_graphql::Result<WithdrawalInitiatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            WithdrawalInitiatePayload,
+            Withdrawal,
+            ctx,
+            app.deposits().initiate_withdrawal(
+                sub,
+                input.deposit_account_id,
+                input.amount,
+                input.beneficiary_id.map(BeneficiaryId::from),
+                input.reference
+            )
+        )
+    }
+
+    pub async fn withdrawal_confirm(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalConfirmInput,
+    ) -> async_graphql::Result<WithdrawalConfirmPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        exec_mutation!(
+            WithdrawalConfirmPayload,
+            Withdrawal,
+            ctx,
+            app.deposits().confirm_withdrawal(sub, input.withdrawal_id)
+        )
+    }
+
+    pub async fn withdrawal_cancel(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalCancelInput,
+    ) -> async_graphql::Result<WithdrawalCancelPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            WithdrawalCancelPayload,
+            Withdrawal,
+            ctx,
+            app.deposits().cancel_withdrawal(sub, input.withdrawal_id)
+        )
+    }
+
+    pub async fn withdrawal_revert(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalRevertInput,
+    ) -> async_graphql::Result<WithdrawalRevertPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            WithdrawalRevertPayload,
+            Withdrawal,
+            ctx,
+            app.deposits().revert_withdrawal(sub, input.withdrawal_id)
+        )
+    }
+
+    /// Writes all confirmed withdrawals not yet sent to the bank into a
+    /// pain.001 credit transfer file.
+    pub async fn payment_batch_generate(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<PaymentBatchGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            PaymentBatchGeneratePayload,
+            PaymentBatch,
+            ctx,
+            app.deposits().generate_payment_batch(sub)
+        )
+    }
+
+    pub async fn payment_batch_download_link_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: PaymentBatchDownloadLinkGenerateInput,
+    ) -> async_graphql::Result<PaymentBatchDownloadLinkGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let link = app
+            .deposits()
+            .generate_payment_file_download_link(sub, input.payment_batch_id)
+            .await?;
+        Ok(PaymentBatchDownloadLinkGeneratePayload::from(
+            PaymentBatchDownloadLink::from(link),
+        ))
+    }
+
+    pub async fn deposit_revert(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositRevertInput,
+    ) -> async_graphql::Result<DepositRevertPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositRevertPayload,
+            Deposit,
+            ctx,
+            app.deposits().revert_deposit(sub, input.deposit_id)
+        )
+    }
+
+    async fn terms_template_create(
+        &self,
+        ctx: &Context<'_>,
+        input: TermsTemplateCreateInput,
+    ) -> async_graphql::Result<TermsTemplateCreatePayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         let term_values = lana_app::terms::TermValues::builder()
             .annual_rate(input.annual_rate)
@@ -1285,6 +1977,7 @@ impl Mutation {
             .liquidation_cvl(input.liquidation_cvl)
             .margin_call_cvl(input.margin_call_cvl)
             .initial_cvl(input.initial_cvl)
+            .interest_capitalization(input.interest_capitalization)
             .build()?;
 
         exec_mutation!(
@@ -1318,6 +2011,7 @@ impl Mutation {
             .liquidation_cvl(input.liquidation_cvl)
             .margin_call_cvl(input.margin_call_cvl)
             .initial_cvl(input.initial_cvl)
+            .interest_capitalization(input.interest_capitalization)
             .build()?;
         exec_mutation!(
             TermsTemplateUpdatePayload,
@@ -1331,6 +2025,59 @@ impl Mutation {
         )
     }
 
+    async fn terms_template_amount_bounds_update(
+        &self,
+        ctx: &Context<'_>,
+        input: TermsTemplateAmountBoundsUpdateInput,
+    ) -> async_graphql::Result<TermsTemplateAmountBoundsUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            TermsTemplateAmountBoundsUpdatePayload,
+            TermsTemplate,
+            ctx,
+            app.credit().terms_templates().update_amount_bounds(
+                sub,
+                TermsTemplateId::from(input.id),
+                FacilityAmountBounds {
+                    min_amount: input.min_facility_amount,
+                    max_amount: input.max_facility_amount,
+                }
+            )
+        )
+    }
+
+    async fn terms_template_retire(
+        &self,
+        ctx: &Context<'_>,
+        input: TermsTemplateRetireInput,
+    ) -> async_graphql::Result<TermsTemplateRetirePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            TermsTemplateRetirePayload,
+            TermsTemplate,
+            ctx,
+            app.credit()
+                .terms_templates()
+                .retire(sub, TermsTemplateId::from(input.id))
+        )
+    }
+
+    async fn terms_template_activate(
+        &self,
+        ctx: &Context<'_>,
+        input: TermsTemplateActivateInput,
+    ) -> async_graphql::Result<TermsTemplateActivatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            TermsTemplateActivatePayload,
+            TermsTemplate,
+            ctx,
+            app.credit()
+                .terms_templates()
+                .activate(sub, TermsTemplateId::from(input.id))
+        )
+    }
+
     async fn credit_module_configure(
         &self,
         ctx: &Context<'_>,
@@ -1348,11 +2095,14 @@ impl Mutation {
             chart_of_account_facility_omnibus_parent_code,
             chart_of_account_collateral_omnibus_parent_code,
             chart_of_account_in_liquidation_omnibus_parent_code,
+            chart_of_account_disbursal_settlement_omnibus_parent_code,
+            chart_of_account_participation_principal_omnibus_parent_code,
             chart_of_account_facility_parent_code,
             chart_of_account_collateral_parent_code,
             chart_of_account_in_liquidation_parent_code,
             chart_of_account_interest_income_parent_code,
             chart_of_account_fee_income_parent_code,
+            chart_of_account_participation_payable_parent_code,
 
             chart_of_account_short_term_individual_disbursed_receivable_parent_code,
             chart_of_account_short_term_government_entity_disbursed_receivable_parent_code,
@@ -1409,6 +2159,14 @@ impl Mutation {
                 chart_of_account_in_liquidation_omnibus_parent_code
                     .parse()?,
             )
+            .chart_of_account_disbursal_settlement_omnibus_parent_code(
+                chart_of_account_disbursal_settlement_omnibus_parent_code
+                    .parse()?,
+            )
+            .chart_of_account_participation_principal_omnibus_parent_code(
+                chart_of_account_participation_principal_omnibus_parent_code
+                    .parse()?,
+            )
             .chart_of_account_facility_parent_code(
                 chart_of_account_facility_parent_code.parse()?,
             )
@@ -1424,6 +2182,9 @@ impl Mutation {
             .chart_of_account_fee_income_parent_code(
                 chart_of_account_fee_income_parent_code.parse()?,
             )
+            .chart_of_account_participation_payable_parent_code(
+                chart_of_account_participation_payable_parent_code.parse()?,
+            )
             .chart_of_account_short_term_individual_disbursed_receivable_parent_code(chart_of_account_short_term_individual_disbursed_receivable_parent_code.parse()?)
             .chart_of_account_short_term_government_entity_disbursed_receivable_parent_code(chart_of_account_short_term_government_entity_disbursed_receivable_parent_code.parse()?)
             .chart_of_account_short_term_private_company_disbursed_receivable_parent_code(chart_of_account_short_term_private_company_disbursed_receivable_parent_code.parse()?)
@@ -1501,6 +2262,7 @@ impl Mutation {
             .liquidation_cvl(terms.liquidation_cvl)
             .margin_call_cvl(terms.margin_call_cvl)
             .initial_cvl(terms.initial_cvl)
+            .interest_capitalization(terms.interest_capitalization)
             .build()?;
 
         exec_mutation!(
@@ -1518,6 +2280,76 @@ impl Mutation {
         )
     }
 
+    pub async fn credit_facility_create_from_terms_template(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityCreateFromTermsTemplateInput,
+    ) -> async_graphql::Result<CreditFacilityCreateFromTermsTemplatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let CreditFacilityCreateFromTermsTemplateInput {
+            facility,
+            customer_id,
+            disbursal_credit_account_id,
+            terms_template_id,
+            custodian_id,
+        } = input;
+
+        exec_mutation!(
+            CreditFacilityCreateFromTermsTemplatePayload,
+            CreditFacility,
+            ctx,
+            app.credit().create_facility_from_terms_template(
+                sub,
+                customer_id,
+                disbursal_credit_account_id,
+                facility,
+                TermsTemplateId::from(terms_template_id),
+                custodian_id
+            )
+        )
+    }
+
+    pub async fn credit_facility_proposal_convert(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityProposalConvertInput,
+    ) -> async_graphql::Result<CreditFacilityProposalConvertPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let CreditFacilityProposalConvertInput {
+            credit_facility_proposal_id,
+            disbursal_credit_account_id,
+            custodian_id,
+        } = input;
+
+        exec_mutation!(
+            CreditFacilityProposalConvertPayload,
+            CreditFacility,
+            ctx,
+            app.credit().convert_facility_proposal(
+                sub,
+                credit_facility_proposal_id,
+                disbursal_credit_account_id,
+                custodian_id
+            )
+        )
+    }
+
+    pub async fn credit_facility_proposal_reject(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityProposalRejectInput,
+    ) -> async_graphql::Result<CreditFacilityProposalRejectPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CreditFacilityProposalRejectPayload,
+            CreditFacilityProposal,
+            ctx,
+            app.credit()
+                .proposals()
+                .reject(sub, input.credit_facility_proposal_id, input.reason)
+        )
+    }
+
     pub async fn credit_facility_collateral_update(
         &self,
         ctx: &Context<'_>,
@@ -1567,8 +2399,64 @@ impl Mutation {
             CreditFacilityDisbursalInitiatePayload,
             CreditFacilityDisbursal,
             ctx,
+            app.credit().initiate_disbursal(
+                sub,
+                input.credit_facility_id.into(),
+                input.amount,
+                input
+                    .external_beneficiary
+                    .map(DisbursalDestination::from)
+                    .unwrap_or_default(),
+            )
+        )
+    }
+
+    pub async fn credit_facility_disbursal_settlement_confirm(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityDisbursalSettlementConfirmInput,
+    ) -> async_graphql::Result<CreditFacilityDisbursalSettlementConfirmPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CreditFacilityDisbursalSettlementConfirmPayload,
+            CreditFacilityDisbursal,
+            ctx,
             app.credit()
-                .initiate_disbursal(sub, input.credit_facility_id.into(), input.amount)
+                .confirm_disbursal_settlement(sub, input.disbursal_id)
+        )
+    }
+
+    pub async fn credit_facility_disbursal_settlement_fail(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityDisbursalSettlementFailInput,
+    ) -> async_graphql::Result<CreditFacilityDisbursalSettlementFailPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CreditFacilityDisbursalSettlementFailPayload,
+            CreditFacilityDisbursal,
+            ctx,
+            app.credit()
+                .fail_disbursal_settlement(sub, input.disbursal_id, input.reason)
+        )
+    }
+
+    async fn credit_facility_participation_create(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityParticipationCreateInput,
+    ) -> async_graphql::Result<CreditFacilityParticipationCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CreditFacilityParticipationCreatePayload,
+            CreditFacilityParticipation,
+            ctx,
+            app.credit().create_participation(
+                sub,
+                input.credit_facility_id,
+                input.partner_name,
+                input.share
+            )
         )
     }
 
@@ -1587,6 +2475,36 @@ impl Mutation {
         )
     }
 
+    async fn credit_facility_cancel(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityCancelInput,
+    ) -> async_graphql::Result<CreditFacilityCancelPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CreditFacilityCancelPayload,
+            CreditFacility,
+            ctx,
+            app.credit()
+                .cancel_facility(sub, input.credit_facility_id, input.reason)
+        )
+    }
+
+    async fn credit_facility_reinstatement_request(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityReinstatementRequestInput,
+    ) -> async_graphql::Result<CreditFacilityReinstatementRequestPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CreditFacilityReinstatementRequestPayload,
+            CreditFacility,
+            ctx,
+            app.credit()
+                .request_reinstatement(sub, input.credit_facility_id)
+        )
+    }
+
     async fn custodian_create(
         &self,
         ctx: &Context<'_>,
@@ -1927,6 +2845,36 @@ impl Mutation {
         Ok(AccountingCsvDownloadLinkGeneratePayload::from(link))
     }
 
+    pub async fn loan_tape_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: LoanTapeGenerateInput,
+    ) -> async_graphql::Result<LoanTapeGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let loan_tape = app
+            .credit()
+            .loan_tapes()
+            .create(sub, input.as_of.into_inner())
+            .await?;
+        Ok(LoanTapeGeneratePayload::from(LoanTape::from(loan_tape)))
+    }
+
+    pub async fn loan_tape_download_link_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: LoanTapeDownloadLinkGenerateInput,
+    ) -> async_graphql::Result<LoanTapeDownloadLinkGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let link = app
+            .credit()
+            .loan_tapes()
+            .generate_download_link(sub, input.document_id)
+            .await?;
+        Ok(LoanTapeDownloadLinkGeneratePayload::from(
+            LoanTapeDownloadLink::from(link),
+        ))
+    }
+
     pub async fn loan_agreement_generate(
         &self,
         ctx: &Context<'_>,
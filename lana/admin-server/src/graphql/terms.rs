@@ -1,8 +1,8 @@
 use async_graphql::*;
 
 pub use lana_app::terms::{
-    AnnualRatePct, CVLPct, FacilityDuration as DomainDuration, InterestInterval,
-    ObligationDuration as DomainObligationDuration, OneTimeFeeRatePct,
+    AnnualRatePct, CVLPct, FacilityDuration as DomainDuration, InterestCapitalization,
+    InterestInterval, ObligationDuration as DomainObligationDuration, OneTimeFeeRatePct,
     TermValues as DomainTermValues,
 };
 
@@ -16,6 +16,7 @@ pub struct TermValues {
     liquidation_cvl: CVLPct,
     margin_call_cvl: CVLPct,
     initial_cvl: CVLPct,
+    interest_capitalization: InterestCapitalization,
 }
 
 impl From<DomainTermValues> for TermValues {
@@ -29,6 +30,7 @@ impl From<DomainTermValues> for TermValues {
             liquidation_cvl: values.liquidation_cvl,
             margin_call_cvl: values.margin_call_cvl,
             initial_cvl: values.initial_cvl,
+            interest_capitalization: values.interest_capitalization,
         }
     }
 }
@@ -46,6 +48,8 @@ pub struct TermsInput {
     pub obligation_liquidation_duration_from_due: DurationInput,
     pub margin_call_cvl: CVLPct,
     pub initial_cvl: CVLPct,
+    #[graphql(default)]
+    pub interest_capitalization: InterestCapitalization,
 }
 
 #[derive(Enum, Copy, Clone, Eq, PartialEq)]
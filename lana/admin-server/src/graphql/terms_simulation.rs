@@ -0,0 +1,98 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{credit_facility::CreditFacilityRepaymentPlanEntry, terms::*};
+
+pub use lana_app::credit::{
+    SimulatedCvlPoint as DomainSimulatedCvlPoint, SimulatedPricePoint,
+    TermsSimulation as DomainTermsSimulation,
+};
+pub use lana_app::primitives::PriceOfOneBTC;
+
+#[derive(InputObject)]
+pub struct PricePointInput {
+    pub date: Date,
+    pub usd_cents_per_btc: UsdCents,
+}
+
+impl From<PricePointInput> for SimulatedPricePoint {
+    fn from(input: PricePointInput) -> Self {
+        let date: chrono::NaiveDate = input.date.into();
+        Self {
+            at: date
+                .and_hms_opt(0, 0, 0)
+                .expect("midnight is a valid time")
+                .and_utc(),
+            price: PriceOfOneBTC::new(input.usd_cents_per_btc),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct TermsSimulationInput {
+    pub terms: TermsInput,
+    pub amount: UsdCents,
+    pub collateral: Satoshis,
+    pub start_date: Option<Date>,
+    pub price_path: Vec<PricePointInput>,
+}
+
+#[derive(SimpleObject)]
+pub struct SimulatedCvlPoint {
+    at: Timestamp,
+    usd_cents_per_btc: UsdCents,
+    outstanding: UsdCents,
+    cvl: CVLPct,
+    collateralization_state: CollateralizationState,
+}
+
+impl From<DomainSimulatedCvlPoint> for SimulatedCvlPoint {
+    fn from(point: DomainSimulatedCvlPoint) -> Self {
+        Self {
+            at: point.at.into(),
+            usd_cents_per_btc: point.price.into_inner(),
+            outstanding: point.outstanding,
+            cvl: point.cvl,
+            collateralization_state: point.collateralization_state,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct TermsSimulation {
+    starts_at: Timestamp,
+    matures_at: Timestamp,
+    structuring_fee: UsdCents,
+    total_interest: UsdCents,
+    total_repayment: UsdCents,
+    margin_call_at: Option<Timestamp>,
+    liquidation_at: Option<Timestamp>,
+    repayment_plan: Vec<CreditFacilityRepaymentPlanEntry>,
+    cvl_points: Vec<SimulatedCvlPoint>,
+}
+
+impl From<DomainTermsSimulation> for TermsSimulation {
+    fn from(simulation: DomainTermsSimulation) -> Self {
+        let total_repayment = simulation.total_repayment();
+        Self {
+            starts_at: simulation.starts_at.into(),
+            matures_at: simulation.matures_at.into(),
+            structuring_fee: simulation.structuring_fee,
+            total_interest: simulation.total_interest,
+            total_repayment,
+            margin_call_at: simulation.margin_call_at.map(Into::into),
+            liquidation_at: simulation.liquidation_at.map(Into::into),
+            repayment_plan: simulation
+                .repayment_plan
+                .into_iter()
+                .map(CreditFacilityRepaymentPlanEntry::from)
+                .collect(),
+            cvl_points: simulation
+                .cvl_points
+                .into_iter()
+                .map(SimulatedCvlPoint::from)
+                .collect(),
+        }
+    }
+}
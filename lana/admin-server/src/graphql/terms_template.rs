@@ -4,7 +4,9 @@ use crate::primitives::*;
 
 use super::terms::*;
 
-use lana_app::credit::TermsTemplate as DomainTermsTemplate;
+pub use lana_app::credit::{
+    FacilityAmountBounds, TermsTemplate as DomainTermsTemplate, TermsTemplateStatus,
+};
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -12,6 +14,8 @@ pub struct TermsTemplate {
     id: ID,
     terms_id: UUID,
     values: TermValues,
+    version: u32,
+    status: TermsTemplateStatus,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -25,6 +29,8 @@ impl From<DomainTermsTemplate> for TermsTemplate {
             created_at: terms.created_at().into(),
             terms_id: terms.id.into(),
             values: terms.values.into(),
+            version: terms.version,
+            status: terms.status,
             entity: Arc::new(terms),
         }
     }
@@ -36,6 +42,14 @@ impl TermsTemplate {
         &self.entity.name
     }
 
+    async fn min_facility_amount(&self) -> Option<UsdCents> {
+        self.entity.amount_bounds.min_amount
+    }
+
+    async fn max_facility_amount(&self) -> Option<UsdCents> {
+        self.entity.amount_bounds.max_amount
+    }
+
     async fn subject_can_update_terms_template(
         &self,
         ctx: &Context<'_>,
@@ -64,6 +78,8 @@ pub(super) struct TermsTemplateCreateInput {
     pub liquidation_cvl: CVLPct,
     pub margin_call_cvl: CVLPct,
     pub initial_cvl: CVLPct,
+    #[graphql(default)]
+    pub interest_capitalization: InterestCapitalization,
 }
 crate::mutation_payload! { TermsTemplateCreatePayload, terms_template: TermsTemplate }
 
@@ -81,5 +97,27 @@ pub(super) struct TermsTemplateUpdateInput {
     pub obligation_liquidation_duration_from_due: DurationInput,
     pub margin_call_cvl: CVLPct,
     pub initial_cvl: CVLPct,
+    #[graphql(default)]
+    pub interest_capitalization: InterestCapitalization,
 }
 crate::mutation_payload! { TermsTemplateUpdatePayload, terms_template: TermsTemplate }
+
+#[derive(InputObject)]
+pub(super) struct TermsTemplateAmountBoundsUpdateInput {
+    pub id: UUID,
+    pub min_facility_amount: Option<UsdCents>,
+    pub max_facility_amount: Option<UsdCents>,
+}
+crate::mutation_payload! { TermsTemplateAmountBoundsUpdatePayload, terms_template: TermsTemplate }
+
+#[derive(InputObject)]
+pub(super) struct TermsTemplateRetireInput {
+    pub id: UUID,
+}
+crate::mutation_payload! { TermsTemplateRetirePayload, terms_template: TermsTemplate }
+
+#[derive(InputObject)]
+pub(super) struct TermsTemplateActivateInput {
+    pub id: UUID,
+}
+crate::mutation_payload! { TermsTemplateActivatePayload, terms_template: TermsTemplate }
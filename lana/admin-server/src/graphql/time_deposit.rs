@@ -0,0 +1,120 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{deposit::DepositAccount, loader::LanaDataLoader};
+
+pub use lana_app::deposit::{
+    DepositInterestRate, EarlyBreakPenaltyRate, TimeDeposit as DomainTimeDeposit,
+    TimeDepositDuration, TimeDepositMaturityInstruction, TimeDepositStatus,
+    TimeDepositTerms as DomainTimeDepositTerms,
+};
+
+#[derive(SimpleObject, Clone)]
+pub struct TimeDepositTerms {
+    annual_rate: DepositInterestRate,
+    duration_months: u32,
+    early_break_penalty: EarlyBreakPenaltyRate,
+}
+
+impl From<DomainTimeDepositTerms> for TimeDepositTerms {
+    fn from(terms: DomainTimeDepositTerms) -> Self {
+        let duration_months = match terms.duration {
+            TimeDepositDuration::Months(months) => months,
+        };
+        Self {
+            annual_rate: terms.annual_rate,
+            duration_months,
+            early_break_penalty: terms.early_break_penalty,
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct TimeDeposit {
+    id: ID,
+    time_deposit_id: UUID,
+    deposit_account_id: UUID,
+    amount: UsdCents,
+    terms: TimeDepositTerms,
+    maturity_instruction: TimeDepositMaturityInstruction,
+    opened_at: Timestamp,
+    maturity_at: Timestamp,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainTimeDeposit>,
+}
+
+impl From<DomainTimeDeposit> for TimeDeposit {
+    fn from(time_deposit: DomainTimeDeposit) -> Self {
+        Self {
+            id: time_deposit.id.to_global_id(),
+            time_deposit_id: UUID::from(time_deposit.id),
+            deposit_account_id: UUID::from(time_deposit.deposit_account_id),
+            amount: time_deposit.amount,
+            terms: time_deposit.terms.into(),
+            maturity_instruction: time_deposit.maturity_instruction,
+            opened_at: time_deposit.opened_at.into(),
+            maturity_at: time_deposit.maturity_at.into(),
+            created_at: time_deposit.created_at().into(),
+
+            entity: Arc::new(time_deposit),
+        }
+    }
+}
+
+#[ComplexObject]
+impl TimeDeposit {
+    async fn reference(&self) -> &str {
+        &self.entity.reference
+    }
+
+    async fn status(&self) -> TimeDepositStatus {
+        self.entity.status()
+    }
+
+    async fn interest_at_maturity(&self) -> UsdCents {
+        self.entity.interest_at_maturity()
+    }
+
+    async fn interest_paid(&self) -> Option<UsdCents> {
+        self.entity.interest_paid()
+    }
+
+    async fn rolled_over_into(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Self>> {
+        let Some(id) = self.entity.rolled_over_into() else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(id).await?)
+    }
+
+    async fn account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.deposit_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+}
+
+#[derive(InputObject)]
+pub struct TimeDepositOpenInput {
+    pub deposit_account_id: UUID,
+    pub amount: UsdCents,
+    pub annual_rate: DepositInterestRate,
+    pub duration_months: u32,
+    pub early_break_penalty: EarlyBreakPenaltyRate,
+    pub maturity_instruction: TimeDepositMaturityInstruction,
+    pub reference: Option<String>,
+}
+crate::mutation_payload! { TimeDepositOpenPayload, time_deposit: TimeDeposit }
+
+#[derive(InputObject)]
+pub struct TimeDepositBreakInput {
+    pub time_deposit_id: UUID,
+}
+crate::mutation_payload! { TimeDepositBreakPayload, time_deposit: TimeDeposit }
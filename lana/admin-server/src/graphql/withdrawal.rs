@@ -3,7 +3,8 @@ use async_graphql::*;
 use crate::primitives::*;
 
 use super::{
-    approval_process::ApprovalProcess, deposit_account::DepositAccount, loader::LanaDataLoader,
+    approval_process::ApprovalProcess, beneficiary::Beneficiary, deposit_account::DepositAccount,
+    loader::LanaDataLoader, payment_batch::PaymentBatch,
 };
 
 pub use lana_app::deposit::{
@@ -18,6 +19,7 @@ pub struct Withdrawal {
     account_id: UUID,
     approval_process_id: UUID,
     amount: UsdCents,
+    fee: UsdCents,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -33,6 +35,7 @@ impl From<lana_app::deposit::Withdrawal> for Withdrawal {
             withdrawal_id: UUID::from(withdraw.id),
             approval_process_id: UUID::from(withdraw.approval_process_id),
             amount: withdraw.amount,
+            fee: withdraw.fee,
             entity: Arc::new(withdraw),
         }
     }
@@ -63,6 +66,26 @@ impl Withdrawal {
         Ok(process)
     }
 
+    async fn beneficiary(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Beneficiary>> {
+        let Some(beneficiary_id) = self.entity.beneficiary_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(beneficiary_id).await?)
+    }
+
+    /// The payment file the withdrawal was sent to the bank with.
+    async fn payment_batch(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<PaymentBatch>> {
+        let Some(payment_batch_id) = self.entity.payment_batch_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(payment_batch_id).await?)
+    }
+
     async fn account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
         let loader = ctx.data_unchecked::<LanaDataLoader>();
         let account = loader
@@ -77,6 +100,7 @@ impl Withdrawal {
 pub struct WithdrawalInitiateInput {
     pub deposit_account_id: UUID,
     pub amount: UsdCents,
+    pub beneficiary_id: Option<UUID>,
     pub reference: Option<String>,
 }
 crate::mutation_payload! { WithdrawalInitiatePayload, withdrawal: Withdrawal }
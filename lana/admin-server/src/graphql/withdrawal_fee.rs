@@ -0,0 +1,65 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::primitives::Decimal;
+
+pub use lana_app::deposit::{
+    WithdrawalFeeRate as DomainWithdrawalFeeRate,
+    WithdrawalFeeSchedule as DomainWithdrawalFeeSchedule,
+};
+
+#[derive(SimpleObject)]
+pub struct WithdrawalFeeSchedule {
+    flat_amount: Option<UsdCents>,
+    percentage_rate: Option<Decimal>,
+    min_fee: Option<UsdCents>,
+    max_fee: Option<UsdCents>,
+}
+
+impl From<DomainWithdrawalFeeSchedule> for WithdrawalFeeSchedule {
+    fn from(schedule: DomainWithdrawalFeeSchedule) -> Self {
+        let (flat_amount, percentage_rate) = match schedule.rate {
+            DomainWithdrawalFeeRate::Flat { amount } => (Some(amount), None),
+            DomainWithdrawalFeeRate::Percentage { rate } => (None, Some(rate.into())),
+        };
+        Self {
+            flat_amount,
+            percentage_rate,
+            min_fee: schedule.min_fee,
+            max_fee: schedule.max_fee,
+        }
+    }
+}
+
+#[derive(OneofObject)]
+pub enum WithdrawalFeeRateInput {
+    FlatAmount(UsdCents),
+    PercentageRate(Decimal),
+}
+
+impl From<WithdrawalFeeRateInput> for DomainWithdrawalFeeRate {
+    fn from(input: WithdrawalFeeRateInput) -> Self {
+        match input {
+            WithdrawalFeeRateInput::FlatAmount(amount) => Self::Flat { amount },
+            WithdrawalFeeRateInput::PercentageRate(rate) => Self::Percentage { rate: rate.into() },
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct WithdrawalFeeScheduleInput {
+    pub rate: WithdrawalFeeRateInput,
+    pub min_fee: Option<UsdCents>,
+    pub max_fee: Option<UsdCents>,
+}
+
+impl From<WithdrawalFeeScheduleInput> for DomainWithdrawalFeeSchedule {
+    fn from(input: WithdrawalFeeScheduleInput) -> Self {
+        Self {
+            rate: input.rate.into(),
+            min_fee: input.min_fee,
+            max_fee: input.max_fee,
+        }
+    }
+}
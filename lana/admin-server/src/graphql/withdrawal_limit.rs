@@ -0,0 +1,97 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::deposit::{
+    WithdrawalLimit as DomainWithdrawalLimit, WithdrawalLimitPeriod,
+    WithdrawalLimitUsage as DomainWithdrawalLimitUsage, WithdrawalLimits as DomainWithdrawalLimits,
+};
+
+#[derive(SimpleObject, Clone)]
+pub struct WithdrawalLimit {
+    max_amount: Option<UsdCents>,
+    max_count: Option<u32>,
+}
+
+impl From<DomainWithdrawalLimit> for WithdrawalLimit {
+    fn from(limit: DomainWithdrawalLimit) -> Self {
+        Self {
+            max_amount: limit.max_amount,
+            max_count: limit.max_count,
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct WithdrawalLimits {
+    daily: WithdrawalLimit,
+    weekly: WithdrawalLimit,
+    monthly: WithdrawalLimit,
+}
+
+impl From<DomainWithdrawalLimits> for WithdrawalLimits {
+    fn from(limits: DomainWithdrawalLimits) -> Self {
+        Self {
+            daily: limits.daily.into(),
+            weekly: limits.weekly.into(),
+            monthly: limits.monthly.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct WithdrawalLimitUsage {
+    period: WithdrawalLimitPeriod,
+    max_amount: Option<UsdCents>,
+    max_count: Option<u32>,
+    used_amount: UsdCents,
+    used_count: u32,
+    remaining_amount: Option<UsdCents>,
+    remaining_count: Option<u32>,
+}
+
+impl From<DomainWithdrawalLimitUsage> for WithdrawalLimitUsage {
+    fn from(usage: DomainWithdrawalLimitUsage) -> Self {
+        Self {
+            period: usage.period,
+            max_amount: usage.limit.max_amount,
+            max_count: usage.limit.max_count,
+            used_amount: usage.used_amount,
+            used_count: usage.used_count,
+            remaining_amount: usage.remaining_amount(),
+            remaining_count: usage.remaining_count(),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct WithdrawalLimitInput {
+    pub max_amount: Option<UsdCents>,
+    pub max_count: Option<u32>,
+}
+
+impl From<WithdrawalLimitInput> for DomainWithdrawalLimit {
+    fn from(input: WithdrawalLimitInput) -> Self {
+        Self {
+            max_amount: input.max_amount,
+            max_count: input.max_count,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct WithdrawalLimitsInput {
+    pub daily: WithdrawalLimitInput,
+    pub weekly: WithdrawalLimitInput,
+    pub monthly: WithdrawalLimitInput,
+}
+
+impl From<WithdrawalLimitsInput> for DomainWithdrawalLimits {
+    fn from(input: WithdrawalLimitsInput) -> Self {
+        Self {
+            daily: input.daily.into(),
+            weekly: input.weekly.into(),
+            monthly: input.monthly.into(),
+        }
+    }
+}
@@ -5,11 +5,14 @@ use serde::{Deserialize, Serialize};
 
 pub use lana_app::{
     primitives::{
-        AccountSpec, ApprovalProcessId, ChartId, CollateralId, CommitteeId, CreditFacilityId,
-        CustodianId, CustomerDocumentId, CustomerId, DepositAccountId, DepositId, DisbursalId,
-        DisbursalStatus, DocumentId, LedgerTransactionId, ManualTransactionId, PaymentAllocationId,
-        PaymentId, PermissionSetId, PolicyId, ReportId, RoleId, Satoshis, SignedSatoshis,
-        SignedUsdCents, Subject, TermsTemplateId, UsdCents, UserId, WalletId, WithdrawalId,
+        AccountSpec, AgingBucket, ApprovalProcessId, BankStatementLineId, BeneficiaryId, ChartId,
+        CollateralId, CommitteeId, CreditFacilityId, CreditFacilityProposalId, CustodianId,
+        CustomerDocumentId, CustomerId, DepositAccountId, DepositAccountStatementId, DepositId,
+        DepositProductId, DisbursalId, DisbursalStatus, DocumentId, HoldId, LedgerTransactionId,
+        ManualTransactionId, ParticipationId, ParticipationSharePct, ParticipationShareType,
+        PaymentAllocationId, PaymentBatchId, PaymentId, PermissionSetId, PolicyId, ReportId,
+        RoleId, Satoshis, SignedSatoshis, SignedUsdCents, Subject, TermsTemplateId, TimeDepositId,
+        TransferId, UsdCents, UserId, WalletId, WithdrawalId,
     },
     public_id::PublicId,
     report::ReportRunId,
@@ -115,6 +118,7 @@ impl_to_global_id! {
     ChartId,
     TermsTemplateId,
     CreditFacilityId,
+    CreditFacilityProposalId,
     CollateralId,
     WalletId,
     CustodianId,
@@ -132,8 +136,17 @@ impl_to_global_id! {
     ManualTransactionId,
     ApprovalProcessId,
     DepositAccountId,
+    DepositProductId,
+    TimeDepositId,
+    TransferId,
+    HoldId,
+    DepositAccountStatementId,
+    BeneficiaryId,
+    BankStatementLineId,
+    PaymentBatchId,
     LedgerTransactionId,
     PaymentAllocationId,
+    ParticipationId,
     PublicId
 }
 
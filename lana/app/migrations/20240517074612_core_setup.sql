@@ -133,10 +133,14 @@ CREATE TABLE core_withdrawals (
   id UUID PRIMARY KEY,
   deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
   approval_process_id UUID REFERENCES core_approval_processes(id),
+  beneficiary_id UUID DEFAULT NULL,
+  status VARCHAR NOT NULL,
   cancelled_tx_id UUID DEFAULT NULL,
+  payment_batch_id UUID DEFAULT NULL,
   reference VARCHAR NOT NULL UNIQUE,
   created_at TIMESTAMPTZ NOT NULL
 );
+CREATE INDEX idx_core_withdrawals_unbatched ON core_withdrawals(status, created_at) WHERE payment_batch_id IS NULL;
 
 CREATE TABLE core_withdrawal_events (
   id UUID NOT NULL REFERENCES core_withdrawals(id),
@@ -147,6 +151,138 @@ CREATE TABLE core_withdrawal_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_deposit_products (
+  id UUID PRIMARY KEY,
+  name VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_deposit_product_events (
+  id UUID NOT NULL REFERENCES core_deposit_products(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_time_deposits (
+  id UUID PRIMARY KEY,
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  reference VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_time_deposit_events (
+  id UUID NOT NULL REFERENCES core_time_deposits(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_holds (
+  id UUID PRIMARY KEY,
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  reference VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_holds_deposit_account_id ON core_holds(deposit_account_id);
+
+CREATE TABLE core_hold_events (
+  id UUID NOT NULL REFERENCES core_holds(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_deposit_account_statements (
+  id UUID PRIMARY KEY,
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  reference VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_deposit_account_statements_deposit_account_id ON core_deposit_account_statements(deposit_account_id);
+
+CREATE TABLE core_deposit_account_statement_events (
+  id UUID NOT NULL REFERENCES core_deposit_account_statements(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_transfers (
+  id UUID PRIMARY KEY,
+  from_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  to_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  approval_process_id UUID REFERENCES core_approval_processes(id),
+  reference VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_transfers_from_account_id ON core_transfers(from_account_id);
+CREATE INDEX idx_core_transfers_to_account_id ON core_transfers(to_account_id);
+
+CREATE TABLE core_transfer_events (
+  id UUID NOT NULL REFERENCES core_transfers(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_beneficiaries (
+  id UUID PRIMARY KEY,
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_beneficiaries_deposit_account_id ON core_beneficiaries(deposit_account_id);
+
+CREATE TABLE core_beneficiary_events (
+  id UUID NOT NULL REFERENCES core_beneficiaries(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_bank_statement_lines (
+  id UUID PRIMARY KEY,
+  status VARCHAR NOT NULL,
+  reference VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_bank_statement_lines_status ON core_bank_statement_lines(status);
+
+CREATE TABLE core_bank_statement_line_events (
+  id UUID NOT NULL REFERENCES core_bank_statement_lines(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_payment_batches (
+  id UUID PRIMARY KEY,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_payment_batch_events (
+  id UUID NOT NULL REFERENCES core_payment_batches(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE core_terms_templates (
   id UUID PRIMARY KEY,
   name VARCHAR NOT NULL UNIQUE,
@@ -247,8 +383,11 @@ CREATE TABLE core_credit_facilities (
   collateralization_state VARCHAR NOT NULL,
   status VARCHAR NOT NULL,
   public_id VARCHAR NOT NULL REFERENCES core_public_ids(id),
+  terms_template_id UUID REFERENCES core_terms_templates(id),
+  terms_template_version INT,
   created_at TIMESTAMPTZ NOT NULL
 );
+CREATE INDEX idx_core_credit_facilities_terms_template ON core_credit_facilities(terms_template_id, terms_template_version);
 
 CREATE TABLE core_credit_facility_events (
   id UUID NOT NULL REFERENCES core_credit_facilities(id),
@@ -259,6 +398,39 @@ CREATE TABLE core_credit_facility_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_credit_facility_proposals (
+  id UUID PRIMARY KEY,
+  customer_id UUID NOT NULL REFERENCES core_customers(id),
+  status VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_credit_facility_proposals_status ON core_credit_facility_proposals(status);
+
+CREATE TABLE core_credit_facility_proposal_events (
+  id UUID NOT NULL REFERENCES core_credit_facility_proposals(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_participations (
+  id UUID PRIMARY KEY,
+  credit_facility_id UUID NOT NULL REFERENCES core_credit_facilities(id),
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_participations_credit_facility_id ON core_participations(credit_facility_id);
+
+CREATE TABLE core_participation_events (
+  id UUID NOT NULL REFERENCES core_participations(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE core_custodians (
   id UUID PRIMARY KEY,
   name VARCHAR NOT NULL UNIQUE,
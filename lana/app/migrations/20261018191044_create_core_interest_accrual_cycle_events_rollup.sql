@@ -8,6 +8,7 @@ CREATE TABLE core_interest_accrual_cycle_events_rollup (
   account_ids JSONB,
   accrued_at TIMESTAMPTZ,
   amount BIGINT,
+  capitalized BOOLEAN,
   effective VARCHAR,
   facility_id UUID,
   facility_matures_at TIMESTAMPTZ,
@@ -46,7 +47,7 @@ BEGIN
   END IF;
 
   -- Validate event type is known
-  IF event_type NOT IN ('initialized', 'interest_accrued', 'interest_accruals_posted') THEN
+  IF event_type NOT IN ('initialized', 'interest_accrued', 'interest_accruals_posted', 'interest_accrual_adjusted') THEN
     RAISE EXCEPTION 'Unknown event type: %', event_type;
   END IF;
 
@@ -67,6 +68,7 @@ BEGIN
        ELSE ARRAY[]::BIGINT[]
      END
 ;
+    new_row.capitalized := (NEW.event ->> 'capitalized')::BOOLEAN;
     new_row.effective := (NEW.event ->> 'effective');
     new_row.facility_id := (NEW.event ->> 'facility_id')::UUID;
     new_row.facility_matures_at := (NEW.event ->> 'facility_matures_at')::TIMESTAMPTZ;
@@ -89,6 +91,7 @@ BEGIN
     new_row.accrued_at := current_row.accrued_at;
     new_row.amount := current_row.amount;
     new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.capitalized := current_row.capitalized;
     new_row.effective := current_row.effective;
     new_row.facility_id := current_row.facility_id;
     new_row.facility_matures_at := current_row.facility_matures_at;
@@ -120,12 +123,19 @@ BEGIN
       new_row.tx_ref := (NEW.event ->> 'tx_ref');
     WHEN 'interest_accruals_posted' THEN
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.capitalized := (NEW.event ->> 'capitalized')::BOOLEAN;
       new_row.effective := (NEW.event ->> 'effective');
       new_row.is_interest_accruals_posted := true;
       new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
       new_row.obligation_id := (NEW.event ->> 'obligation_id')::UUID;
       new_row.total := (NEW.event ->> 'total')::BIGINT;
       new_row.tx_ref := (NEW.event ->> 'tx_ref');
+    WHEN 'interest_accrual_adjusted' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.obligation_id := (NEW.event ->> 'obligation_id')::UUID;
+      new_row.tx_ref := (NEW.event ->> 'tx_ref');
   END CASE;
 
   INSERT INTO core_interest_accrual_cycle_events_rollup (
@@ -137,6 +147,7 @@ BEGIN
     accrued_at,
     amount,
     audit_entry_ids,
+    capitalized,
     effective,
     facility_id,
     facility_matures_at,
@@ -158,6 +169,7 @@ BEGIN
     new_row.accrued_at,
     new_row.amount,
     new_row.audit_entry_ids,
+    new_row.capitalized,
     new_row.effective,
     new_row.facility_id,
     new_row.facility_matures_at,
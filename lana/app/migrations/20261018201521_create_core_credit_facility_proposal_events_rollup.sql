@@ -0,0 +1,139 @@
+-- Auto-generated rollup table for CreditFacilityProposalEvent
+CREATE TABLE core_credit_facility_proposal_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  amount BIGINT,
+  credit_facility_id UUID,
+  customer_id UUID,
+  reason VARCHAR,
+  terms JSONB,
+  terms_template JSONB,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[],
+
+  -- Toggle fields
+  is_converted BOOLEAN DEFAULT false,
+  is_rejected BOOLEAN DEFAULT false
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for CreditFacilityProposalEvent
+CREATE OR REPLACE FUNCTION core_credit_facility_proposal_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_credit_facility_proposal_events_rollup%ROWTYPE;
+  new_row core_credit_facility_proposal_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_credit_facility_proposal_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'converted', 'rejected') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
+    new_row.customer_id := (NEW.event ->> 'customer_id')::UUID;
+    new_row.is_converted := false;
+    new_row.is_rejected := false;
+    new_row.reason := (NEW.event ->> 'reason');
+    new_row.terms := (NEW.event -> 'terms');
+    new_row.terms_template := (NEW.event -> 'terms_template');
+  ELSE
+    -- Default all fields to current values
+    new_row.amount := current_row.amount;
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.credit_facility_id := current_row.credit_facility_id;
+    new_row.customer_id := current_row.customer_id;
+    new_row.is_converted := current_row.is_converted;
+    new_row.is_rejected := current_row.is_rejected;
+    new_row.reason := current_row.reason;
+    new_row.terms := current_row.terms;
+    new_row.terms_template := current_row.terms_template;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.customer_id := (NEW.event ->> 'customer_id')::UUID;
+      new_row.terms := (NEW.event -> 'terms');
+      new_row.terms_template := (NEW.event -> 'terms_template');
+    WHEN 'converted' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
+      new_row.is_converted := true;
+    WHEN 'rejected' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.is_rejected := true;
+      new_row.reason := (NEW.event ->> 'reason');
+  END CASE;
+
+  INSERT INTO core_credit_facility_proposal_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    amount,
+    audit_entry_ids,
+    credit_facility_id,
+    customer_id,
+    is_converted,
+    is_rejected,
+    reason,
+    terms,
+    terms_template
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.amount,
+    new_row.audit_entry_ids,
+    new_row.credit_facility_id,
+    new_row.customer_id,
+    new_row.is_converted,
+    new_row.is_rejected,
+    new_row.reason,
+    new_row.terms,
+    new_row.terms_template
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for CreditFacilityProposalEvent
+CREATE TRIGGER core_credit_facility_proposal_events_rollup_trigger
+  AFTER INSERT ON core_credit_facility_proposal_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_credit_facility_proposal_events_rollup_trigger();
@@ -18,6 +18,7 @@ CREATE TABLE core_approval_process_events_rollup (
   deny_reasons VARCHAR[],
 
   -- Toggle fields
+  is_cancelled BOOLEAN DEFAULT false,
   is_concluded BOOLEAN DEFAULT false
 ,
   PRIMARY KEY (id, version)
@@ -41,7 +42,7 @@ BEGIN
   END IF;
 
   -- Validate event type is known
-  IF event_type NOT IN ('initialized', 'approved', 'denied', 'concluded') THEN
+  IF event_type NOT IN ('initialized', 'approved', 'denied', 'concluded', 'cancelled') THEN
     RAISE EXCEPTION 'Unknown event type: %', event_type;
   END IF;
 
@@ -78,6 +79,7 @@ BEGIN
        ELSE ARRAY[]::VARCHAR[]
      END
 ;
+    new_row.is_cancelled := false;
     new_row.is_concluded := false;
     new_row.policy_id := (NEW.event ->> 'policy_id')::UUID;
     new_row.process_type := (NEW.event ->> 'process_type');
@@ -90,6 +92,7 @@ BEGIN
     new_row.audit_entry_ids := current_row.audit_entry_ids;
     new_row.denier_ids := current_row.denier_ids;
     new_row.deny_reasons := current_row.deny_reasons;
+    new_row.is_cancelled := current_row.is_cancelled;
     new_row.is_concluded := current_row.is_concluded;
     new_row.policy_id := current_row.policy_id;
     new_row.process_type := current_row.process_type;
@@ -116,6 +119,9 @@ BEGIN
       new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
       new_row.is_concluded := true;
+    WHEN 'cancelled' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.is_cancelled := true;
   END CASE;
 
   INSERT INTO core_approval_process_events_rollup (
@@ -128,6 +134,7 @@ BEGIN
     audit_entry_ids,
     denier_ids,
     deny_reasons,
+    is_cancelled,
     is_concluded,
     policy_id,
     process_type,
@@ -144,6 +151,7 @@ BEGIN
     new_row.audit_entry_ids,
     new_row.denier_ids,
     new_row.deny_reasons,
+    new_row.is_cancelled,
     new_row.is_concluded,
     new_row.policy_id,
     new_row.process_type,
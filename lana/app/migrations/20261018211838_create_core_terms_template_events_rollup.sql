@@ -5,6 +5,7 @@ CREATE TABLE core_terms_template_events_rollup (
   created_at TIMESTAMPTZ NOT NULL,
   modified_at TIMESTAMPTZ NOT NULL,
   -- Flattened fields from the event JSON
+  amount_bounds JSONB,
   name VARCHAR,
   values JSONB,
 
@@ -32,7 +33,7 @@ BEGIN
   END IF;
 
   -- Validate event type is known
-  IF event_type NOT IN ('initialized', 'term_values_updated') THEN
+  IF event_type NOT IN ('initialized', 'term_values_updated', 'retired', 'activated', 'amount_bounds_updated') THEN
     RAISE EXCEPTION 'Unknown event type: %', event_type;
   END IF;
 
@@ -44,6 +45,7 @@ BEGIN
 
   -- Initialize fields with default values if this is a new record
   IF current_row.id IS NULL THEN
+    new_row.amount_bounds := (NEW.event -> 'amount_bounds');
     new_row.audit_entry_ids := CASE
        WHEN NEW.event ? 'audit_entry_ids' THEN
          ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
@@ -54,6 +56,7 @@ BEGIN
     new_row.values := (NEW.event -> 'values');
   ELSE
     -- Default all fields to current values
+    new_row.amount_bounds := current_row.amount_bounds;
     new_row.audit_entry_ids := current_row.audit_entry_ids;
     new_row.name := current_row.name;
     new_row.values := current_row.values;
@@ -68,6 +71,13 @@ BEGIN
     WHEN 'term_values_updated' THEN
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
       new_row.values := (NEW.event -> 'values');
+    WHEN 'retired' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+    WHEN 'activated' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+    WHEN 'amount_bounds_updated' THEN
+      new_row.amount_bounds := (NEW.event -> 'amount_bounds');
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
   END CASE;
 
   INSERT INTO core_terms_template_events_rollup (
@@ -75,6 +85,7 @@ BEGIN
     version,
     created_at,
     modified_at,
+    amount_bounds,
     audit_entry_ids,
     name,
     values
@@ -84,6 +95,7 @@ BEGIN
     new_row.version,
     new_row.created_at,
     new_row.modified_at,
+    new_row.amount_bounds,
     new_row.audit_entry_ids,
     new_row.name,
     new_row.values
@@ -6,6 +6,7 @@ CREATE TABLE core_obligation_events_rollup (
   modified_at TIMESTAMPTZ NOT NULL,
   -- Flattened fields from the event JSON
   amount BIGINT,
+  approval_process_id UUID,
   credit_facility_id UUID,
   defaulted_account_id UUID,
   defaulted_amount BIGINT,
@@ -25,6 +26,7 @@ CREATE TABLE core_obligation_events_rollup (
   overdue_date TIMESTAMPTZ,
   payment_allocation_amount BIGINT,
   reference VARCHAR,
+  reinstated_amount BIGINT,
 
   -- Collection rollups
   audit_entry_ids BIGINT[],
@@ -59,7 +61,7 @@ BEGIN
   END IF;
 
   -- Validate event type is known
-  IF event_type NOT IN ('initialized', 'due_recorded', 'overdue_recorded', 'defaulted_recorded', 'payment_allocated', 'liquidation_process_started', 'liquidation_process_concluded', 'completed') THEN
+  IF event_type NOT IN ('initialized', 'due_recorded', 'overdue_recorded', 'defaulted_recorded', 'reinstatement_requested', 'reinstatement_denied', 'reinstated', 'payment_allocated', 'adjustment_recorded', 'liquidation_process_started', 'liquidation_process_concluded', 'completed') THEN
     RAISE EXCEPTION 'Unknown event type: %', event_type;
   END IF;
 
@@ -72,6 +74,7 @@ BEGIN
   -- Initialize fields with default values if this is a new record
   IF current_row.id IS NULL THEN
     new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
     new_row.audit_entry_ids := CASE
        WHEN NEW.event ? 'audit_entry_ids' THEN
          ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
@@ -119,9 +122,11 @@ BEGIN
      END
 ;
     new_row.reference := (NEW.event ->> 'reference');
+    new_row.reinstated_amount := (NEW.event ->> 'reinstated_amount')::BIGINT;
   ELSE
     -- Default all fields to current values
     new_row.amount := current_row.amount;
+    new_row.approval_process_id := current_row.approval_process_id;
     new_row.audit_entry_ids := current_row.audit_entry_ids;
     new_row.credit_facility_id := current_row.credit_facility_id;
     new_row.defaulted_account_id := current_row.defaulted_account_id;
@@ -149,6 +154,7 @@ BEGIN
     new_row.payment_allocation_ids := current_row.payment_allocation_ids;
     new_row.payment_ids := current_row.payment_ids;
     new_row.reference := current_row.reference;
+    new_row.reinstated_amount := current_row.reinstated_amount;
   END IF;
 
   -- Update only the fields that are modified by the specific event
@@ -185,11 +191,26 @@ BEGIN
       new_row.defaulted_amount := (NEW.event ->> 'defaulted_amount')::BIGINT;
       new_row.is_defaulted_recorded := true;
       new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+    WHEN 'reinstatement_requested' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+    WHEN 'reinstatement_denied' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+    WHEN 'reinstated' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.reinstated_amount := (NEW.event ->> 'reinstated_amount')::BIGINT;
     WHEN 'payment_allocated' THEN
       new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
       new_row.payment_allocation_amount := (NEW.event ->> 'payment_allocation_amount')::BIGINT;
       new_row.payment_allocation_ids := array_append(COALESCE(current_row.payment_allocation_ids, ARRAY[]::UUID[]), (NEW.event ->> 'payment_allocation_id')::UUID);
       new_row.payment_ids := array_append(COALESCE(current_row.payment_ids, ARRAY[]::UUID[]), (NEW.event ->> 'payment_id')::UUID);
+    WHEN 'adjustment_recorded' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
     WHEN 'liquidation_process_started' THEN
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
       new_row.effective := (NEW.event ->> 'effective');
@@ -210,6 +231,7 @@ BEGIN
     created_at,
     modified_at,
     amount,
+    approval_process_id,
     audit_entry_ids,
     credit_facility_id,
     defaulted_account_id,
@@ -236,7 +258,8 @@ BEGIN
     payment_allocation_amount,
     payment_allocation_ids,
     payment_ids,
-    reference
+    reference,
+    reinstated_amount
   )
   VALUES (
     new_row.id,
@@ -244,6 +267,7 @@ BEGIN
     new_row.created_at,
     new_row.modified_at,
     new_row.amount,
+    new_row.approval_process_id,
     new_row.audit_entry_ids,
     new_row.credit_facility_id,
     new_row.defaulted_account_id,
@@ -270,7 +294,8 @@ BEGIN
     new_row.payment_allocation_amount,
     new_row.payment_allocation_ids,
     new_row.payment_ids,
-    new_row.reference
+    new_row.reference,
+    new_row.reinstated_amount
   );
 
   RETURN NEW;
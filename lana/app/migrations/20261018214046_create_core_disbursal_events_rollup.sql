@@ -9,6 +9,8 @@ CREATE TABLE core_disbursal_events_rollup (
   amount BIGINT,
   approval_process_id UUID,
   approved BOOLEAN,
+  beneficiary JSONB,
+  destination JSONB,
   disbursal_credit_account_id UUID,
   due_date TIMESTAMPTZ,
   effective VARCHAR,
@@ -18,6 +20,7 @@ CREATE TABLE core_disbursal_events_rollup (
   obligation_id UUID,
   overdue_date TIMESTAMPTZ,
   public_id VARCHAR,
+  reason VARCHAR,
 
   -- Collection rollups
   audit_entry_ids BIGINT[],
@@ -25,7 +28,9 @@ CREATE TABLE core_disbursal_events_rollup (
   -- Toggle fields
   is_approval_process_concluded BOOLEAN DEFAULT false,
   is_cancelled BOOLEAN DEFAULT false,
-  is_settled BOOLEAN DEFAULT false
+  is_settled BOOLEAN DEFAULT false,
+  is_settlement_failed BOOLEAN DEFAULT false,
+  is_settlement_initiated BOOLEAN DEFAULT false
 ,
   PRIMARY KEY (id, version)
 );
@@ -48,7 +53,7 @@ BEGIN
   END IF;
 
   -- Validate event type is known
-  IF event_type NOT IN ('initialized', 'approval_process_concluded', 'settled', 'cancelled') THEN
+  IF event_type NOT IN ('initialized', 'approval_process_concluded', 'settlement_initiated', 'settlement_failed', 'settled', 'cancelled') THEN
     RAISE EXCEPTION 'Unknown event type: %', event_type;
   END IF;
 
@@ -70,6 +75,8 @@ BEGIN
        ELSE ARRAY[]::BIGINT[]
      END
 ;
+    new_row.beneficiary := (NEW.event -> 'beneficiary');
+    new_row.destination := (NEW.event -> 'destination');
     new_row.disbursal_credit_account_id := (NEW.event ->> 'disbursal_credit_account_id')::UUID;
     new_row.due_date := (NEW.event ->> 'due_date')::TIMESTAMPTZ;
     new_row.effective := (NEW.event ->> 'effective');
@@ -77,11 +84,14 @@ BEGIN
     new_row.is_approval_process_concluded := false;
     new_row.is_cancelled := false;
     new_row.is_settled := false;
+    new_row.is_settlement_failed := false;
+    new_row.is_settlement_initiated := false;
     new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
     new_row.liquidation_date := (NEW.event ->> 'liquidation_date')::TIMESTAMPTZ;
     new_row.obligation_id := (NEW.event ->> 'obligation_id')::UUID;
     new_row.overdue_date := (NEW.event ->> 'overdue_date')::TIMESTAMPTZ;
     new_row.public_id := (NEW.event ->> 'public_id');
+    new_row.reason := (NEW.event ->> 'reason');
   ELSE
     -- Default all fields to current values
     new_row.account_ids := current_row.account_ids;
@@ -89,6 +99,8 @@ BEGIN
     new_row.approval_process_id := current_row.approval_process_id;
     new_row.approved := current_row.approved;
     new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.beneficiary := current_row.beneficiary;
+    new_row.destination := current_row.destination;
     new_row.disbursal_credit_account_id := current_row.disbursal_credit_account_id;
     new_row.due_date := current_row.due_date;
     new_row.effective := current_row.effective;
@@ -96,11 +108,14 @@ BEGIN
     new_row.is_approval_process_concluded := current_row.is_approval_process_concluded;
     new_row.is_cancelled := current_row.is_cancelled;
     new_row.is_settled := current_row.is_settled;
+    new_row.is_settlement_failed := current_row.is_settlement_failed;
+    new_row.is_settlement_initiated := current_row.is_settlement_initiated;
     new_row.ledger_tx_id := current_row.ledger_tx_id;
     new_row.liquidation_date := current_row.liquidation_date;
     new_row.obligation_id := current_row.obligation_id;
     new_row.overdue_date := current_row.overdue_date;
     new_row.public_id := current_row.public_id;
+    new_row.reason := current_row.reason;
   END IF;
 
   -- Update only the fields that are modified by the specific event
@@ -110,6 +125,7 @@ BEGIN
       new_row.amount := (NEW.event ->> 'amount')::BIGINT;
       new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.destination := (NEW.event -> 'destination');
       new_row.disbursal_credit_account_id := (NEW.event ->> 'disbursal_credit_account_id')::UUID;
       new_row.due_date := (NEW.event ->> 'due_date')::TIMESTAMPTZ;
       new_row.facility_id := (NEW.event ->> 'facility_id')::UUID;
@@ -121,6 +137,14 @@ BEGIN
       new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
       new_row.is_approval_process_concluded := true;
+    WHEN 'settlement_initiated' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.beneficiary := (NEW.event -> 'beneficiary');
+      new_row.is_settlement_initiated := true;
+    WHEN 'settlement_failed' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.is_settlement_failed := true;
+      new_row.reason := (NEW.event ->> 'reason');
     WHEN 'settled' THEN
       new_row.amount := (NEW.event ->> 'amount')::BIGINT;
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
@@ -144,6 +168,8 @@ BEGIN
     approval_process_id,
     approved,
     audit_entry_ids,
+    beneficiary,
+    destination,
     disbursal_credit_account_id,
     due_date,
     effective,
@@ -151,11 +177,14 @@ BEGIN
     is_approval_process_concluded,
     is_cancelled,
     is_settled,
+    is_settlement_failed,
+    is_settlement_initiated,
     ledger_tx_id,
     liquidation_date,
     obligation_id,
     overdue_date,
-    public_id
+    public_id,
+    reason
   )
   VALUES (
     new_row.id,
@@ -167,6 +196,8 @@ BEGIN
     new_row.approval_process_id,
     new_row.approved,
     new_row.audit_entry_ids,
+    new_row.beneficiary,
+    new_row.destination,
     new_row.disbursal_credit_account_id,
     new_row.due_date,
     new_row.effective,
@@ -174,11 +205,14 @@ BEGIN
     new_row.is_approval_process_concluded,
     new_row.is_cancelled,
     new_row.is_settled,
+    new_row.is_settlement_failed,
+    new_row.is_settlement_initiated,
     new_row.ledger_tx_id,
     new_row.liquidation_date,
     new_row.obligation_id,
     new_row.overdue_date,
-    new_row.public_id
+    new_row.public_id,
+    new_row.reason
   );
 
   RETURN NEW;
@@ -0,0 +1,152 @@
+-- Auto-generated rollup table for ParticipationEvent
+CREATE TABLE core_participation_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  credit_facility_id UUID,
+  effective VARCHAR,
+  facility_amount BIGINT,
+  partner_amount BIGINT,
+  partner_name VARCHAR,
+  payable_account_id UUID,
+  share VARCHAR,
+  share_type VARCHAR,
+  source_tx_id UUID,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[],
+  ledger_tx_ids UUID[]
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for ParticipationEvent
+CREATE OR REPLACE FUNCTION core_participation_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_participation_events_rollup%ROWTYPE;
+  new_row core_participation_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_participation_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'share_allocated') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
+    new_row.effective := (NEW.event ->> 'effective');
+    new_row.facility_amount := (NEW.event ->> 'facility_amount')::BIGINT;
+    new_row.ledger_tx_ids := CASE
+       WHEN NEW.event ? 'ledger_tx_ids' THEN
+         ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'ledger_tx_ids'))
+       ELSE ARRAY[]::UUID[]
+     END
+;
+    new_row.partner_amount := (NEW.event ->> 'partner_amount')::BIGINT;
+    new_row.partner_name := (NEW.event ->> 'partner_name');
+    new_row.payable_account_id := (NEW.event ->> 'payable_account_id')::UUID;
+    new_row.share := (NEW.event ->> 'share');
+    new_row.share_type := (NEW.event ->> 'share_type');
+    new_row.source_tx_id := (NEW.event ->> 'source_tx_id')::UUID;
+  ELSE
+    -- Default all fields to current values
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.credit_facility_id := current_row.credit_facility_id;
+    new_row.effective := current_row.effective;
+    new_row.facility_amount := current_row.facility_amount;
+    new_row.ledger_tx_ids := current_row.ledger_tx_ids;
+    new_row.partner_amount := current_row.partner_amount;
+    new_row.partner_name := current_row.partner_name;
+    new_row.payable_account_id := current_row.payable_account_id;
+    new_row.share := current_row.share;
+    new_row.share_type := current_row.share_type;
+    new_row.source_tx_id := current_row.source_tx_id;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
+      new_row.partner_name := (NEW.event ->> 'partner_name');
+      new_row.payable_account_id := (NEW.event ->> 'payable_account_id')::UUID;
+      new_row.share := (NEW.event ->> 'share');
+    WHEN 'share_allocated' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.facility_amount := (NEW.event ->> 'facility_amount')::BIGINT;
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+      new_row.partner_amount := (NEW.event ->> 'partner_amount')::BIGINT;
+      new_row.share_type := (NEW.event ->> 'share_type');
+      new_row.source_tx_id := (NEW.event ->> 'source_tx_id')::UUID;
+  END CASE;
+
+  INSERT INTO core_participation_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    audit_entry_ids,
+    credit_facility_id,
+    effective,
+    facility_amount,
+    ledger_tx_ids,
+    partner_amount,
+    partner_name,
+    payable_account_id,
+    share,
+    share_type,
+    source_tx_id
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.audit_entry_ids,
+    new_row.credit_facility_id,
+    new_row.effective,
+    new_row.facility_amount,
+    new_row.ledger_tx_ids,
+    new_row.partner_amount,
+    new_row.partner_name,
+    new_row.payable_account_id,
+    new_row.share,
+    new_row.share_type,
+    new_row.source_tx_id
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for ParticipationEvent
+CREATE TRIGGER core_participation_events_rollup_trigger
+  AFTER INSERT ON core_participation_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_participation_events_rollup_trigger();
@@ -19,9 +19,14 @@ CREATE TABLE core_credit_facility_events_rollup (
   interest_accrual_cycle_idx INTEGER,
   interest_period JSONB,
   outstanding JSONB,
+  past_due_since VARCHAR,
   price JSONB,
+  principal_outstanding BIGINT,
   public_id VARCHAR,
+  reason VARCHAR,
   terms JSONB,
+  terms_template JSONB,
+  underwriting JSONB,
 
   -- Collection rollups
   audit_entry_ids BIGINT[],
@@ -32,6 +37,7 @@ CREATE TABLE core_credit_facility_events_rollup (
   -- Toggle fields
   is_activated BOOLEAN DEFAULT false,
   is_approval_process_concluded BOOLEAN DEFAULT false,
+  is_cancelled BOOLEAN DEFAULT false,
   is_completed BOOLEAN DEFAULT false
 ,
   PRIMARY KEY (id, version)
@@ -55,7 +61,7 @@ BEGIN
   END IF;
 
   -- Validate event type is known
-  IF event_type NOT IN ('initialized', 'approval_process_concluded', 'activated', 'interest_accrual_cycle_started', 'interest_accrual_cycle_concluded', 'collateralization_state_changed', 'collateralization_ratio_changed', 'completed') THEN
+  IF event_type NOT IN ('initialized', 'approval_process_concluded', 'activated', 'interest_accrual_cycle_started', 'interest_accrual_cycle_concluded', 'collateralization_state_changed', 'collateralization_ratio_changed', 'past_due_since_updated', 'completed', 'cancelled') THEN
     RAISE EXCEPTION 'Unknown event type: %', event_type;
   END IF;
 
@@ -94,6 +100,7 @@ BEGIN
     new_row.interest_period := (NEW.event -> 'interest_period');
     new_row.is_activated := false;
     new_row.is_approval_process_concluded := false;
+    new_row.is_cancelled := false;
     new_row.is_completed := false;
     new_row.ledger_tx_ids := CASE
        WHEN NEW.event ? 'ledger_tx_ids' THEN
@@ -108,9 +115,14 @@ BEGIN
      END
 ;
     new_row.outstanding := (NEW.event -> 'outstanding');
+    new_row.past_due_since := (NEW.event ->> 'past_due_since');
     new_row.price := (NEW.event -> 'price');
+    new_row.principal_outstanding := (NEW.event ->> 'principal_outstanding')::BIGINT;
     new_row.public_id := (NEW.event ->> 'public_id');
+    new_row.reason := (NEW.event ->> 'reason');
     new_row.terms := (NEW.event -> 'terms');
+    new_row.terms_template := (NEW.event -> 'terms_template');
+    new_row.underwriting := (NEW.event -> 'underwriting');
   ELSE
     -- Default all fields to current values
     new_row.account_ids := current_row.account_ids;
@@ -130,13 +142,19 @@ BEGIN
     new_row.interest_period := current_row.interest_period;
     new_row.is_activated := current_row.is_activated;
     new_row.is_approval_process_concluded := current_row.is_approval_process_concluded;
+    new_row.is_cancelled := current_row.is_cancelled;
     new_row.is_completed := current_row.is_completed;
     new_row.ledger_tx_ids := current_row.ledger_tx_ids;
     new_row.obligation_ids := current_row.obligation_ids;
     new_row.outstanding := current_row.outstanding;
+    new_row.past_due_since := current_row.past_due_since;
     new_row.price := current_row.price;
+    new_row.principal_outstanding := current_row.principal_outstanding;
     new_row.public_id := current_row.public_id;
+    new_row.reason := current_row.reason;
     new_row.terms := current_row.terms;
+    new_row.terms_template := current_row.terms_template;
+    new_row.underwriting := current_row.underwriting;
   END IF;
 
   -- Update only the fields that are modified by the specific event
@@ -152,6 +170,8 @@ BEGIN
       new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
       new_row.public_id := (NEW.event ->> 'public_id');
       new_row.terms := (NEW.event -> 'terms');
+      new_row.terms_template := (NEW.event -> 'terms_template');
+      new_row.underwriting := (NEW.event -> 'underwriting');
     WHEN 'approval_process_concluded' THEN
       new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
       new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
@@ -181,9 +201,17 @@ BEGIN
     WHEN 'collateralization_ratio_changed' THEN
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
       new_row.collateralization_ratio := (NEW.event ->> 'collateralization_ratio');
+    WHEN 'past_due_since_updated' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.past_due_since := (NEW.event ->> 'past_due_since');
+      new_row.principal_outstanding := (NEW.event ->> 'principal_outstanding')::BIGINT;
     WHEN 'completed' THEN
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
       new_row.is_completed := true;
+    WHEN 'cancelled' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.is_cancelled := true;
+      new_row.reason := (NEW.event ->> 'reason');
   END CASE;
 
   INSERT INTO core_credit_facility_events_rollup (
@@ -208,13 +236,19 @@ BEGIN
     interest_period,
     is_activated,
     is_approval_process_concluded,
+    is_cancelled,
     is_completed,
     ledger_tx_ids,
     obligation_ids,
     outstanding,
+    past_due_since,
     price,
+    principal_outstanding,
     public_id,
-    terms
+    reason,
+    terms,
+    terms_template,
+    underwriting
   )
   VALUES (
     new_row.id,
@@ -238,13 +272,19 @@ BEGIN
     new_row.interest_period,
     new_row.is_activated,
     new_row.is_approval_process_concluded,
+    new_row.is_cancelled,
     new_row.is_completed,
     new_row.ledger_tx_ids,
     new_row.obligation_ids,
     new_row.outstanding,
+    new_row.past_due_since,
     new_row.price,
+    new_row.principal_outstanding,
     new_row.public_id,
-    new_row.terms
+    new_row.reason,
+    new_row.terms,
+    new_row.terms_template,
+    new_row.underwriting
   );
 
   RETURN NEW;
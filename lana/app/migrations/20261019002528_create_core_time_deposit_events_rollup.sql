@@ -0,0 +1,190 @@
+-- Auto-generated rollup table for TimeDepositEvent
+CREATE TABLE core_time_deposit_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  amount BIGINT,
+  deposit_account_id UUID,
+  interest BIGINT,
+  interest_tx_id UUID,
+  ledger_tx_id UUID,
+  maturity_at TIMESTAMPTZ,
+  maturity_instruction JSONB,
+  opened_at TIMESTAMPTZ,
+  penalty BIGINT,
+  reference VARCHAR,
+  rolled_over_from UUID,
+  rolled_over_into UUID,
+  settlement_tx_id UUID,
+  terms JSONB,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[],
+
+  -- Toggle fields
+  is_broken BOOLEAN DEFAULT false,
+  is_matured BOOLEAN DEFAULT false
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for TimeDepositEvent
+CREATE OR REPLACE FUNCTION core_time_deposit_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_time_deposit_events_rollup%ROWTYPE;
+  new_row core_time_deposit_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_time_deposit_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'matured', 'broken') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+    new_row.interest := (NEW.event ->> 'interest')::BIGINT;
+    new_row.interest_tx_id := (NEW.event ->> 'interest_tx_id')::UUID;
+    new_row.is_broken := false;
+    new_row.is_matured := false;
+    new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+    new_row.maturity_at := (NEW.event ->> 'maturity_at')::TIMESTAMPTZ;
+    new_row.maturity_instruction := (NEW.event -> 'maturity_instruction');
+    new_row.opened_at := (NEW.event ->> 'opened_at')::TIMESTAMPTZ;
+    new_row.penalty := (NEW.event ->> 'penalty')::BIGINT;
+    new_row.reference := (NEW.event ->> 'reference');
+    new_row.rolled_over_from := (NEW.event ->> 'rolled_over_from')::UUID;
+    new_row.rolled_over_into := (NEW.event ->> 'rolled_over_into')::UUID;
+    new_row.settlement_tx_id := (NEW.event ->> 'settlement_tx_id')::UUID;
+    new_row.terms := (NEW.event -> 'terms');
+  ELSE
+    -- Default all fields to current values
+    new_row.amount := current_row.amount;
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.deposit_account_id := current_row.deposit_account_id;
+    new_row.interest := current_row.interest;
+    new_row.interest_tx_id := current_row.interest_tx_id;
+    new_row.is_broken := current_row.is_broken;
+    new_row.is_matured := current_row.is_matured;
+    new_row.ledger_tx_id := current_row.ledger_tx_id;
+    new_row.maturity_at := current_row.maturity_at;
+    new_row.maturity_instruction := current_row.maturity_instruction;
+    new_row.opened_at := current_row.opened_at;
+    new_row.penalty := current_row.penalty;
+    new_row.reference := current_row.reference;
+    new_row.rolled_over_from := current_row.rolled_over_from;
+    new_row.rolled_over_into := current_row.rolled_over_into;
+    new_row.settlement_tx_id := current_row.settlement_tx_id;
+    new_row.terms := current_row.terms;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+      new_row.maturity_at := (NEW.event ->> 'maturity_at')::TIMESTAMPTZ;
+      new_row.maturity_instruction := (NEW.event -> 'maturity_instruction');
+      new_row.opened_at := (NEW.event ->> 'opened_at')::TIMESTAMPTZ;
+      new_row.reference := (NEW.event ->> 'reference');
+      new_row.rolled_over_from := (NEW.event ->> 'rolled_over_from')::UUID;
+      new_row.terms := (NEW.event -> 'terms');
+    WHEN 'matured' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.interest := (NEW.event ->> 'interest')::BIGINT;
+      new_row.interest_tx_id := (NEW.event ->> 'interest_tx_id')::UUID;
+      new_row.is_matured := true;
+      new_row.rolled_over_into := (NEW.event ->> 'rolled_over_into')::UUID;
+      new_row.settlement_tx_id := (NEW.event ->> 'settlement_tx_id')::UUID;
+    WHEN 'broken' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.interest := (NEW.event ->> 'interest')::BIGINT;
+      new_row.interest_tx_id := (NEW.event ->> 'interest_tx_id')::UUID;
+      new_row.is_broken := true;
+      new_row.penalty := (NEW.event ->> 'penalty')::BIGINT;
+      new_row.settlement_tx_id := (NEW.event ->> 'settlement_tx_id')::UUID;
+  END CASE;
+
+  INSERT INTO core_time_deposit_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    amount,
+    audit_entry_ids,
+    deposit_account_id,
+    interest,
+    interest_tx_id,
+    is_broken,
+    is_matured,
+    ledger_tx_id,
+    maturity_at,
+    maturity_instruction,
+    opened_at,
+    penalty,
+    reference,
+    rolled_over_from,
+    rolled_over_into,
+    settlement_tx_id,
+    terms
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.amount,
+    new_row.audit_entry_ids,
+    new_row.deposit_account_id,
+    new_row.interest,
+    new_row.interest_tx_id,
+    new_row.is_broken,
+    new_row.is_matured,
+    new_row.ledger_tx_id,
+    new_row.maturity_at,
+    new_row.maturity_instruction,
+    new_row.opened_at,
+    new_row.penalty,
+    new_row.reference,
+    new_row.rolled_over_from,
+    new_row.rolled_over_into,
+    new_row.settlement_tx_id,
+    new_row.terms
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for TimeDepositEvent
+CREATE TRIGGER core_time_deposit_events_rollup_trigger
+  AFTER INSERT ON core_time_deposit_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_time_deposit_events_rollup_trigger();
@@ -1,5 +1,5 @@
--- Auto-generated rollup table for WithdrawalEvent
-CREATE TABLE core_withdrawal_events_rollup (
+-- Auto-generated rollup table for TransferEvent
+CREATE TABLE core_transfer_events_rollup (
   id UUID NOT NULL,
   version INT NOT NULL,
   created_at TIMESTAMPTZ NOT NULL,
@@ -8,9 +8,10 @@ CREATE TABLE core_withdrawal_events_rollup (
   amount BIGINT,
   approval_process_id UUID,
   approved BOOLEAN,
-  deposit_account_id UUID,
+  from_account_id UUID,
   reference VARCHAR,
   status VARCHAR,
+  to_account_id UUID,
 
   -- Collection rollups
   audit_entry_ids BIGINT[],
@@ -24,25 +25,25 @@ CREATE TABLE core_withdrawal_events_rollup (
   PRIMARY KEY (id, version)
 );
 
--- Auto-generated trigger function for WithdrawalEvent
-CREATE OR REPLACE FUNCTION core_withdrawal_events_rollup_trigger()
+-- Auto-generated trigger function for TransferEvent
+CREATE OR REPLACE FUNCTION core_transfer_events_rollup_trigger()
 RETURNS TRIGGER AS $$
 DECLARE
   event_type TEXT;
-  current_row core_withdrawal_events_rollup%ROWTYPE;
-  new_row core_withdrawal_events_rollup%ROWTYPE;
+  current_row core_transfer_events_rollup%ROWTYPE;
+  new_row core_transfer_events_rollup%ROWTYPE;
 BEGIN
   event_type := NEW.event_type;
 
   -- Load the previous version if this isn't the first event
   IF NEW.sequence > 1 THEN
     SELECT * INTO current_row
-    FROM core_withdrawal_events_rollup
+    FROM core_transfer_events_rollup
     WHERE id = NEW.id AND version = NEW.sequence - 1;
   END IF;
 
   -- Validate event type is known
-  IF event_type NOT IN ('initialized', 'approval_process_concluded', 'confirmed', 'cancelled', 'reverted') THEN
+  IF event_type NOT IN ('initialized', 'approval_process_concluded', 'confirmed', 'cancelled') THEN
     RAISE EXCEPTION 'Unknown event type: %', event_type;
   END IF;
 
@@ -63,7 +64,7 @@ BEGIN
        ELSE ARRAY[]::BIGINT[]
      END
 ;
-    new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+    new_row.from_account_id := (NEW.event ->> 'from_account_id')::UUID;
     new_row.is_approval_process_concluded := false;
     new_row.is_cancelled := false;
     new_row.is_confirmed := false;
@@ -75,19 +76,21 @@ BEGIN
 ;
     new_row.reference := (NEW.event ->> 'reference');
     new_row.status := (NEW.event ->> 'status');
+    new_row.to_account_id := (NEW.event ->> 'to_account_id')::UUID;
   ELSE
     -- Default all fields to current values
     new_row.amount := current_row.amount;
     new_row.approval_process_id := current_row.approval_process_id;
     new_row.approved := current_row.approved;
     new_row.audit_entry_ids := current_row.audit_entry_ids;
-    new_row.deposit_account_id := current_row.deposit_account_id;
+    new_row.from_account_id := current_row.from_account_id;
     new_row.is_approval_process_concluded := current_row.is_approval_process_concluded;
     new_row.is_cancelled := current_row.is_cancelled;
     new_row.is_confirmed := current_row.is_confirmed;
     new_row.ledger_tx_ids := current_row.ledger_tx_ids;
     new_row.reference := current_row.reference;
     new_row.status := current_row.status;
+    new_row.to_account_id := current_row.to_account_id;
   END IF;
 
   -- Update only the fields that are modified by the specific event
@@ -96,10 +99,11 @@ BEGIN
       new_row.amount := (NEW.event ->> 'amount')::BIGINT;
       new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
-      new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+      new_row.from_account_id := (NEW.event ->> 'from_account_id')::UUID;
       new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
       new_row.reference := (NEW.event ->> 'reference');
       new_row.status := (NEW.event ->> 'status');
+      new_row.to_account_id := (NEW.event ->> 'to_account_id')::UUID;
     WHEN 'approval_process_concluded' THEN
       new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
       new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
@@ -116,13 +120,9 @@ BEGIN
       new_row.is_cancelled := true;
       new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
       new_row.status := (NEW.event ->> 'status');
-    WHEN 'reverted' THEN
-      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
-      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
-      new_row.status := (NEW.event ->> 'status');
   END CASE;
 
-  INSERT INTO core_withdrawal_events_rollup (
+  INSERT INTO core_transfer_events_rollup (
     id,
     version,
     created_at,
@@ -131,13 +131,14 @@ BEGIN
     approval_process_id,
     approved,
     audit_entry_ids,
-    deposit_account_id,
+    from_account_id,
     is_approval_process_concluded,
     is_cancelled,
     is_confirmed,
     ledger_tx_ids,
     reference,
-    status
+    status,
+    to_account_id
   )
   VALUES (
     new_row.id,
@@ -148,21 +149,22 @@ BEGIN
     new_row.approval_process_id,
     new_row.approved,
     new_row.audit_entry_ids,
-    new_row.deposit_account_id,
+    new_row.from_account_id,
     new_row.is_approval_process_concluded,
     new_row.is_cancelled,
     new_row.is_confirmed,
     new_row.ledger_tx_ids,
     new_row.reference,
-    new_row.status
+    new_row.status,
+    new_row.to_account_id
   );
 
   RETURN NEW;
 END;
 $$ LANGUAGE plpgsql;
 
--- Auto-generated trigger for WithdrawalEvent
-CREATE TRIGGER core_withdrawal_events_rollup_trigger
-  AFTER INSERT ON core_withdrawal_events
+-- Auto-generated trigger for TransferEvent
+CREATE TRIGGER core_transfer_events_rollup_trigger
+  AFTER INSERT ON core_transfer_events
   FOR EACH ROW
-  EXECUTE FUNCTION core_withdrawal_events_rollup_trigger();
+  EXECUTE FUNCTION core_transfer_events_rollup_trigger();
@@ -1,43 +1,43 @@
--- Auto-generated rollup table for DepositAccountEvent
-CREATE TABLE core_deposit_account_events_rollup (
+-- Auto-generated rollup table for HoldEvent
+CREATE TABLE core_hold_events_rollup (
   id UUID NOT NULL,
   version INT NOT NULL,
   created_at TIMESTAMPTZ NOT NULL,
   modified_at TIMESTAMPTZ NOT NULL,
   -- Flattened fields from the event JSON
-  account_holder_id UUID,
-  description VARCHAR,
-  ledger_account_id UUID,
-  name VARCHAR,
-  public_id VARCHAR,
+  amount BIGINT,
+  deposit_account_id UUID,
+  expires_at TIMESTAMPTZ,
+  reason VARCHAR,
   reference VARCHAR,
   status VARCHAR,
 
   -- Collection rollups
-  audit_entry_ids BIGINT[]
+  audit_entry_ids BIGINT[],
+  ledger_tx_ids UUID[]
 ,
   PRIMARY KEY (id, version)
 );
 
--- Auto-generated trigger function for DepositAccountEvent
-CREATE OR REPLACE FUNCTION core_deposit_account_events_rollup_trigger()
+-- Auto-generated trigger function for HoldEvent
+CREATE OR REPLACE FUNCTION core_hold_events_rollup_trigger()
 RETURNS TRIGGER AS $$
 DECLARE
   event_type TEXT;
-  current_row core_deposit_account_events_rollup%ROWTYPE;
-  new_row core_deposit_account_events_rollup%ROWTYPE;
+  current_row core_hold_events_rollup%ROWTYPE;
+  new_row core_hold_events_rollup%ROWTYPE;
 BEGIN
   event_type := NEW.event_type;
 
   -- Load the previous version if this isn't the first event
   IF NEW.sequence > 1 THEN
     SELECT * INTO current_row
-    FROM core_deposit_account_events_rollup
+    FROM core_hold_events_rollup
     WHERE id = NEW.id AND version = NEW.sequence - 1;
   END IF;
 
   -- Validate event type is known
-  IF event_type NOT IN ('initialized', 'account_status_updated') THEN
+  IF event_type NOT IN ('initialized', 'released') THEN
     RAISE EXCEPTION 'Unknown event type: %', event_type;
   END IF;
 
@@ -49,27 +49,32 @@ BEGIN
 
   -- Initialize fields with default values if this is a new record
   IF current_row.id IS NULL THEN
-    new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
     new_row.audit_entry_ids := CASE
        WHEN NEW.event ? 'audit_entry_ids' THEN
          ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
        ELSE ARRAY[]::BIGINT[]
      END
 ;
-    new_row.description := (NEW.event ->> 'description');
-    new_row.ledger_account_id := (NEW.event ->> 'ledger_account_id')::UUID;
-    new_row.name := (NEW.event ->> 'name');
-    new_row.public_id := (NEW.event ->> 'public_id');
+    new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+    new_row.expires_at := (NEW.event ->> 'expires_at')::TIMESTAMPTZ;
+    new_row.ledger_tx_ids := CASE
+       WHEN NEW.event ? 'ledger_tx_ids' THEN
+         ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'ledger_tx_ids'))
+       ELSE ARRAY[]::UUID[]
+     END
+;
+    new_row.reason := (NEW.event ->> 'reason');
     new_row.reference := (NEW.event ->> 'reference');
     new_row.status := (NEW.event ->> 'status');
   ELSE
     -- Default all fields to current values
-    new_row.account_holder_id := current_row.account_holder_id;
+    new_row.amount := current_row.amount;
     new_row.audit_entry_ids := current_row.audit_entry_ids;
-    new_row.description := current_row.description;
-    new_row.ledger_account_id := current_row.ledger_account_id;
-    new_row.name := current_row.name;
-    new_row.public_id := current_row.public_id;
+    new_row.deposit_account_id := current_row.deposit_account_id;
+    new_row.expires_at := current_row.expires_at;
+    new_row.ledger_tx_ids := current_row.ledger_tx_ids;
+    new_row.reason := current_row.reason;
     new_row.reference := current_row.reference;
     new_row.status := current_row.status;
   END IF;
@@ -77,30 +82,30 @@ BEGIN
   -- Update only the fields that are modified by the specific event
   CASE event_type
     WHEN 'initialized' THEN
-      new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
-      new_row.description := (NEW.event ->> 'description');
-      new_row.ledger_account_id := (NEW.event ->> 'ledger_account_id')::UUID;
-      new_row.name := (NEW.event ->> 'name');
-      new_row.public_id := (NEW.event ->> 'public_id');
+      new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+      new_row.expires_at := (NEW.event ->> 'expires_at')::TIMESTAMPTZ;
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+      new_row.reason := (NEW.event ->> 'reason');
       new_row.reference := (NEW.event ->> 'reference');
-      new_row.status := (NEW.event ->> 'status');
-    WHEN 'account_status_updated' THEN
+    WHEN 'released' THEN
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
       new_row.status := (NEW.event ->> 'status');
   END CASE;
 
-  INSERT INTO core_deposit_account_events_rollup (
+  INSERT INTO core_hold_events_rollup (
     id,
     version,
     created_at,
     modified_at,
-    account_holder_id,
+    amount,
     audit_entry_ids,
-    description,
-    ledger_account_id,
-    name,
-    public_id,
+    deposit_account_id,
+    expires_at,
+    ledger_tx_ids,
+    reason,
     reference,
     status
   )
@@ -109,12 +114,12 @@ BEGIN
     new_row.version,
     new_row.created_at,
     new_row.modified_at,
-    new_row.account_holder_id,
+    new_row.amount,
     new_row.audit_entry_ids,
-    new_row.description,
-    new_row.ledger_account_id,
-    new_row.name,
-    new_row.public_id,
+    new_row.deposit_account_id,
+    new_row.expires_at,
+    new_row.ledger_tx_ids,
+    new_row.reason,
     new_row.reference,
     new_row.status
   );
@@ -123,8 +128,8 @@ BEGIN
 END;
 $$ LANGUAGE plpgsql;
 
--- Auto-generated trigger for DepositAccountEvent
-CREATE TRIGGER core_deposit_account_events_rollup_trigger
-  AFTER INSERT ON core_deposit_account_events
+-- Auto-generated trigger for HoldEvent
+CREATE TRIGGER core_hold_events_rollup_trigger
+  AFTER INSERT ON core_hold_events
   FOR EACH ROW
-  EXECUTE FUNCTION core_deposit_account_events_rollup_trigger();
+  EXECUTE FUNCTION core_hold_events_rollup_trigger();
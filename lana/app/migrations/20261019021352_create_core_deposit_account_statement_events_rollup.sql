@@ -0,0 +1,121 @@
+-- Auto-generated rollup table for DepositAccountStatementEvent
+CREATE TABLE core_deposit_account_statement_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  closing_balance BIGINT,
+  deposit_account_id UUID,
+  document_id UUID,
+  opening_balance BIGINT,
+  period JSONB,
+  reference VARCHAR,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[]
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for DepositAccountStatementEvent
+CREATE OR REPLACE FUNCTION core_deposit_account_statement_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_deposit_account_statement_events_rollup%ROWTYPE;
+  new_row core_deposit_account_statement_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_deposit_account_statement_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.closing_balance := (NEW.event ->> 'closing_balance')::BIGINT;
+    new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+    new_row.document_id := (NEW.event ->> 'document_id')::UUID;
+    new_row.opening_balance := (NEW.event ->> 'opening_balance')::BIGINT;
+    new_row.period := (NEW.event -> 'period');
+    new_row.reference := (NEW.event ->> 'reference');
+  ELSE
+    -- Default all fields to current values
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.closing_balance := current_row.closing_balance;
+    new_row.deposit_account_id := current_row.deposit_account_id;
+    new_row.document_id := current_row.document_id;
+    new_row.opening_balance := current_row.opening_balance;
+    new_row.period := current_row.period;
+    new_row.reference := current_row.reference;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.closing_balance := (NEW.event ->> 'closing_balance')::BIGINT;
+      new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+      new_row.document_id := (NEW.event ->> 'document_id')::UUID;
+      new_row.opening_balance := (NEW.event ->> 'opening_balance')::BIGINT;
+      new_row.period := (NEW.event -> 'period');
+      new_row.reference := (NEW.event ->> 'reference');
+  END CASE;
+
+  INSERT INTO core_deposit_account_statement_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    audit_entry_ids,
+    closing_balance,
+    deposit_account_id,
+    document_id,
+    opening_balance,
+    period,
+    reference
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.audit_entry_ids,
+    new_row.closing_balance,
+    new_row.deposit_account_id,
+    new_row.document_id,
+    new_row.opening_balance,
+    new_row.period,
+    new_row.reference
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for DepositAccountStatementEvent
+CREATE TRIGGER core_deposit_account_statement_events_rollup_trigger
+  AFTER INSERT ON core_deposit_account_statement_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_deposit_account_statement_events_rollup_trigger();
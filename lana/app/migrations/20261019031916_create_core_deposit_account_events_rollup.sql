@@ -0,0 +1,230 @@
+-- Auto-generated rollup table for DepositAccountEvent
+CREATE TABLE core_deposit_account_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  account_holder_id UUID,
+  accrued_on VARCHAR,
+  amount VARCHAR,
+  balance_sweep JSONB,
+  capitalized_on VARCHAR,
+  deposit_product_id UUID,
+  description VARCHAR,
+  interest_accrual_starts_on VARCHAR,
+  ledger_account_id UUID,
+  ledger_tx_id UUID,
+  ledger_velocity_control_id UUID,
+  name VARCHAR,
+  public_id VARCHAR,
+  reason VARCHAR,
+  recorded_at TIMESTAMPTZ,
+  reference VARCHAR,
+  scope VARCHAR,
+  settled_balance BIGINT,
+  status VARCHAR,
+  withdrawal_limits JSONB,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[]
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for DepositAccountEvent
+CREATE OR REPLACE FUNCTION core_deposit_account_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_deposit_account_events_rollup%ROWTYPE;
+  new_row core_deposit_account_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_deposit_account_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'account_status_updated', 'product_assigned', 'interest_accrued', 'interest_capitalized', 'frozen', 'unfrozen', 'withdrawal_limits_overridden', 'withdrawal_limits_applied', 'customer_activity_recorded', 'dormancy_status_updated', 'closed') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+    new_row.accrued_on := (NEW.event ->> 'accrued_on');
+    new_row.amount := (NEW.event ->> 'amount');
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.balance_sweep := (NEW.event -> 'balance_sweep');
+    new_row.capitalized_on := (NEW.event ->> 'capitalized_on');
+    new_row.deposit_product_id := (NEW.event ->> 'deposit_product_id')::UUID;
+    new_row.description := (NEW.event ->> 'description');
+    new_row.interest_accrual_starts_on := (NEW.event ->> 'interest_accrual_starts_on');
+    new_row.ledger_account_id := (NEW.event ->> 'ledger_account_id')::UUID;
+    new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+    new_row.ledger_velocity_control_id := (NEW.event ->> 'ledger_velocity_control_id')::UUID;
+    new_row.name := (NEW.event ->> 'name');
+    new_row.public_id := (NEW.event ->> 'public_id');
+    new_row.reason := (NEW.event ->> 'reason');
+    new_row.recorded_at := (NEW.event ->> 'recorded_at')::TIMESTAMPTZ;
+    new_row.reference := (NEW.event ->> 'reference');
+    new_row.scope := (NEW.event ->> 'scope');
+    new_row.settled_balance := (NEW.event ->> 'settled_balance')::BIGINT;
+    new_row.status := (NEW.event ->> 'status');
+    new_row.withdrawal_limits := (NEW.event -> 'withdrawal_limits');
+  ELSE
+    -- Default all fields to current values
+    new_row.account_holder_id := current_row.account_holder_id;
+    new_row.accrued_on := current_row.accrued_on;
+    new_row.amount := current_row.amount;
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.balance_sweep := current_row.balance_sweep;
+    new_row.capitalized_on := current_row.capitalized_on;
+    new_row.deposit_product_id := current_row.deposit_product_id;
+    new_row.description := current_row.description;
+    new_row.interest_accrual_starts_on := current_row.interest_accrual_starts_on;
+    new_row.ledger_account_id := current_row.ledger_account_id;
+    new_row.ledger_tx_id := current_row.ledger_tx_id;
+    new_row.ledger_velocity_control_id := current_row.ledger_velocity_control_id;
+    new_row.name := current_row.name;
+    new_row.public_id := current_row.public_id;
+    new_row.reason := current_row.reason;
+    new_row.recorded_at := current_row.recorded_at;
+    new_row.reference := current_row.reference;
+    new_row.scope := current_row.scope;
+    new_row.settled_balance := current_row.settled_balance;
+    new_row.status := current_row.status;
+    new_row.withdrawal_limits := current_row.withdrawal_limits;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.description := (NEW.event ->> 'description');
+      new_row.ledger_account_id := (NEW.event ->> 'ledger_account_id')::UUID;
+      new_row.name := (NEW.event ->> 'name');
+      new_row.public_id := (NEW.event ->> 'public_id');
+      new_row.reference := (NEW.event ->> 'reference');
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'account_status_updated' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'product_assigned' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.deposit_product_id := (NEW.event ->> 'deposit_product_id')::UUID;
+      new_row.interest_accrual_starts_on := (NEW.event ->> 'interest_accrual_starts_on');
+    WHEN 'interest_accrued' THEN
+      new_row.accrued_on := (NEW.event ->> 'accrued_on');
+      new_row.amount := (NEW.event ->> 'amount');
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.settled_balance := (NEW.event ->> 'settled_balance')::BIGINT;
+    WHEN 'interest_capitalized' THEN
+      new_row.amount := (NEW.event ->> 'amount');
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.capitalized_on := (NEW.event ->> 'capitalized_on');
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+    WHEN 'frozen' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.reason := (NEW.event ->> 'reason');
+      new_row.scope := (NEW.event ->> 'scope');
+    WHEN 'unfrozen' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+    WHEN 'withdrawal_limits_overridden' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.withdrawal_limits := (NEW.event -> 'withdrawal_limits');
+    WHEN 'withdrawal_limits_applied' THEN
+      new_row.ledger_velocity_control_id := (NEW.event ->> 'ledger_velocity_control_id')::UUID;
+      new_row.withdrawal_limits := (NEW.event -> 'withdrawal_limits');
+    WHEN 'customer_activity_recorded' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.recorded_at := (NEW.event ->> 'recorded_at')::TIMESTAMPTZ;
+    WHEN 'dormancy_status_updated' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'closed' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.balance_sweep := (NEW.event -> 'balance_sweep');
+  END CASE;
+
+  INSERT INTO core_deposit_account_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    account_holder_id,
+    accrued_on,
+    amount,
+    audit_entry_ids,
+    balance_sweep,
+    capitalized_on,
+    deposit_product_id,
+    description,
+    interest_accrual_starts_on,
+    ledger_account_id,
+    ledger_tx_id,
+    ledger_velocity_control_id,
+    name,
+    public_id,
+    reason,
+    recorded_at,
+    reference,
+    scope,
+    settled_balance,
+    status,
+    withdrawal_limits
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.account_holder_id,
+    new_row.accrued_on,
+    new_row.amount,
+    new_row.audit_entry_ids,
+    new_row.balance_sweep,
+    new_row.capitalized_on,
+    new_row.deposit_product_id,
+    new_row.description,
+    new_row.interest_accrual_starts_on,
+    new_row.ledger_account_id,
+    new_row.ledger_tx_id,
+    new_row.ledger_velocity_control_id,
+    new_row.name,
+    new_row.public_id,
+    new_row.reason,
+    new_row.recorded_at,
+    new_row.reference,
+    new_row.scope,
+    new_row.settled_balance,
+    new_row.status,
+    new_row.withdrawal_limits
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for DepositAccountEvent
+CREATE TRIGGER core_deposit_account_events_rollup_trigger
+  AFTER INSERT ON core_deposit_account_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_deposit_account_events_rollup_trigger();
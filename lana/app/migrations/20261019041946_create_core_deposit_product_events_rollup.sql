@@ -0,0 +1,116 @@
+-- Auto-generated rollup table for DepositProductEvent
+CREATE TABLE core_deposit_product_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  interest_terms JSONB,
+  name VARCHAR,
+  withdrawal_fee_schedule JSONB,
+  withdrawal_limits JSONB,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[]
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for DepositProductEvent
+CREATE OR REPLACE FUNCTION core_deposit_product_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_deposit_product_events_rollup%ROWTYPE;
+  new_row core_deposit_product_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_deposit_product_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'interest_terms_updated', 'withdrawal_limits_updated', 'withdrawal_fee_schedule_updated') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.interest_terms := (NEW.event -> 'interest_terms');
+    new_row.name := (NEW.event ->> 'name');
+    new_row.withdrawal_fee_schedule := (NEW.event -> 'withdrawal_fee_schedule');
+    new_row.withdrawal_limits := (NEW.event -> 'withdrawal_limits');
+  ELSE
+    -- Default all fields to current values
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.interest_terms := current_row.interest_terms;
+    new_row.name := current_row.name;
+    new_row.withdrawal_fee_schedule := current_row.withdrawal_fee_schedule;
+    new_row.withdrawal_limits := current_row.withdrawal_limits;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.interest_terms := (NEW.event -> 'interest_terms');
+      new_row.name := (NEW.event ->> 'name');
+    WHEN 'interest_terms_updated' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.interest_terms := (NEW.event -> 'interest_terms');
+    WHEN 'withdrawal_limits_updated' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.withdrawal_limits := (NEW.event -> 'withdrawal_limits');
+    WHEN 'withdrawal_fee_schedule_updated' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.withdrawal_fee_schedule := (NEW.event -> 'withdrawal_fee_schedule');
+  END CASE;
+
+  INSERT INTO core_deposit_product_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    audit_entry_ids,
+    interest_terms,
+    name,
+    withdrawal_fee_schedule,
+    withdrawal_limits
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.audit_entry_ids,
+    new_row.interest_terms,
+    new_row.name,
+    new_row.withdrawal_fee_schedule,
+    new_row.withdrawal_limits
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for DepositProductEvent
+CREATE TRIGGER core_deposit_product_events_rollup_trigger
+  AFTER INSERT ON core_deposit_product_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_deposit_product_events_rollup_trigger();
@@ -0,0 +1,140 @@
+-- Auto-generated rollup table for BeneficiaryEvent
+CREATE TABLE core_beneficiary_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  approval_process_id UUID,
+  approved BOOLEAN,
+  bank_account JSONB,
+  deposit_account_id UUID,
+  replaces UUID,
+  usable_from TIMESTAMPTZ,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[],
+
+  -- Toggle fields
+  is_approval_process_concluded BOOLEAN DEFAULT false,
+  is_removed BOOLEAN DEFAULT false
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for BeneficiaryEvent
+CREATE OR REPLACE FUNCTION core_beneficiary_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_beneficiary_events_rollup%ROWTYPE;
+  new_row core_beneficiary_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_beneficiary_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'approval_process_concluded', 'removed') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+    new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.bank_account := (NEW.event -> 'bank_account');
+    new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+    new_row.is_approval_process_concluded := false;
+    new_row.is_removed := false;
+    new_row.replaces := (NEW.event ->> 'replaces')::UUID;
+    new_row.usable_from := (NEW.event ->> 'usable_from')::TIMESTAMPTZ;
+  ELSE
+    -- Default all fields to current values
+    new_row.approval_process_id := current_row.approval_process_id;
+    new_row.approved := current_row.approved;
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.bank_account := current_row.bank_account;
+    new_row.deposit_account_id := current_row.deposit_account_id;
+    new_row.is_approval_process_concluded := current_row.is_approval_process_concluded;
+    new_row.is_removed := current_row.is_removed;
+    new_row.replaces := current_row.replaces;
+    new_row.usable_from := current_row.usable_from;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.bank_account := (NEW.event -> 'bank_account');
+      new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+      new_row.replaces := (NEW.event ->> 'replaces')::UUID;
+    WHEN 'approval_process_concluded' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.is_approval_process_concluded := true;
+      new_row.usable_from := (NEW.event ->> 'usable_from')::TIMESTAMPTZ;
+    WHEN 'removed' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.is_removed := true;
+  END CASE;
+
+  INSERT INTO core_beneficiary_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    approval_process_id,
+    approved,
+    audit_entry_ids,
+    bank_account,
+    deposit_account_id,
+    is_approval_process_concluded,
+    is_removed,
+    replaces,
+    usable_from
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.approval_process_id,
+    new_row.approved,
+    new_row.audit_entry_ids,
+    new_row.bank_account,
+    new_row.deposit_account_id,
+    new_row.is_approval_process_concluded,
+    new_row.is_removed,
+    new_row.replaces,
+    new_row.usable_from
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for BeneficiaryEvent
+CREATE TRIGGER core_beneficiary_events_rollup_trigger
+  AFTER INSERT ON core_beneficiary_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_beneficiary_events_rollup_trigger();
@@ -0,0 +1,161 @@
+-- Auto-generated rollup table for BankStatementLineEvent
+CREATE TABLE core_bank_statement_line_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  amount BIGINT,
+  booked_on VARCHAR,
+  creditor_account VARCHAR,
+  creditor_reference VARCHAR,
+  debtor_name VARCHAR,
+  deposit_account_id UUID,
+  deposit_id UUID,
+  matched_on_import BOOLEAN,
+  reference VARCHAR,
+  remittance_information VARCHAR,
+  statement_id VARCHAR,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[],
+
+  -- Toggle fields
+  is_deposit_recorded BOOLEAN DEFAULT false
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for BankStatementLineEvent
+CREATE OR REPLACE FUNCTION core_bank_statement_line_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_bank_statement_line_events_rollup%ROWTYPE;
+  new_row core_bank_statement_line_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_bank_statement_line_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'deposit_recorded') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.booked_on := (NEW.event ->> 'booked_on');
+    new_row.creditor_account := (NEW.event ->> 'creditor_account');
+    new_row.creditor_reference := (NEW.event ->> 'creditor_reference');
+    new_row.debtor_name := (NEW.event ->> 'debtor_name');
+    new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+    new_row.deposit_id := (NEW.event ->> 'deposit_id')::UUID;
+    new_row.is_deposit_recorded := false;
+    new_row.matched_on_import := (NEW.event ->> 'matched_on_import')::BOOLEAN;
+    new_row.reference := (NEW.event ->> 'reference');
+    new_row.remittance_information := (NEW.event ->> 'remittance_information');
+    new_row.statement_id := (NEW.event ->> 'statement_id');
+  ELSE
+    -- Default all fields to current values
+    new_row.amount := current_row.amount;
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.booked_on := current_row.booked_on;
+    new_row.creditor_account := current_row.creditor_account;
+    new_row.creditor_reference := current_row.creditor_reference;
+    new_row.debtor_name := current_row.debtor_name;
+    new_row.deposit_account_id := current_row.deposit_account_id;
+    new_row.deposit_id := current_row.deposit_id;
+    new_row.is_deposit_recorded := current_row.is_deposit_recorded;
+    new_row.matched_on_import := current_row.matched_on_import;
+    new_row.reference := current_row.reference;
+    new_row.remittance_information := current_row.remittance_information;
+    new_row.statement_id := current_row.statement_id;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.booked_on := (NEW.event ->> 'booked_on');
+      new_row.creditor_account := (NEW.event ->> 'creditor_account');
+      new_row.creditor_reference := (NEW.event ->> 'creditor_reference');
+      new_row.debtor_name := (NEW.event ->> 'debtor_name');
+      new_row.reference := (NEW.event ->> 'reference');
+      new_row.remittance_information := (NEW.event ->> 'remittance_information');
+      new_row.statement_id := (NEW.event ->> 'statement_id');
+    WHEN 'deposit_recorded' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+      new_row.deposit_id := (NEW.event ->> 'deposit_id')::UUID;
+      new_row.is_deposit_recorded := true;
+      new_row.matched_on_import := (NEW.event ->> 'matched_on_import')::BOOLEAN;
+  END CASE;
+
+  INSERT INTO core_bank_statement_line_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    amount,
+    audit_entry_ids,
+    booked_on,
+    creditor_account,
+    creditor_reference,
+    debtor_name,
+    deposit_account_id,
+    deposit_id,
+    is_deposit_recorded,
+    matched_on_import,
+    reference,
+    remittance_information,
+    statement_id
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.amount,
+    new_row.audit_entry_ids,
+    new_row.booked_on,
+    new_row.creditor_account,
+    new_row.creditor_reference,
+    new_row.debtor_name,
+    new_row.deposit_account_id,
+    new_row.deposit_id,
+    new_row.is_deposit_recorded,
+    new_row.matched_on_import,
+    new_row.reference,
+    new_row.remittance_information,
+    new_row.statement_id
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for BankStatementLineEvent
+CREATE TRIGGER core_bank_statement_line_events_rollup_trigger
+  AFTER INSERT ON core_bank_statement_line_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_bank_statement_line_events_rollup_trigger();
@@ -0,0 +1,201 @@
+-- Auto-generated rollup table for WithdrawalEvent
+CREATE TABLE core_withdrawal_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  amount BIGINT,
+  approval_process_id UUID,
+  approved BOOLEAN,
+  beneficiary_id UUID,
+  deposit_account_id UUID,
+  fee BIGINT,
+  fee_refund_tx_id UUID,
+  payment_batch_id UUID,
+  reference VARCHAR,
+  status VARCHAR,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[],
+  ledger_tx_ids UUID[],
+
+  -- Toggle fields
+  is_approval_process_concluded BOOLEAN DEFAULT false,
+  is_cancelled BOOLEAN DEFAULT false,
+  is_confirmed BOOLEAN DEFAULT false,
+  is_included_in_payment_batch BOOLEAN DEFAULT false
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for WithdrawalEvent
+CREATE OR REPLACE FUNCTION core_withdrawal_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_withdrawal_events_rollup%ROWTYPE;
+  new_row core_withdrawal_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_withdrawal_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'approval_process_concluded', 'confirmed', 'cancelled', 'reverted', 'included_in_payment_batch') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+    new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.beneficiary_id := (NEW.event ->> 'beneficiary_id')::UUID;
+    new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+    new_row.fee := (NEW.event ->> 'fee')::BIGINT;
+    new_row.fee_refund_tx_id := (NEW.event ->> 'fee_refund_tx_id')::UUID;
+    new_row.is_approval_process_concluded := false;
+    new_row.is_cancelled := false;
+    new_row.is_confirmed := false;
+    new_row.is_included_in_payment_batch := false;
+    new_row.ledger_tx_ids := CASE
+       WHEN NEW.event ? 'ledger_tx_ids' THEN
+         ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'ledger_tx_ids'))
+       ELSE ARRAY[]::UUID[]
+     END
+;
+    new_row.payment_batch_id := (NEW.event ->> 'payment_batch_id')::UUID;
+    new_row.reference := (NEW.event ->> 'reference');
+    new_row.status := (NEW.event ->> 'status');
+  ELSE
+    -- Default all fields to current values
+    new_row.amount := current_row.amount;
+    new_row.approval_process_id := current_row.approval_process_id;
+    new_row.approved := current_row.approved;
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.beneficiary_id := current_row.beneficiary_id;
+    new_row.deposit_account_id := current_row.deposit_account_id;
+    new_row.fee := current_row.fee;
+    new_row.fee_refund_tx_id := current_row.fee_refund_tx_id;
+    new_row.is_approval_process_concluded := current_row.is_approval_process_concluded;
+    new_row.is_cancelled := current_row.is_cancelled;
+    new_row.is_confirmed := current_row.is_confirmed;
+    new_row.is_included_in_payment_batch := current_row.is_included_in_payment_batch;
+    new_row.ledger_tx_ids := current_row.ledger_tx_ids;
+    new_row.payment_batch_id := current_row.payment_batch_id;
+    new_row.reference := current_row.reference;
+    new_row.status := current_row.status;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.beneficiary_id := (NEW.event ->> 'beneficiary_id')::UUID;
+      new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+      new_row.fee := (NEW.event ->> 'fee')::BIGINT;
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+      new_row.reference := (NEW.event ->> 'reference');
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'approval_process_concluded' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.is_approval_process_concluded := true;
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'confirmed' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.is_confirmed := true;
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'cancelled' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.fee_refund_tx_id := (NEW.event ->> 'fee_refund_tx_id')::UUID;
+      new_row.is_cancelled := true;
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'reverted' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.fee_refund_tx_id := (NEW.event ->> 'fee_refund_tx_id')::UUID;
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+      new_row.status := (NEW.event ->> 'status');
+    WHEN 'included_in_payment_batch' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.is_included_in_payment_batch := true;
+      new_row.payment_batch_id := (NEW.event ->> 'payment_batch_id')::UUID;
+  END CASE;
+
+  INSERT INTO core_withdrawal_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    amount,
+    approval_process_id,
+    approved,
+    audit_entry_ids,
+    beneficiary_id,
+    deposit_account_id,
+    fee,
+    fee_refund_tx_id,
+    is_approval_process_concluded,
+    is_cancelled,
+    is_confirmed,
+    is_included_in_payment_batch,
+    ledger_tx_ids,
+    payment_batch_id,
+    reference,
+    status
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.amount,
+    new_row.approval_process_id,
+    new_row.approved,
+    new_row.audit_entry_ids,
+    new_row.beneficiary_id,
+    new_row.deposit_account_id,
+    new_row.fee,
+    new_row.fee_refund_tx_id,
+    new_row.is_approval_process_concluded,
+    new_row.is_cancelled,
+    new_row.is_confirmed,
+    new_row.is_included_in_payment_batch,
+    new_row.ledger_tx_ids,
+    new_row.payment_batch_id,
+    new_row.reference,
+    new_row.status
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for WithdrawalEvent
+CREATE TRIGGER core_withdrawal_events_rollup_trigger
+  AFTER INSERT ON core_withdrawal_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_withdrawal_events_rollup_trigger();
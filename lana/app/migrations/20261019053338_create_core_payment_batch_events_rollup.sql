@@ -0,0 +1,109 @@
+-- Auto-generated rollup table for PaymentBatchEvent
+CREATE TABLE core_payment_batch_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  document_id UUID,
+  requested_execution_date VARCHAR,
+  total_amount BIGINT,
+  withdrawal_ids JSONB,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[]
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for PaymentBatchEvent
+CREATE OR REPLACE FUNCTION core_payment_batch_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_payment_batch_events_rollup%ROWTYPE;
+  new_row core_payment_batch_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_payment_batch_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.document_id := (NEW.event ->> 'document_id')::UUID;
+    new_row.requested_execution_date := (NEW.event ->> 'requested_execution_date');
+    new_row.total_amount := (NEW.event ->> 'total_amount')::BIGINT;
+    new_row.withdrawal_ids := (NEW.event -> 'withdrawal_ids');
+  ELSE
+    -- Default all fields to current values
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.document_id := current_row.document_id;
+    new_row.requested_execution_date := current_row.requested_execution_date;
+    new_row.total_amount := current_row.total_amount;
+    new_row.withdrawal_ids := current_row.withdrawal_ids;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.document_id := (NEW.event ->> 'document_id')::UUID;
+      new_row.requested_execution_date := (NEW.event ->> 'requested_execution_date');
+      new_row.total_amount := (NEW.event ->> 'total_amount')::BIGINT;
+      new_row.withdrawal_ids := (NEW.event -> 'withdrawal_ids');
+  END CASE;
+
+  INSERT INTO core_payment_batch_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    audit_entry_ids,
+    document_id,
+    requested_execution_date,
+    total_amount,
+    withdrawal_ids
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.audit_entry_ids,
+    new_row.document_id,
+    new_row.requested_execution_date,
+    new_row.total_amount,
+    new_row.withdrawal_ids
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for PaymentBatchEvent
+CREATE TRIGGER core_payment_batch_events_rollup_trigger
+  AFTER INSERT ON core_payment_batch_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_payment_batch_events_rollup_trigger();
@@ -15,11 +15,14 @@ struct CreditConfigData {
     facility_omnibus_parent_code: String,
     collateral_omnibus_parent_code: String,
     in_liquidation_omnibus_parent_code: String,
+    disbursal_settlement_omnibus_parent_code: String,
+    participation_principal_omnibus_parent_code: String,
     facility_parent_code: String,
     collateral_parent_code: String,
     in_liquidation_parent_code: String,
     interest_income_parent_code: String,
     fee_income_parent_code: String,
+    participation_payable_parent_code: String,
     short_term_individual_interest_receivable_parent_code: String,
     short_term_government_entity_interest_receivable_parent_code: String,
     short_term_private_company_interest_receivable_parent_code: String,
@@ -67,11 +70,14 @@ pub(in crate::accounting_init::seed) async fn credit_module_configure(
         facility_omnibus_parent_code,
         collateral_omnibus_parent_code,
         in_liquidation_omnibus_parent_code,
+        disbursal_settlement_omnibus_parent_code,
+        participation_principal_omnibus_parent_code,
         facility_parent_code,
         collateral_parent_code,
         in_liquidation_parent_code,
         interest_income_parent_code,
         fee_income_parent_code,
+        participation_payable_parent_code,
         short_term_individual_interest_receivable_parent_code,
         short_term_government_entity_interest_receivable_parent_code,
         short_term_private_company_interest_receivable_parent_code,
@@ -116,11 +122,20 @@ pub(in crate::accounting_init::seed) async fn credit_module_configure(
         .chart_of_account_in_liquidation_omnibus_parent_code(
             in_liquidation_omnibus_parent_code.parse()?,
         )
+        .chart_of_account_disbursal_settlement_omnibus_parent_code(
+            disbursal_settlement_omnibus_parent_code.parse()?,
+        )
+        .chart_of_account_participation_principal_omnibus_parent_code(
+            participation_principal_omnibus_parent_code.parse()?,
+        )
         .chart_of_account_facility_parent_code(facility_parent_code.parse()?)
         .chart_of_account_collateral_parent_code(collateral_parent_code.parse()?)
         .chart_of_account_in_liquidation_parent_code(in_liquidation_parent_code.parse()?)
         .chart_of_account_interest_income_parent_code(interest_income_parent_code.parse()?)
         .chart_of_account_fee_income_parent_code(fee_income_parent_code.parse()?)
+        .chart_of_account_participation_payable_parent_code(
+            participation_payable_parent_code.parse()?,
+        )
         .chart_of_account_short_term_individual_interest_receivable_parent_code(
             short_term_individual_interest_receivable_parent_code.parse()?,
         )
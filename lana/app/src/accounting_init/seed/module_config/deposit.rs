@@ -13,6 +13,8 @@ use rbac_types::Subject;
 #[derive(Deserialize)]
 struct DepositConfigData {
     omnibus_parent_code: String,
+    interest_expense_parent_code: String,
+    fee_income_parent_code: String,
     individual_deposit_accounts_parent_code: String,
     government_entity_deposit_accounts_parent_code: String,
     private_company_deposit_accounts_parent_code: String,
@@ -29,6 +31,8 @@ pub(in crate::accounting_init::seed) async fn deposit_module_configure(
     let data = fs::read_to_string(config_path)?;
     let DepositConfigData {
         omnibus_parent_code,
+        interest_expense_parent_code,
+        fee_income_parent_code,
         individual_deposit_accounts_parent_code,
         government_entity_deposit_accounts_parent_code,
         private_company_deposit_accounts_parent_code,
@@ -40,6 +44,8 @@ pub(in crate::accounting_init::seed) async fn deposit_module_configure(
     let config_values = ChartOfAccountsIntegrationConfig::builder()
         .chart_of_accounts_id(chart.id)
         .chart_of_accounts_omnibus_parent_code(omnibus_parent_code.parse()?)
+        .chart_of_accounts_interest_expense_parent_code(interest_expense_parent_code.parse()?)
+        .chart_of_accounts_fee_income_parent_code(fee_income_parent_code.parse()?)
         .chart_of_accounts_individual_deposit_accounts_parent_code(
             individual_deposit_accounts_parent_code.parse()?,
         )
@@ -4,9 +4,9 @@ use std::path::PathBuf;
 
 use crate::{
     access::config::AccessConfig, applicant::SumsubConfig, credit::CreditConfig,
-    custody::CustodyConfig, customer_sync::CustomerSyncConfig, deposit_sync::DepositSyncConfig,
-    job::JobsConfig, notification::NotificationConfig, report::ReportConfig,
-    storage::config::StorageConfig, user_onboarding::UserOnboardingConfig,
+    custody::CustodyConfig, customer_sync::CustomerSyncConfig, deposit::DepositConfig,
+    deposit_sync::DepositSyncConfig, job::JobsConfig, notification::NotificationConfig,
+    report::ReportConfig, storage::config::StorageConfig, user_onboarding::UserOnboardingConfig,
 };
 
 #[derive(Clone, Default, Debug, Deserialize, Serialize)]
@@ -20,6 +20,8 @@ pub struct AppConfig {
     #[serde(default)]
     pub credit: CreditConfig,
     #[serde(default)]
+    pub deposit: DepositConfig,
+    #[serde(default)]
     pub storage: StorageConfig,
     #[serde(default)]
     pub user_onboarding: UserOnboardingConfig,
@@ -120,6 +120,7 @@ impl LanaApp {
         );
         let deposits = Deposits::init(
             &pool,
+            config.deposit,
             &authz,
             &outbox,
             &governance,
@@ -127,6 +128,7 @@ impl LanaApp {
             &cala,
             journal_init.journal_id,
             &public_ids,
+            documents.clone(),
         )
         .await?;
         let customer_sync =
@@ -158,6 +160,7 @@ impl LanaApp {
             &cala,
             journal_init.journal_id,
             &public_ids,
+            documents.clone(),
         )
         .await?;
 
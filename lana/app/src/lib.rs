@@ -30,7 +30,7 @@ pub mod outbox {
 }
 pub mod dashboard {
     pub type Dashboard = dashboard::Dashboard<crate::authorization::Authorization>;
-    pub use dashboard::DashboardValues;
+    pub use dashboard::{AgingTile, DashboardValues, PortfolioAgingTiles};
 }
 
 pub mod user_onboarding {
@@ -90,6 +90,9 @@ pub mod governance {
     pub type Governance = governance::Governance<Authorization, LanaEvent>;
     pub use crate::credit::APPROVE_CREDIT_FACILITY_PROCESS;
     pub use crate::credit::APPROVE_DISBURSAL_PROCESS;
+    pub use crate::credit::APPROVE_OBLIGATION_REINSTATEMENT_PROCESS;
+    pub use core_deposit::APPROVE_BENEFICIARY_PROCESS;
+    pub use core_deposit::APPROVE_TRANSFER_PROCESS;
     pub use core_deposit::APPROVE_WITHDRAWAL_PROCESS;
     pub use governance::{
         ApprovalProcess, ApprovalProcessStatus, ApprovalProcessType, ApprovalRules, Committee,
@@ -110,10 +113,20 @@ pub mod audit {
 
 pub mod deposit {
     pub use core_deposit::{
+        AccountFreezeScope, BalanceSweepDestination, BankStatementLine, BankStatementLineId,
+        BankStatementLineStatus, BankStatementLinesByCreatedAtCursor, Beneficiary,
+        BeneficiaryBankAccount, BeneficiaryId, BeneficiaryStatus, CapitalizationFrequency,
         ChartOfAccountsIntegrationConfig, CoreDepositEvent, Deposit, DepositAccount,
-        DepositAccountBalance, DepositAccountHistoryCursor, DepositAccountHistoryEntry, DepositId,
-        DepositStatus, DepositsByCreatedAtCursor, Withdrawal, WithdrawalId, WithdrawalStatus,
-        WithdrawalsByCreatedAtCursor, error,
+        DepositAccountBalance, DepositAccountHistoryCursor, DepositAccountHistoryEntry,
+        DepositAccountStatement, DepositAccountStatementId, DepositConfig, DepositId,
+        DepositInterestRate, DepositInterestTerms, DepositProduct, DepositStatus,
+        DepositsByCreatedAtCursor, DormancyStatus, EarlyBreakPenaltyRate, Hold, HoldId, HoldStatus,
+        PaymentBatch, PaymentBatchId, PaymentBatchesByCreatedAtCursor, StatementPeriod,
+        TimeDeposit, TimeDepositDuration, TimeDepositId, TimeDepositMaturityInstruction,
+        TimeDepositStatus, TimeDepositTerms, Transfer, TransferId, TransferStatus,
+        TransfersByCreatedAtCursor, Withdrawal, WithdrawalFeeRate, WithdrawalFeeSchedule,
+        WithdrawalId, WithdrawalLimit, WithdrawalLimitPeriod, WithdrawalLimitUsage,
+        WithdrawalLimits, WithdrawalStatus, WithdrawalsByCreatedAtCursor, error,
     };
 
     pub type Deposits =
@@ -157,15 +170,23 @@ pub mod custody {
 pub mod credit {
     pub use core_credit::{
         APPROVE_CREDIT_FACILITY_PROCESS, APPROVE_DISBURSAL_PROCESS,
-        ChartOfAccountsIntegrationConfig, Collateral, CollateralUpdated, CollateralizationUpdated,
-        CoreCreditEvent, CreditConfig, CreditFacilitiesCursor, CreditFacilitiesSortBy,
-        CreditFacility, CreditFacilityApproved, CreditFacilityBalanceSummary,
-        CreditFacilityHistoryEntry, CreditFacilityRepaymentPlanEntry, CreditFacilityStatus,
-        Disbursal, DisbursalExecuted, DisbursalStatus, DisbursalsCursor, DisbursalsSortBy,
-        FacilityCVL, FindManyCreditFacilities, FindManyDisbursals, IncrementalPayment,
-        InterestAccrualsPosted, ListDirection, ObligationMovedToLiquidation, Payment,
-        PaymentAllocation, RepaymentStatus, RepaymentType, Sort, TermsTemplate, error,
-        terms_template_error,
+        APPROVE_OBLIGATION_REINSTATEMENT_PROCESS, ChartOfAccountsIntegrationConfig, Collateral,
+        CollateralUpdated, CollateralizationUpdated, CoreCreditEvent, CreditConfig,
+        CreditFacilitiesCursor, CreditFacilitiesSortBy, CreditFacility, CreditFacilityApproved,
+        CreditFacilityBalanceSummary, CreditFacilityHistoryEntry, CreditFacilityProposal,
+        CreditFacilityProposalError, CreditFacilityProposalsByCreatedAtCursor,
+        CreditFacilityRepaymentPlanEntry, CreditFacilityStatus, DefaultedObligationReinstated,
+        Disbursal, DisbursalDestination, DisbursalExecuted, DisbursalStatus, DisbursalsCursor,
+        DisbursalsSortBy, ExternalBeneficiary, FacilityAmountBounds, FacilityCVL,
+        FindManyCreditFacilities, FindManyDisbursals, IncrementalPayment, InterestAccrualAdjusted,
+        InterestAccrualsPosted, ListDirection, LoanTape, LoanTapeFormat,
+        ObligationMovedToLiquidation, Participation, ParticipationError,
+        ParticipationStatementEntry, Payment, PaymentAllocation, PortfolioAging,
+        PortfolioAgingBucket, PriceShockScenario, PriceShockStressTest, PriceShockStressTestError,
+        RepaymentStatus, RepaymentType, SimulatedCvlPoint, SimulatedPricePoint, Sort,
+        StressedExposure, TermsSimulation, TermsSimulationError, TermsTemplate,
+        TermsTemplateStatus, TermsTemplateVersionRef, UnderwritingDecision, UnderwritingFinding,
+        UnderwritingOutcome, UnderwritingRule, error, terms_template_error,
     };
 
     pub type Credit =
@@ -174,8 +195,8 @@ pub mod credit {
 
 pub mod terms {
     pub use core_credit::{
-        AnnualRatePct, CVLPct, CollateralizationState, FacilityDuration, InterestInterval,
-        ObligationDuration, OneTimeFeeRatePct, TermValues,
+        AnnualRatePct, CVLPct, CollateralizationState, FacilityDuration, InterestCapitalization,
+        InterestInterval, ObligationDuration, OneTimeFeeRatePct, TermValues,
     };
 }
 
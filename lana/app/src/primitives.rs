@@ -3,12 +3,18 @@ pub use core_accounting::{
     AccountSpec, BalanceRange, Chart, ChartId, LedgerTransactionId, ManualTransactionId,
 };
 pub use core_credit::{
-    CollateralAction, CollateralId, CreditFacilityId, CreditFacilityStatus, DisbursalId,
-    DisbursalStatus, PaymentAllocationId, PaymentId, TermsTemplateId,
+    AgingBucket, CollateralAction, CollateralId, CreditFacilityId, CreditFacilityProposalId,
+    CreditFacilityProposalStatus, CreditFacilityStatus, DisbursalDestination, DisbursalId,
+    DisbursalStatus, ParticipationId, ParticipationSharePct, ParticipationShareType,
+    PaymentAllocationId, PaymentId, TermsTemplateId,
 };
 pub use core_custody::{CustodianId, WalletId};
 pub use core_customer::{CustomerDocumentId, CustomerId};
-pub use core_deposit::{DepositAccountHolderId, DepositAccountId, DepositId, WithdrawalId};
+pub use core_deposit::{
+    BankStatementLineId, BeneficiaryId, DepositAccountHolderId, DepositAccountId,
+    DepositAccountStatementId, DepositId, DepositProductId, HoldId, PaymentBatchId, TimeDepositId,
+    TransferId, WithdrawalId,
+};
 pub use core_money::*;
 pub use core_price::PriceOfOneBTC;
 pub use core_report::ReportId;
@@ -0,0 +1,131 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::deposit::{
+    Beneficiary as DomainBeneficiary, BeneficiaryBankAccount, BeneficiaryStatus,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct Beneficiary {
+    id: ID,
+    beneficiary_id: UUID,
+    deposit_account_id: UUID,
+    account_holder_name: String,
+    account_number: String,
+    bank_name: String,
+    bank_identifier: String,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainBeneficiary>,
+}
+
+impl From<DomainBeneficiary> for Beneficiary {
+    fn from(beneficiary: DomainBeneficiary) -> Self {
+        Beneficiary {
+            id: beneficiary.id.to_global_id(),
+            beneficiary_id: UUID::from(beneficiary.id),
+            deposit_account_id: beneficiary.deposit_account_id.into(),
+            account_holder_name: beneficiary.bank_account.account_holder_name.clone(),
+            account_number: beneficiary.bank_account.account_number.clone(),
+            bank_name: beneficiary.bank_account.bank_name.clone(),
+            bank_identifier: beneficiary.bank_account.bank_identifier.clone(),
+            created_at: beneficiary.created_at().into(),
+            entity: Arc::new(beneficiary),
+        }
+    }
+}
+
+#[ComplexObject]
+impl Beneficiary {
+    async fn usable_from(&self) -> Option<Timestamp> {
+        self.entity.usable_from().map(Into::into)
+    }
+
+    async fn status(&self, ctx: &Context<'_>) -> async_graphql::Result<BeneficiaryStatus> {
+        let (app, _) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(app
+            .deposits()
+            .ensure_up_to_date_beneficiary_status(&self.entity)
+            .await?
+            .map(|b| b.status())
+            .unwrap_or_else(|| self.entity.status()))
+    }
+}
+
+#[derive(InputObject)]
+pub struct BeneficiaryBankAccountInput {
+    pub account_holder_name: String,
+    pub account_number: String,
+    pub bank_name: String,
+    pub bank_identifier: String,
+}
+
+impl From<BeneficiaryBankAccountInput> for BeneficiaryBankAccount {
+    fn from(input: BeneficiaryBankAccountInput) -> Self {
+        Self {
+            account_holder_name: input.account_holder_name,
+            account_number: input.account_number,
+            bank_name: input.bank_name,
+            bank_identifier: input.bank_identifier,
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct BeneficiaryRegisterInput {
+    pub deposit_account_id: UUID,
+    pub bank_account: BeneficiaryBankAccountInput,
+}
+
+#[derive(InputObject)]
+pub struct BeneficiaryUpdateInput {
+    pub beneficiary_id: UUID,
+    pub bank_account: BeneficiaryBankAccountInput,
+}
+
+#[derive(InputObject)]
+pub struct BeneficiaryRemoveInput {
+    pub beneficiary_id: UUID,
+}
+
+#[derive(SimpleObject)]
+pub struct BeneficiaryRegisterPayload {
+    beneficiary: Beneficiary,
+}
+
+impl From<DomainBeneficiary> for BeneficiaryRegisterPayload {
+    fn from(beneficiary: DomainBeneficiary) -> Self {
+        Self {
+            beneficiary: Beneficiary::from(beneficiary),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct BeneficiaryUpdatePayload {
+    beneficiary: Beneficiary,
+}
+
+impl From<DomainBeneficiary> for BeneficiaryUpdatePayload {
+    fn from(beneficiary: DomainBeneficiary) -> Self {
+        Self {
+            beneficiary: Beneficiary::from(beneficiary),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct BeneficiaryRemovePayload {
+    beneficiary: Beneficiary,
+}
+
+impl From<DomainBeneficiary> for BeneficiaryRemovePayload {
+    fn from(beneficiary: DomainBeneficiary) -> Self {
+        Self {
+            beneficiary: Beneficiary::from(beneficiary),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::terms::*;
+
+pub use lana_app::credit::{
+    CreditFacilityProposal as DomainCreditFacilityProposal, TermsTemplate as DomainTermsTemplate,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct CreditFacilityProposal {
+    id: ID,
+    credit_facility_proposal_id: UUID,
+    amount: UsdCents,
+    status: CreditFacilityProposalStatus,
+    rejection_reason: Option<String>,
+    created_at: Timestamp,
+    concluded_at: Option<Timestamp>,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainCreditFacilityProposal>,
+}
+
+impl From<DomainCreditFacilityProposal> for CreditFacilityProposal {
+    fn from(proposal: DomainCreditFacilityProposal) -> Self {
+        Self {
+            id: proposal.id.to_global_id(),
+            credit_facility_proposal_id: UUID::from(proposal.id),
+            amount: proposal.amount,
+            status: proposal.status,
+            rejection_reason: proposal.rejection_reason.clone(),
+            created_at: proposal.created_at().into(),
+            concluded_at: proposal.concluded_at().map(Into::into),
+            entity: Arc::new(proposal),
+        }
+    }
+}
+
+#[ComplexObject]
+impl CreditFacilityProposal {
+    async fn terms(&self) -> TermValues {
+        self.entity.terms.into()
+    }
+
+    async fn credit_facility_id(&self) -> Option<UUID> {
+        self.entity.credit_facility_id.map(UUID::from)
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct TermsTemplate {
+    id: ID,
+    terms_template_id: UUID,
+    name: String,
+    version: u32,
+    values: TermValues,
+}
+
+impl From<DomainTermsTemplate> for TermsTemplate {
+    fn from(template: DomainTermsTemplate) -> Self {
+        Self {
+            id: template.id.to_global_id(),
+            terms_template_id: UUID::from(template.id),
+            name: template.name,
+            version: template.version,
+            values: template.values.into(),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreditFacilityProposalCreateInput {
+    pub amount: UsdCents,
+    pub terms_template_id: UUID,
+}
+
+#[derive(SimpleObject)]
+pub struct CreditFacilityProposalCreatePayload {
+    credit_facility_proposal: CreditFacilityProposal,
+}
+
+impl From<DomainCreditFacilityProposal> for CreditFacilityProposalCreatePayload {
+    fn from(proposal: DomainCreditFacilityProposal) -> Self {
+        Self {
+            credit_facility_proposal: CreditFacilityProposal::from(proposal),
+        }
+    }
+}
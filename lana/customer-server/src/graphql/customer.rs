@@ -5,7 +5,7 @@ use core_customer::{AccountStatus, Customer as DomainCustomer, CustomerType, Kyc
 
 use crate::primitives::*;
 
-use super::{credit_facility::*, deposit_account::*};
+use super::{credit_facility::*, credit_facility_proposal::*, deposit_account::*};
 
 use thiserror::Error;
 
@@ -84,4 +84,20 @@ impl Customer {
             .map(CreditFacility::from)
             .collect())
     }
+
+    async fn credit_facility_proposals(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<CreditFacilityProposal>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        Ok(app
+            .credit()
+            .for_subject(sub)?
+            .list_facility_proposals()
+            .await?
+            .into_iter()
+            .map(CreditFacilityProposal::from)
+            .collect())
+    }
 }
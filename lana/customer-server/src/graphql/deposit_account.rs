@@ -7,7 +7,10 @@ pub use lana_app::deposit::{
     DepositAccountHistoryEntry as DomainDepositAccountHistoryEntry,
 };
 
-use super::{deposit::*, deposit_account_history::*, withdrawal::*};
+use super::{
+    beneficiary::*, deposit::*, deposit_account_history::*, deposit_account_statement::*,
+    withdrawal::*,
+};
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -38,6 +41,8 @@ impl From<DomainDepositAccount> for DepositAccount {
 pub struct DepositAccountBalance {
     settled: UsdCents,
     pending: UsdCents,
+    held: UsdCents,
+    available: UsdCents,
 }
 
 impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
@@ -45,6 +50,8 @@ impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
         Self {
             settled: balance.settled,
             pending: balance.pending,
+            held: balance.held,
+            available: balance.available,
         }
     }
 }
@@ -81,6 +88,32 @@ impl DepositAccount {
         Ok(withdrawals.into_iter().map(Withdrawal::from).collect())
     }
 
+    async fn beneficiaries(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Beneficiary>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let beneficiaries = app
+            .deposits()
+            .for_subject(sub)?
+            .list_beneficiaries_for_account(self.entity.id)
+            .await?;
+        Ok(beneficiaries.into_iter().map(Beneficiary::from).collect())
+    }
+
+    async fn statements(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<DepositAccountStatement>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let statements = app
+            .deposits()
+            .for_subject(sub)?
+            .list_statements_for_account(self.entity.id)
+            .await?;
+        Ok(statements
+            .into_iter()
+            .map(DepositAccountStatement::from)
+            .collect())
+    }
+
     async fn history(
         &self,
         ctx: &Context<'_>,
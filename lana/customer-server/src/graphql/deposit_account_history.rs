@@ -13,8 +13,17 @@ pub enum DepositAccountHistoryEntry {
     Deposit(DepositEntry),
     Withdrawal(WithdrawalEntry),
     CancelledWithdrawal(CancelledWithdrawalEntry),
+    WithdrawalFee(WithdrawalFeeEntry),
+    WithdrawalFeeRefund(WithdrawalFeeRefundEntry),
     Disbursal(DisbursalEntry),
     Payment(PaymentEntry),
+    InterestCapitalization(InterestCapitalizationEntry),
+    TimeDepositOpening(TimeDepositOpeningEntry),
+    TimeDepositSettlement(TimeDepositSettlementEntry),
+    TransferOut(TransferOutEntry),
+    TransferIn(TransferInEntry),
+    CancelledTransfer(CancelledTransferEntry),
+    ClosingBalanceSweep(ClosingBalanceSweepEntry),
     Unknown(UnknownEntry),
 }
 
@@ -57,6 +66,69 @@ pub struct PaymentEntry {
     pub recorded_at: Timestamp,
 }
 
+#[derive(SimpleObject)]
+pub struct WithdrawalFeeEntry {
+    pub tx_id: UUID,
+    pub amount: UsdCents,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+pub struct WithdrawalFeeRefundEntry {
+    pub tx_id: UUID,
+    pub amount: UsdCents,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+pub struct InterestCapitalizationEntry {
+    pub tx_id: UUID,
+    pub amount: UsdCents,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+pub struct TimeDepositOpeningEntry {
+    pub tx_id: UUID,
+    pub amount: UsdCents,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+pub struct TimeDepositSettlementEntry {
+    pub tx_id: UUID,
+    pub amount: UsdCents,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+pub struct TransferOutEntry {
+    pub tx_id: UUID,
+    pub amount: UsdCents,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+pub struct TransferInEntry {
+    pub tx_id: UUID,
+    pub amount: UsdCents,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+pub struct CancelledTransferEntry {
+    pub tx_id: UUID,
+    pub amount: UsdCents,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+pub struct ClosingBalanceSweepEntry {
+    pub tx_id: UUID,
+    pub amount: UsdCents,
+    pub recorded_at: Timestamp,
+}
+
 #[derive(SimpleObject)]
 pub struct UnknownEntry {
     pub tx_id: UUID,
@@ -162,6 +234,20 @@ impl From<lana_app::deposit::DepositAccountHistoryEntry> for DepositAccountHisto
                     recorded_at: entry.recorded_at.into(),
                 })
             }
+            lana_app::deposit::DepositAccountHistoryEntry::WithdrawalFee(entry) => {
+                Self::WithdrawalFee(WithdrawalFeeEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    amount: entry.amount,
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::WithdrawalFeeRefund(entry) => {
+                Self::WithdrawalFeeRefund(WithdrawalFeeRefundEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    amount: entry.amount,
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
             lana_app::deposit::DepositAccountHistoryEntry::Disbursal(entry) => {
                 Self::Disbursal(DisbursalEntry {
                     tx_id: UUID::from(entry.tx_id),
@@ -174,6 +260,55 @@ impl From<lana_app::deposit::DepositAccountHistoryEntry> for DepositAccountHisto
                     recorded_at: entry.recorded_at.into(),
                 })
             }
+            lana_app::deposit::DepositAccountHistoryEntry::InterestCapitalization(entry) => {
+                Self::InterestCapitalization(InterestCapitalizationEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    amount: entry.amount,
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::TimeDepositOpening(entry) => {
+                Self::TimeDepositOpening(TimeDepositOpeningEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    amount: entry.amount,
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::TimeDepositSettlement(entry) => {
+                Self::TimeDepositSettlement(TimeDepositSettlementEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    amount: entry.amount,
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::TransferOut(entry) => {
+                Self::TransferOut(TransferOutEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    amount: entry.amount,
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::TransferIn(entry) => {
+                Self::TransferIn(TransferInEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    amount: entry.amount,
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::CancelledTransfer(entry) => {
+                Self::CancelledTransfer(CancelledTransferEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    amount: entry.amount,
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::ClosingBalanceSweep(entry) => {
+                Self::ClosingBalanceSweep(ClosingBalanceSweepEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    amount: entry.amount,
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
             lana_app::deposit::DepositAccountHistoryEntry::Unknown(entry) => {
                 Self::Unknown(UnknownEntry {
                     tx_id: UUID::from(entry.tx_id),
@@ -1,11 +1,14 @@
 #[macro_use]
 pub mod macros;
 mod authenticated_subject;
+mod beneficiary;
 mod credit_facility;
+mod credit_facility_proposal;
 mod customer;
 mod deposit;
 mod deposit_account;
 mod deposit_account_history;
+mod deposit_account_statement;
 mod price;
 mod schema;
 mod terms;
@@ -17,8 +20,8 @@ pub use schema::*;
 
 use lana_app::app::LanaApp;
 
-pub fn schema(app: Option<LanaApp>) -> Schema<Query, EmptyMutation, EmptySubscription> {
-    let mut schema_builder = Schema::build(Query, EmptyMutation, EmptySubscription);
+pub fn schema(app: Option<LanaApp>) -> Schema<Query, Mutation, EmptySubscription> {
+    let mut schema_builder = Schema::build(Query, Mutation, EmptySubscription);
 
     if let Some(app) = app {
         schema_builder = schema_builder.data(app);
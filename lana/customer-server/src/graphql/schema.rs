@@ -2,7 +2,10 @@ use async_graphql::{Context, Object};
 
 use crate::{LanaApp, primitives::*};
 
-use super::{authenticated_subject::*, credit_facility::*, price::*};
+use super::{
+    authenticated_subject::*, beneficiary::*, credit_facility::*, credit_facility_proposal::*,
+    deposit_account_statement::*, price::*,
+};
 
 pub struct Query;
 
@@ -29,9 +32,107 @@ impl Query {
             .map(CreditFacility::from))
     }
 
+    async fn terms_templates(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<TermsTemplate>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        Ok(app
+            .credit()
+            .for_subject(sub)?
+            .list_terms_templates()
+            .await?
+            .into_iter()
+            .map(TermsTemplate::from)
+            .collect())
+    }
+
     async fn realtime_price(&self, ctx: &Context<'_>) -> async_graphql::Result<RealtimePrice> {
         let app = ctx.data_unchecked::<LanaApp>();
         let usd_cents_per_btc = app.price().usd_cents_per_btc().await?;
         Ok(usd_cents_per_btc.into())
     }
 }
+
+pub struct Mutation;
+
+#[Object]
+impl Mutation {
+    async fn credit_facility_proposal_create(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityProposalCreateInput,
+    ) -> async_graphql::Result<CreditFacilityProposalCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let proposal = app
+            .credit()
+            .for_subject(sub)?
+            .create_facility_proposal(input.amount, input.terms_template_id)
+            .await?;
+        Ok(CreditFacilityProposalCreatePayload::from(proposal))
+    }
+
+    async fn beneficiary_register(
+        &self,
+        ctx: &Context<'_>,
+        input: BeneficiaryRegisterInput,
+    ) -> async_graphql::Result<BeneficiaryRegisterPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let beneficiary = app
+            .deposits()
+            .for_subject(sub)?
+            .register_beneficiary(input.deposit_account_id, input.bank_account.into())
+            .await?;
+        Ok(BeneficiaryRegisterPayload::from(beneficiary))
+    }
+
+    async fn beneficiary_update(
+        &self,
+        ctx: &Context<'_>,
+        input: BeneficiaryUpdateInput,
+    ) -> async_graphql::Result<BeneficiaryUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let beneficiary = app
+            .deposits()
+            .for_subject(sub)?
+            .update_beneficiary(input.beneficiary_id, input.bank_account.into())
+            .await?;
+        Ok(BeneficiaryUpdatePayload::from(beneficiary))
+    }
+
+    async fn beneficiary_remove(
+        &self,
+        ctx: &Context<'_>,
+        input: BeneficiaryRemoveInput,
+    ) -> async_graphql::Result<BeneficiaryRemovePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let beneficiary = app
+            .deposits()
+            .for_subject(sub)?
+            .remove_beneficiary(input.beneficiary_id)
+            .await?;
+        Ok(BeneficiaryRemovePayload::from(beneficiary))
+    }
+
+    async fn deposit_account_statement_download_link_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountStatementDownloadLinkGenerateInput,
+    ) -> async_graphql::Result<DepositAccountStatementDownloadLinkGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let link = app
+            .deposits()
+            .for_subject(sub)?
+            .generate_statement_download_link(input.deposit_account_statement_id)
+            .await?;
+        Ok(DepositAccountStatementDownloadLinkGeneratePayload::from(
+            link,
+        ))
+    }
+}
@@ -1,8 +1,8 @@
 use async_graphql::*;
 
 pub use lana_app::terms::{
-    AnnualRatePct, CVLPct, FacilityDuration as DomainDuration, InterestInterval, OneTimeFeeRatePct,
-    TermValues as DomainTermValues,
+    AnnualRatePct, CVLPct, FacilityDuration as DomainDuration, InterestCapitalization,
+    InterestInterval, OneTimeFeeRatePct, TermValues as DomainTermValues,
 };
 
 #[derive(SimpleObject, Clone)]
@@ -15,6 +15,7 @@ pub struct TermValues {
     liquidation_cvl: CVLPct,
     margin_call_cvl: CVLPct,
     initial_cvl: CVLPct,
+    interest_capitalization: InterestCapitalization,
 }
 
 impl From<DomainTermValues> for TermValues {
@@ -28,6 +29,7 @@ impl From<DomainTermValues> for TermValues {
             liquidation_cvl: values.liquidation_cvl,
             margin_call_cvl: values.margin_call_cvl,
             initial_cvl: values.initial_cvl,
+            interest_capitalization: values.interest_capitalization,
         }
     }
 }
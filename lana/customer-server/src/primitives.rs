@@ -5,9 +5,10 @@ pub use std::sync::Arc;
 
 pub use lana_app::{
     primitives::{
-        CreditFacilityId, CreditFacilityStatus, CustomerId, DepositAccountId, DepositId,
-        DisbursalId, DisbursalStatus, PaymentAllocationId, Satoshis, Subject, UsdCents,
-        WithdrawalId,
+        BeneficiaryId, CreditFacilityId, CreditFacilityProposalId, CreditFacilityProposalStatus,
+        CreditFacilityStatus, CustomerId, DepositAccountId, DepositAccountStatementId, DepositId,
+        DisbursalId, DisbursalStatus, PaymentAllocationId, Satoshis, Subject, TermsTemplateId,
+        UsdCents, WithdrawalId,
     },
     terms::CollateralizationState,
 };
@@ -75,11 +76,15 @@ macro_rules! impl_to_global_id {
 }
 
 impl_to_global_id! {
+    BeneficiaryId,
     CustomerId,
     DepositAccountId,
+    DepositAccountStatementId,
     DepositId,
     WithdrawalId,
     CreditFacilityId,
+    CreditFacilityProposalId,
     DisbursalId,
-    PaymentAllocationId
+    PaymentAllocationId,
+    TermsTemplateId
 }
@@ -76,7 +76,8 @@ where
                 DashboardModuleAction::DASHBOARD_READ,
             )
             .await?;
-        let res = self.repo.load().await?;
+        let mut res = self.repo.load().await?;
+        res.refresh_portfolio_aging(chrono::Utc::now().date_naive());
         Ok(res)
     }
 }
@@ -1,5 +1,6 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use core_money::{Satoshis, UsdCents};
 use lana_events::*;
@@ -10,13 +11,57 @@ pub struct DashboardValues {
     pub pending_facilities: u32,
     pub total_disbursed: UsdCents,
     pub total_collateral: Satoshis,
+    #[serde(default)]
+    pub portfolio_aging: PortfolioAgingTiles,
     pub last_updated: DateTime<Utc>,
+    #[serde(default)]
+    past_due_facilities: HashMap<CreditFacilityId, PastDueFacility>,
+}
+
+#[derive(Clone, Copy, Default, Serialize, Deserialize, Debug)]
+pub struct AgingTile {
+    pub facilities: u32,
+    pub outstanding: UsdCents,
+}
+
+impl AgingTile {
+    fn add(&mut self, outstanding: UsdCents) {
+        self.facilities += 1;
+        self.outstanding += outstanding;
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize, Debug)]
+pub struct PortfolioAgingTiles {
+    pub current: AgingTile,
+    pub days_1_to_30: AgingTile,
+    pub days_31_to_60: AgingTile,
+    pub days_61_to_90: AgingTile,
+    pub over_90_days: AgingTile,
+}
+
+impl PortfolioAgingTiles {
+    fn tile_mut(&mut self, bucket: AgingBucket) -> &mut AgingTile {
+        match bucket {
+            AgingBucket::Current => &mut self.current,
+            AgingBucket::Days1To30 => &mut self.days_1_to_30,
+            AgingBucket::Days31To60 => &mut self.days_31_to_60,
+            AgingBucket::Days61To90 => &mut self.days_61_to_90,
+            AgingBucket::Over90Days => &mut self.over_90_days,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize, Debug)]
+struct PastDueFacility {
+    past_due_since: NaiveDate,
+    outstanding: UsdCents,
 }
 
 impl DashboardValues {
     pub(crate) fn process_event(&mut self, recorded_at: DateTime<Utc>, event: &LanaEvent) -> bool {
         self.last_updated = recorded_at;
-        match event {
+        let updated = match event {
             LanaEvent::Credit(CoreCreditEvent::FacilityCreated { .. }) => {
                 self.pending_facilities += 1;
                 true
@@ -26,20 +71,58 @@ impl DashboardValues {
                 self.active_facilities += 1;
                 true
             }
-            LanaEvent::Credit(CoreCreditEvent::FacilityCompleted { .. }) => {
+            LanaEvent::Credit(CoreCreditEvent::FacilityCompleted { id, .. }) => {
                 self.active_facilities -= 1;
+                self.past_due_facilities.remove(id);
+                true
+            }
+            LanaEvent::Credit(CoreCreditEvent::FacilityCancelled { .. }) => {
+                self.pending_facilities -= 1;
                 true
             }
-            LanaEvent::Credit(CoreCreditEvent::DisbursalSettled { amount, .. }) => {
+            LanaEvent::Credit(CoreCreditEvent::DisbursalSettled {
+                credit_facility_id,
+                amount,
+                ..
+            }) => {
                 self.total_disbursed += *amount;
+                if let Some(facility) = self.past_due_facilities.get_mut(credit_facility_id) {
+                    facility.outstanding += *amount;
+                }
                 true
             }
             LanaEvent::Credit(CoreCreditEvent::FacilityRepaymentRecorded {
+                credit_facility_id,
                 obligation_type: ObligationType::Disbursal,
                 amount,
                 ..
             }) => {
                 self.total_disbursed -= *amount;
+                if let Some(facility) = self.past_due_facilities.get_mut(credit_facility_id) {
+                    facility.outstanding = saturating_sub(facility.outstanding, *amount);
+                }
+                true
+            }
+            LanaEvent::Credit(CoreCreditEvent::FacilityPastDueUpdated {
+                id,
+                past_due_since,
+                principal_outstanding,
+                ..
+            }) => {
+                match past_due_since {
+                    Some(past_due_since) => {
+                        self.past_due_facilities.insert(
+                            *id,
+                            PastDueFacility {
+                                past_due_since: *past_due_since,
+                                outstanding: *principal_outstanding,
+                            },
+                        );
+                    }
+                    None => {
+                        self.past_due_facilities.remove(id);
+                    }
+                }
                 true
             }
             LanaEvent::Credit(CoreCreditEvent::FacilityCollateralUpdated {
@@ -59,6 +142,37 @@ impl DashboardValues {
                 true
             }
             _ => false,
+        };
+
+        if updated {
+            self.refresh_portfolio_aging(recorded_at.date_naive());
+        }
+        updated
+    }
+
+    /// Days past due keep growing between events, so the aging tiles are
+    /// recomputed against the date the dashboard is read.
+    pub(crate) fn refresh_portfolio_aging(&mut self, as_of: NaiveDate) {
+        let mut tiles = PortfolioAgingTiles::default();
+        let mut past_due = AgingTile::default();
+        for facility in self.past_due_facilities.values() {
+            let days_past_due = (as_of - facility.past_due_since).num_days();
+            let bucket = AgingBucket::from_days_past_due(u32::try_from(days_past_due).unwrap_or(0));
+            if bucket == AgingBucket::Current {
+                continue;
+            }
+            tiles.tile_mut(bucket).add(facility.outstanding);
+            past_due.add(facility.outstanding);
         }
+
+        tiles.current = AgingTile {
+            facilities: self.active_facilities.saturating_sub(past_due.facilities),
+            outstanding: saturating_sub(self.total_disbursed, past_due.outstanding),
+        };
+        self.portfolio_aging = tiles;
     }
 }
+
+fn saturating_sub(amount: UsdCents, other: UsdCents) -> UsdCents {
+    UsdCents::from(amount.into_inner().saturating_sub(other.into_inner()))
+}
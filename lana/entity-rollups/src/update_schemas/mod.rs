@@ -6,13 +6,17 @@ use colored::*;
 use core_access::event_schema::{PermissionSetEvent, RoleEvent, UserEvent};
 use core_accounting::event_schema::{ChartEvent, ManualTransactionEvent};
 use core_credit::event_schema::{
-    CollateralEvent, CreditFacilityEvent, DisbursalEvent, InterestAccrualCycleEvent,
-    LiquidationProcessEvent, ObligationEvent, PaymentAllocationEvent, PaymentEvent,
-    TermsTemplateEvent,
+    CollateralEvent, CreditFacilityEvent, CreditFacilityProposalEvent, DisbursalEvent,
+    InterestAccrualCycleEvent, LiquidationProcessEvent, ObligationEvent, ParticipationEvent,
+    PaymentAllocationEvent, PaymentEvent, TermsTemplateEvent,
 };
 use core_custody::event_schema::CustodianEvent;
 use core_customer::event_schema::CustomerEvent;
-use core_deposit::event_schema::{DepositAccountEvent, DepositEvent, WithdrawalEvent};
+use core_deposit::event_schema::{
+    BankStatementLineEvent, BeneficiaryEvent, DepositAccountEvent, DepositAccountStatementEvent,
+    DepositEvent, DepositProductEvent, HoldEvent, PaymentBatchEvent, TimeDepositEvent,
+    TransferEvent, WithdrawalEvent,
+};
 use document_storage::event_schema::DocumentEvent;
 use governance::event_schema::{ApprovalProcessEvent, CommitteeEvent, PolicyEvent};
 use schemars::schema_for;
@@ -160,7 +164,7 @@ pub fn update_schemas(
                     remove_events: vec![],
                 },
             ],
-            toggle_events: vec!["Concluded"],
+            toggle_events: vec!["Concluded", "Cancelled"],
             generate_schema: || serde_json::to_value(schema_for!(ApprovalProcessEvent)).unwrap(),
             ..Default::default()
         },
@@ -195,6 +199,12 @@ pub fn update_schemas(
             generate_schema: || serde_json::to_value(schema_for!(DepositAccountEvent)).unwrap(),
             ..Default::default()
         },
+        SchemaInfo {
+            name: "DepositProductEvent",
+            filename: "deposit_product_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(DepositProductEvent)).unwrap(),
+            ..Default::default()
+        },
         SchemaInfo {
             name: "DepositEvent",
             filename: "deposit_event_schema.json",
@@ -221,10 +231,79 @@ pub fn update_schemas(
                 ],
                 remove_events: vec![],
             }],
-            toggle_events: vec!["ApprovalProcessConcluded", "Confirmed", "Cancelled"],
+            toggle_events: vec![
+                "ApprovalProcessConcluded",
+                "Confirmed",
+                "Cancelled",
+                "IncludedInPaymentBatch",
+            ],
             generate_schema: || serde_json::to_value(schema_for!(WithdrawalEvent)).unwrap(),
             ..Default::default()
         },
+        SchemaInfo {
+            name: "TimeDepositEvent",
+            filename: "time_deposit_event_schema.json",
+            toggle_events: vec!["Matured", "Broken"],
+            generate_schema: || serde_json::to_value(schema_for!(TimeDepositEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "HoldEvent",
+            filename: "hold_event_schema.json",
+            collections: vec![CollectionRollup {
+                column_name: "ledger_tx_ids",
+                values: "ledger_tx_id",
+                add_events: vec!["Initialized".to_string(), "Released".to_string()],
+                remove_events: vec![],
+            }],
+            generate_schema: || serde_json::to_value(schema_for!(HoldEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "DepositAccountStatementEvent",
+            filename: "deposit_account_statement_event_schema.json",
+            generate_schema: || {
+                serde_json::to_value(schema_for!(DepositAccountStatementEvent)).unwrap()
+            },
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "TransferEvent",
+            filename: "transfer_event_schema.json",
+            collections: vec![CollectionRollup {
+                column_name: "ledger_tx_ids",
+                values: "ledger_tx_id",
+                add_events: vec![
+                    "Initialized".to_string(),
+                    "Confirmed".to_string(),
+                    "Cancelled".to_string(),
+                ],
+                remove_events: vec![],
+            }],
+            toggle_events: vec!["ApprovalProcessConcluded", "Confirmed", "Cancelled"],
+            generate_schema: || serde_json::to_value(schema_for!(TransferEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "BeneficiaryEvent",
+            filename: "beneficiary_event_schema.json",
+            toggle_events: vec!["ApprovalProcessConcluded", "Removed"],
+            generate_schema: || serde_json::to_value(schema_for!(BeneficiaryEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "BankStatementLineEvent",
+            filename: "bank_statement_line_event_schema.json",
+            toggle_events: vec!["DepositRecorded"],
+            generate_schema: || serde_json::to_value(schema_for!(BankStatementLineEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "PaymentBatchEvent",
+            filename: "payment_batch_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(PaymentBatchEvent)).unwrap(),
+            ..Default::default()
+        },
         SchemaInfo {
             name: "CustodianEvent",
             filename: "custodian_event_schema.json",
@@ -270,14 +349,46 @@ pub fn update_schemas(
                     remove_events: vec![],
                 },
             ],
-            toggle_events: vec!["ApprovalProcessConcluded", "Activated", "Completed"],
+            toggle_events: vec![
+                "ApprovalProcessConcluded",
+                "Activated",
+                "Completed",
+                "Cancelled",
+            ],
             generate_schema: || serde_json::to_value(schema_for!(CreditFacilityEvent)).unwrap(),
             ..Default::default()
         },
+        SchemaInfo {
+            name: "CreditFacilityProposalEvent",
+            filename: "credit_facility_proposal_event_schema.json",
+            toggle_events: vec!["Converted", "Rejected"],
+            generate_schema: || {
+                serde_json::to_value(schema_for!(CreditFacilityProposalEvent)).unwrap()
+            },
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "ParticipationEvent",
+            filename: "participation_event_schema.json",
+            collections: vec![CollectionRollup {
+                column_name: "ledger_tx_ids",
+                values: "ledger_tx_id",
+                add_events: vec!["ShareAllocated".to_string()],
+                remove_events: vec![],
+            }],
+            generate_schema: || serde_json::to_value(schema_for!(ParticipationEvent)).unwrap(),
+            ..Default::default()
+        },
         SchemaInfo {
             name: "DisbursalEvent",
             filename: "disbursal_event_schema.json",
-            toggle_events: vec!["ApprovalProcessConcluded", "Settled", "Cancelled"],
+            toggle_events: vec![
+                "ApprovalProcessConcluded",
+                "SettlementInitiated",
+                "SettlementFailed",
+                "Settled",
+                "Cancelled",
+            ],
             generate_schema: || serde_json::to_value(schema_for!(DisbursalEvent)).unwrap(),
             ..Default::default()
         },
@@ -311,6 +422,7 @@ pub fn update_schemas(
                         "DueRecorded".to_string(),
                         "OverdueRecorded".to_string(),
                         "DefaultedRecorded".to_string(),
+                        "Reinstated".to_string(),
                         "PaymentAllocated".to_string(),
                     ],
                     remove_events: vec![],
@@ -332,6 +444,7 @@ pub fn update_schemas(
                 "DueRecorded",
                 "OverdueRecorded",
                 "DefaultedRecorded",
+                "Reinstated",
                 "Completed",
             ],
             generate_schema: || serde_json::to_value(schema_for!(ObligationEvent)).unwrap(),
@@ -4,7 +4,10 @@
 use serde::{Deserialize, Serialize};
 
 pub use core_access::CoreAccessEvent;
-pub use core_credit::{CollateralAction, CoreCreditEvent, ObligationStatus, ObligationType};
+pub use core_credit::{
+    AgingBucket, CollateralAction, CoreCreditEvent, CreditFacilityId, ObligationStatus,
+    ObligationType,
+};
 pub use core_custody::CoreCustodyEvent;
 pub use core_customer::CoreCustomerEvent;
 pub use core_deposit::CoreDepositEvent;
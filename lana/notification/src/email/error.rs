@@ -22,4 +22,6 @@ pub enum EmailError {
     Obligation(#[from] core_credit::ObligationError),
     #[error("EmailError - CreditFacility: {0}")]
     CreditFacility(#[from] core_credit::CreditFacilityError),
+    #[error("EmailError - CreditFacilityProposal: {0}")]
+    CreditFacilityProposal(#[from] core_credit::CreditFacilityProposalError),
 }
@@ -91,15 +91,50 @@ impl EmailEventListenerRunner {
         db: &mut es_entity::DbOp<'_>,
         event: &LanaEvent,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        if let LanaEvent::Credit(CoreCreditEvent::ObligationOverdue {
-            id,
-            credit_facility_id,
-            amount,
-        }) = event
-        {
-            self.email_notification
-                .send_obligation_overdue_notification(db, id, credit_facility_id, amount)
-                .await?;
+        match event {
+            LanaEvent::Credit(CoreCreditEvent::ObligationOverdue {
+                id,
+                credit_facility_id,
+                amount,
+            }) => {
+                self.email_notification
+                    .send_obligation_overdue_notification(db, id, credit_facility_id, amount)
+                    .await?;
+            }
+            LanaEvent::Credit(CoreCreditEvent::FacilityProposalSubmitted {
+                id,
+                customer_id,
+                amount,
+                ..
+            }) => {
+                self.email_notification
+                    .send_facility_proposal_submitted_notification(db, id, customer_id, amount)
+                    .await?;
+            }
+            LanaEvent::Credit(CoreCreditEvent::FacilityProposalConverted {
+                customer_id,
+                credit_facility_id,
+                ..
+            }) => {
+                self.email_notification
+                    .send_facility_proposal_converted_notification(
+                        db,
+                        customer_id,
+                        credit_facility_id,
+                    )
+                    .await?;
+            }
+            LanaEvent::Credit(CoreCreditEvent::FacilityProposalRejected {
+                id,
+                customer_id,
+                reason,
+                ..
+            }) => {
+                self.email_notification
+                    .send_facility_proposal_rejected_notification(db, id, customer_id, reason)
+                    .await?;
+            }
+            _ => {}
         }
         Ok(())
     }
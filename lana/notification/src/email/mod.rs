@@ -7,14 +7,20 @@ pub mod templates;
 
 use ::job::{JobId, Jobs};
 use core_access::user::Users;
-use core_credit::{CoreCredit, CreditFacilityId, ObligationId, ObligationType};
+use core_credit::{
+    CoreCredit, CreditFacilityId, CreditFacilityProposalId, CustomerId, ObligationId,
+    ObligationType,
+};
 use core_customer::Customers;
 use job::{EmailSenderConfig, EmailSenderInit};
 use lana_events::LanaEvent;
 
 use crate::{Authorization, LanaAudit};
 use smtp::SmtpClient;
-use templates::{EmailTemplate, EmailType, OverduePaymentEmailData};
+use templates::{
+    EmailTemplate, EmailType, FacilityProposalConvertedEmailData,
+    FacilityProposalRejectedEmailData, FacilityProposalSubmittedEmailData, OverduePaymentEmailData,
+};
 
 pub use config::EmailConfig;
 pub use error::EmailError;
@@ -82,6 +88,94 @@ impl EmailNotification {
             customer_email: customer.email,
         };
 
+        self.send_to_all_users(db, EmailType::OverduePayment(email_data))
+            .await
+    }
+
+    pub async fn send_facility_proposal_submitted_notification(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        proposal_id: &CreditFacilityProposalId,
+        customer_id: &CustomerId,
+        amount: &core_money::UsdCents,
+    ) -> Result<(), EmailError> {
+        let customer = self
+            .customers
+            .find_by_id_without_audit(*customer_id)
+            .await?;
+        let email_data = FacilityProposalSubmittedEmailData {
+            proposal_id: proposal_id.to_string(),
+            amount: *amount,
+            customer_email: customer.email,
+        };
+        self.send_to_all_users(db, EmailType::FacilityProposalSubmitted(email_data))
+            .await
+    }
+
+    pub async fn send_facility_proposal_converted_notification(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        customer_id: &CustomerId,
+        credit_facility_id: &CreditFacilityId,
+    ) -> Result<(), EmailError> {
+        let customer = self
+            .customers
+            .find_by_id_without_audit(*customer_id)
+            .await?;
+        let credit_facility = self
+            .credit
+            .facilities()
+            .find_by_id_without_audit(*credit_facility_id)
+            .await?;
+        let email_data = FacilityProposalConvertedEmailData {
+            facility_id: credit_facility_id.to_string(),
+            amount: credit_facility.amount,
+        };
+        let email_config = EmailSenderConfig {
+            recipient: customer.email,
+            email_type: EmailType::FacilityProposalConverted(email_data),
+        };
+        self.jobs
+            .create_and_spawn_in_op(db, JobId::new(), email_config)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn send_facility_proposal_rejected_notification(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        proposal_id: &CreditFacilityProposalId,
+        customer_id: &CustomerId,
+        reason: &str,
+    ) -> Result<(), EmailError> {
+        let customer = self
+            .customers
+            .find_by_id_without_audit(*customer_id)
+            .await?;
+        let proposal = self
+            .credit
+            .proposals()
+            .find_by_id_without_audit(*proposal_id)
+            .await?;
+        let email_data = FacilityProposalRejectedEmailData {
+            amount: proposal.amount,
+            reason: reason.to_owned(),
+        };
+        let email_config = EmailSenderConfig {
+            recipient: customer.email,
+            email_type: EmailType::FacilityProposalRejected(email_data),
+        };
+        self.jobs
+            .create_and_spawn_in_op(db, JobId::new(), email_config)
+            .await?;
+        Ok(())
+    }
+
+    async fn send_to_all_users(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        email_type: EmailType,
+    ) -> Result<(), EmailError> {
         let mut query = es_entity::PaginatedQueryArgs::default();
         loop {
             let first = query.first;
@@ -96,7 +190,7 @@ impl EmailNotification {
             for user in entities {
                 let email_config = EmailSenderConfig {
                     recipient: user.email,
-                    email_type: EmailType::OverduePayment(email_data.clone()),
+                    email_type: email_type.clone(),
                 };
                 self.jobs
                     .create_and_spawn_in_op(db, JobId::new(), email_config)
@@ -6,9 +6,12 @@ use serde_json::json;
 
 use crate::email::error::EmailError;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub enum EmailType {
     OverduePayment(OverduePaymentEmailData),
+    FacilityProposalSubmitted(FacilityProposalSubmittedEmailData),
+    FacilityProposalConverted(FacilityProposalConvertedEmailData),
+    FacilityProposalRejected(FacilityProposalRejectedEmailData),
     General { subject: String, body: String },
 }
 
@@ -25,6 +28,18 @@ impl EmailTemplate {
         handlebars.register_template_string("styles", include_str!("partials/styles.hbs"))?;
         handlebars.register_template_string("general", include_str!("views/general.hbs"))?;
         handlebars.register_template_string("overdue", include_str!("views/overdue.hbs"))?;
+        handlebars.register_template_string(
+            "proposal_submitted",
+            include_str!("views/proposal_submitted.hbs"),
+        )?;
+        handlebars.register_template_string(
+            "proposal_converted",
+            include_str!("views/proposal_converted.hbs"),
+        )?;
+        handlebars.register_template_string(
+            "proposal_rejected",
+            include_str!("views/proposal_rejected.hbs"),
+        )?;
         Ok(Self {
             handlebars,
             admin_panel_url,
@@ -34,6 +49,15 @@ impl EmailTemplate {
     pub fn render_email(&self, email_type: &EmailType) -> Result<(String, String), EmailError> {
         match email_type {
             EmailType::OverduePayment(data) => self.render_overdue_payment_email(data),
+            EmailType::FacilityProposalSubmitted(data) => {
+                self.render_facility_proposal_submitted_email(data)
+            }
+            EmailType::FacilityProposalConverted(data) => {
+                self.render_facility_proposal_converted_email(data)
+            }
+            EmailType::FacilityProposalRejected(data) => {
+                self.render_facility_proposal_rejected_email(data)
+            }
             EmailType::General { subject, body } => self.generic_email_template(subject, body),
         }
     }
@@ -76,6 +100,56 @@ impl EmailTemplate {
         let html_body = self.handlebars.render("overdue", &data)?;
         Ok((subject, html_body))
     }
+
+    fn render_facility_proposal_submitted_email(
+        &self,
+        data: &FacilityProposalSubmittedEmailData,
+    ) -> Result<(String, String), EmailError> {
+        let subject = format!(
+            "Lana Bank: New Credit Facility Proposal - {}",
+            data.amount.formatted_usd()
+        );
+        let proposal_url = format!(
+            "{}/credit-facility-proposals/{}",
+            self.admin_panel_url, data.proposal_id
+        );
+        let data = json!({
+            "subject": &subject,
+            "amount": data.amount.formatted_usd(),
+            "customer_email": &data.customer_email,
+            "proposal_url": &proposal_url,
+        });
+        let html_body = self.handlebars.render("proposal_submitted", &data)?;
+        Ok((subject, html_body))
+    }
+
+    fn render_facility_proposal_converted_email(
+        &self,
+        data: &FacilityProposalConvertedEmailData,
+    ) -> Result<(String, String), EmailError> {
+        let subject = "Lana Bank: Your Credit Facility Proposal Was Approved".to_string();
+        let data = json!({
+            "subject": &subject,
+            "amount": data.amount.formatted_usd(),
+            "facility_id": &data.facility_id,
+        });
+        let html_body = self.handlebars.render("proposal_converted", &data)?;
+        Ok((subject, html_body))
+    }
+
+    fn render_facility_proposal_rejected_email(
+        &self,
+        data: &FacilityProposalRejectedEmailData,
+    ) -> Result<(String, String), EmailError> {
+        let subject = "Lana Bank: Your Credit Facility Proposal Was Declined".to_string();
+        let data = json!({
+            "subject": &subject,
+            "amount": data.amount.formatted_usd(),
+            "reason": &data.reason,
+        });
+        let html_body = self.handlebars.render("proposal_rejected", &data)?;
+        Ok((subject, html_body))
+    }
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -87,3 +161,22 @@ pub struct OverduePaymentEmailData {
     pub due_date: DateTime<Utc>,
     pub customer_email: String,
 }
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FacilityProposalSubmittedEmailData {
+    pub proposal_id: String,
+    pub amount: UsdCents,
+    pub customer_email: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FacilityProposalConvertedEmailData {
+    pub facility_id: String,
+    pub amount: UsdCents,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FacilityProposalRejectedEmailData {
+    pub amount: UsdCents,
+    pub reason: String,
+}
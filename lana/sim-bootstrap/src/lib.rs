@@ -98,7 +98,12 @@ async fn create_and_process_facility(
                 if cf.id == *id =>
             {
                 app.credit()
-                    .initiate_disbursal(&sub, cf.id, UsdCents::try_from_usd(dec!(1_000_000))?)
+                    .initiate_disbursal(
+                        &sub,
+                        cf.id,
+                        UsdCents::try_from_usd(dec!(1_000_000))?,
+                        DisbursalDestination::DepositAccount,
+                    )
                     .await?;
             }
             Some(LanaEvent::Credit(CoreCreditEvent::ObligationDue {
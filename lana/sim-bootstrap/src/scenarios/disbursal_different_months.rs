@@ -48,7 +48,12 @@ pub async fn disbursal_different_months_scenario(
                 if cf.id == *id =>
             {
                 app.credit()
-                    .initiate_disbursal(&sub, cf.id, UsdCents::try_from_usd(dec!(1_000_000))?)
+                    .initiate_disbursal(
+                        &sub,
+                        cf.id,
+                        UsdCents::try_from_usd(dec!(1_000_000))?,
+                        DisbursalDestination::DepositAccount,
+                    )
                     .await?;
 
                 break;
@@ -113,14 +118,24 @@ async fn do_disbursal_in_different_months(
 
     // disbursal in month 2
     app.credit()
-        .initiate_disbursal(&sub, id, UsdCents::try_from_usd(dec!(2_000_000))?)
+        .initiate_disbursal(
+            &sub,
+            id,
+            UsdCents::try_from_usd(dec!(2_000_000))?,
+            DisbursalDestination::DepositAccount,
+        )
         .await?;
 
     sim_time::sleep(one_month * 2).await;
 
     // disbursal in month 3
     app.credit()
-        .initiate_disbursal(&sub, id, UsdCents::try_from_usd(dec!(5_000_000))?)
+        .initiate_disbursal(
+            &sub,
+            id,
+            UsdCents::try_from_usd(dec!(5_000_000))?,
+            DisbursalDestination::DepositAccount,
+        )
         .await?;
 
     Ok(())
@@ -45,7 +45,12 @@ pub async fn interest_late_scenario(sub: Subject, app: &LanaApp) -> anyhow::Resu
                 if cf.id == *id =>
             {
                 app.credit()
-                    .initiate_disbursal(&sub, cf.id, UsdCents::try_from_usd(dec!(1_000_000))?)
+                    .initiate_disbursal(
+                        &sub,
+                        cf.id,
+                        UsdCents::try_from_usd(dec!(1_000_000))?,
+                        DisbursalDestination::DepositAccount,
+                    )
                     .await?;
 
                 break;
@@ -51,7 +51,12 @@ pub async fn interest_under_payment_scenario(sub: Subject, app: &LanaApp) -> any
                 if cf.id == *id =>
             {
                 app.credit()
-                    .initiate_disbursal(&sub, cf.id, UsdCents::try_from_usd(dec!(1_000_000))?)
+                    .initiate_disbursal(
+                        &sub,
+                        cf.id,
+                        UsdCents::try_from_usd(dec!(1_000_000))?,
+                        DisbursalDestination::DepositAccount,
+                    )
                     .await?;
 
                 break;